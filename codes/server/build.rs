@@ -0,0 +1,62 @@
+//! 빌드 정보 주입 스크립트
+//!
+//! `/health/info`에서 노출할 git commit hash와 빌드 시각을 컴파일 타임 환경변수로 주입합니다.
+//! CI 등에서 `GIT_COMMIT_HASH`, `BUILD_TIMESTAMP`를 직접 지정하면 그 값을 우선 사용합니다.
+
+use std::path::Path;
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+fn main() {
+    println!("cargo:rerun-if-env-changed=GIT_COMMIT_HASH");
+    println!("cargo:rerun-if-env-changed=BUILD_TIMESTAMP");
+    watch_git_head(Path::new("../../.git"));
+
+    let git_commit_hash = std::env::var("GIT_COMMIT_HASH")
+        .ok()
+        .filter(|v| !v.trim().is_empty())
+        .or_else(|| {
+            Command::new("git")
+                .args(["rev-parse", "--short", "HEAD"])
+                .output()
+                .ok()
+                .filter(|output| output.status.success())
+                .and_then(|output| String::from_utf8(output.stdout).ok())
+                .map(|hash| hash.trim().to_string())
+        })
+        .unwrap_or_else(|| "unknown".to_string());
+
+    // 빌드 시각은 UNIX epoch(초)로 주입하고, 런타임에서 RFC3339로 변환합니다.
+    let build_timestamp = std::env::var("BUILD_TIMESTAMP")
+        .ok()
+        .filter(|v| v.trim().parse::<i64>().is_ok())
+        .unwrap_or_else(|| {
+            SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs().to_string())
+                .unwrap_or_else(|_| "0".to_string())
+        });
+
+    println!("cargo:rustc-env=GIT_COMMIT_HASH={}", git_commit_hash);
+    println!("cargo:rustc-env=BUILD_TIMESTAMP={}", build_timestamp);
+}
+
+/// 커밋이 바뀌면 빌드 스크립트가 다시 실행되도록 git 파일 감시
+///
+/// HEAD는 브랜치를 바꿀 때만 바뀌므로, HEAD가 가리키는 브랜치 ref 파일과 `packed-refs`도 함께 감시합니다.
+/// 없는 파일을 감시하면 매 빌드마다 다시 실행되므로 존재하는 파일만 등록합니다.
+fn watch_git_head(git_dir: &Path) {
+    let head = git_dir.join("HEAD");
+    let mut watched = vec![git_dir.join("packed-refs")];
+    if let Some(ref_name) = std::fs::read_to_string(&head)
+        .ok()
+        .and_then(|content| content.strip_prefix("ref: ").map(|r| r.trim().to_string()))
+    {
+        watched.push(git_dir.join(ref_name));
+    }
+    watched.insert(0, head);
+
+    for path in watched.iter().filter(|path| path.exists()) {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+}
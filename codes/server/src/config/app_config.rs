@@ -18,6 +18,80 @@ pub struct AppConfig {
 
     // AI Service
     pub openai_api_key: String,
+
+    // Runtime
+    /// 실행 환경 (dev/prod)
+    pub app_env: String,
+    /// `/health/info`에서 commit hash, 빌드 시각 등 상세 정보 노출 여부
+    pub health_info_detail: bool,
+
+    // Background Jobs
+    /// 임시저장 정리 대상이 되는 회고 시작 후 경과 일수
+    pub draft_cleanup_cutoff_days: i64,
+    /// Owner 경고 후 실제 정리까지의 유예 일수
+    pub draft_cleanup_grace_days: i64,
+    /// 임시저장 정리 잡 실행 주기 (초, 0이면 비활성화)
+    pub draft_cleanup_interval_secs: u64,
+    /// 실패한 회고 분석 재시도 잡 실행 주기 (초, 0이면 비활성화)
+    pub analysis_retry_interval_secs: u64,
+    /// 회고 분석 최대 재시도 횟수 (초과 시 FAILED 확정)
+    pub analysis_retry_max_attempts: i32,
+    /// 회고 분석 재시도 기본 대기 시간 (초, 재시도마다 2배씩 증가)
+    pub analysis_retry_base_delay_secs: i64,
+
+    // Admin
+    /// 관리 API 접근이 허용된 회원 ID 목록 (비어 있으면 관리 API 사용 불가)
+    pub admin_member_ids: Vec<i64>,
+
+    // Notification
+    /// 알림 딥링크 앞에 붙는 base path (예: "/app"). 비어 있으면 루트 기준 상대 경로
+    pub deep_link_base_path: String,
+
+    // Retrospect
+    /// 제출 답변 유사도 경고 임계값 (0.0~1.0, 이 값 이상이면 경고)
+    pub similarity_warning_threshold: f64,
+    /// 타이핑 이벤트가 없을 때 자동으로 입력 중지 처리하기까지의 시간 (초)
+    pub typing_idle_timeout_secs: u64,
+    /// 회고 분석 시 팀원별로 생성하는 개인 미션 수 (1~10)
+    pub missions_per_user: usize,
+
+    // Auth
+    /// 이메일별 잠금 전까지 허용하는 로그인 시도 횟수
+    pub login_max_failed_attempts: u32,
+    /// IP별 잠금 전까지 허용하는 로그인 시도 횟수
+    pub login_max_attempts_per_ip: u32,
+    /// 로그인 잠금 유지 시간 (초)
+    pub login_lockout_secs: u64,
+
+    // Storage
+    /// S3 호환 스토리지 엔드포인트 (예: "https://s3.ap-northeast-2.amazonaws.com"). 비어 있으면 업로드 비활성화
+    pub storage_endpoint: String,
+    /// 업로드 대상 버킷
+    pub storage_bucket: String,
+    /// 서명에 사용하는 리전
+    pub storage_region: String,
+    pub storage_access_key_id: String,
+    pub storage_secret_access_key: String,
+    /// 업로드 서명 URL 유효 시간 (초, 1~3600)
+    pub upload_url_expires_secs: u64,
+    /// 업로드 허용 최대 파일 크기 (바이트)
+    pub upload_max_size_bytes: u64,
+
+    // Metrics
+    /// Prometheus 메트릭 수집 및 `/metrics` 노출 여부
+    pub metrics_enabled: bool,
+    /// `/metrics`를 내부망(loopback, 사설 IP) 요청에만 허용할지 여부
+    pub metrics_internal_only: bool,
+
+    // Mail
+    /// SMTP 서버 호스트 (비어 있으면 메일 발송 비활성화)
+    pub smtp_host: String,
+    /// SMTP 포트 (STARTTLS)
+    pub smtp_port: u16,
+    pub smtp_username: String,
+    pub smtp_password: String,
+    /// 발신자 주소 (예: "모아로그 <no-reply@moalog.me>")
+    pub mail_from: String,
 }
 
 impl AppConfig {
@@ -79,6 +153,147 @@ impl AppConfig {
             );
             "test-key".to_string()
         });
+
+        let app_env = env::var("APP_ENV")
+            .map(|v| v.trim().to_lowercase())
+            .ok()
+            .filter(|v| !v.is_empty())
+            .unwrap_or_else(|| "dev".to_string());
+
+        // 프로덕션에서는 기본적으로 상세 빌드 정보를 감춥니다.
+        let health_info_detail = match env::var("HEALTH_INFO_DETAIL") {
+            Ok(v) => v
+                .parse()
+                .map_err(|_| ConfigError::InvalidHealthInfoDetail)?,
+            Err(_) => app_env != "prod",
+        };
+
+        let draft_cleanup_cutoff_days = env::var("DRAFT_CLEANUP_CUTOFF_DAYS")
+            .unwrap_or_else(|_| "30".to_string())
+            .parse()
+            .map_err(|_| ConfigError::InvalidDraftCleanupConfig)?;
+
+        let draft_cleanup_grace_days = env::var("DRAFT_CLEANUP_GRACE_DAYS")
+            .unwrap_or_else(|_| "7".to_string())
+            .parse()
+            .map_err(|_| ConfigError::InvalidDraftCleanupConfig)?;
+
+        let draft_cleanup_interval_secs = env::var("DRAFT_CLEANUP_INTERVAL_SECS")
+            .unwrap_or_else(|_| "86400".to_string()) // Default 1 day
+            .parse()
+            .map_err(|_| ConfigError::InvalidDraftCleanupConfig)?;
+
+        let analysis_retry_interval_secs = env::var("ANALYSIS_RETRY_INTERVAL_SECS")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse()
+            .map_err(|_| ConfigError::InvalidAnalysisRetryConfig)?;
+
+        let analysis_retry_max_attempts = env::var("ANALYSIS_RETRY_MAX_ATTEMPTS")
+            .unwrap_or_else(|_| "3".to_string())
+            .parse::<i32>()
+            .ok()
+            .filter(|v| *v > 0)
+            .ok_or(ConfigError::InvalidAnalysisRetryConfig)?;
+
+        let analysis_retry_base_delay_secs = env::var("ANALYSIS_RETRY_BASE_DELAY_SECS")
+            .unwrap_or_else(|_| "60".to_string())
+            .parse::<i64>()
+            .ok()
+            .filter(|v| *v > 0)
+            .ok_or(ConfigError::InvalidAnalysisRetryConfig)?;
+
+        let admin_member_ids =
+            parse_admin_member_ids(&env::var("ADMIN_MEMBER_IDS").unwrap_or_default())?;
+
+        let deep_link_base_path =
+            normalize_base_path(&env::var("DEEP_LINK_BASE_PATH").unwrap_or_default());
+
+        let similarity_warning_threshold = env::var("SIMILARITY_WARNING_THRESHOLD")
+            .unwrap_or_else(|_| "0.8".to_string())
+            .parse::<f64>()
+            .ok()
+            .filter(|v| (0.0..=1.0).contains(v))
+            .ok_or(ConfigError::InvalidSimilarityThreshold)?;
+
+        let typing_idle_timeout_secs = env::var("TYPING_IDLE_TIMEOUT_SECS")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse::<u64>()
+            .ok()
+            .filter(|v| *v > 0)
+            .ok_or(ConfigError::InvalidTypingIdleTimeout)?;
+
+        let missions_per_user = env::var("MISSIONS_PER_USER")
+            .unwrap_or_else(|_| "3".to_string())
+            .parse::<usize>()
+            .ok()
+            .filter(|v| (1..=10).contains(v))
+            .ok_or(ConfigError::InvalidMissionsPerUser)?;
+
+        let login_max_failed_attempts = env::var("LOGIN_MAX_FAILED_ATTEMPTS")
+            .unwrap_or_else(|_| "5".to_string())
+            .parse::<u32>()
+            .ok()
+            .filter(|v| *v > 0)
+            .ok_or(ConfigError::InvalidLoginLockoutConfig)?;
+
+        let login_max_attempts_per_ip = env::var("LOGIN_MAX_ATTEMPTS_PER_IP")
+            .unwrap_or_else(|_| "20".to_string())
+            .parse::<u32>()
+            .ok()
+            .filter(|v| *v > 0)
+            .ok_or(ConfigError::InvalidLoginLockoutConfig)?;
+
+        let login_lockout_secs = env::var("LOGIN_LOCKOUT_SECS")
+            .unwrap_or_else(|_| "900".to_string()) // Default 15 minutes
+            .parse()
+            .map_err(|_| ConfigError::InvalidLoginLockoutConfig)?;
+
+        let storage_endpoint = env::var("STORAGE_ENDPOINT")
+            .unwrap_or_default()
+            .trim()
+            .trim_end_matches('/')
+            .to_string();
+        let storage_bucket = env::var("STORAGE_BUCKET").unwrap_or_default();
+        let storage_region =
+            env::var("STORAGE_REGION").unwrap_or_else(|_| "ap-northeast-2".to_string());
+        let storage_access_key_id = env::var("STORAGE_ACCESS_KEY_ID").unwrap_or_default();
+        let storage_secret_access_key = env::var("STORAGE_SECRET_ACCESS_KEY").unwrap_or_default();
+
+        let upload_url_expires_secs = env::var("UPLOAD_URL_EXPIRES_SECS")
+            .unwrap_or_else(|_| "300".to_string()) // Default 5 minutes
+            .parse::<u64>()
+            .ok()
+            .filter(|v| (1..=3600).contains(v))
+            .ok_or(ConfigError::InvalidUploadConfig)?;
+
+        let upload_max_size_bytes = env::var("UPLOAD_MAX_SIZE_BYTES")
+            .unwrap_or_else(|_| "10485760".to_string()) // Default 10MB
+            .parse::<u64>()
+            .ok()
+            .filter(|v| *v > 0)
+            .ok_or(ConfigError::InvalidUploadConfig)?;
+
+        let metrics_enabled = match env::var("METRICS_ENABLED") {
+            Ok(v) => v.parse().map_err(|_| ConfigError::InvalidMetricsConfig)?,
+            Err(_) => true,
+        };
+
+        // 프로덕션에서는 기본적으로 내부망에서만 메트릭을 수집할 수 있습니다.
+        let metrics_internal_only = match env::var("METRICS_INTERNAL_ONLY") {
+            Ok(v) => v.parse().map_err(|_| ConfigError::InvalidMetricsConfig)?,
+            Err(_) => app_env == "prod",
+        };
+
+        let smtp_host = env::var("SMTP_HOST").unwrap_or_default().trim().to_string();
+        let smtp_port = env::var("SMTP_PORT")
+            .unwrap_or_else(|_| "587".to_string())
+            .parse::<u16>()
+            .map_err(|_| ConfigError::InvalidMailConfig)?;
+        let smtp_username = env::var("SMTP_USERNAME").unwrap_or_default();
+        let smtp_password = env::var("SMTP_PASSWORD").unwrap_or_default();
+        let mail_from =
+            env::var("MAIL_FROM").unwrap_or_else(|_| "모아로그 <no-reply@moalog.me>".to_string());
+
         Ok(Self {
             server_port,
             jwt_secret,
@@ -90,8 +305,77 @@ impl AppConfig {
             kakao_client_id,
             kakao_client_secret,
             openai_api_key,
+            app_env,
+            health_info_detail,
+            draft_cleanup_cutoff_days,
+            draft_cleanup_grace_days,
+            draft_cleanup_interval_secs,
+            analysis_retry_interval_secs,
+            analysis_retry_max_attempts,
+            analysis_retry_base_delay_secs,
+            admin_member_ids,
+            deep_link_base_path,
+            similarity_warning_threshold,
+            typing_idle_timeout_secs,
+            missions_per_user,
+            login_max_failed_attempts,
+            login_max_attempts_per_ip,
+            login_lockout_secs,
+            storage_endpoint,
+            storage_bucket,
+            storage_region,
+            storage_access_key_id,
+            storage_secret_access_key,
+            upload_url_expires_secs,
+            upload_max_size_bytes,
+            metrics_enabled,
+            metrics_internal_only,
+            smtp_host,
+            smtp_port,
+            smtp_username,
+            smtp_password,
+            mail_from,
         })
     }
+
+    /// 관리자 여부 확인
+    pub fn is_admin(&self, member_id: i64) -> bool {
+        self.admin_member_ids.contains(&member_id)
+    }
+
+    /// 업로드 스토리지 설정 여부 (엔드포인트, 버킷, 자격 증명이 모두 있어야 함)
+    pub fn is_storage_configured(&self) -> bool {
+        !self.storage_endpoint.is_empty()
+            && !self.storage_bucket.is_empty()
+            && !self.storage_access_key_id.is_empty()
+            && !self.storage_secret_access_key.is_empty()
+    }
+
+    /// 메일 발송 설정 여부 (SMTP 호스트와 자격 증명이 모두 있어야 함)
+    pub fn is_mail_configured(&self) -> bool {
+        !self.smtp_host.is_empty()
+            && !self.smtp_username.is_empty()
+            && !self.smtp_password.is_empty()
+    }
+}
+
+/// 쉼표로 구분된 관리자 회원 ID 목록 파싱 (예: "1,2,3")
+fn parse_admin_member_ids(raw: &str) -> Result<Vec<i64>, ConfigError> {
+    raw.split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse().map_err(|_| ConfigError::InvalidAdminMemberIds))
+        .collect()
+}
+
+/// base path 정규화 (앞에 `/`를 붙이고 끝의 `/`는 제거, 예: "app/" -> "/app")
+fn normalize_base_path(raw: &str) -> String {
+    let trimmed = raw.trim().trim_matches('/');
+    if trimmed.is_empty() {
+        String::new()
+    } else {
+        format!("/{}", trimmed)
+    }
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -106,4 +390,26 @@ pub enum ConfigError {
     MissingGoogleClientSecret,
     #[error("KAKAO_CLIENT_SECRET environment variable is required in production")]
     MissingKakaoClientSecret,
+    #[error("HEALTH_INFO_DETAIL must be true or false")]
+    InvalidHealthInfoDetail,
+    #[error("Invalid draft cleanup configuration")]
+    InvalidDraftCleanupConfig,
+    #[error("Invalid analysis retry configuration")]
+    InvalidAnalysisRetryConfig,
+    #[error("ADMIN_MEMBER_IDS must be a comma-separated list of member ids")]
+    InvalidAdminMemberIds,
+    #[error("SIMILARITY_WARNING_THRESHOLD must be a number between 0.0 and 1.0")]
+    InvalidSimilarityThreshold,
+    #[error("TYPING_IDLE_TIMEOUT_SECS must be a positive number of seconds")]
+    InvalidTypingIdleTimeout,
+    #[error("MISSIONS_PER_USER must be a number between 1 and 10")]
+    InvalidMissionsPerUser,
+    #[error("LOGIN_MAX_FAILED_ATTEMPTS and LOGIN_MAX_ATTEMPTS_PER_IP must be positive numbers and LOGIN_LOCKOUT_SECS a number of seconds")]
+    InvalidLoginLockoutConfig,
+    #[error("UPLOAD_URL_EXPIRES_SECS must be between 1 and 3600 and UPLOAD_MAX_SIZE_BYTES a positive number of bytes")]
+    InvalidUploadConfig,
+    #[error("METRICS_ENABLED and METRICS_INTERNAL_ONLY must be true or false")]
+    InvalidMetricsConfig,
+    #[error("SMTP_PORT must be a valid port number")]
+    InvalidMailConfig,
 }
@@ -1,10 +1,23 @@
 use crate::domain::{
-    member::entity::{assistant_usage, member, member_response, member_retro, member_retro_room},
+    ai::entity::{ai_usage_log, ai_usage_quota},
+    member::entity::{
+        answer_feedback_usage, assistant_usage, member, member_badge, member_response,
+        member_retro, member_retro_room, room_membership_history,
+    },
+    notification::entity::notification,
     retrospect::entity::{
-        response, response_comment, response_like, retro_reference, retro_room, retrospect,
+        analysis_job, check_in_token, custom_method, mention, question_guide_cache, response,
+        response_comment, response_label, response_like, response_view, retro_analysis,
+        retro_reference, retro_room, retrospect, retrospect_pdf_cache, room_subscription,
+        shared_link,
     },
 };
-use sea_orm::{ConnectionTrait, Database, DatabaseConnection, DbErr, Schema, Statement};
+use crate::utils::timezone::{local_to_utc, resolve_timezone};
+use chrono::NaiveDateTime;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, Database, DatabaseConnection, DbErr, EntityTrait, QueryFilter,
+    QuerySelect, Schema, Statement,
+};
 use std::env;
 use tracing::info;
 
@@ -45,6 +58,7 @@ async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
     // 1. Independent Entities
     create_table_if_not_exists(db, &schema, member::Entity).await?;
     create_table_if_not_exists(db, &schema, retro_room::Entity).await?;
+    create_table_if_not_exists(db, &schema, question_guide_cache::Entity).await?;
 
     // 2. Dependent Entities (Level 1)
     create_table_if_not_exists(db, &schema, retrospect::Entity).await?;
@@ -52,11 +66,76 @@ async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
     // 3. Dependent Entities (Level 2)
     create_table_if_not_exists(db, &schema, response::Entity).await?;
     create_table_if_not_exists(db, &schema, retro_reference::Entity).await?;
+    create_table_if_not_exists(db, &schema, shared_link::Entity).await?;
+    create_table_if_not_exists(db, &schema, check_in_token::Entity).await?;
+    create_table_if_not_exists(db, &schema, analysis_job::Entity).await?;
+    create_table_if_not_exists(db, &schema, retrospect_pdf_cache::Entity).await?;
+    create_table_if_not_exists(db, &schema, retro_analysis::Entity).await?;
+    create_unique_index_if_not_exists(
+        db,
+        "uq_retro_analysis_retrospect_version",
+        "retro_analysis",
+        &["retrospect_id", "version"],
+    )
+    .await?;
+    // 재시도 대상 작업 조회용 인덱스
+    create_index_if_not_exists(
+        db,
+        "idx_analysis_job_status_next_retry",
+        "analysis_job",
+        &["status", "next_retry_at"],
+    )
+    .await?;
     create_table_if_not_exists(db, &schema, member_retro_room::Entity).await?;
+    create_table_if_not_exists(db, &schema, custom_method::Entity).await?;
+    // 회고방별 커스텀 방식 목록 조회용 인덱스
+    create_index_if_not_exists(
+        db,
+        "idx_custom_method_room",
+        "custom_method",
+        &["retro_room_id"],
+    )
+    .await?;
 
     // 4. Dependent Entities (Level 3 & Join Tables)
     create_table_if_not_exists(db, &schema, response_comment::Entity).await?;
     create_table_if_not_exists(db, &schema, response_like::Entity).await?;
+    create_table_if_not_exists(db, &schema, response_label::Entity).await?;
+    create_table_if_not_exists(db, &schema, response_view::Entity).await?;
+    create_unique_index_if_not_exists(
+        db,
+        "uq_response_view_member_response",
+        "response_view",
+        &["member_id", "response_id"],
+    )
+    .await?;
+    create_table_if_not_exists(db, &schema, mention::Entity).await?;
+    create_table_if_not_exists(db, &schema, room_subscription::Entity).await?;
+    create_unique_index_if_not_exists(
+        db,
+        "uq_room_subscription_member_room",
+        "room_subscription",
+        &["member_id", "retro_room_id"],
+    )
+    .await?;
+    // 내 멘션 모아보기 조회용 인덱스
+    create_index_if_not_exists(
+        db,
+        "idx_mention_member_created",
+        "mention",
+        &["member_id", "created_at"],
+    )
+    .await?;
+    create_unique_index_if_not_exists(
+        db,
+        "uq_response_label_response_label",
+        "response_label",
+        &["response_id", "label"],
+    )
+    .await?;
+    // 라벨 필터 조회용 인덱스
+    create_index_if_not_exists(db, "idx_response_label_label", "response_label", &["label"])
+        .await?;
     create_table_if_not_exists(db, &schema, assistant_usage::Entity).await?;
     // 월간 사용량 쿼리 최적화를 위한 인덱스
     create_index_if_not_exists(
@@ -66,6 +145,14 @@ async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
         &["member_id", "created_at"],
     )
     .await?;
+    create_table_if_not_exists(db, &schema, answer_feedback_usage::Entity).await?;
+    create_index_if_not_exists(
+        db,
+        "idx_answer_feedback_usage_member_created",
+        "answer_feedback_usage",
+        &["member_id", "created_at"],
+    )
+    .await?;
     create_unique_index_if_not_exists(
         db,
         "uq_response_like_member_response",
@@ -75,6 +162,50 @@ async fn create_tables(db: &DatabaseConnection) -> Result<(), DbErr> {
     .await?;
     create_table_if_not_exists(db, &schema, member_response::Entity).await?;
     create_table_if_not_exists(db, &schema, member_retro::Entity).await?;
+    create_table_if_not_exists(db, &schema, ai_usage_log::Entity).await?;
+    // 일/월 단위 토큰 집계 쿼리 최적화를 위한 인덱스
+    create_index_if_not_exists(
+        db,
+        "idx_ai_usage_log_created",
+        "ai_usage_log",
+        &["created_at"],
+    )
+    .await?;
+    create_table_if_not_exists(db, &schema, ai_usage_quota::Entity).await?;
+    // 사용자·용도·월별 카운터 행은 하나만 존재 (예약 시 조건부 UPDATE 대상)
+    create_unique_index_if_not_exists(
+        db,
+        "uq_ai_usage_quota_member_purpose_period",
+        "ai_usage_quota",
+        &["member_id", "purpose", "period"],
+    )
+    .await?;
+    create_table_if_not_exists(db, &schema, room_membership_history::Entity).await?;
+    // 회고방별 참여 기록 타임라인 조회용 인덱스
+    create_index_if_not_exists(
+        db,
+        "idx_room_membership_history_room_created",
+        "room_membership_history",
+        &["retrospect_room_id", "created_at"],
+    )
+    .await?;
+    create_table_if_not_exists(db, &schema, notification::Entity).await?;
+    create_index_if_not_exists(
+        db,
+        "idx_notification_member_read",
+        "notification",
+        &["member_id", "is_read"],
+    )
+    .await?;
+    create_table_if_not_exists(db, &schema, member_badge::Entity).await?;
+    // 같은 뱃지 중복 부여 방지
+    create_unique_index_if_not_exists(
+        db,
+        "uq_member_badge_member_type",
+        "member_badge",
+        &["member_id", "badge_type"],
+    )
+    .await?;
 
     // Apply migrations for existing tables
     apply_migrations(db).await?;
@@ -108,16 +239,314 @@ async fn apply_migrations(db: &DatabaseConnection) -> Result<(), DbErr> {
     )
     .await?;
 
+    // Migration: Add draft_warned_at column to member_retro table (임시저장 정리 잡)
+    add_column_if_not_exists(db, "member_retro", "draft_warned_at", "DATETIME NULL").await?;
+
+    // Migration: Add last_seen_at column to member_retro_room table (미확인 활동 수 집계)
+    add_column_if_not_exists(db, "member_retro_room", "last_seen_at", "DATETIME NULL").await?;
+
+    // Migration: Add edit session columns to member_retro table (다중 기기 작성 충돌 감지)
+    add_column_if_not_exists(db, "member_retro", "last_edit_session", "VARCHAR(64) NULL").await?;
+    add_column_if_not_exists(db, "member_retro", "last_edit_at", "DATETIME NULL").await?;
+
+    // Migration: Add max_active_retrospects column to retro_room table (방당 진행 중 회고 수 제한)
+    add_column_if_not_exists(db, "retro_room", "max_active_retrospects", "INT NULL").await?;
+
+    // Migration: Add source_key/deleted_at columns to notification table (좋아요 취소 시 알림 철회)
+    add_column_if_not_exists(db, "notification", "source_key", "VARCHAR(100) NULL").await?;
+    add_column_if_not_exists(db, "notification", "deleted_at", "DATETIME NULL").await?;
+    create_index_if_not_exists(
+        db,
+        "idx_notification_source_key",
+        "notification",
+        &["source_key"],
+    )
+    .await?;
+
+    // Migration: Add timezone column to retrospects table (회고별 타임존, 기존 데이터는 KST)
+    add_column_if_not_exists(
+        db,
+        "retrospects",
+        "timezone",
+        "VARCHAR(64) NOT NULL DEFAULT 'Asia/Seoul'",
+    )
+    .await?;
+
+    // Migration: Add summary column to response table (AI 한 줄 요약)
+    add_column_if_not_exists(db, "response", "summary", "VARCHAR(200) NULL").await?;
+
+    // Migration: Drop global unique index on retro_room.title (이름 중복은 소유자 범위에서만 검사)
+    drop_index_if_exists(db, "title", "retro_room").await?;
+
+    // Migration: Add auto analyze columns to retrospects table (전원 제출 시 자동 분석)
+    add_column_if_not_exists(
+        db,
+        "retrospects",
+        "auto_analyze",
+        "BOOLEAN NOT NULL DEFAULT FALSE",
+    )
+    .await?;
+    add_column_if_not_exists(
+        db,
+        "retrospects",
+        "auto_analysis_triggered_at",
+        "DATETIME NULL",
+    )
+    .await?;
+
+    // Migration: Add scope column to shared_link table (기존 링크는 분석 결과 공유)
+    add_column_if_not_exists(
+        db,
+        "shared_link",
+        "scope",
+        "VARCHAR(20) NOT NULL DEFAULT 'ANALYSIS'",
+    )
+    .await?;
+
+    // Migration: Add like_count column to response table (좋아요 수 캐시)
+    // 컬럼을 새로 추가한 경우에만 기존 좋아요 수로 한 번 채웁니다.
+    // 이후 불일치 검증·재계산은 `integrity-check [--fix]`로 수행합니다.
+    if add_column_if_not_exists(db, "response", "like_count", "INT NOT NULL DEFAULT 0").await? {
+        db.execute(Statement::from_string(
+            db.get_database_backend(),
+            "UPDATE response r \
+             JOIN (SELECT response_id, COUNT(*) AS cnt FROM response_like GROUP BY response_id) rl \
+               ON r.response_id = rl.response_id \
+             SET r.like_count = rl.cnt"
+                .to_string(),
+        ))
+        .await?;
+    }
+
+    // Migration: Add deep_link column to notification table (알림 딥링크)
+    add_column_if_not_exists(db, "notification", "deep_link", "VARCHAR(255) NULL").await?;
+
+    // Migration: Add MODERATOR to member_retro_room.role enum (역할 계층 Owner > Moderator > Member)
+    modify_column_if_type_differs(
+        db,
+        "member_retro_room",
+        "role",
+        "ENUM('OWNER', 'MODERATOR', 'MEMBER')",
+        "NOT NULL",
+    )
+    .await?;
+
+    // Migration: Add notification_enabled column to member table (알림 수신 여부, 기존 회원은 수신)
+    add_column_if_not_exists(
+        db,
+        "member",
+        "notification_enabled",
+        "BOOLEAN NOT NULL DEFAULT TRUE",
+    )
+    .await?;
+
+    // Migration: Add last_edit_session column to response table (질문 단위 임시 저장 병합)
+    add_column_if_not_exists(db, "response", "last_edit_session", "VARCHAR(64) NULL").await?;
+
+    // Migration: Add unique_retrospect_title column to retro_room table (방 내 회고 제목 중복 방지, 기존 방은 허용)
+    add_column_if_not_exists(
+        db,
+        "retro_room",
+        "unique_retrospect_title",
+        "BOOLEAN NOT NULL DEFAULT FALSE",
+    )
+    .await?;
+
+    // Migration: Add invite_grant_role column to retro_room table (초대 코드 참여 시 부여 역할)
+    add_column_if_not_exists(
+        db,
+        "retro_room",
+        "invite_grant_role",
+        "ENUM('OWNER', 'MODERATOR', 'MEMBER') NOT NULL DEFAULT 'MEMBER'",
+    )
+    .await?;
+
+    // Migration: Add writing time columns to member_retro table (답변 작성 소요 시간)
+    add_column_if_not_exists(db, "member_retro", "writing_started_at", "DATETIME NULL").await?;
+    add_column_if_not_exists(db, "member_retro", "writing_duration_secs", "BIGINT NULL").await?;
+
+    // Migration: Add min_answer_length column to retrospects table (제출 시 답변 최소 글자 수)
+    add_column_if_not_exists(
+        db,
+        "retrospects",
+        "min_answer_length",
+        "INT NOT NULL DEFAULT 1",
+    )
+    .await?;
+
+    // Migration: retrospects.retrospect_method enum 값 목록 (CUSTOM: 팀 전용 커스텀 방식, SAILBOAT: 세일보트, DAKI: DAKI 회고 방식)
+    // 방식을 추가할 때는 별도 ALTER를 추가하지 말고 이 목록에 값을 추가합니다.
+    // (좁은 목록의 ALTER가 먼저 실행되면 기존 행의 값이 잘리거나 시작이 실패합니다)
+    // 컬럼 타입이 이미 같으면 테이블 재작성 없이 건너뜁니다.
+    modify_column_if_type_differs(
+        db,
+        "retrospects",
+        "retrospect_method",
+        "ENUM('KPT', 'FOUR_L', 'FIVE_F', 'PMI', 'FREE', 'CUSTOM', 'SAILBOAT', 'DAKI')",
+        "NOT NULL",
+    )
+    .await?;
+
+    // Migration: Add custom method columns to retrospects table (커스텀 방식 질문 스냅샷)
+    add_column_if_not_exists(db, "retrospects", "custom_method_id", "BIGINT NULL").await?;
+    add_column_if_not_exists(db, "retrospects", "custom_questions", "TEXT NULL").await?;
+
+    // Migration: Add view_count column to response table (답변 조회수)
+    add_column_if_not_exists(db, "response", "view_count", "INT NOT NULL DEFAULT 0").await?;
+
+    // Migration: Add questions_finalized column to retrospects table (질문 지연 생성 모드)
+    add_column_if_not_exists(
+        db,
+        "retrospects",
+        "questions_finalized",
+        "BOOLEAN NOT NULL DEFAULT TRUE",
+    )
+    .await?;
+
+    // Migration: Add do-not-disturb columns to member table (사용자별 방해금지 시간대)
+    add_column_if_not_exists(db, "member", "dnd_start", "TIME NULL").await?;
+    add_column_if_not_exists(db, "member", "dnd_end", "TIME NULL").await?;
+    add_column_if_not_exists(db, "member", "dnd_timezone", "VARCHAR(64) NULL").await?;
+
+    // Migration: Add deliver_at column to notification table (방해금지 시간대 알림 보류)
+    add_column_if_not_exists(db, "notification", "deliver_at", "DATETIME NULL").await?;
+
+    // Migration: Add created_at column to response_like table (좋아요 급상승 집계, 기존 좋아요는 NULL)
+    add_column_if_not_exists(db, "response_like", "created_at", "DATETIME NULL").await?;
+    create_index_if_not_exists(
+        db,
+        "idx_response_like_created",
+        "response_like",
+        &["created_at"],
+    )
+    .await?;
+
+    // Migration: Add slack_webhook_url column to retro_room table (분석 결과 Slack 연동)
+    add_column_if_not_exists(db, "retro_room", "slack_webhook_url", "VARCHAR(255) NULL").await?;
+
+    // Migration: Add last_active_at column to member_retro table (작성 중 하트비트)
+    add_column_if_not_exists(db, "member_retro", "last_active_at", "DATETIME NULL").await?;
+
+    // Migration: Add last_seen_at column to member table (회고방 멤버 마지막 접속 표시)
+    add_column_if_not_exists(db, "member", "last_seen_at", "DATETIME NULL").await?;
+
+    // Migration: Widen retrospect_pdf_cache.pdf_data to LONGBLOB (BLOB은 64KB 제한)
+    modify_column_if_type_differs(
+        db,
+        "retrospect_pdf_cache",
+        "pdf_data",
+        "LONGBLOB",
+        "NOT NULL",
+    )
+    .await?;
+
+    // Migration: Add auto_category column to response table (AI 답변 자동 분류)
+    add_column_if_not_exists(db, "response", "auto_category", "VARCHAR(20) NULL").await?;
+
+    // Migration: Add display_order column to member_retro_room table (멤버 표시 순서)
+    add_column_if_not_exists(db, "member_retro_room", "display_order", "INT NULL").await?;
+
+    // Migration: Add is_locked column to retrospects table (답변 편집 잠금)
+    add_column_if_not_exists(
+        db,
+        "retrospects",
+        "is_locked",
+        "BOOLEAN NOT NULL DEFAULT FALSE",
+    )
+    .await?;
+
+    // Migration: Add placeholder columns (질문별 답변 작성 힌트, 미설정 시 방식별 기본값)
+    add_column_if_not_exists(db, "custom_method", "placeholders_json", "TEXT NULL").await?;
+    add_column_if_not_exists(db, "retrospects", "custom_placeholders", "TEXT NULL").await?;
+
+    // Migration: Backfill retro_analysis version 1 (분석 버전 관리 도입 전 분석된 회고)
+    // 당시에는 감정 순위·미션 원본을 저장하지 않았으므로 팀 인사이트만 채웁니다.
+    db.execute(Statement::from_string(
+        db.get_database_backend(),
+        "INSERT INTO retro_analysis (retrospect_id, version, team_insight, result_json, created_at) \
+         SELECT r.retrospect_id, 1, r.insight, \
+                JSON_OBJECT('insight', r.insight, 'emotionRank', JSON_ARRAY(), 'personalMissions', JSON_ARRAY()), \
+                r.updated_at \
+         FROM retrospects r \
+         WHERE r.insight IS NOT NULL \
+           AND NOT EXISTS (SELECT 1 FROM retro_analysis ra WHERE ra.retrospect_id = r.retrospect_id)"
+            .to_string(),
+    ))
+    .await?;
+
+    // Migration: Add question weight columns (질문별 가중치, 미설정 시 동일 가중치)
+    add_column_if_not_exists(db, "custom_method", "weights_json", "TEXT NULL").await?;
+    add_column_if_not_exists(db, "retrospects", "custom_weights", "TEXT NULL").await?;
+
+    // Migration: Add share_email column to member table (이메일 공유 옵트인, 기존 회원은 비공개)
+    add_column_if_not_exists(
+        db,
+        "member",
+        "share_email",
+        "BOOLEAN NOT NULL DEFAULT FALSE",
+    )
+    .await?;
+
+    // Migration: Add email_list_viewed_at column to member_retro_room table (멤버 이메일 조회 간격 제한)
+    add_column_if_not_exists(
+        db,
+        "member_retro_room",
+        "email_list_viewed_at",
+        "DATETIME NULL",
+    )
+    .await?;
+
+    // Migration: Add summary_mail_sent_at column to retrospects table (요약 메일 중복 발송 방지)
+    add_column_if_not_exists(db, "retrospects", "summary_mail_sent_at", "DATETIME NULL").await?;
+
+    // Migration: Add start_time_utc column to retrospects table (진행 중 회고 수를 SQL에서 집계)
+    // 컬럼을 새로 추가한 경우에만 기존 회고의 값을 채웁니다 (타임존 변환은 MySQL 타임존 테이블 없이 애플리케이션에서 수행).
+    if add_column_if_not_exists(db, "retrospects", "start_time_utc", "DATETIME NULL").await? {
+        backfill_retrospect_start_time_utc(db).await?;
+    }
+
+    Ok(())
+}
+
+/// 기존 회고의 start_time_utc 채우기 (회고별 타임존의 start_time을 UTC로 변환)
+async fn backfill_retrospect_start_time_utc(db: &DatabaseConnection) -> Result<(), DbErr> {
+    let rows: Vec<(i64, NaiveDateTime, String)> = retrospect::Entity::find()
+        .select_only()
+        .column(retrospect::Column::RetrospectId)
+        .column(retrospect::Column::StartTime)
+        .column(retrospect::Column::Timezone)
+        .filter(retrospect::Column::StartTimeUtc.is_null())
+        .into_tuple()
+        .all(db)
+        .await?;
+
+    for (retrospect_id, start_time, timezone) in &rows {
+        retrospect::Entity::update_many()
+            .col_expr(
+                retrospect::Column::StartTimeUtc,
+                sea_orm::sea_query::Expr::value(local_to_utc(
+                    *start_time,
+                    resolve_timezone(timezone),
+                )),
+            )
+            .filter(retrospect::Column::RetrospectId.eq(*retrospect_id))
+            .exec(db)
+            .await?;
+    }
+
+    info!("Backfilled start_time_utc for {} retrospects", rows.len());
     Ok(())
 }
 
 /// Add a column to a table if it doesn't already exist.
+///
+/// Returns `true` only when the column was newly added, so one-time backfills can run right after it.
 async fn add_column_if_not_exists(
     db: &DatabaseConnection,
     table_name: &str,
     column_name: &str,
     column_definition: &str,
-) -> Result<(), DbErr> {
+) -> Result<bool, DbErr> {
     let backend = db.get_database_backend();
     let sql = format!(
         "ALTER TABLE {} ADD COLUMN {} {}",
@@ -128,7 +557,7 @@ async fn add_column_if_not_exists(
     match db.execute(stmt).await {
         Ok(_) => {
             info!("Added column '{}' to table '{}'", column_name, table_name);
-            Ok(())
+            Ok(true)
         }
         Err(e) => {
             // Ignore "column already exists" errors for idempotency
@@ -137,7 +566,7 @@ async fn add_column_if_not_exists(
                 || err_str.contains("already exists")
                 || err_str.contains("duplicate column")
             {
-                Ok(())
+                Ok(false)
             } else {
                 tracing::error!(
                     "Failed to add column '{}' to table '{}': {}",
@@ -151,6 +580,84 @@ async fn add_column_if_not_exists(
     }
 }
 
+/// Modify a column only when its current type differs from `column_type`.
+///
+/// The current type is read from `information_schema.COLUMNS.COLUMN_TYPE` and compared
+/// case-insensitively with whitespace ignored, so the table is not rebuilt on every boot.
+/// Missing columns are left alone (new tables are created from the entity definition).
+async fn modify_column_if_type_differs(
+    db: &DatabaseConnection,
+    table_name: &str,
+    column_name: &str,
+    column_type: &str,
+    column_options: &str,
+) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+    let row = db
+        .query_one(Statement::from_sql_and_values(
+            backend,
+            "SELECT COLUMN_TYPE AS column_type FROM information_schema.COLUMNS \
+             WHERE TABLE_SCHEMA = DATABASE() AND TABLE_NAME = ? AND COLUMN_NAME = ?",
+            [table_name.into(), column_name.into()],
+        ))
+        .await?;
+    let Some(row) = row else {
+        return Ok(());
+    };
+
+    let current_type: String = row.try_get("", "column_type")?;
+    let normalize = |t: &str| -> String {
+        t.chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase()
+    };
+    if normalize(&current_type) == normalize(column_type) {
+        return Ok(());
+    }
+
+    let sql = format!(
+        "ALTER TABLE {} MODIFY COLUMN {} {} {}",
+        table_name, column_name, column_type, column_options
+    );
+    db.execute(Statement::from_string(backend, sql)).await?;
+    info!(
+        "Modified column '{}' on table '{}' from {} to {}",
+        column_name, table_name, current_type, column_type
+    );
+    Ok(())
+}
+
+/// Drop an index from a table if it exists.
+async fn drop_index_if_exists(
+    db: &DatabaseConnection,
+    index_name: &str,
+    table_name: &str,
+) -> Result<(), DbErr> {
+    let backend = db.get_database_backend();
+    let sql = format!("DROP INDEX {} ON {}", index_name, table_name);
+    let stmt = Statement::from_string(backend, sql);
+    match db.execute(stmt).await {
+        Ok(_) => {
+            info!("Dropped index '{}' from table '{}'", index_name, table_name);
+            Ok(())
+        }
+        Err(e) => {
+            // Ignore "index does not exist" errors for idempotency
+            let err_str = e.to_string().to_lowercase();
+            if err_str.contains("check that column/key exists")
+                || err_str.contains("can't drop")
+                || err_str.contains("doesn't exist")
+            {
+                Ok(())
+            } else {
+                tracing::error!("Failed to drop index {}: {}", index_name, e);
+                Err(e)
+            }
+        }
+    }
+}
+
 async fn create_index_if_not_exists(
     db: &DatabaseConnection,
     index_name: &str,
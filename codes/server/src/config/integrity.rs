@@ -0,0 +1,351 @@
+//! 데이터 정합성 검증 (관리 커맨드)
+//!
+//! Auto-Schema 구조에서 누적될 수 있는 고아 레코드와 캐시 컬럼(`response.like_count`) 불일치를 탐지하고,
+//! 명시적으로 요청한 경우에만 정리·재계산합니다.
+//!
+//! ```bash
+//! # 리포트만 출력 (dry-run, 기본값)
+//! cargo run -- integrity-check
+//!
+//! # 고아 레코드 정리까지 수행
+//! cargo run -- integrity-check --fix
+//! ```
+
+use sea_orm::{ConnectionTrait, DatabaseConnection, DbErr, Statement, TransactionTrait};
+use tracing::{info, warn};
+
+/// 정합성 규칙 단위 검사 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IntegrityReport {
+    /// 규칙 식별자
+    pub rule: &'static str,
+    /// 규칙 설명
+    pub description: &'static str,
+    /// 탐지된 고아 레코드 수
+    pub orphan_count: i64,
+    /// 정리된 레코드 수 (dry-run이면 None)
+    pub fixed_count: Option<u64>,
+}
+
+/// 정합성 규칙 정의
+struct IntegrityRule {
+    rule: &'static str,
+    description: &'static str,
+    count_sql: &'static str,
+    /// 정리 시 순서대로 실행할 SQL (하위 테이블 → 대상 테이블 순서)
+    fix_sqls: &'static [&'static str],
+}
+
+/// 규칙 1: 회고가 없는 response
+fn orphan_responses_rule() -> IntegrityRule {
+    IntegrityRule {
+        rule: "orphan_response",
+        description: "회고(retrospects)가 존재하지 않는 response",
+        count_sql: "SELECT COUNT(*) AS cnt FROM response r \
+             LEFT JOIN retrospects t ON r.retrospect_id = t.retrospect_id \
+             WHERE t.retrospect_id IS NULL",
+        fix_sqls: &[
+            "DELETE rc FROM response_comment rc \
+             JOIN response r ON rc.response_id = r.response_id \
+             LEFT JOIN retrospects t ON r.retrospect_id = t.retrospect_id \
+             WHERE t.retrospect_id IS NULL",
+            "DELETE rl FROM response_like rl \
+             JOIN response r ON rl.response_id = r.response_id \
+             LEFT JOIN retrospects t ON r.retrospect_id = t.retrospect_id \
+             WHERE t.retrospect_id IS NULL",
+            "DELETE lb FROM response_label lb \
+             JOIN response r ON lb.response_id = r.response_id \
+             LEFT JOIN retrospects t ON r.retrospect_id = t.retrospect_id \
+             WHERE t.retrospect_id IS NULL",
+            "DELETE mr FROM member_response mr \
+             JOIN response r ON mr.response_id = r.response_id \
+             LEFT JOIN retrospects t ON r.retrospect_id = t.retrospect_id \
+             WHERE t.retrospect_id IS NULL",
+            "DELETE r FROM response r \
+             LEFT JOIN retrospects t ON r.retrospect_id = t.retrospect_id \
+             WHERE t.retrospect_id IS NULL",
+        ],
+    }
+}
+
+/// 규칙 2: 회고방이 없는 retrospect
+///
+/// 회고에 딸린 답변/참여/참고자료/사용 기록까지 함께 정리합니다.
+fn orphan_retrospects_rule() -> IntegrityRule {
+    IntegrityRule {
+        rule: "orphan_retrospect",
+        description: "회고방(retro_room)이 존재하지 않는 retrospect",
+        count_sql: "SELECT COUNT(*) AS cnt FROM retrospects t \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+        fix_sqls: &[
+            "DELETE rc FROM response_comment rc \
+             JOIN response r ON rc.response_id = r.response_id \
+             JOIN retrospects t ON r.retrospect_id = t.retrospect_id \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+            "DELETE rl FROM response_like rl \
+             JOIN response r ON rl.response_id = r.response_id \
+             JOIN retrospects t ON r.retrospect_id = t.retrospect_id \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+            "DELETE lb FROM response_label lb \
+             JOIN response r ON lb.response_id = r.response_id \
+             JOIN retrospects t ON r.retrospect_id = t.retrospect_id \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+            "DELETE mr FROM member_response mr \
+             JOIN response r ON mr.response_id = r.response_id \
+             JOIN retrospects t ON r.retrospect_id = t.retrospect_id \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+            "DELETE r FROM response r \
+             JOIN retrospects t ON r.retrospect_id = t.retrospect_id \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+            "DELETE mrt FROM member_retro mrt \
+             JOIN retrospects t ON mrt.retrospect_id = t.retrospect_id \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+            "DELETE rf FROM retro_reference rf \
+             JOIN retrospects t ON rf.retrospect_id = t.retrospect_id \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+            "DELETE au FROM assistant_usage au \
+             JOIN retrospects t ON au.retrospect_id = t.retrospect_id \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+            "DELETE afu FROM answer_feedback_usage afu \
+             JOIN retrospects t ON afu.retrospect_id = t.retrospect_id \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+            "DELETE sl FROM shared_link sl \
+             JOIN retrospects t ON sl.retrospect_id = t.retrospect_id \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+            "DELETE cit FROM check_in_token cit \
+             JOIN retrospects t ON cit.retrospect_id = t.retrospect_id \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+            "DELETE t FROM retrospects t \
+             LEFT JOIN retro_room rr ON t.retrospect_room_id = rr.retrospect_room_id \
+             WHERE rr.retrospect_room_id IS NULL",
+        ],
+    }
+}
+
+/// 규칙 3: 존재하지 않는 member를 가리키는 member_retro
+///
+/// 탈퇴 회원의 member_id는 정책상 NULL로 유지되므로 대상이 아니며,
+/// 정리 시에도 행을 삭제하지 않고 탈퇴 처리와 동일하게 member_id를 NULL로 변경합니다.
+fn orphan_member_retros_rule() -> IntegrityRule {
+    IntegrityRule {
+        rule: "orphan_member_retro",
+        description: "존재하지 않는 member를 참조하는 member_retro",
+        count_sql: "SELECT COUNT(*) AS cnt FROM member_retro mrt \
+             LEFT JOIN member m ON mrt.member_id = m.member_id \
+             WHERE mrt.member_id IS NOT NULL AND m.member_id IS NULL",
+        fix_sqls: &["UPDATE member_retro mrt \
+             LEFT JOIN member m ON mrt.member_id = m.member_id \
+             SET mrt.member_id = NULL \
+             WHERE mrt.member_id IS NOT NULL AND m.member_id IS NULL"],
+    }
+}
+
+/// 규칙 4: response_like 실제 개수와 다른 response.like_count 캐시
+///
+/// 캐시 불일치는 행을 삭제하지 않고 response_like 기준으로 재계산합니다.
+fn like_count_mismatch_rule() -> IntegrityRule {
+    IntegrityRule {
+        rule: "like_count_mismatch",
+        description: "response_like 개수와 like_count가 다른 response",
+        count_sql: "SELECT COUNT(*) AS cnt FROM response r \
+             LEFT JOIN (SELECT response_id, COUNT(*) AS cnt FROM response_like \
+               GROUP BY response_id) rl ON r.response_id = rl.response_id \
+             WHERE r.like_count <> COALESCE(rl.cnt, 0)",
+        fix_sqls: &["UPDATE response r \
+             LEFT JOIN (SELECT response_id, COUNT(*) AS cnt FROM response_like \
+               GROUP BY response_id) rl ON r.response_id = rl.response_id \
+             SET r.like_count = COALESCE(rl.cnt, 0) \
+             WHERE r.like_count <> COALESCE(rl.cnt, 0)"],
+    }
+}
+
+/// 검사 대상 규칙 목록
+///
+/// 정리 시 상위 데이터(회고)를 먼저 처리해야 하위 고아 레코드 수가 정확히 계산됩니다.
+/// 좋아요 수 캐시는 고아 좋아요가 정리된 뒤에 재계산합니다.
+fn integrity_rules() -> Vec<IntegrityRule> {
+    vec![
+        orphan_retrospects_rule(),
+        orphan_responses_rule(),
+        orphan_member_retros_rule(),
+        like_count_mismatch_rule(),
+    ]
+}
+
+/// 정합성 검사 실행
+///
+/// `fix`가 false(기본값)이면 탐지 결과만 반환하고 데이터는 변경하지 않습니다.
+/// `fix`가 true이면 규칙별로 트랜잭션을 열어 고아 레코드를 정리합니다.
+pub async fn run_integrity_check(
+    db: &DatabaseConnection,
+    fix: bool,
+) -> Result<Vec<IntegrityReport>, DbErr> {
+    let mut reports = Vec::new();
+
+    for rule in integrity_rules() {
+        let orphan_count = count_rows(db, rule.count_sql).await?;
+
+        let fixed_count = if fix && orphan_count > 0 {
+            let txn = db.begin().await?;
+            let mut affected = 0;
+            for sql in rule.fix_sqls {
+                affected = execute(&txn, sql).await?;
+            }
+            txn.commit().await?;
+            warn!(
+                rule = rule.rule,
+                fixed_count = affected,
+                "고아 레코드 정리 완료"
+            );
+            Some(affected)
+        } else {
+            None
+        };
+
+        info!(
+            rule = rule.rule,
+            orphan_count = orphan_count,
+            "정합성 규칙 검사 완료"
+        );
+
+        reports.push(IntegrityReport {
+            rule: rule.rule,
+            description: rule.description,
+            orphan_count,
+            fixed_count,
+        });
+    }
+
+    Ok(reports)
+}
+
+/// 리포트를 사람이 읽기 쉬운 형태로 변환
+pub fn format_report(reports: &[IntegrityReport], fix: bool) -> String {
+    let mut lines = vec![format!(
+        "[데이터 정합성 검사] 모드: {}",
+        if fix { "fix" } else { "dry-run" }
+    )];
+
+    for report in reports {
+        let fixed = match report.fixed_count {
+            Some(count) => format!(" (정리: {}건)", count),
+            None => String::new(),
+        };
+        lines.push(format!(
+            "- {}: {}건{} - {}",
+            report.rule, report.orphan_count, fixed, report.description
+        ));
+    }
+
+    let total: i64 = reports.iter().map(|r| r.orphan_count).sum();
+    if total > 0 && !fix {
+        lines.push("정리하려면 --fix 플래그를 명시적으로 지정하세요.".to_string());
+    }
+
+    lines.join("\n")
+}
+
+async fn count_rows(db: &DatabaseConnection, sql: &str) -> Result<i64, DbErr> {
+    let backend = db.get_database_backend();
+    let row = db
+        .query_one(Statement::from_string(backend, sql.to_string()))
+        .await?;
+
+    match row {
+        Some(row) => row.try_get::<i64>("", "cnt"),
+        None => Ok(0),
+    }
+}
+
+/// SQL 실행 후 영향받은 행 수 반환 (마지막 SQL이 대상 테이블의 정리 건수)
+async fn execute<C: ConnectionTrait>(db: &C, sql: &str) -> Result<u64, DbErr> {
+    let backend = db.get_database_backend();
+    let result = db
+        .execute(Statement::from_string(backend, sql.to_string()))
+        .await?;
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_format_dry_run_report_with_fix_hint() {
+        // Arrange
+        let reports = vec![IntegrityReport {
+            rule: "orphan_response",
+            description: "회고(retrospects)가 존재하지 않는 response",
+            orphan_count: 3,
+            fixed_count: None,
+        }];
+
+        // Act
+        let output = format_report(&reports, false);
+
+        // Assert
+        assert!(output.contains("dry-run"));
+        assert!(output.contains("orphan_response: 3건"));
+        assert!(output.contains("--fix"));
+    }
+
+    #[test]
+    fn should_format_fix_report_with_fixed_count() {
+        // Arrange
+        let reports = vec![IntegrityReport {
+            rule: "orphan_member_retro",
+            description: "존재하지 않는 member를 참조하는 member_retro",
+            orphan_count: 2,
+            fixed_count: Some(2),
+        }];
+
+        // Act
+        let output = format_report(&reports, true);
+
+        // Assert
+        assert!(output.contains("모드: fix"));
+        assert!(output.contains("(정리: 2건)"));
+        assert!(!output.contains("--fix 플래그"));
+    }
+
+    #[test]
+    fn should_check_parent_rules_before_child_rules() {
+        // Act
+        let rules: Vec<&str> = integrity_rules().iter().map(|r| r.rule).collect();
+
+        // Assert
+        assert_eq!(
+            rules,
+            vec![
+                "orphan_retrospect",
+                "orphan_response",
+                "orphan_member_retro",
+                "like_count_mismatch"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_only_modify_data_in_fix_sqls() {
+        // Arrange & Act
+        let rules = integrity_rules();
+
+        // Assert: count 쿼리는 조회 전용이어야 함
+        for rule in rules {
+            assert!(rule.count_sql.trim_start().starts_with("SELECT"));
+            assert!(!rule.fix_sqls.is_empty());
+        }
+    }
+}
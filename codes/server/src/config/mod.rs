@@ -1,5 +1,6 @@
 pub mod app_config;
 pub mod database;
+pub mod integrity;
 
 pub use app_config::AppConfig;
 pub use database::establish_connection;
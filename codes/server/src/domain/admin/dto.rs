@@ -0,0 +1,153 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::domain::ai::entity::ai_usage_log::AiUsagePurpose;
+use crate::domain::retrospect::dto::StorageRangeFilter;
+use crate::domain::retrospect::entity::retrospect::RetrospectMethod;
+
+// ============================================
+// AI 토큰 사용량 집계 DTO
+// ============================================
+
+/// 토큰 사용량 집계 단위
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AiUsagePeriod {
+    /// 일 단위 (YYYY-MM-DD, KST)
+    #[default]
+    Daily,
+    /// 월 단위 (YYYY-MM, KST)
+    Monthly,
+}
+
+impl AiUsagePeriod {
+    /// MySQL DATE_FORMAT 포맷 문자열
+    pub fn date_format(&self) -> &'static str {
+        match self {
+            AiUsagePeriod::Daily => "%Y-%m-%d",
+            AiUsagePeriod::Monthly => "%Y-%m",
+        }
+    }
+}
+
+/// 토큰 사용량 조회 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct AiUsageQueryParams {
+    /// 집계 단위 (DAILY 또는 MONTHLY, 기본값: DAILY)
+    pub period: Option<AiUsagePeriod>,
+    /// 조회 시작일 (YYYY-MM-DD, KST, 기본값: 30일 전)
+    pub from: Option<String>,
+    /// 조회 종료일 (YYYY-MM-DD, KST, 포함, 기본값: 오늘)
+    pub to: Option<String>,
+}
+
+/// 기간·용도별 토큰 사용량 합계 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AiUsageSummaryItem {
+    /// 집계 기간 (DAILY: YYYY-MM-DD, MONTHLY: YYYY-MM)
+    pub period: String,
+    /// 호출 용도
+    pub purpose: AiUsagePurpose,
+    /// 호출 횟수
+    pub call_count: i64,
+    /// 프롬프트 토큰 합계
+    pub prompt_tokens: i64,
+    /// 응답 토큰 합계
+    pub completion_tokens: i64,
+    /// 전체 토큰 합계
+    pub total_tokens: i64,
+}
+
+/// Swagger용 토큰 사용량 조회 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessAiUsageSummaryResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: Vec<AiUsageSummaryItem>,
+}
+
+// ============================================
+// 회고 방식 사용 통계 DTO
+// ============================================
+
+/// 회고 방식 사용 통계 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodStatsQueryParams {
+    /// 기간 필터 (ALL, 3_MONTHS, 6_MONTHS, 1_YEAR, 기본값: ALL, 회고 생성일 기준)
+    pub range: Option<StorageRangeFilter>,
+}
+
+/// 회고 방식별 사용 통계 아이템
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodStatItem {
+    /// 회고 방식
+    pub method: RetrospectMethod,
+    /// 해당 방식으로 생성된 회고 수
+    pub count: i64,
+    /// 전체 대비 비율 (0.0~100.0, 소수점 첫째 자리, 전체가 0이면 0.0)
+    pub percentage: f64,
+    /// 답변 평균 작성 시간 (초, 작성 시작~제출이 기록된 제출 기준, 기록이 없으면 null)
+    pub average_writing_seconds: Option<i64>,
+}
+
+/// 회고 방식 사용 통계 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodStatsResponse {
+    /// 적용된 기간 필터
+    pub range: String,
+    /// 기간 내 전체 회고 수
+    pub total_count: i64,
+    /// 전체 답변 평균 작성 시간 (초, 기록이 없으면 null)
+    pub average_writing_seconds: Option<i64>,
+    /// 방식별 통계 (사용 횟수 내림차순, 사용되지 않은 방식도 0으로 포함)
+    pub methods: Vec<MethodStatItem>,
+}
+
+/// Swagger용 회고 방식 사용 통계 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessMethodStatsResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: MethodStatsResponse,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_deserialize_period_in_screaming_snake_case() {
+        let period: AiUsagePeriod = serde_json::from_str("\"MONTHLY\"").unwrap();
+        assert_eq!(period, AiUsagePeriod::Monthly);
+    }
+
+    #[test]
+    fn should_serialize_summary_item_in_camel_case() {
+        // Arrange
+        let item = AiUsageSummaryItem {
+            period: "2026-10".to_string(),
+            purpose: AiUsagePurpose::Analysis,
+            call_count: 3,
+            prompt_tokens: 1200,
+            completion_tokens: 300,
+            total_tokens: 1500,
+        };
+
+        // Act
+        let json = serde_json::to_string(&item).unwrap();
+
+        // Assert
+        assert!(json.contains("\"purpose\":\"ANALYSIS\""));
+        assert!(json.contains("\"callCount\":3"));
+        assert!(json.contains("\"totalTokens\":1500"));
+    }
+}
@@ -0,0 +1,83 @@
+use axum::{
+    extract::{Query, State},
+    Json,
+};
+
+use super::dto::{
+    AiUsageQueryParams, AiUsageSummaryItem, MethodStatsQueryParams, MethodStatsResponse,
+};
+use super::service::AdminService;
+use crate::state::AppState;
+use crate::utils::auth::AuthUser;
+use crate::utils::error::AppError;
+use crate::utils::BaseResponse;
+
+/// AI 토큰 사용량 조회 API (관리자 전용)
+///
+/// 일·월 단위로 AI 호출 용도별 토큰 사용량 합계를 조회합니다.
+/// `ADMIN_MEMBER_IDS`에 등록된 회원만 호출할 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/ai-usage",
+    params(AiUsageQueryParams),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "토큰 사용량 조회 성공", body = SuccessAiUsageSummaryResponse),
+        (status = 400, description = "잘못된 조회 기간", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "관리자 권한 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Admin"
+)]
+pub async fn get_ai_usage_summary(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(params): Query<AiUsageQueryParams>,
+) -> Result<Json<BaseResponse<Vec<AiUsageSummaryItem>>>, AppError> {
+    let member_id = user.user_id()?;
+
+    let result = AdminService::get_ai_usage_summary(state, member_id, params).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "AI 토큰 사용량 조회를 성공했습니다.",
+    )))
+}
+
+/// 회고 방식 사용 통계 API (관리자 전용)
+///
+/// 조직 전체 회고를 방식별로 집계해 분포를 조회합니다.
+/// `ADMIN_MEMBER_IDS`에 등록된 회원만 호출할 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/method-stats",
+    params(MethodStatsQueryParams),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "회고 방식 사용 통계 조회 성공", body = SuccessMethodStatsResponse),
+        (status = 400, description = "잘못된 기간 필터", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "관리자 권한 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Admin"
+)]
+pub async fn get_method_stats(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(params): Query<MethodStatsQueryParams>,
+) -> Result<Json<BaseResponse<MethodStatsResponse>>, AppError> {
+    let member_id = user.user_id()?;
+
+    let result = AdminService::get_method_stats(state, member_id, params).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고 방식 사용 통계 조회를 성공했습니다.",
+    )))
+}
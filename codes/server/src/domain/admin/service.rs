@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, NaiveDate, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, EntityTrait, FromQueryResult, Iterable, JoinType, QueryFilter,
+    QuerySelect, RelationTrait, Statement,
+};
+use tracing::info;
+
+use crate::domain::ai::entity::ai_usage_log::AiUsagePurpose;
+use crate::domain::member::entity::member_retro;
+use crate::domain::retrospect::entity::retrospect::{self, RetrospectMethod};
+use crate::state::AppState;
+use crate::utils::error::AppError;
+
+use super::dto::{
+    AiUsageQueryParams, AiUsageSummaryItem, MethodStatItem, MethodStatsQueryParams,
+    MethodStatsResponse,
+};
+
+/// 토큰 사용량 기본 조회 기간 (일)
+const AI_USAGE_DEFAULT_DAYS: i64 = 30;
+/// 토큰 사용량 최대 조회 기간 (일)
+const AI_USAGE_MAX_DAYS: i64 = 366;
+
+pub struct AdminService;
+
+impl AdminService {
+    /// 관리자 권한 확인 (ADMIN_MEMBER_IDS에 등록된 회원만 허용)
+    pub fn ensure_admin(state: &AppState, member_id: i64) -> Result<(), AppError> {
+        if !state.config.is_admin(member_id) {
+            return Err(AppError::Forbidden(
+                "관리자만 접근할 수 있습니다.".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// AI 토큰 사용량 기간·용도별 합계 조회
+    pub async fn get_ai_usage_summary(
+        state: AppState,
+        member_id: i64,
+        params: AiUsageQueryParams,
+    ) -> Result<Vec<AiUsageSummaryItem>, AppError> {
+        info!(member_id = member_id, "AI 토큰 사용량 조회 요청");
+
+        // 1. 관리자 권한 확인
+        Self::ensure_admin(&state, member_id)?;
+
+        // 2. 조회 기간 계산 (KST 날짜 기준, 종료일 포함)
+        let kst_offset = Duration::hours(9);
+        let today_kst = (Utc::now().naive_utc() + kst_offset).date();
+        let to = parse_date_param(params.to.as_deref(), "to")?.unwrap_or(today_kst);
+        let from = parse_date_param(params.from.as_deref(), "from")?
+            .unwrap_or(to - Duration::days(AI_USAGE_DEFAULT_DAYS - 1));
+
+        if from > to {
+            return Err(AppError::BadRequest(
+                "from은 to보다 이후일 수 없습니다.".to_string(),
+            ));
+        }
+        if (to - from).num_days() >= AI_USAGE_MAX_DAYS {
+            return Err(AppError::BadRequest(format!(
+                "조회 기간은 최대 {}일입니다.",
+                AI_USAGE_MAX_DAYS
+            )));
+        }
+
+        let start_utc = from.and_hms_opt(0, 0, 0).unwrap_or_default() - kst_offset;
+        let end_utc = (to + Duration::days(1))
+            .and_hms_opt(0, 0, 0)
+            .unwrap_or_default()
+            - kst_offset;
+
+        // 3. 기간·용도별 합계 집계 (KST 기준 버킷)
+        #[derive(FromQueryResult)]
+        struct UsageRow {
+            period: String,
+            purpose: AiUsagePurpose,
+            call_count: i64,
+            prompt_tokens: i64,
+            completion_tokens: i64,
+        }
+
+        const USAGE_SUMMARY_SQL: &str = "SELECT \
+             DATE_FORMAT(DATE_ADD(created_at, INTERVAL 9 HOUR), ?) AS period, \
+             purpose, \
+             COUNT(*) AS call_count, \
+             CAST(COALESCE(SUM(prompt_tokens), 0) AS SIGNED) AS prompt_tokens, \
+             CAST(COALESCE(SUM(completion_tokens), 0) AS SIGNED) AS completion_tokens \
+             FROM ai_usage_log \
+             WHERE created_at >= ? AND created_at < ? \
+             GROUP BY period, purpose \
+             ORDER BY period ASC, purpose ASC";
+
+        let period = params.period.unwrap_or_default();
+        let statement = Statement::from_sql_and_values(
+            state.db.get_database_backend(),
+            USAGE_SUMMARY_SQL,
+            [
+                period.date_format().into(),
+                start_utc.into(),
+                end_utc.into(),
+            ],
+        );
+
+        let rows = UsageRow::find_by_statement(statement)
+            .all(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        Ok(rows
+            .into_iter()
+            .map(|row| AiUsageSummaryItem {
+                period: row.period,
+                purpose: row.purpose,
+                call_count: row.call_count,
+                prompt_tokens: row.prompt_tokens,
+                completion_tokens: row.completion_tokens,
+                total_tokens: row.prompt_tokens + row.completion_tokens,
+            })
+            .collect())
+    }
+
+    /// 회고 방식별 사용 통계 조회 (조직 전체)
+    pub async fn get_method_stats(
+        state: AppState,
+        member_id: i64,
+        params: MethodStatsQueryParams,
+    ) -> Result<MethodStatsResponse, AppError> {
+        let range_filter = params.range.unwrap_or_default();
+
+        info!(
+            member_id = member_id,
+            range = %range_filter,
+            "회고 방식 사용 통계 조회 요청"
+        );
+
+        // 1. 관리자 권한 확인
+        Self::ensure_admin(&state, member_id)?;
+
+        // 2. 방식별 회고 수 집계 (단일 GROUP BY 쿼리)
+        let mut query = retrospect::Entity::find()
+            .select_only()
+            .column(retrospect::Column::RetrospectMethod)
+            .column_as(retrospect::Column::RetrospectId.count(), "count")
+            .group_by(retrospect::Column::RetrospectMethod);
+
+        // 3. 방식별 답변 작성 시간 합계/건수 집계 (작성 시간이 기록된 제출만)
+        let mut writing_query = member_retro::Entity::find()
+            .select_only()
+            .join(
+                JoinType::InnerJoin,
+                member_retro::Relation::Retrospect.def(),
+            )
+            .column(retrospect::Column::RetrospectMethod)
+            .column_as(
+                Expr::cust("CAST(SUM(member_retro.writing_duration_secs) AS SIGNED)"),
+                "total_secs",
+            )
+            .column_as(
+                member_retro::Column::WritingDurationSecs.count(),
+                "timed_count",
+            )
+            .filter(member_retro::Column::WritingDurationSecs.is_not_null())
+            .group_by(retrospect::Column::RetrospectMethod);
+
+        if let Some(days) = range_filter.days() {
+            let cutoff = Utc::now().naive_utc() - Duration::days(days);
+            query = query.filter(retrospect::Column::CreatedAt.gte(cutoff));
+            writing_query = writing_query.filter(retrospect::Column::CreatedAt.gte(cutoff));
+        }
+
+        let counts: Vec<(RetrospectMethod, i64)> = query
+            .into_tuple()
+            .all(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        let writing_totals: Vec<(RetrospectMethod, i64, i64)> = writing_query
+            .into_tuple()
+            .all(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        // 4. 전체 방식 기준으로 분포 구성 (방식이 추가되면 자동 반영)
+        let average_writing_seconds = average_seconds(
+            writing_totals.iter().map(|(_, secs, _)| secs).sum(),
+            writing_totals.iter().map(|(_, _, count)| count).sum(),
+        );
+        let methods = build_method_stats(counts, writing_totals);
+        let total_count = methods.iter().map(|item| item.count).sum();
+
+        Ok(MethodStatsResponse {
+            range: range_filter.to_string(),
+            total_count,
+            average_writing_seconds,
+            methods,
+        })
+    }
+}
+
+/// 합계와 건수로 평균 초 계산 (건수가 0이면 None, 반올림)
+fn average_seconds(total_secs: i64, count: i64) -> Option<i64> {
+    (count > 0).then(|| (total_secs as f64 / count as f64).round() as i64)
+}
+
+/// 방식별 집계 결과를 전체 방식 목록과 합쳐 사용 횟수 내림차순으로 정렬
+///
+/// 집계 결과에 없는 방식은 0건으로 포함하며, 동률이면 enum 선언 순서를 따릅니다.
+/// `writing_totals`는 방식별 (작성 시간 합계 초, 기록 건수)입니다.
+fn build_method_stats(
+    counts: Vec<(RetrospectMethod, i64)>,
+    writing_totals: Vec<(RetrospectMethod, i64, i64)>,
+) -> Vec<MethodStatItem> {
+    let total: i64 = counts.iter().map(|(_, count)| count).sum();
+    let count_map: HashMap<String, i64> = counts
+        .into_iter()
+        .map(|(method, count)| (method.to_string(), count))
+        .collect();
+    let writing_map: HashMap<String, (i64, i64)> = writing_totals
+        .into_iter()
+        .map(|(method, secs, count)| (method.to_string(), (secs, count)))
+        .collect();
+
+    let mut items: Vec<MethodStatItem> = RetrospectMethod::iter()
+        .map(|method| {
+            let count = count_map.get(&method.to_string()).copied().unwrap_or(0);
+            let percentage = if total > 0 {
+                (count as f64 * 1000.0 / total as f64).round() / 10.0
+            } else {
+                0.0
+            };
+            let average_writing_seconds = writing_map
+                .get(&method.to_string())
+                .and_then(|(secs, count)| average_seconds(*secs, *count));
+            MethodStatItem {
+                method,
+                count,
+                percentage,
+                average_writing_seconds,
+            }
+        })
+        .collect();
+
+    // 안정 정렬이므로 동률은 enum 선언 순서 유지
+    items.sort_by(|a, b| b.count.cmp(&a.count));
+    items
+}
+
+/// YYYY-MM-DD 형식의 날짜 쿼리 파라미터 파싱
+fn parse_date_param(value: Option<&str>, name: &str) -> Result<Option<NaiveDate>, AppError> {
+    value
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| {
+            NaiveDate::parse_from_str(v, "%Y-%m-%d").map_err(|_| {
+                AppError::BadRequest(format!("{}는 YYYY-MM-DD 형식이어야 합니다.", name))
+            })
+        })
+        .transpose()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_valid_date_param() {
+        let result = parse_date_param(Some("2026-10-16"), "from").unwrap();
+        assert_eq!(result, NaiveDate::from_ymd_opt(2026, 10, 16));
+    }
+
+    #[test]
+    fn should_treat_empty_date_param_as_none() {
+        assert_eq!(parse_date_param(Some("  "), "from").unwrap(), None);
+        assert_eq!(parse_date_param(None, "from").unwrap(), None);
+    }
+
+    #[test]
+    fn should_include_unused_methods_and_sort_by_count() {
+        // Arrange
+        let counts = vec![(RetrospectMethod::Pmi, 1), (RetrospectMethod::Kpt, 3)];
+
+        // Act
+        let items = build_method_stats(counts, Vec::new());
+
+        // Assert
+        assert_eq!(items.len(), RetrospectMethod::iter().count());
+        assert_eq!(items[0].method, RetrospectMethod::Kpt);
+        assert_eq!(items[0].count, 3);
+        assert_eq!(items[0].percentage, 75.0);
+        assert_eq!(items[1].method, RetrospectMethod::Pmi);
+        assert_eq!(items[1].percentage, 25.0);
+        assert!(items[2..].iter().all(|item| item.count == 0));
+    }
+
+    #[test]
+    fn should_return_zero_percentage_when_no_retrospects() {
+        // Act
+        let items = build_method_stats(Vec::new(), Vec::new());
+
+        // Assert
+        assert!(items.iter().all(|item| item.percentage == 0.0));
+        assert!(items
+            .iter()
+            .all(|item| item.average_writing_seconds.is_none()));
+    }
+
+    #[test]
+    fn should_average_writing_seconds_per_method() {
+        // Arrange
+        let counts = vec![(RetrospectMethod::Kpt, 2), (RetrospectMethod::Pmi, 1)];
+        let writing_totals = vec![(RetrospectMethod::Kpt, 1_000, 3)];
+
+        // Act
+        let items = build_method_stats(counts, writing_totals);
+
+        // Assert
+        assert_eq!(items[0].method, RetrospectMethod::Kpt);
+        assert_eq!(items[0].average_writing_seconds, Some(333));
+        assert_eq!(items[1].average_writing_seconds, None);
+    }
+
+    #[test]
+    fn should_reject_invalid_date_param() {
+        let result = parse_date_param(Some("2026/10/16"), "to");
+        assert!(matches!(result, Err(AppError::BadRequest(_))));
+    }
+}
@@ -0,0 +1,62 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// AI 호출 용도 Enum
+///
+/// 용도 추가 시 ENUM 컬럼 마이그레이션이 필요 없도록 문자열 컬럼으로 저장합니다.
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(30))")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AiUsagePurpose {
+    /// 회고 종합 분석
+    #[sea_orm(string_value = "ANALYSIS")]
+    Analysis,
+    /// 회고 어시스턴트 가이드
+    #[sea_orm(string_value = "ASSISTANT")]
+    Assistant,
+    /// 제출 전 답변 피드백
+    #[sea_orm(string_value = "ANSWER_FEEDBACK")]
+    AnswerFeedback,
+    /// 번역
+    #[sea_orm(string_value = "TRANSLATION")]
+    Translation,
+    /// 답변 한 줄 요약
+    #[sea_orm(string_value = "RESPONSE_SUMMARY")]
+    ResponseSummary,
+    /// 답변 카테고리 자동 분류
+    #[sea_orm(string_value = "AUTO_CATEGORIZE")]
+    AutoCategorize,
+    /// 질문별 초기 어시스턴트 가이드 캐시 프리로드
+    #[sea_orm(string_value = "GUIDE_PRELOAD")]
+    GuidePreload,
+}
+
+/// AI 호출 토큰 사용량 로그 엔티티
+///
+/// 비용 모니터링 용도로, 회고/회원 삭제 후에도 집계가 유지되도록 FK를 두지 않습니다.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ai_usage_log")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub ai_usage_log_id: i64,
+    /// 호출한 사용자 ID
+    pub member_id: Option<i64>,
+    /// 관련 회고 ID
+    pub retrospect_id: Option<i64>,
+    /// 호출 용도
+    pub purpose: AiUsagePurpose,
+    /// 프롬프트 토큰 수
+    pub prompt_tokens: i32,
+    /// 응답(completion) 토큰 수
+    pub completion_tokens: i32,
+    /// 호출 일시
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
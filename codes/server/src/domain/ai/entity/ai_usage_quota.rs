@@ -0,0 +1,31 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::ai_usage_log::AiUsagePurpose;
+
+/// 사용자별·용도별 월간 AI 사용량 카운터 엔티티
+///
+/// AI 호출 전에 `used_count < 한도` 조건부 UPDATE로 한 건을 예약해, 동시 요청에서도 한도를 넘지 않도록 합니다.
+/// (member_id, purpose, period)에 유니크 인덱스가 있습니다.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "ai_usage_quota")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub ai_usage_quota_id: i64,
+    /// 사용자 ID
+    pub member_id: i64,
+    /// 호출 용도
+    pub purpose: AiUsagePurpose,
+    /// 집계 월 (KST 기준 "YYYY-MM")
+    #[sea_orm(column_type = "String(StringLen::N(7))")]
+    pub period: String,
+    /// 예약된 사용 횟수
+    pub used_count: i32,
+    /// 마지막 갱신 일시
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
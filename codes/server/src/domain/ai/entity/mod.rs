@@ -0,0 +1,2 @@
+pub mod ai_usage_log;
+pub mod ai_usage_quota;
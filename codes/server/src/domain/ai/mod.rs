@@ -1,2 +1,3 @@
+pub mod entity;
 pub mod prompt;
 pub mod service;
@@ -1,3 +1,8 @@
+use crate::domain::retrospect::entity::retrospect::RetrospectMethod;
+
+/// 분석 시스템 프롬프트에서 팀원별 개인 미션 수로 치환되는 자리표시자
+const MISSIONS_PER_USER_PLACEHOLDER: &str = "{missions_per_user}";
+
 /// 회고 분석 프롬프트 템플릿
 pub struct AnalysisPrompt;
 
@@ -5,12 +10,127 @@ pub struct AnalysisPrompt;
 pub struct MemberAnswerData {
     pub user_id: i64,
     pub user_name: String,
-    pub answers: Vec<(String, String)>, // (질문, 답변)
+    pub answers: Vec<AnswerData>,
+}
+
+/// 회고 분석 입력: 질문 하나에 대한 답변
+pub struct AnswerData {
+    pub question: String,
+    pub answer: String,
+    /// 회고 방식상 질문이 속한 항목 (예: "Keep(유지할 점)")
+    pub category: Option<String>,
+    /// 질문 가중치 (1~5, 미설정이면 다른 질문과 동일하게 취급)
+    pub weight: Option<i32>,
 }
 
 impl AnalysisPrompt {
     /// 회고 분석 시스템 프롬프트 생성
-    pub fn system_prompt() -> String {
+    ///
+    /// 공통 분석 규칙 뒤에 회고 방식별 해석 가이드와 few-shot 예제를 덧붙입니다.
+    /// 팀원별 개인 미션 수는 `missions_per_user`로 지정합니다.
+    pub fn system_prompt(method: &RetrospectMethod, missions_per_user: usize) -> String {
+        format!(
+            "{}\n\n{}",
+            Self::base_system_prompt().replace(
+                MISSIONS_PER_USER_PLACEHOLDER,
+                &missions_per_user.to_string()
+            ),
+            Self::method_guide(method)
+        )
+    }
+
+    /// 회고 방식별 해석 가이드 및 few-shot 예제
+    fn method_guide(method: &RetrospectMethod) -> &'static str {
+        match method {
+            RetrospectMethod::Kpt => {
+                r#"## 회고 방식: KPT (Keep / Problem / Try)
+
+- Keep 답변은 팀의 강점과 긍정 감정(성취, 뿌듯, 감사)의 근거로 사용해요.
+- Problem 답변은 아쉬움과 부정 감정(피로, 압박, 답답)의 근거로 사용해요.
+- Try 답변은 개인 미션의 출발점이에요. 본인이 적은 Try를 실천 가능한 행동으로 구체화해요.
+
+예시)
+- 입력: [Problem] "리뷰가 밀려서 배포가 늦어졌어요" / [Try] "PR을 작게 나눠볼래요"
+- emotionRank: { "label": "답답", "description": "리뷰 지연으로 배포가 늦어져 답답함을 느꼈어요" }
+- missions: { "missionTitle": "PR 작게 나누기", "missionDesc": "리뷰 지연이 아쉬웠던 만큼 PR을 기능 단위로 나누면 배포 흐름이 빨라져요." }"#
+            }
+            RetrospectMethod::FourL => {
+                r#"## 회고 방식: 4L (Liked / Learned / Lacked / Longed for)
+
+- Liked 답변은 긍정 감정, Lacked 답변은 아쉬움과 부정 감정의 근거로 사용해요.
+- Learned 답변에서 드러난 성장을 인사이트에 반영해요.
+- Longed for 답변은 개인 미션의 출발점이에요. 바라는 점을 스스로 할 수 있는 행동으로 바꿔요.
+
+예시)
+- 입력: [Lacked] "기획 공유가 늦어 불안했어요" / [Longed for] "초기에 기획 싱크를 맞추고 싶어요"
+- emotionRank: { "label": "불안", "description": "기획 공유가 늦어 불안함을 느꼈어요" }
+- missions: { "missionTitle": "초기 기획 싱크 제안하기", "missionDesc": "공유 지연으로 불안했던 만큼 킥오프 때 기획 싱크를 먼저 제안하면 좋아요." }"#
+            }
+            RetrospectMethod::FiveF => {
+                r#"## 회고 방식: 5F (Facts / Feelings / Findings / Future / Feedback)
+
+- Feelings 답변을 감정 랭킹의 1차 근거로, Facts 답변을 감정의 원인 설명에 사용해요.
+- Findings 답변에서 드러난 발견을 인사이트에 반영해요.
+- Future 답변은 개인 미션의 출발점이고, Feedback 답변은 팀 관계에 대한 미션에 참고해요.
+
+예시)
+- 입력: [Feelings] "마감 직전 뿌듯했지만 지쳤어요" / [Future] "일정을 미리 나눠두고 싶어요"
+- emotionRank: { "label": "피로", "description": "마감 직전 몰아서 작업해 피로함을 느꼈어요" }
+- missions: { "missionTitle": "일정 미리 쪼개기", "missionDesc": "마감 직전 지쳤던 경험을 바탕으로 작업을 주 단위로 나눠두면 여유가 생겨요." }"#
+            }
+            RetrospectMethod::Pmi => {
+                r#"## 회고 방식: PMI (Plus / Minus / Interesting)
+
+- Plus 답변은 긍정 감정, Minus 답변은 부정 감정의 근거로 사용해요.
+- Interesting 답변은 긍정/부정으로 나누지 말고 새로운 발견으로 인사이트에 반영해요.
+- 개인 미션은 Plus를 유지하고 Minus를 줄이는 방향으로 제안해요.
+
+예시)
+- 입력: [Minus] "회의가 길어져 집중이 깨졌어요" / [Interesting] "비동기 공유가 의외로 효과적이었어요"
+- emotionRank: { "label": "답답", "description": "긴 회의로 집중이 깨져 답답함을 느꼈어요" }
+- missions: { "missionTitle": "비동기 공유 늘리기", "missionDesc": "긴 회의가 아쉬웠던 만큼 효과를 본 비동기 공유를 더 활용해보면 좋아요." }"#
+            }
+            RetrospectMethod::Sailboat => {
+                r#"## 회고 방식: Sailboat (Wind / Anchor / Rocks / Island)
+
+- Wind(순풍) 답변은 긍정 감정, Anchor(닻) 답변은 부정 감정의 근거로 사용해요.
+- Rocks(암초) 답변은 앞으로의 위험 요소로 인사이트에 반영해요.
+- Island(목표) 답변을 개인 미션의 도착점으로 삼고, 닻과 암초를 피하는 행동으로 제안해요.
+
+예시)
+- 입력: [Anchor] "리뷰 대기가 길어 작업이 자주 멈췄어요" / [Island] "다음 스프린트엔 배포를 매주 하고 싶어요"
+- emotionRank: { "label": "답답", "description": "리뷰 대기로 작업이 멈춰 답답함을 느꼈어요" }
+- missions: { "missionTitle": "리뷰 요청 작게 나누기", "missionDesc": "리뷰 대기가 닻이 되었던 만큼 PR을 작게 나누면 매주 배포라는 목표에 가까워져요." }"#
+            }
+            RetrospectMethod::Daki => {
+                r#"## 회고 방식: DAKI (Drop / Add / Keep / Improve)
+
+- Keep(유지할 것) 답변은 긍정 감정, Drop(버릴 것) 답변은 부정 감정의 근거로 사용해요.
+- Add(추가할 것)와 Improve(개선할 것) 답변은 인사이트와 개인 미션의 직접적인 재료로 활용해요.
+- 미션은 버릴 것을 줄이고 추가·개선할 것을 실천하는 구체적인 행동으로 제안해요.
+
+예시)
+- 입력: [Drop] "결론 없이 길어지는 회의는 그만하고 싶어요" / [Add] "회의 전에 안건을 미리 공유하면 좋겠어요"
+- emotionRank: { "label": "피로", "description": "결론 없는 긴 회의로 피로감을 느꼈어요" }
+- missions: { "missionTitle": "회의 안건 미리 공유하기", "missionDesc": "길어지는 회의가 부담이었던 만큼 안건을 먼저 공유하면 회의를 짧게 끝낼 수 있어요." }"#
+            }
+            RetrospectMethod::Free => {
+                r#"## 회고 방식: 자유 형식
+
+- 질문 구분이 없으므로 답변 전체에서 긍정/부정 감정과 다음 행동을 직접 찾아요.
+- 다음에 하고 싶은 일이나 개선 의지가 드러난 문장을 개인 미션의 출발점으로 사용해요."#
+            }
+            RetrospectMethod::Custom => {
+                r#"## 회고 방식: 팀 커스텀 방식
+
+- 팀이 직접 정의한 질문이므로 각 질문의 의도를 질문 문장에서 파악해요.
+- 질문 구분과 관계없이 답변 전체에서 긍정/부정 감정과 다음 행동을 찾아요.
+- 앞으로의 다짐이나 개선 의지가 드러난 답변을 개인 미션의 출발점으로 사용해요."#
+            }
+        }
+    }
+
+    fn base_system_prompt() -> &'static str {
         r#"당신은 팀 회고 데이터를 종합 분석하는 따뜻한 AI 분석가입니다.
 팀원들이 작성한 회고 답변을 분석하여 인사이트, 감정 통계, 개인별 맞춤 미션을 생성합니다.
 
@@ -40,7 +160,7 @@ impl AnalysisPrompt {
 - count: 해당 감정과 연관된 응답 수 (추정치)
 
 ### 3. 개인 미션 (personalMissions)
-- 각 팀원의 답변을 근거로 성장 미션 3개를 제안해요.
+- 각 팀원의 답변을 근거로 성장 미션 {missions_per_user}개를 제안해요.
 - missionTitle: 동사형 행동 미션 (예: "감정 표현 적극적으로 하기", "스프린트 분량 조절하기")
 - missionDesc: 해당 팀원의 답변에서 드러난 근거를 바탕으로 구체적인 제안을 작성해요. 상냥체(~어요)로 작성하세요.
   - 좋은 예: "즉각적인 응답과 활발한 협업툴 사용은 팀 운영의 안정성을 높였고, 스프린트 분량 조절과 작은 PR 단위로 나누면 더 효율적인 리뷰가 가능해져요."
@@ -100,16 +220,28 @@ impl AnalysisPrompt {
 1. 모든 텍스트는 상냥체(~어요/했어요)로 작성합니다. 격식체(~습니다) 절대 금지.
 2. emotionRank는 반드시 정확히 3개여야 합니다.
 3. emotionRank의 description은 1문장, 최대 30자 내외로 짧게 작성합니다.
-4. 각 사용자의 missions는 반드시 정확히 3개여야 합니다.
+4. 각 사용자의 missions는 반드시 정확히 {missions_per_user}개여야 합니다. 출력 형식 예시의 미션 수와 달라도 이 개수를 따릅니다.
 5. emotionRank는 count 기준 내림차순으로 정렬합니다.
 6. personalMissions는 입력 데이터의 userId를 그대로 사용합니다.
 7. JSON 형식만 출력합니다. 마크다운 코드 블록이나 추가 설명을 포함하지 마세요."#
-            .to_string()
     }
 
     /// 회고 분석 사용자 프롬프트 생성
-    pub fn user_prompt(members_data: &[MemberAnswerData]) -> String {
-        let mut prompt = String::from("다음 팀원들의 회고 답변을 종합 분석해주세요.\n\n");
+    pub fn user_prompt(method: &RetrospectMethod, members_data: &[MemberAnswerData]) -> String {
+        let mut prompt = format!(
+            "다음 팀원들의 {} 회고 답변을 종합 분석해주세요.\n\n",
+            method
+        );
+
+        let weighted = members_data
+            .iter()
+            .flat_map(|m| &m.answers)
+            .any(|a| a.weight.is_some());
+        if weighted {
+            prompt.push_str(
+                "질문별 가중치(1~5)가 표시되어 있습니다. 가중치가 높은 질문의 답변을 인사이트와 미션에 더 비중 있게 반영하고, 표시가 없는 질문은 보통 비중으로 다뤄주세요.\n\n",
+            );
+        }
 
         for member in members_data {
             prompt.push_str(&format!(
@@ -117,15 +249,26 @@ impl AnalysisPrompt {
                 member.user_id, member.user_name
             ));
 
-            for (i, (question, answer)) in member.answers.iter().enumerate() {
+            for (i, item) in member.answers.iter().enumerate() {
+                let category = item
+                    .category
+                    .as_deref()
+                    .map(|c| format!("[{}] ", c))
+                    .unwrap_or_default();
+                let weight = item
+                    .weight
+                    .map(|w| format!(" (가중치 {})", w))
+                    .unwrap_or_default();
                 prompt.push_str(&format!(
-                    "- Q{}: {}\n  A: {}\n",
+                    "- Q{}: {}{}{}\n  A: {}\n",
                     i + 1,
-                    question,
-                    if answer.trim().is_empty() {
+                    category,
+                    item.question,
+                    weight,
+                    if item.answer.trim().is_empty() {
                         "(답변 없음)"
                     } else {
-                        answer
+                        &item.answer
                     }
                 ));
             }
@@ -271,6 +414,152 @@ impl AssistantPrompt {
     }
 }
 
+/// 답변 피드백 프롬프트 템플릿
+pub struct FeedbackPrompt;
+
+impl FeedbackPrompt {
+    /// 답변 피드백 시스템 프롬프트 생성
+    pub fn system_prompt() -> String {
+        r#"당신은 회고 답변의 품질을 높여주는 따뜻한 AI 코치입니다.
+사용자가 제출 전에 작성 중인 답변을 검토하고, 구체성과 건설성 관점에서 개선 제안을 제공합니다.
+
+## 말투 규칙 (매우 중요)
+
+모든 문장은 반드시 상냥체(~어요, ~면 좋아요, ~해보면 좋아요)로 작성합니다.
+격식체(~습니다, ~하세요)를 절대 사용하지 마세요.
+
+## 검토 관점
+
+- SPECIFICITY (구체성): 언제, 어떤 상황에서, 무엇을 했는지 사례와 근거가 드러나는지 확인합니다.
+- CONSTRUCTIVENESS (건설성): 비난이나 감정 표출에 그치지 않고, 다음 행동이나 개선 방향으로 이어지는지 확인합니다.
+
+## 출력 형식
+
+반드시 아래 JSON 형식만 출력하세요. JSON 외의 텍스트를 포함하지 마세요.
+
+```json
+{
+  "summary": "경험은 잘 드러나 있어요. 다음에 시도할 행동을 덧붙이면 더 좋아요",
+  "suggestions": [
+    {
+      "aspect": "SPECIFICITY",
+      "title": "구체적인 장면 덧붙이기",
+      "description": "일정이 밀렸던 시점과 그때 겪은 상황을 한 문장으로 덧붙이면 좋아요"
+    },
+    {
+      "aspect": "CONSTRUCTIVENESS",
+      "title": "다음 행동 정하기",
+      "description": "같은 문제가 생기지 않도록 다음 스프린트에서 해볼 일을 적어보면 좋아요"
+    }
+  ]
+}
+```
+
+## 규칙
+
+1. summary는 답변에 대한 총평을 1문장으로 작성합니다 (최대 60자).
+2. suggestions는 1~3개를 생성하며, aspect는 SPECIFICITY 또는 CONSTRUCTIVENESS 중 하나입니다.
+3. title은 "~하기" 형태의 행동 지침으로 작성합니다 (최대 15자).
+4. description은 사용자의 답변 내용을 참조하여 구체적인 개선 방법을 1문장으로 작성합니다 (최대 60자).
+5. 답변을 대신 작성하지 말고, 사용자가 스스로 보완할 수 있도록 방향만 제시합니다.
+6. JSON 형식만 출력합니다. 마크다운 코드 블록이나 추가 설명을 포함하지 마세요."#
+            .to_string()
+    }
+
+    /// 답변 피드백 사용자 프롬프트 생성
+    pub fn user_prompt(question_content: &str, draft: &str) -> String {
+        format!(
+            r#"다음 회고 질문과 사용자가 작성 중인 답변을 검토하여 구체성과 건설성 관점의 개선 제안을 생성해주세요.
+
+## 질문
+{}
+
+## 사용자가 작성 중인 답변
+{}
+
+위 답변이 더 구체적이고 건설적인 회고가 되도록 코칭해주세요."#,
+            question_content, draft
+        )
+    }
+}
+
+/// 답변 한 줄 요약 프롬프트 템플릿
+pub struct SummaryPrompt;
+
+impl SummaryPrompt {
+    /// 답변 요약 시스템 프롬프트 생성
+    pub fn system_prompt() -> String {
+        r#"당신은 팀 회고 답변을 한 줄로 정리하는 AI입니다.
+목록에서 빠르게 훑어볼 수 있도록 답변의 핵심만 짧게 요약합니다.
+
+## 규칙
+1. 요약은 반드시 한 문장, 공백 포함 50자 이내로 작성합니다.
+2. 답변에 없는 내용을 추측하거나 덧붙이지 않습니다.
+3. 상냥체(~어요/했어요)로 작성합니다.
+4. 아래 JSON 형식만 출력합니다. 마크다운 코드 블록이나 추가 설명을 포함하지 마세요.
+
+{"summary": "리뷰가 밀려 배포가 늦어진 점이 아쉬웠어요"}"#
+            .to_string()
+    }
+
+    /// 답변 요약 사용자 프롬프트 생성
+    pub fn user_prompt(question_content: &str, content: &str) -> String {
+        format!(
+            r#"다음 회고 답변을 한 줄로 요약해주세요.
+
+## 질문
+{}
+
+## 답변
+{}"#,
+            question_content, content
+        )
+    }
+}
+
+/// 답변 자동 분류 대상 (응답 ID, 질문, 답변)
+pub struct CategorizeItem {
+    pub response_id: i64,
+    pub question: String,
+    pub content: String,
+}
+
+/// 답변 카테고리 자동 분류 프롬프트 템플릿
+pub struct CategorizePrompt;
+
+impl CategorizePrompt {
+    /// 답변 분류 시스템 프롬프트 생성
+    pub fn system_prompt() -> String {
+        r#"당신은 팀 회고 답변을 Keep/Problem/Try로 분류하는 AI입니다.
+각 답변의 내용을 읽고 가장 알맞은 카테고리 하나를 고릅니다.
+
+## 카테고리
+- KEEP: 잘 된 점, 앞으로도 유지하고 싶은 점
+- PROBLEM: 아쉬웠던 점, 문제였던 점
+- TRY: 다음에 새롭게 시도하거나 개선하고 싶은 점
+
+## 규칙
+1. 모든 답변에 대해 responseId를 그대로 사용해 결과를 하나씩 작성합니다.
+2. 어느 카테고리에도 해당하지 않으면 "UNCATEGORIZED"로 작성합니다.
+3. 아래 JSON 형식만 출력합니다. 마크다운 코드 블록이나 추가 설명을 포함하지 마세요.
+
+{"categories": [{"responseId": 1, "category": "KEEP"}, {"responseId": 2, "category": "TRY"}]}"#
+            .to_string()
+    }
+
+    /// 답변 분류 사용자 프롬프트 생성
+    pub fn user_prompt(items: &[CategorizeItem]) -> String {
+        let mut prompt = String::from("다음 회고 답변들을 분류해주세요.\n");
+        for item in items {
+            prompt.push_str(&format!(
+                "\n## responseId: {}\n질문: {}\n답변: {}\n",
+                item.response_id, item.question, item.content
+            ));
+        }
+        prompt
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -278,7 +567,7 @@ mod tests {
     #[test]
     fn should_generate_analysis_system_prompt() {
         // Act
-        let prompt = AnalysisPrompt::system_prompt();
+        let prompt = AnalysisPrompt::system_prompt(&RetrospectMethod::Kpt, 3);
 
         // Assert
         assert!(prompt.contains("종합 분석"));
@@ -288,6 +577,40 @@ mod tests {
         assert!(prompt.contains("정확히 3개"));
     }
 
+    #[test]
+    fn should_use_configured_mission_count_in_analysis_system_prompt() {
+        // Act
+        let prompt = AnalysisPrompt::system_prompt(&RetrospectMethod::Kpt, 5);
+
+        // Assert
+        assert!(prompt.contains("성장 미션 5개"));
+        assert!(prompt.contains("missions는 반드시 정확히 5개"));
+        assert!(!prompt.contains(MISSIONS_PER_USER_PLACEHOLDER));
+    }
+
+    #[test]
+    fn should_include_method_guide_in_analysis_system_prompt() {
+        // Act
+        let kpt = AnalysisPrompt::system_prompt(&RetrospectMethod::Kpt, 3);
+        let pmi = AnalysisPrompt::system_prompt(&RetrospectMethod::Pmi, 3);
+
+        // Assert
+        assert!(kpt.contains("회고 방식: KPT"));
+        assert!(kpt.contains("Try 답변은 개인 미션의 출발점"));
+        assert!(!kpt.contains("회고 방식: PMI"));
+        assert!(pmi.contains("회고 방식: PMI"));
+        assert!(pmi.contains("Interesting"));
+    }
+
+    fn answer(question: &str, answer: &str, category: Option<&str>) -> AnswerData {
+        AnswerData {
+            question: question.to_string(),
+            answer: answer.to_string(),
+            category: category.map(str::to_string),
+            weight: None,
+        }
+    }
+
     #[test]
     fn should_generate_analysis_user_prompt_with_members() {
         // Arrange
@@ -296,30 +619,31 @@ mod tests {
                 user_id: 1,
                 user_name: "소은".to_string(),
                 answers: vec![
-                    (
-                        "유지하고 싶은 점은?".to_string(),
-                        "협업이 좋았어요".to_string(),
+                    answer(
+                        "유지하고 싶은 점은?",
+                        "협업이 좋았어요",
+                        Some("Keep(유지할 점)"),
                     ),
-                    ("문제점은?".to_string(), "시간이 부족했음".to_string()),
+                    answer("문제점은?", "시간이 부족했음", Some("Problem(문제점)")),
                 ],
             },
             MemberAnswerData {
                 user_id: 2,
                 user_name: "민수".to_string(),
                 answers: vec![
-                    (
-                        "유지하고 싶은 점은?".to_string(),
-                        "코드 리뷰가 도움이 됨".to_string(),
-                    ),
-                    ("문제점은?".to_string(), "일정 관리 필요".to_string()),
+                    answer("유지하고 싶은 점은?", "코드 리뷰가 도움이 됨", None),
+                    answer("문제점은?", "일정 관리 필요", None),
                 ],
             },
         ];
 
         // Act
-        let prompt = AnalysisPrompt::user_prompt(&members);
+        let prompt = AnalysisPrompt::user_prompt(&RetrospectMethod::Kpt, &members);
 
         // Assert
+        assert!(prompt.contains("KPT 회고"));
+        assert!(prompt.contains("Q1: [Keep(유지할 점)] 유지하고 싶은 점은?"));
+        assert!(prompt.contains("Q2: 문제점은?"));
         assert!(prompt.contains("userId: 1"));
         assert!(prompt.contains("소은"));
         assert!(prompt.contains("협업이 좋았어요"));
@@ -334,16 +658,45 @@ mod tests {
         let members = vec![MemberAnswerData {
             user_id: 1,
             user_name: "테스트".to_string(),
-            answers: vec![("질문1".to_string(), "".to_string())],
+            answers: vec![answer("질문1", "", None)],
         }];
 
         // Act
-        let prompt = AnalysisPrompt::user_prompt(&members);
+        let prompt = AnalysisPrompt::user_prompt(&RetrospectMethod::Free, &members);
 
         // Assert
         assert!(prompt.contains("(답변 없음)"));
     }
 
+    #[test]
+    fn should_mark_question_weights_in_analysis_prompt() {
+        // Arrange
+        let mut weighted = answer("가장 중요한 개선점은?", "배포 자동화", None);
+        weighted.weight = Some(5);
+        let members = vec![MemberAnswerData {
+            user_id: 1,
+            user_name: "소은".to_string(),
+            answers: vec![weighted, answer("기타 의견은?", "없음", None)],
+        }];
+
+        // Act
+        let prompt = AnalysisPrompt::user_prompt(&RetrospectMethod::Custom, &members);
+        let unweighted = AnalysisPrompt::user_prompt(
+            &RetrospectMethod::Custom,
+            &[MemberAnswerData {
+                user_id: 1,
+                user_name: "소은".to_string(),
+                answers: vec![answer("기타 의견은?", "없음", None)],
+            }],
+        );
+
+        // Assert
+        assert!(prompt.contains("Q1: 가장 중요한 개선점은? (가중치 5)"));
+        assert!(prompt.contains("Q2: 기타 의견은?\n"));
+        assert!(prompt.contains("가중치가 높은 질문의 답변"));
+        assert!(!unweighted.contains("가중치"));
+    }
+
     // ===== AssistantPrompt 테스트 =====
 
     #[test]
@@ -398,4 +751,77 @@ mod tests {
         assert!(prompt.contains(content));
         assert!(prompt.contains("더 풍부하고 구체적"));
     }
+
+    // ===== FeedbackPrompt 테스트 =====
+
+    #[test]
+    fn should_generate_feedback_system_prompt() {
+        // Act
+        let prompt = FeedbackPrompt::system_prompt();
+
+        // Assert
+        assert!(prompt.contains("SPECIFICITY"));
+        assert!(prompt.contains("CONSTRUCTIVENESS"));
+        assert!(prompt.contains("summary"));
+        assert!(prompt.contains("suggestions"));
+    }
+
+    #[test]
+    fn should_generate_feedback_user_prompt_with_draft() {
+        // Arrange
+        let question = "이번 작업/프로젝트에서 아쉬웠던 점은 무엇인가요?";
+        let draft = "일정이 너무 빠듯했다.";
+
+        // Act
+        let prompt = FeedbackPrompt::user_prompt(question, draft);
+
+        // Assert
+        assert!(prompt.contains(question));
+        assert!(prompt.contains(draft));
+        assert!(prompt.contains("구체성과 건설성"));
+    }
+
+    // ===== SummaryPrompt 테스트 =====
+
+    #[test]
+    fn should_generate_summary_prompts() {
+        // Act
+        let system = SummaryPrompt::system_prompt();
+        let user = SummaryPrompt::user_prompt("문제점은?", "리뷰가 늦어졌어요");
+
+        // Assert
+        assert!(system.contains("한 문장"));
+        assert!(system.contains("summary"));
+        assert!(user.contains("문제점은?"));
+        assert!(user.contains("리뷰가 늦어졌어요"));
+    }
+
+    // ===== CategorizePrompt 테스트 =====
+
+    #[test]
+    fn should_include_every_response_id_in_categorize_prompt() {
+        // Arrange
+        let items = vec![
+            CategorizeItem {
+                response_id: 11,
+                question: "자유롭게 작성해주세요".to_string(),
+                content: "페어 프로그래밍이 좋았어요".to_string(),
+            },
+            CategorizeItem {
+                response_id: 12,
+                question: "자유롭게 작성해주세요".to_string(),
+                content: "배포가 늦어졌어요".to_string(),
+            },
+        ];
+
+        // Act
+        let system = CategorizePrompt::system_prompt();
+        let user = CategorizePrompt::user_prompt(&items);
+
+        // Assert
+        assert!(system.contains("UNCATEGORIZED"));
+        assert!(user.contains("responseId: 11"));
+        assert!(user.contains("responseId: 12"));
+        assert!(user.contains("배포가 늦어졌어요"));
+    }
 }
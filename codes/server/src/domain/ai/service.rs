@@ -1,4 +1,5 @@
-use std::time::Duration;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
 
 use async_openai::{
     config::OpenAIConfig,
@@ -8,13 +9,25 @@ use async_openai::{
     },
     Client,
 };
+use chrono::Utc;
+use sea_orm::{ActiveEnum, ActiveModelTrait, DatabaseConnection, Set};
 use tracing::{info, instrument, warn};
 
 use crate::config::AppConfig;
-use crate::domain::retrospect::dto::{AnalysisResponse, GuideItem};
+use crate::domain::ai::entity::ai_usage_log::{self, AiUsagePurpose};
+use crate::domain::retrospect::dto::{AnalysisResponse, FeedbackSuggestionItem, GuideItem};
+use crate::domain::retrospect::entity::response::AnswerCategory;
+use crate::domain::retrospect::entity::retrospect::RetrospectMethod;
+use crate::monitoring::metrics::{self, AiCallOutcome};
 use crate::utils::AppError;
 
-use super::prompt::{AnalysisPrompt, AssistantPrompt, MemberAnswerData};
+use super::prompt::{
+    AnalysisPrompt, AssistantPrompt, CategorizeItem, CategorizePrompt, FeedbackPrompt,
+    MemberAnswerData, SummaryPrompt,
+};
+
+/// 답변 한 줄 요약 최대 길이 (문자 수)
+const RESPONSE_SUMMARY_MAX_CHARS: usize = 100;
 
 /// 어시스턴트 가이드 응답 (내부용)
 #[derive(Debug, serde::Deserialize)]
@@ -22,10 +35,80 @@ pub struct AssistantGuideRaw {
     pub guides: Vec<GuideItem>,
 }
 
+/// 답변 요약 응답 (내부용)
+#[derive(Debug, serde::Deserialize)]
+pub struct ResponseSummaryRaw {
+    pub summary: String,
+}
+
+/// 답변 분류 응답 (내부용)
+#[derive(Debug, serde::Deserialize)]
+pub struct CategorizeRaw {
+    #[serde(default)]
+    pub categories: Vec<CategorizeRawItem>,
+}
+
+/// 답변 분류 응답 항목 (내부용)
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategorizeRawItem {
+    pub response_id: i64,
+    pub category: String,
+}
+
+/// 답변 피드백 응답 (내부용)
+#[derive(Debug, serde::Deserialize)]
+pub struct AnswerFeedbackRaw {
+    pub summary: String,
+    pub suggestions: Vec<FeedbackSuggestionItem>,
+}
+
+/// AI 호출 사용량 기록 컨텍스트 (누가, 어떤 회고에서, 어떤 용도로 호출했는지)
+#[derive(Debug, Clone)]
+pub struct AiUsageContext {
+    pub member_id: Option<i64>,
+    pub retrospect_id: Option<i64>,
+    pub purpose: AiUsagePurpose,
+}
+
+impl AiUsageContext {
+    pub fn new(purpose: AiUsagePurpose, member_id: i64, retrospect_id: i64) -> Self {
+        Self {
+            member_id: Some(member_id),
+            retrospect_id: Some(retrospect_id),
+            purpose,
+        }
+    }
+}
+
+/// OpenAI 호출 응답 (파싱 전 원문과 메트릭 기록용 정보)
+struct AiRawResponse {
+    content: String,
+    purpose: AiUsagePurpose,
+    elapsed: Duration,
+}
+
+impl AiRawResponse {
+    /// 응답 파싱·검증 결과까지 반영해 AI 호출 메트릭 기록
+    ///
+    /// HTTP 호출이 성공해도 응답을 사용할 수 없으면 `parse_error`로 집계합니다.
+    fn record_outcome<T>(&self, parsed: &Result<T, AppError>) {
+        let outcome = match parsed {
+            Ok(_) => AiCallOutcome::Success,
+            Err(_) => AiCallOutcome::ParseError,
+        };
+        metrics::record_ai_call(&self.purpose.to_value(), outcome, self.elapsed);
+    }
+}
+
 /// AI 서비스
 #[derive(Clone)]
 pub struct AiService {
     client: Client<OpenAIConfig>,
+    /// 토큰 사용량 로그 저장용 DB 연결 (없으면 tracing 로그만 남김)
+    usage_db: Option<DatabaseConnection>,
+    /// 회고 분석 시 팀원별 개인 미션 수
+    missions_per_user: usize,
 }
 
 impl AiService {
@@ -34,24 +117,54 @@ impl AiService {
         let openai_config = OpenAIConfig::new().with_api_key(&config.openai_api_key);
         let client = Client::with_config(openai_config);
 
-        Self { client }
+        Self {
+            client,
+            usage_db: None,
+            missions_per_user: config.missions_per_user,
+        }
+    }
+
+    /// 토큰 사용량을 `ai_usage_log` 테이블에 기록하도록 설정
+    pub fn with_usage_logging(mut self, db: DatabaseConnection) -> Self {
+        self.usage_db = Some(db);
+        self
     }
 
     /// 회고 종합 분석 (API-022)
     #[instrument(skip(self, members_data), fields(member_count = members_data.len()))]
     pub async fn analyze_retrospective(
         &self,
+        method: &RetrospectMethod,
         members_data: &[MemberAnswerData],
+        usage: AiUsageContext,
     ) -> Result<AnalysisResponse, AppError> {
-        info!("회고 종합 분석 시작 (참여자 {}명)", members_data.len());
+        info!(
+            "회고 종합 분석 시작 (방식 {}, 참여자 {}명)",
+            method,
+            members_data.len()
+        );
+
+        let system_prompt = AnalysisPrompt::system_prompt(method, self.missions_per_user);
+        let user_prompt = AnalysisPrompt::user_prompt(method, members_data);
 
-        let system_prompt = AnalysisPrompt::system_prompt();
-        let user_prompt = AnalysisPrompt::user_prompt(members_data);
+        let raw = self
+            .call_openai(&system_prompt, &user_prompt, usage)
+            .await?;
+        let parsed = Self::parse_analysis(&raw.content);
+        raw.record_outcome(&parsed);
+        let mut analysis = parsed?;
 
-        let raw_response = self.call_openai(&system_prompt, &user_prompt).await?;
+        // 응답 보정: 각 사용자의 missions를 설정 개수에 맞춤
+        Self::normalize_mission_counts(&mut analysis, self.missions_per_user);
 
+        info!("회고 종합 분석 완료");
+        Ok(analysis)
+    }
+
+    /// 회고 분석 응답 파싱 및 검증
+    fn parse_analysis(raw_response: &str) -> Result<AnalysisResponse, AppError> {
         // JSON 파싱 (코드 블록 제거 후 파싱 시도)
-        let json_str = Self::extract_json(&raw_response);
+        let json_str = Self::extract_json(raw_response);
         let analysis: AnalysisResponse = serde_json::from_str(json_str).map_err(|e| {
             warn!("AI 응답 JSON 파싱 실패: {}", e);
             warn!(
@@ -69,19 +182,31 @@ impl AiService {
             )));
         }
 
-        // 응답 검증: 각 사용자의 missions 정확히 3개
-        for pm in &analysis.personal_missions {
-            if pm.missions.len() != 3 {
-                return Err(AppError::AiAnalysisFailed(format!(
-                    "사용자 {}의 미션이 3개여야 하지만 {}개입니다",
+        Ok(analysis)
+    }
+
+    /// 사용자별 미션 수를 설정 개수에 맞게 보정
+    ///
+    /// 초과분은 잘라내고, 부족하면 분석을 실패시키지 않고 경고만 남깁니다.
+    fn normalize_mission_counts(analysis: &mut AnalysisResponse, missions_per_user: usize) {
+        for pm in &mut analysis.personal_missions {
+            if pm.missions.len() > missions_per_user {
+                warn!(
+                    "사용자 {}의 미션이 {}개여야 하지만 {}개입니다. 초과분을 잘라냅니다",
+                    pm.user_id,
+                    missions_per_user,
+                    pm.missions.len()
+                );
+                pm.missions.truncate(missions_per_user);
+            } else if pm.missions.len() < missions_per_user {
+                warn!(
+                    "사용자 {}의 미션이 {}개여야 하지만 {}개입니다",
                     pm.user_id,
+                    missions_per_user,
                     pm.missions.len()
-                )));
+                );
             }
         }
-
-        info!("회고 종합 분석 완료");
-        Ok(analysis)
     }
 
     /// 회고 어시스턴트 가이드 생성 (API-029)
@@ -90,6 +215,7 @@ impl AiService {
         &self,
         question_content: &str,
         user_content: Option<&str>,
+        usage: AiUsageContext,
     ) -> Result<Vec<GuideItem>, AppError> {
         let (system_prompt, user_prompt) = match user_content {
             Some(content) if !content.trim().is_empty() => {
@@ -108,10 +234,21 @@ impl AiService {
             }
         };
 
-        let raw_response = self.call_openai(&system_prompt, &user_prompt).await?;
+        let raw = self
+            .call_openai(&system_prompt, &user_prompt, usage)
+            .await?;
+        let parsed = Self::parse_assistant_guides(&raw.content);
+        raw.record_outcome(&parsed);
+        let guides = parsed?;
+
+        info!("어시스턴트 가이드 생성 완료");
+        Ok(guides)
+    }
 
+    /// 어시스턴트 가이드 응답 파싱 및 검증
+    fn parse_assistant_guides(raw_response: &str) -> Result<Vec<GuideItem>, AppError> {
         // JSON 파싱
-        let json_str = Self::extract_json(&raw_response);
+        let json_str = Self::extract_json(raw_response);
         let guide_response: AssistantGuideRaw = serde_json::from_str(json_str).map_err(|e| {
             warn!("AI 응답 JSON 파싱 실패: {}", e);
             warn!(
@@ -130,10 +267,151 @@ impl AiService {
             )));
         }
 
-        info!("어시스턴트 가이드 생성 완료");
         Ok(guide_response.guides)
     }
 
+    /// 제출 전 답변 피드백 생성
+    #[instrument(skip(self, draft), fields(draft_len = draft.chars().count()))]
+    pub async fn review_answer(
+        &self,
+        question_content: &str,
+        draft: &str,
+        usage: AiUsageContext,
+    ) -> Result<AnswerFeedbackRaw, AppError> {
+        info!("답변 피드백 생성 요청");
+
+        let system_prompt = FeedbackPrompt::system_prompt();
+        let user_prompt = FeedbackPrompt::user_prompt(question_content, draft);
+
+        let raw = self
+            .call_openai(&system_prompt, &user_prompt, usage)
+            .await?;
+        let parsed = Self::parse_answer_feedback(&raw.content);
+        raw.record_outcome(&parsed);
+        let feedback = parsed?;
+
+        info!("답변 피드백 생성 완료");
+        Ok(feedback)
+    }
+
+    /// 답변 피드백 응답 파싱 및 검증
+    fn parse_answer_feedback(raw_response: &str) -> Result<AnswerFeedbackRaw, AppError> {
+        // JSON 파싱
+        let json_str = Self::extract_json(raw_response);
+        let feedback: AnswerFeedbackRaw = serde_json::from_str(json_str).map_err(|e| {
+            warn!("AI 응답 JSON 파싱 실패: {}", e);
+            warn!(
+                "AI 원본 응답 길이: {} (내용은 개인정보 보호를 위해 생략)",
+                raw_response.len()
+            );
+            AppError::AiAnalysisFailed(format!("AI 응답을 파싱할 수 없습니다: {}", e))
+        })?;
+
+        // 응답 검증: suggestions 1~3개
+        let suggestion_count = feedback.suggestions.len();
+        if suggestion_count == 0 || suggestion_count > 3 {
+            return Err(AppError::AiAnalysisFailed(format!(
+                "피드백 제안은 1~3개여야 하지만 {}개입니다",
+                suggestion_count
+            )));
+        }
+
+        Ok(feedback)
+    }
+
+    /// 답변 한 줄 요약 생성
+    #[instrument(skip(self, content), fields(content_len = content.chars().count()))]
+    pub async fn summarize_response(
+        &self,
+        question_content: &str,
+        content: &str,
+        usage: AiUsageContext,
+    ) -> Result<String, AppError> {
+        let system_prompt = SummaryPrompt::system_prompt();
+        let user_prompt = SummaryPrompt::user_prompt(question_content, content);
+
+        let raw = self
+            .call_openai(&system_prompt, &user_prompt, usage)
+            .await?;
+        let parsed = Self::parse_summary(&raw.content);
+        raw.record_outcome(&parsed);
+        parsed
+    }
+
+    /// 답변 요약 응답 파싱 및 검증
+    fn parse_summary(raw_response: &str) -> Result<String, AppError> {
+        let json_str = Self::extract_json(raw_response);
+        let parsed: ResponseSummaryRaw = serde_json::from_str(json_str).map_err(|e| {
+            warn!("AI 응답 JSON 파싱 실패: {}", e);
+            AppError::AiAnalysisFailed(format!("AI 응답을 파싱할 수 없습니다: {}", e))
+        })?;
+
+        Self::normalize_summary(&parsed.summary)
+            .ok_or_else(|| AppError::AiAnalysisFailed("AI가 빈 요약을 반환했습니다.".to_string()))
+    }
+
+    /// 답변 카테고리 자동 분류
+    ///
+    /// 응답에서 빠졌거나 알 수 없는 카테고리로 분류된 답변은 미분류로 채워 반환합니다.
+    #[instrument(skip(self, items), fields(item_count = items.len()))]
+    pub async fn categorize_responses(
+        &self,
+        items: &[CategorizeItem],
+        usage: AiUsageContext,
+    ) -> Result<HashMap<i64, AnswerCategory>, AppError> {
+        info!("답변 자동 분류 요청");
+
+        let system_prompt = CategorizePrompt::system_prompt();
+        let user_prompt = CategorizePrompt::user_prompt(items);
+
+        let raw = self
+            .call_openai(&system_prompt, &user_prompt, usage)
+            .await?;
+        let json_str = Self::extract_json(&raw.content);
+        let parsed: Result<CategorizeRaw, AppError> = serde_json::from_str(json_str).map_err(|e| {
+            warn!("AI 응답 JSON 파싱 실패: {}", e);
+            AppError::AiAnalysisFailed(format!("AI 응답을 파싱할 수 없습니다: {}", e))
+        });
+        raw.record_outcome(&parsed);
+        let parsed = parsed?;
+
+        let response_ids: Vec<i64> = items.iter().map(|item| item.response_id).collect();
+        Ok(Self::map_categories(&response_ids, parsed))
+    }
+
+    /// AI 분류 결과를 요청한 답변 ID 기준으로 정리
+    ///
+    /// 요청하지 않은 ID는 버리고, 누락되었거나 알 수 없는 값은 미분류로 처리합니다.
+    fn map_categories(response_ids: &[i64], raw: CategorizeRaw) -> HashMap<i64, AnswerCategory> {
+        let mut result: HashMap<i64, AnswerCategory> = response_ids
+            .iter()
+            .map(|id| (*id, AnswerCategory::Uncategorized))
+            .collect();
+
+        for item in raw.categories {
+            let category = match item.category.trim().to_ascii_uppercase().as_str() {
+                "KEEP" => AnswerCategory::Keep,
+                "PROBLEM" => AnswerCategory::Problem,
+                "TRY" => AnswerCategory::Try,
+                _ => AnswerCategory::Uncategorized,
+            };
+            if let Some(slot) = result.get_mut(&item.response_id) {
+                *slot = category;
+            }
+        }
+
+        result
+    }
+
+    /// 요약 문자열을 한 줄로 정리하고 최대 길이로 자름
+    fn normalize_summary(summary: &str) -> Option<String> {
+        let one_line = summary.split_whitespace().collect::<Vec<_>>().join(" ");
+        if one_line.is_empty() {
+            return None;
+        }
+        Some(one_line.chars().take(RESPONSE_SUMMARY_MAX_CHARS).collect())
+    }
+
     /// AI 응답에서 JSON 부분 추출 (코드 블록 제거)
     fn extract_json(response: &str) -> &str {
         let trimmed = response.trim();
@@ -149,11 +427,15 @@ impl AiService {
     }
 
     /// OpenAI API 호출 (타임아웃 포함)
+    ///
+    /// 타임아웃·API 오류는 여기서 메트릭에 기록하고, 응답을 받은 호출은 호출자가 파싱·검증 후
+    /// `AiRawResponse::record_outcome`으로 기록합니다.
     async fn call_openai(
         &self,
         system_prompt: &str,
         user_prompt: &str,
-    ) -> Result<String, AppError> {
+        usage: AiUsageContext,
+    ) -> Result<AiRawResponse, AppError> {
         let messages: Vec<ChatCompletionRequestMessage> = vec![
             ChatCompletionRequestSystemMessageArgs::default()
                 .content(system_prompt)
@@ -177,8 +459,20 @@ impl AiService {
 
         let chat = self.client.chat();
         let api_call = chat.create(request);
-        let response = tokio::time::timeout(Duration::from_secs(30), api_call)
-            .await
+        let started_at = Instant::now();
+        let result = tokio::time::timeout(Duration::from_secs(30), api_call).await;
+        let elapsed = started_at.elapsed();
+        let purpose = usage.purpose.clone();
+        let failure = match &result {
+            Ok(Ok(_)) => None,
+            Ok(Err(_)) => Some(AiCallOutcome::Error),
+            Err(_) => Some(AiCallOutcome::Timeout),
+        };
+        if let Some(outcome) = failure {
+            metrics::record_ai_call(&purpose.to_value(), outcome, elapsed);
+        }
+
+        let response = result
             .map_err(|_| {
                 AppError::AiServiceUnavailable("AI 서비스 응답 시간이 초과되었습니다".to_string())
             })?
@@ -195,14 +489,64 @@ impl AiService {
                 }
             })?;
 
-        let content = response
+        // 토큰 사용량 기록 (응답 파싱 성공 여부와 무관하게 과금되므로 먼저 기록)
+        if let Some(token_usage) = &response.usage {
+            self.record_usage(
+                usage,
+                token_usage.prompt_tokens,
+                token_usage.completion_tokens,
+            );
+        }
+
+        let Some(content) = response
             .choices
             .first()
             .and_then(|choice| choice.message.content.clone())
-            .ok_or_else(|| AppError::AiGeneralError("AI 응답이 비어있습니다".to_string()))?;
+        else {
+            metrics::record_ai_call(&purpose.to_value(), AiCallOutcome::ParseError, elapsed);
+            return Err(AppError::AiGeneralError(
+                "AI 응답이 비어있습니다".to_string(),
+            ));
+        };
 
         info!("AI response received successfully");
-        Ok(content)
+        Ok(AiRawResponse {
+            content,
+            purpose,
+            elapsed,
+        })
+    }
+
+    /// 토큰 사용량 로그 저장
+    ///
+    /// 로깅 실패가 AI 응답 반환을 막지 않도록 별도 태스크에서 저장하고 실패는 경고만 남깁니다.
+    fn record_usage(&self, usage: AiUsageContext, prompt_tokens: u32, completion_tokens: u32) {
+        info!(
+            purpose = ?usage.purpose,
+            prompt_tokens = prompt_tokens,
+            completion_tokens = completion_tokens,
+            "AI 토큰 사용량"
+        );
+
+        let Some(db) = self.usage_db.clone() else {
+            return;
+        };
+
+        tokio::spawn(async move {
+            let log = ai_usage_log::ActiveModel {
+                member_id: Set(usage.member_id),
+                retrospect_id: Set(usage.retrospect_id),
+                purpose: Set(usage.purpose),
+                prompt_tokens: Set(i32::try_from(prompt_tokens).unwrap_or(i32::MAX)),
+                completion_tokens: Set(i32::try_from(completion_tokens).unwrap_or(i32::MAX)),
+                created_at: Set(Utc::now().naive_utc()),
+                ..Default::default()
+            };
+
+            if let Err(e) = log.insert(&db).await {
+                warn!("AI 토큰 사용량 로그 저장 실패: {}", e);
+            }
+        });
     }
 }
 
@@ -211,6 +555,27 @@ mod tests {
     use super::*;
     use crate::domain::retrospect::dto::AnalysisResponse;
 
+    // ===== normalize_summary 테스트 =====
+
+    #[test]
+    fn should_collapse_summary_into_single_line() {
+        // Act
+        let result = AiService::normalize_summary("  리뷰가 늦어\n배포가   밀렸어요 ");
+
+        // Assert
+        assert_eq!(result.as_deref(), Some("리뷰가 늦어 배포가 밀렸어요"));
+    }
+
+    #[test]
+    fn should_truncate_long_summary_and_reject_empty() {
+        // Act
+        let long = AiService::normalize_summary(&"가".repeat(150)).unwrap();
+
+        // Assert
+        assert_eq!(long.chars().count(), RESPONSE_SUMMARY_MAX_CHARS);
+        assert!(AiService::normalize_summary("   ").is_none());
+    }
+
     // ===== extract_json 테스트 =====
 
     #[test]
@@ -283,4 +648,97 @@ mod tests {
         assert_eq!(analysis.personal_missions.len(), 1);
         assert_eq!(analysis.personal_missions[0].missions.len(), 3);
     }
+
+    // ===== normalize_mission_counts 테스트 =====
+
+    fn analysis_with_missions(counts: &[usize]) -> AnalysisResponse {
+        use crate::domain::retrospect::dto::{MissionItem, PersonalMissionItem};
+
+        AnalysisResponse {
+            insight: "인사이트".to_string(),
+            emotion_rank: vec![],
+            personal_missions: counts
+                .iter()
+                .enumerate()
+                .map(|(i, count)| PersonalMissionItem {
+                    user_id: i as i64 + 1,
+                    user_name: format!("멤버{}", i + 1),
+                    missions: (1..=*count)
+                        .map(|n| MissionItem {
+                            mission_title: format!("미션{}", n),
+                            mission_desc: format!("설명{}", n),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn should_truncate_missions_over_configured_count() {
+        // Arrange
+        let missions_per_user = 2;
+        let mut analysis = analysis_with_missions(&[3, 2]);
+
+        // Act
+        AiService::normalize_mission_counts(&mut analysis, missions_per_user);
+
+        // Assert
+        for pm in &analysis.personal_missions {
+            assert_eq!(pm.missions.len(), missions_per_user);
+        }
+        assert_eq!(
+            analysis.personal_missions[0].missions[1].mission_title,
+            "미션2"
+        );
+    }
+
+    #[test]
+    fn should_keep_missions_under_configured_count() {
+        // Arrange
+        let mut analysis = analysis_with_missions(&[1, 5]);
+
+        // Act
+        AiService::normalize_mission_counts(&mut analysis, 5);
+
+        // Assert
+        assert_eq!(analysis.personal_missions[0].missions.len(), 1);
+        assert_eq!(analysis.personal_missions[1].missions.len(), 5);
+    }
+
+    // ===== map_categories 테스트 =====
+
+    #[test]
+    fn should_map_known_categories_case_insensitively() {
+        // Arrange
+        let raw: CategorizeRaw = serde_json::from_str(
+            r#"{"categories": [{"responseId": 1, "category": "keep"}, {"responseId": 2, "category": " TRY "}]}"#,
+        )
+        .unwrap();
+
+        // Act
+        let result = AiService::map_categories(&[1, 2], raw);
+
+        // Assert
+        assert_eq!(result[&1], AnswerCategory::Keep);
+        assert_eq!(result[&2], AnswerCategory::Try);
+    }
+
+    #[test]
+    fn should_fallback_to_uncategorized_for_missing_or_unknown_category() {
+        // Arrange
+        let raw: CategorizeRaw = serde_json::from_str(
+            r#"{"categories": [{"responseId": 1, "category": "LIKED"}, {"responseId": 99, "category": "KEEP"}]}"#,
+        )
+        .unwrap();
+
+        // Act
+        let result = AiService::map_categories(&[1, 2], raw);
+
+        // Assert
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[&1], AnswerCategory::Uncategorized);
+        assert_eq!(result[&2], AnswerCategory::Uncategorized);
+        assert!(!result.contains_key(&99));
+    }
 }
@@ -1,9 +1,10 @@
 use axum::{
-    extract::State,
+    extract::{ConnectInfo, State},
     http::{header::COOKIE, HeaderMap},
     response::IntoResponse,
     Json,
 };
+use std::net::SocketAddr;
 use utoipa;
 use validator::Validate;
 
@@ -45,6 +46,7 @@ pub async fn auth_test(user: AuthUser) -> Json<BaseResponse<String>> {
 /// 이메일 기반 로그인 (테스트/개발용)
 ///
 /// 비밀번호 없이 이메일만으로 로그인을 진행합니다. (존재하는 유저만 가능)
+/// 성공 여부와 관계없이 같은 이메일 또는 같은 IP의 시도가 설정 한도를 넘으면 일정 시간 로그인이 잠깁니다.
 /// 성공 시 accessToken, refreshToken을 쿠키로 설정합니다.
 #[utoipa::path(
     post,
@@ -52,19 +54,21 @@ pub async fn auth_test(user: AuthUser) -> Json<BaseResponse<String>> {
     request_body = EmailLoginRequest,
     responses(
         (status = 200, description = "로그인 성공", body = SuccessEmailLoginResponse),
-        (status = 401, description = "존재하지 않는 사용자", body = ErrorResponse)
+        (status = 401, description = "존재하지 않는 사용자", body = ErrorResponse),
+        (status = 429, description = "시도 횟수 초과로 로그인 잠금", body = ErrorResponse)
     ),
     tag = "Auth"
 )]
 pub async fn login_by_email(
     State(state): State<AppState>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
     Json(req): Json<EmailLoginRequest>,
 ) -> Result<impl IntoResponse, AppError> {
     req.validate()?;
 
     let jwt_expiration = state.config.jwt_expiration;
     let refresh_token_expiration = state.config.refresh_token_expiration;
-    let result = AuthService::login_by_email(state, req).await?;
+    let result = AuthService::login_by_email(state, req, addr.ip()).await?;
 
     // 쿠키 설정
     let mut headers = HeaderMap::new();
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tracing::warn;
+
+/// 로그인 시도 식별 키별 시도 기록
+#[derive(Debug, Clone, Copy)]
+struct LoginAttempt {
+    /// 잠금 시간 창 안의 시도 횟수
+    attempt_count: u32,
+    /// 마지막 시도 시각
+    last_attempt_at: Instant,
+    /// 잠금 해제 시각 (잠금 중이 아니면 None)
+    locked_until: Option<Instant>,
+}
+
+/// 로그인 시도 횟수 제한 (in-memory)
+///
+/// 이메일 로그인은 비밀번호가 없어 존재하는 이메일이면 곧바로 성공하므로,
+/// 성공/실패와 관계없이 모든 시도를 이메일 키와 IP 키 양쪽에 기록합니다.
+/// 이메일 키는 `max_attempts`, IP 키는 `ip_max_attempts`번에 도달하면 `lockout` 동안 잠급니다.
+/// 마지막 시도 후 `lockout`이 지나면 시도 횟수는 초기화됩니다.
+/// Clone 시 내부 상태를 `Arc`로 공유하므로 AppState에 넣어 전 핸들러에서 같은 기록을 사용합니다.
+#[derive(Debug, Clone)]
+pub struct LoginAttemptGuard {
+    max_attempts: u32,
+    ip_max_attempts: u32,
+    lockout: Duration,
+    attempts: Arc<Mutex<HashMap<String, LoginAttempt>>>,
+}
+
+impl LoginAttemptGuard {
+    /// IP 키 한도는 기본적으로 이메일 키 한도의 4배 (NAT 뒤 여러 사용자 고려)
+    pub fn new(max_attempts: u32, lockout: Duration) -> Self {
+        Self {
+            max_attempts,
+            ip_max_attempts: max_attempts.saturating_mul(4),
+            lockout,
+            attempts: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// IP 키 시도 한도 설정
+    pub fn with_ip_limit(mut self, ip_max_attempts: u32) -> Self {
+        self.ip_max_attempts = ip_max_attempts;
+        self
+    }
+
+    /// 로그인 시도 등록
+    ///
+    /// 이메일 키 또는 IP 키가 잠금 중이면 남은 잠금 시간을 `Err`로 반환하고 기록하지 않습니다.
+    /// 잠금이 아니면 두 키 모두에 시도를 기록합니다 (이번 시도로 한도에 도달해도 이번 시도는 허용).
+    pub fn register_attempt(&self, email: &str, ip: IpAddr) -> Result<(), Duration> {
+        self.register_attempt_at(email, ip, Instant::now())
+    }
+
+    fn register_attempt_at(&self, email: &str, ip: IpAddr, now: Instant) -> Result<(), Duration> {
+        let email_key = login_attempt_key(email);
+        let ip_key = login_ip_key(ip);
+        let mut attempts = self.lock_attempts();
+
+        // 잠금이 풀렸고 시도 기록도 오래된 키는 정리 (메모리 누수 방지)
+        let lockout = self.lockout;
+        attempts.retain(|_, attempt| {
+            attempt.locked_until.is_some_and(|until| until > now)
+                || now.duration_since(attempt.last_attempt_at) < lockout
+        });
+
+        let remaining = [&email_key, &ip_key]
+            .iter()
+            .filter_map(|key| attempts.get(key.as_str()))
+            .filter_map(|attempt| attempt.locked_until)
+            .filter(|until| *until > now)
+            .map(|until| until - now)
+            .max();
+        if let Some(remaining) = remaining {
+            return Err(remaining);
+        }
+
+        Self::record(&mut attempts, email_key, self.max_attempts, lockout, now);
+        Self::record(&mut attempts, ip_key, self.ip_max_attempts, lockout, now);
+        Ok(())
+    }
+
+    fn record(
+        attempts: &mut HashMap<String, LoginAttempt>,
+        key: String,
+        max_attempts: u32,
+        lockout: Duration,
+        now: Instant,
+    ) {
+        let attempt = attempts.entry(key).or_insert(LoginAttempt {
+            attempt_count: 0,
+            last_attempt_at: now,
+            locked_until: None,
+        });
+
+        // 잠금이 만료된 키는 새로 카운트
+        if attempt.locked_until.is_some_and(|until| until <= now) {
+            attempt.attempt_count = 0;
+            attempt.locked_until = None;
+        }
+
+        attempt.attempt_count += 1;
+        attempt.last_attempt_at = now;
+
+        if attempt.attempt_count >= max_attempts {
+            attempt.locked_until = Some(now + lockout);
+            warn!(
+                attempt_count = attempt.attempt_count,
+                lockout_secs = lockout.as_secs(),
+                "로그인 시도 한도 초과로 잠금 처리"
+            );
+        }
+    }
+
+    fn lock_attempts(&self) -> std::sync::MutexGuard<'_, HashMap<String, LoginAttempt>> {
+        match self.attempts.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("로그인 시도 기록 mutex poisoned, 복구합니다");
+                poisoned.into_inner()
+            }
+        }
+    }
+}
+
+/// 로그인 시도 식별 키 (이메일 대소문자·공백 차이로 우회하지 못하도록 정규화)
+pub fn login_attempt_key(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+/// IP 기반 로그인 시도 식별 키 (이메일 키와 겹치지 않도록 접두사 부여)
+pub fn login_ip_key(ip: IpAddr) -> String {
+    format!("ip:{}", ip)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    const LOCKOUT: Duration = Duration::from_secs(300);
+    const IP_A: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+    const IP_B: IpAddr = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+
+    #[test]
+    fn should_lock_email_after_max_attempts_even_when_attempts_succeed() {
+        // Arrange
+        let guard = LoginAttemptGuard::new(3, LOCKOUT).with_ip_limit(100);
+        let now = Instant::now();
+
+        // Act: 서로 다른 IP에서 같은 이메일로 시도
+        let first = guard.register_attempt_at("a@moalog.me", IP_A, now);
+        let second = guard.register_attempt_at("a@moalog.me", IP_B, now);
+        let third = guard.register_attempt_at("A@MoaLog.me ", IP_A, now);
+        let fourth = guard.register_attempt_at("a@moalog.me", IP_B, now);
+
+        // Assert
+        assert!(first.is_ok() && second.is_ok() && third.is_ok());
+        assert_eq!(fourth, Err(LOCKOUT));
+        assert!(guard.register_attempt_at("b@moalog.me", IP_B, now).is_ok());
+    }
+
+    #[test]
+    fn should_lock_ip_after_ip_limit_across_emails() {
+        // Arrange
+        let guard = LoginAttemptGuard::new(100, LOCKOUT).with_ip_limit(2);
+        let now = Instant::now();
+        guard.register_attempt_at("a@moalog.me", IP_A, now).unwrap();
+        guard.register_attempt_at("b@moalog.me", IP_A, now).unwrap();
+
+        // Act
+        let same_ip = guard.register_attempt_at("c@moalog.me", IP_A, now);
+        let other_ip = guard.register_attempt_at("c@moalog.me", IP_B, now);
+
+        // Assert
+        assert_eq!(same_ip, Err(LOCKOUT));
+        assert!(other_ip.is_ok());
+    }
+
+    #[test]
+    fn should_unlock_after_lockout_expires() {
+        // Arrange
+        let guard = LoginAttemptGuard::new(1, LOCKOUT);
+        let now = Instant::now();
+        guard.register_attempt_at("a@moalog.me", IP_A, now).unwrap();
+
+        // Act
+        let during = guard.register_attempt_at("a@moalog.me", IP_B, now);
+        let after = guard.register_attempt_at("a@moalog.me", IP_B, now + LOCKOUT);
+
+        // Assert
+        assert!(during.is_err());
+        assert!(after.is_ok());
+    }
+
+    #[test]
+    fn should_normalize_email_for_attempt_key() {
+        assert_eq!(login_attempt_key("  User@MoaLog.me "), "user@moalog.me");
+        assert_eq!(login_ip_key(IP_A), "ip:10.0.0.1");
+    }
+}
@@ -1,3 +1,4 @@
 pub mod dto;
 pub mod handler;
+pub mod login_guard;
 pub mod service;
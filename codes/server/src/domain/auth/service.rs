@@ -1,12 +1,14 @@
 use chrono::{Duration, Utc};
 use reqwest::Client;
 use sea_orm::{DbErr, RuntimeErr, *};
+use std::net::IpAddr;
 use std::time::Duration as StdDuration;
 
 use super::dto::{
     EmailLoginRequest, LogoutRequest, SignupRequest, SocialLoginRequest, SocialLoginResponse,
     TokenRefreshRequest,
 };
+use super::login_guard::LoginAttemptGuard;
 use crate::domain::member::entity::member::{self, Entity as Member, SocialType};
 use crate::state::AppState;
 use crate::utils::error::AppError;
@@ -234,10 +236,17 @@ impl AuthService {
     }
 
     /// 이메일 기반 로그인 (테스트/개발용)
+    ///
+    /// 비밀번호 없이 이메일만으로 로그인하므로 성공 여부와 관계없이 모든 시도를
+    /// 이메일·IP 기준으로 세고, 한도를 넘으면 잠금 시간 동안 `AccountLocked`를 반환합니다.
     pub async fn login_by_email(
         state: AppState,
         req: EmailLoginRequest,
+        client_ip: IpAddr,
     ) -> Result<EmailLoginResult, AppError> {
+        // 시도 등록 (잠금 중이면 유효한 계정이어도 거부)
+        Self::check_login_attempt(&state.login_guard, &req.email, client_ip)?;
+
         // DB에서 유저 조회 (이메일 기반)
         let member = Member::find()
             .filter(member::Column::Email.eq(&req.email))
@@ -245,8 +254,9 @@ impl AuthService {
             .await
             .map_err(|e| AppError::InternalError(format!("DB Error: {}", e)))?;
 
-        let member =
-            member.ok_or_else(|| AppError::Unauthorized("존재하지 않는 사용자입니다.".into()))?;
+        let Some(member) = member else {
+            return Err(AppError::Unauthorized("존재하지 않는 사용자입니다.".into()));
+        };
 
         // JWT 발급
         let access_token = encode_token(
@@ -277,6 +287,22 @@ impl AuthService {
         })
     }
 
+    /// 이메일 로그인 시도 등록 (이메일·IP 중 하나라도 잠금 중이면 `AccountLocked`)
+    pub fn check_login_attempt(
+        guard: &LoginAttemptGuard,
+        email: &str,
+        client_ip: IpAddr,
+    ) -> Result<(), AppError> {
+        guard
+            .register_attempt(email, client_ip)
+            .map_err(|remaining| {
+                AppError::AccountLocked(format!(
+                    "로그인 시도가 너무 많습니다. {}초 후 다시 시도해주세요.",
+                    remaining.as_secs().max(1)
+                ))
+            })
+    }
+
     /// [API-003] 토큰 갱신
     pub async fn refresh_token(
         state: AppState,
@@ -0,0 +1,224 @@
+use std::collections::HashSet;
+
+use super::entity::member_badge::BadgeType;
+
+/// 뱃지 조건 평가를 일으키는 이벤트
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadgeEvent {
+    /// 회고 제출
+    RetrospectSubmitted,
+    /// 내 답변이 좋아요를 받음
+    LikeReceived,
+}
+
+/// 뱃지 부여 조건
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BadgeCriterion {
+    /// 제출한 회고 수가 기준 이상
+    SubmittedCount(u64),
+    /// 같은 회고방의 최근 회고를 기준 횟수 이상 연속 제출
+    SubmitStreak(u64),
+    /// 한 답변이 받은 좋아요 수가 기준 이상
+    LikesOnResponse(i32),
+}
+
+impl BadgeCriterion {
+    /// 조건을 평가하는 이벤트
+    pub fn event(&self) -> BadgeEvent {
+        match self {
+            BadgeCriterion::SubmittedCount(_) | BadgeCriterion::SubmitStreak(_) => {
+                BadgeEvent::RetrospectSubmitted
+            }
+            BadgeCriterion::LikesOnResponse(_) => BadgeEvent::LikeReceived,
+        }
+    }
+
+    fn is_met(&self, stats: &BadgeStats) -> bool {
+        match *self {
+            BadgeCriterion::SubmittedCount(min) => stats.submitted_count >= min,
+            BadgeCriterion::SubmitStreak(min) => stats.submit_streak >= min,
+            BadgeCriterion::LikesOnResponse(min) => stats.response_like_count >= min,
+        }
+    }
+}
+
+/// 뱃지 규칙 (유형, 표시 정보, 부여 조건)
+#[derive(Debug)]
+pub struct BadgeRule {
+    pub badge_type: BadgeType,
+    pub name: &'static str,
+    pub description: &'static str,
+    pub criterion: BadgeCriterion,
+}
+
+/// 뱃지 규칙 목록
+///
+/// 새 뱃지는 `BadgeType`에 유형을 추가하고 여기에 규칙을 등록합니다.
+/// 새 조건 종류가 필요하면 `BadgeCriterion`과 `BadgeStats`에 지표를 함께 추가합니다.
+pub const BADGE_RULES: &[BadgeRule] = &[
+    BadgeRule {
+        badge_type: BadgeType::FirstRetrospect,
+        name: "첫 회고",
+        description: "처음으로 회고를 제출했어요.",
+        criterion: BadgeCriterion::SubmittedCount(1),
+    },
+    BadgeRule {
+        badge_type: BadgeType::SubmitStreak3,
+        name: "꾸준한 회고러",
+        description: "같은 회고방의 회고를 3번 연속 제출했어요.",
+        criterion: BadgeCriterion::SubmitStreak(3),
+    },
+    BadgeRule {
+        badge_type: BadgeType::Retrospect10,
+        name: "회고 10회",
+        description: "회고를 10번 제출했어요.",
+        criterion: BadgeCriterion::SubmittedCount(10),
+    },
+    BadgeRule {
+        badge_type: BadgeType::PopularAnswer,
+        name: "인기 답변",
+        description: "한 답변에 좋아요를 10개 이상 받았어요.",
+        criterion: BadgeCriterion::LikesOnResponse(10),
+    },
+];
+
+/// 뱃지 조건 평가용 회원 활동 지표 (이벤트와 관련된 지표만 채움)
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BadgeStats {
+    /// 제출(분석 완료 포함)한 회고 수
+    pub submitted_count: u64,
+    /// 제출한 회고방의 최근 회고 연속 제출 횟수
+    pub submit_streak: u64,
+    /// 좋아요를 받은 답변의 좋아요 수
+    pub response_like_count: i32,
+}
+
+/// 뱃지 유형의 규칙 조회
+pub fn badge_rule(badge_type: BadgeType) -> Option<&'static BadgeRule> {
+    BADGE_RULES
+        .iter()
+        .find(|rule| rule.badge_type == badge_type)
+}
+
+/// 연속 제출 판단에 필요한 최근 회고 수 (규칙의 최대 연속 기준)
+pub fn max_streak_threshold() -> u64 {
+    BADGE_RULES
+        .iter()
+        .filter_map(|rule| match rule.criterion {
+            BadgeCriterion::SubmitStreak(min) => Some(min),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// 최근 회고부터 끊기지 않고 제출한 횟수
+///
+/// `recent_retrospect_ids`는 최신순이어야 합니다.
+pub fn leading_submit_streak(recent_retrospect_ids: &[i64], submitted_ids: &HashSet<i64>) -> u64 {
+    recent_retrospect_ids
+        .iter()
+        .take_while(|id| submitted_ids.contains(id))
+        .count() as u64
+}
+
+/// 이벤트 발생 시 조건을 만족한 뱃지 유형 (이미 보유한 뱃지는 제외)
+pub fn earned_badges(
+    event: BadgeEvent,
+    stats: &BadgeStats,
+    owned: &HashSet<BadgeType>,
+) -> Vec<BadgeType> {
+    BADGE_RULES
+        .iter()
+        .filter(|rule| rule.criterion.event() == event)
+        .filter(|rule| !owned.contains(&rule.badge_type))
+        .filter(|rule| rule.criterion.is_met(stats))
+        .map(|rule| rule.badge_type)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_define_rule_for_every_badge_type_once() {
+        use sea_orm::Iterable;
+
+        for badge_type in BadgeType::iter() {
+            let count = BADGE_RULES
+                .iter()
+                .filter(|rule| rule.badge_type == badge_type)
+                .count();
+            assert_eq!(count, 1, "{:?}", badge_type);
+        }
+    }
+
+    #[test]
+    fn should_award_first_retrospect_on_first_submit() {
+        // Arrange
+        let stats = BadgeStats {
+            submitted_count: 1,
+            submit_streak: 1,
+            ..Default::default()
+        };
+
+        // Act
+        let badges = earned_badges(BadgeEvent::RetrospectSubmitted, &stats, &HashSet::new());
+
+        // Assert
+        assert_eq!(badges, vec![BadgeType::FirstRetrospect]);
+    }
+
+    #[test]
+    fn should_skip_owned_badges() {
+        // Arrange
+        let stats = BadgeStats {
+            submitted_count: 10,
+            submit_streak: 3,
+            ..Default::default()
+        };
+        let owned = HashSet::from([BadgeType::FirstRetrospect]);
+
+        // Act
+        let badges = earned_badges(BadgeEvent::RetrospectSubmitted, &stats, &owned);
+
+        // Assert
+        assert_eq!(
+            badges,
+            vec![BadgeType::SubmitStreak3, BadgeType::Retrospect10]
+        );
+    }
+
+    #[test]
+    fn should_only_evaluate_rules_for_event() {
+        // Arrange
+        let stats = BadgeStats {
+            submitted_count: 10,
+            response_like_count: 10,
+            ..Default::default()
+        };
+
+        // Act
+        let badges = earned_badges(BadgeEvent::LikeReceived, &stats, &HashSet::new());
+
+        // Assert
+        assert_eq!(badges, vec![BadgeType::PopularAnswer]);
+    }
+
+    #[test]
+    fn should_count_streak_until_first_missed_retrospect() {
+        // Arrange
+        let submitted = HashSet::from([5, 4, 2]);
+
+        // Act & Assert
+        assert_eq!(leading_submit_streak(&[5, 4, 3, 2], &submitted), 2);
+        assert_eq!(leading_submit_streak(&[6, 5, 4], &submitted), 0);
+        assert_eq!(leading_submit_streak(&[], &submitted), 0);
+    }
+
+    #[test]
+    fn should_use_largest_streak_rule_as_lookback() {
+        assert_eq!(max_streak_threshold(), 3);
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::{HashMap, HashSet};
+
+/// 중복 닉네임 구분자로 사용할 회원 ID 뒷자리 수
+const MEMBER_ID_SUFFIX_LEN: usize = 4;
+
+/// 회원 표시 이름
+///
+/// 같은 방에 닉네임이 같은 멤버가 있으면 `닉네임(회원 ID 뒷자리)` 형태로 구분자를 붙입니다.
+pub fn display_member_name(nickname: &str, member_id: i64, duplicated: bool) -> String {
+    if duplicated {
+        format!("{}({})", nickname, member_id_suffix(member_id))
+    } else {
+        nickname.to_string()
+    }
+}
+
+/// 같은 방 멤버들의 표시 이름 (회원 ID → 표시 이름)
+///
+/// 닉네임이 겹치는 멤버에게만 구분자를 붙입니다.
+/// 겹치는 멤버끼리 ID 뒷자리까지 같으면 해당 닉네임 그룹은 전체 회원 ID를 구분자로 사용합니다.
+pub fn display_member_names(members: &[(i64, String)]) -> HashMap<i64, String> {
+    let mut groups: HashMap<&str, Vec<i64>> = HashMap::new();
+    for (member_id, nickname) in members {
+        groups
+            .entry(nickname.as_str())
+            .or_default()
+            .push(*member_id);
+    }
+
+    let mut names = HashMap::new();
+    for (nickname, member_ids) in groups {
+        if member_ids.len() == 1 {
+            names.insert(member_ids[0], nickname.to_string());
+            continue;
+        }
+
+        let suffixes: HashSet<String> = member_ids.iter().map(|id| member_id_suffix(*id)).collect();
+        let suffix_unique = suffixes.len() == member_ids.len();
+        for member_id in member_ids {
+            let name = if suffix_unique {
+                display_member_name(nickname, member_id, true)
+            } else {
+                format!("{}({})", nickname, member_id)
+            };
+            names.insert(member_id, name);
+        }
+    }
+    names
+}
+
+/// 회원 ID 뒷자리 (ID가 짧으면 전체)
+fn member_id_suffix(member_id: i64) -> String {
+    let id = member_id.to_string();
+    let start = id.len().saturating_sub(MEMBER_ID_SUFFIX_LEN);
+    id[start..].to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_keep_nickname_when_not_duplicated() {
+        assert_eq!(display_member_name("홍길동", 12345, false), "홍길동");
+    }
+
+    #[test]
+    fn should_append_member_id_suffix_when_duplicated() {
+        assert_eq!(display_member_name("홍길동", 12345, true), "홍길동(2345)");
+        assert_eq!(display_member_name("홍길동", 7, true), "홍길동(7)");
+    }
+
+    #[test]
+    fn should_only_disambiguate_duplicated_nicknames() {
+        // Arrange
+        let members = vec![
+            (101, "홍길동".to_string()),
+            (202, "홍길동".to_string()),
+            (303, "제이".to_string()),
+        ];
+
+        // Act
+        let names = display_member_names(&members);
+
+        // Assert
+        assert_eq!(names[&101], "홍길동(101)");
+        assert_eq!(names[&202], "홍길동(202)");
+        assert_eq!(names[&303], "제이");
+    }
+
+    #[test]
+    fn should_use_full_member_id_when_suffixes_collide() {
+        // Arrange
+        let members = vec![(10001, "케이".to_string()), (20001, "케이".to_string())];
+
+        // Act
+        let names = display_member_names(&members);
+
+        // Assert
+        assert_eq!(names[&10001], "케이(10001)");
+        assert_eq!(names[&20001], "케이(20001)");
+    }
+}
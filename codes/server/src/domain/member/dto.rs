@@ -1,8 +1,10 @@
 use chrono::{DateTime, Utc};
-use serde::Serialize;
-use utoipa::ToSchema;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
 
 use super::entity::member::SocialType;
+use super::entity::member_badge::BadgeType;
+use crate::domain::notification::entity::notification::NotificationType;
 use crate::utils::BaseResponse;
 
 /// 회원 프로필 응답
@@ -14,6 +16,8 @@ pub struct MemberProfileResponse {
     pub nickname: Option<String>,
     pub insight_count: i32,
     pub social_type: SocialType,
+    /// 회고방 Owner에게 이메일 공유 허용 여부
+    pub share_email: bool,
     pub created_at: DateTime<Utc>,
 }
 
@@ -37,6 +41,296 @@ pub struct SuccessWithdrawResponse {
     pub result: Option<()>,
 }
 
+/// 최근 수정한 답변 조회 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentResponsesQuery {
+    /// 마지막으로 조회된 답변 ID (첫 요청 시 생략)
+    pub cursor: Option<i64>,
+    /// 페이지당 조회 개수 (기본값: 20, 최대: 100)
+    pub size: Option<i32>,
+}
+
+/// 최근 수정한 답변 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentResponseItem {
+    pub response_id: i64,
+    pub retrospect_id: i64,
+    /// 회고 제목 (프로젝트 이름)
+    pub retrospect_title: String,
+    pub question: String,
+    pub content: String,
+    /// 마지막 수정 시각 (회고 타임존 기준, yyyy-MM-ddTHH:mm:ss)
+    pub updated_at: String,
+}
+
+/// 최근 수정한 답변 목록 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RecentResponsesResponse {
+    /// 답변 리스트 (최근 수정순)
+    pub responses: Vec<RecentResponseItem>,
+    /// 다음 페이지 존재 여부
+    pub has_next: bool,
+    /// 다음 조회를 위한 커서 ID (마지막 페이지면 null)
+    pub next_cursor: Option<i64>,
+}
+
+/// 최근 수정한 답변 조회 성공 응답 (Swagger 문서용)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRecentResponsesResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: RecentResponsesResponse,
+}
+
+/// 내 멘션 목록 조회 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct MentionsQuery {
+    /// 마지막으로 조회된 멘션 ID (첫 요청 시 생략)
+    pub cursor: Option<i64>,
+    /// 페이지당 조회 개수 (기본값: 20, 최대: 100)
+    pub size: Option<i32>,
+    /// 읽지 않은 멘션만 조회 (기본값: false)
+    pub unread_only: Option<bool>,
+}
+
+/// 멘션 위치 구분
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MentionSourceType {
+    /// 회고 답변
+    Response,
+    /// 답변 댓글
+    Comment,
+}
+
+/// 내 멘션 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MentionItem {
+    pub mention_id: i64,
+    /// 멘션 위치 (답변/댓글)
+    pub source_type: MentionSourceType,
+    pub retrospect_id: i64,
+    /// 회고 제목 (프로젝트 이름)
+    pub retrospect_title: String,
+    pub response_id: i64,
+    /// 댓글 멘션이면 댓글 ID (답변 멘션이면 null)
+    pub comment_id: Option<i64>,
+    /// 멘션한 사람 닉네임
+    pub author_nickname: String,
+    /// 본문 미리보기 (최대 50자)
+    pub snippet: String,
+    pub is_read: bool,
+    /// 멘션 시각 (회고 타임존 기준, yyyy-MM-ddTHH:mm:ss)
+    pub created_at: String,
+}
+
+/// 내 멘션 목록 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MentionsResponse {
+    /// 멘션 리스트 (최신순)
+    pub mentions: Vec<MentionItem>,
+    /// 다음 페이지 존재 여부
+    pub has_next: bool,
+    /// 다음 조회를 위한 커서 ID (마지막 페이지면 null)
+    pub next_cursor: Option<i64>,
+}
+
+/// 내 멘션 목록 조회 성공 응답 (Swagger 문서용)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessMentionsResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: MentionsResponse,
+}
+
+/// 알림 일괄 읽음 처리 대상 유형 (멘션 + 알림 유형)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NotificationReadType {
+    /// 답변/댓글 멘션
+    Mention,
+    DraftExpiryWarning,
+    ResponseLiked,
+    RetrospectCreated,
+    RetrospectSubmitted,
+    ResponseCommented,
+    SubmissionReminder,
+    RoleChanged,
+}
+
+impl NotificationReadType {
+    /// 알림 테이블의 유형으로 변환 (멘션은 별도 테이블이라 None)
+    pub fn notification_type(self) -> Option<NotificationType> {
+        match self {
+            Self::Mention => None,
+            Self::DraftExpiryWarning => Some(NotificationType::DraftExpiryWarning),
+            Self::ResponseLiked => Some(NotificationType::ResponseLiked),
+            Self::RetrospectCreated => Some(NotificationType::RetrospectCreated),
+            Self::RetrospectSubmitted => Some(NotificationType::RetrospectSubmitted),
+            Self::ResponseCommented => Some(NotificationType::ResponseCommented),
+            Self::SubmissionReminder => Some(NotificationType::SubmissionReminder),
+            Self::RoleChanged => Some(NotificationType::RoleChanged),
+        }
+    }
+}
+
+/// 알림 일괄 읽음 처리 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct ReadAllNotificationsQuery {
+    /// 읽음 처리할 유형 (생략 시 멘션과 모든 알림)
+    #[serde(rename = "type")]
+    pub read_type: Option<NotificationReadType>,
+}
+
+/// 알림 일괄 읽음 처리 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReadAllNotificationsResponse {
+    /// 이번 요청으로 읽음 처리된 개수 (멘션 포함, 이미 읽은 항목 제외)
+    pub read_count: u64,
+}
+
+/// 알림 일괄 읽음 처리 성공 응답 (Swagger 문서용)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessReadAllNotificationsResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: ReadAllNotificationsResponse,
+}
+
+/// 방해금지 시간대 설정 요청
+///
+/// 시작/종료를 모두 null로 보내면 방해금지를 해제합니다.
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateDndRequest {
+    /// 방해금지 시작 시각 (HH:mm)
+    pub dnd_start: Option<String>,
+    /// 방해금지 종료 시각 (HH:mm, 시작보다 이르면 다음 날 종료)
+    pub dnd_end: Option<String>,
+    /// 기준 타임존 (IANA 이름, 예: "Asia/Seoul"). 미지정 시 KST
+    pub timezone: Option<String>,
+}
+
+/// 방해금지 시간대 설정 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DndSettingsResponse {
+    /// 방해금지 사용 여부
+    pub enabled: bool,
+    /// 방해금지 시작 시각 (HH:mm, 미설정 시 null)
+    pub dnd_start: Option<String>,
+    /// 방해금지 종료 시각 (HH:mm, 미설정 시 null)
+    pub dnd_end: Option<String>,
+    /// 기준 타임존 (미설정 시 null)
+    pub timezone: Option<String>,
+}
+
+/// 방해금지 시간대 설정 성공 응답 (Swagger 문서용)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessDndSettingsResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: DndSettingsResponse,
+}
+
+/// 이메일 공유 설정 요청
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateEmailSharingRequest {
+    /// 회고방 Owner에게 이메일 공유 허용 여부
+    pub share_email: bool,
+}
+
+/// 이메일 공유 설정 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailSharingResponse {
+    /// 회고방 Owner에게 이메일 공유 허용 여부
+    pub share_email: bool,
+}
+
+/// 이메일 공유 설정 성공 응답 (Swagger 문서용)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessEmailSharingResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: EmailSharingResponse,
+}
+
+/// 알림 수신 설정 요청
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateNotificationSettingsRequest {
+    /// 알림(회고 생성 구독 알림, 제출 독려, 분석 요약 메일) 수신 여부
+    pub notification_enabled: bool,
+}
+
+/// 알림 수신 설정 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationSettingsResponse {
+    /// 알림 수신 여부
+    pub notification_enabled: bool,
+}
+
+/// 알림 수신 설정 성공 응답 (Swagger 문서용)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessNotificationSettingsResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: NotificationSettingsResponse,
+}
+
+/// 내 뱃지 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BadgeItem {
+    pub badge_type: BadgeType,
+    /// 뱃지 이름
+    pub name: String,
+    /// 획득 조건 설명
+    pub description: String,
+    /// 획득 시각 (기본 타임존 Asia/Seoul, yyyy-MM-ddTHH:mm:ss)
+    pub earned_at: String,
+}
+
+/// 내 뱃지 목록 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BadgesResponse {
+    /// 획득한 뱃지 리스트 (획득순)
+    pub badges: Vec<BadgeItem>,
+}
+
+/// 내 뱃지 목록 조회 성공 응답 (Swagger 문서용)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessBadgesResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: BadgesResponse,
+}
+
 impl From<BaseResponse<()>> for SuccessWithdrawResponse {
     fn from(res: BaseResponse<()>) -> Self {
         Self {
@@ -29,6 +29,21 @@ pub struct Model {
     pub refresh_token_expires_at: Option<DateTime>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    /// 알림 수신 여부 (false면 제출 독려 등 알림 발송 대상에서 제외)
+    #[sea_orm(default_value = true)]
+    pub notification_enabled: bool,
+    /// 방해금지 시작 시각 (dnd_timezone 기준 벽시계 시간, NULL이면 미설정)
+    pub dnd_start: Option<Time>,
+    /// 방해금지 종료 시각 (시작보다 이르면 다음 날 종료로 해석)
+    pub dnd_end: Option<Time>,
+    /// 방해금지 시간대 기준 타임존 (IANA 이름, 예: "Asia/Seoul")
+    #[sea_orm(column_type = "String(StringLen::N(64))", nullable)]
+    pub dnd_timezone: Option<String>,
+    /// 마지막 활동 시각 (UTC, 인증 요청 시 스로틀 간격마다 갱신)
+    pub last_seen_at: Option<DateTime>,
+    /// 회고방 Owner에게 이메일 공유 허용 여부 (옵트인, 기본 false)
+    #[sea_orm(default_value = false)]
+    pub share_email: bool,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -0,0 +1,66 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 뱃지 유형 (부여 조건은 `badge::BADGE_RULES`에 정의)
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(30))")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BadgeType {
+    /// 첫 회고 제출
+    #[sea_orm(string_value = "FIRST_RETROSPECT")]
+    FirstRetrospect,
+    /// 같은 회고방 회고 3회 연속 제출
+    #[sea_orm(string_value = "SUBMIT_STREAK_3")]
+    SubmitStreak3,
+    /// 회고 10회 제출
+    #[sea_orm(string_value = "RETROSPECT_10")]
+    Retrospect10,
+    /// 한 답변에 좋아요 10개 이상
+    #[sea_orm(string_value = "POPULAR_ANSWER")]
+    PopularAnswer,
+}
+
+/// 회원 뱃지 엔티티 (회원·뱃지 유형당 1건)
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "member_badge")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub member_badge_id: i64,
+    pub member_id: i64,
+    pub badge_type: BadgeType,
+    /// 뱃지 획득 일시 (UTC)
+    pub earned_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::member::Entity",
+        from = "Column::MemberId",
+        to = "super::member::Column::MemberId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Member,
+}
+
+impl Related<super::member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Member.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
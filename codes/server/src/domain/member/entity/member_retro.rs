@@ -30,6 +30,18 @@ pub struct Model {
     pub retrospect_id: i64,
     pub status: RetrospectStatus,
     pub submitted_at: Option<DateTime>,
+    /// 오래된 임시저장 정리 예고 시각 (정리 잡에서 사용)
+    pub draft_warned_at: Option<DateTime>,
+    /// 현재 활성 답변 작성 세션 ID (다중 기기 동시 작성 충돌 감지)
+    pub last_edit_session: Option<String>,
+    /// 마지막 임시 저장 시각
+    pub last_edit_at: Option<DateTime>,
+    /// 답변 작성 시작 시각 (처음 작성을 시작한 시점, 재진입 시 갱신하지 않음)
+    pub writing_started_at: Option<DateTime>,
+    /// 작성 시작부터 제출까지 걸린 시간 (초, 작성 시작 기록이 없으면 NULL)
+    pub writing_duration_secs: Option<i64>,
+    /// 마지막 작성 하트비트 수신 시각 (최근 하트비트가 있으면 "작성 중"으로 판단)
+    pub last_active_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
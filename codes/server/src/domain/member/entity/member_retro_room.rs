@@ -6,10 +6,49 @@ use serde::{Deserialize, Serialize};
 pub enum RoomRole {
     #[sea_orm(string_value = "OWNER")]
     Owner,
+    #[sea_orm(string_value = "MODERATOR")]
+    Moderator,
     #[sea_orm(string_value = "MEMBER")]
     Member,
 }
 
+impl RoomRole {
+    /// 역할 계층 순위 (Owner > Moderator > Member)
+    fn rank(&self) -> u8 {
+        match self {
+            RoomRole::Owner => 2,
+            RoomRole::Moderator => 1,
+            RoomRole::Member => 0,
+        }
+    }
+
+    /// `min_role` 이상의 역할인지 확인
+    pub fn is_at_least(&self, min_role: &RoomRole) -> bool {
+        self >= min_role
+    }
+
+    /// API 응답용 역할 문자열
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoomRole::Owner => "OWNER",
+            RoomRole::Moderator => "MODERATOR",
+            RoomRole::Member => "MEMBER",
+        }
+    }
+}
+
+impl PartialOrd for RoomRole {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RoomRole {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.rank().cmp(&other.rank())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "member_retro_room")]
 pub struct Model {
@@ -21,6 +60,12 @@ pub struct Model {
     #[sea_orm(default_value = "1")]
     pub order_index: i32,
     pub created_at: DateTime,
+    /// 회고방 활동 마지막 확인 시각 (NULL이면 참여 시각 기준으로 미확인 활동 집계)
+    pub last_seen_at: Option<DateTime>,
+    /// Owner가 지정한 멤버 표시 순서 (NULL이면 역할·가입일 기준으로 지정된 멤버 뒤에 배치)
+    pub display_order: Option<i32>,
+    /// 멤버 이메일 목록 마지막 조회 시각 (Owner 조회 간격 제한용, UTC)
+    pub email_list_viewed_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
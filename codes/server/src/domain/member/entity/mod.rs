@@ -1,5 +1,8 @@
+pub mod answer_feedback_usage;
 pub mod assistant_usage;
 pub mod member;
+pub mod member_badge;
 pub mod member_response;
 pub mod member_retro;
 pub mod member_retro_room;
+pub mod room_membership_history;
@@ -0,0 +1,74 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 회고방 참여 이벤트 유형
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MembershipEvent {
+    /// 회고방 참여 (생성자 포함)
+    #[sea_orm(string_value = "JOIN")]
+    Join,
+    /// 스스로 회고방을 나감
+    #[sea_orm(string_value = "LEAVE")]
+    Leave,
+    /// Owner에 의해 내보내짐
+    #[sea_orm(string_value = "KICK")]
+    Kick,
+    /// Owner에 의해 역할이 변경됨
+    #[sea_orm(string_value = "ROLE_CHANGE")]
+    RoleChange,
+    /// Owner가 멤버 이메일 목록을 조회함 (개인정보 조회 감사 기록, member_id는 조회자)
+    #[sea_orm(string_value = "EMAIL_LIST_VIEW")]
+    EmailListView,
+}
+
+/// 회고방 참여/이탈 기록 엔티티
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "room_membership_history")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub room_membership_history_id: i64,
+    pub retrospect_room_id: i64,
+    /// 대상 멤버 ID (탈퇴 시 NULL)
+    pub member_id: Option<i64>,
+    pub event: MembershipEvent,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::member::Entity",
+        from = "Column::MemberId",
+        to = "super::member::Column::MemberId",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Member,
+    #[sea_orm(
+        belongs_to = "crate::domain::retrospect::entity::retro_room::Entity",
+        from = "Column::RetrospectRoomId",
+        to = "crate::domain::retrospect::entity::retro_room::Column::RetrospectRoomId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    RetroRoom,
+}
+
+impl Related<super::member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Member.def()
+    }
+}
+
+impl Related<crate::domain::retrospect::entity::retro_room::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RetroRoom.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,6 +1,14 @@
-use axum::{extract::State, Json};
+use axum::{
+    extract::{Path, Query, State},
+    Json,
+};
 
-use super::dto::MemberProfileResponse;
+use super::dto::{
+    BadgesResponse, DndSettingsResponse, EmailSharingResponse, MemberProfileResponse,
+    MentionsQuery, MentionsResponse, NotificationSettingsResponse, ReadAllNotificationsQuery,
+    ReadAllNotificationsResponse, RecentResponsesQuery, RecentResponsesResponse, UpdateDndRequest,
+    UpdateEmailSharingRequest, UpdateNotificationSettingsRequest,
+};
 use super::service::MemberService;
 use crate::state::AppState;
 use crate::utils::auth::AuthUser;
@@ -71,3 +79,311 @@ pub async fn withdraw(
         result: None,
     }))
 }
+
+/// 최근 수정한 답변 조회 API
+///
+/// 여러 회고방/회고에 걸쳐 본인이 작성한 답변을 최근 수정순으로 조회합니다. 빈 답변은 제외됩니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/members/me/recent-responses",
+    params(RecentResponsesQuery),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "최근 수정한 답변 조회 성공", body = SuccessRecentResponsesResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Member"
+)]
+pub async fn list_recent_responses(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(query): Query<RecentResponsesQuery>,
+) -> Result<Json<BaseResponse<RecentResponsesResponse>>, AppError> {
+    if let Some(cursor) = query.cursor {
+        if cursor < 1 {
+            return Err(AppError::BadRequest(
+                "cursor는 1 이상의 양수여야 합니다.".to_string(),
+            ));
+        }
+    }
+
+    let size = query.size.unwrap_or(20);
+    if !(1..=100).contains(&size) {
+        return Err(AppError::BadRequest(
+            "size는 1~100 범위의 정수여야 합니다.".to_string(),
+        ));
+    }
+
+    let member_id = user.user_id()?;
+    let result =
+        MemberService::list_recent_responses(&state, member_id, query.cursor, size).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "최근 수정한 답변 조회를 성공했습니다.",
+    )))
+}
+
+/// 내 멘션 목록 조회 API
+///
+/// 회고 답변/댓글에서 나를 멘션(@닉네임)한 항목을 최신순으로 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/members/me/mentions",
+    params(MentionsQuery),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "멘션 목록 조회 성공", body = SuccessMentionsResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Member"
+)]
+pub async fn list_mentions(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(query): Query<MentionsQuery>,
+) -> Result<Json<BaseResponse<MentionsResponse>>, AppError> {
+    if let Some(cursor) = query.cursor {
+        if cursor < 1 {
+            return Err(AppError::BadRequest(
+                "cursor는 1 이상의 양수여야 합니다.".to_string(),
+            ));
+        }
+    }
+
+    let size = query.size.unwrap_or(20);
+    if !(1..=100).contains(&size) {
+        return Err(AppError::BadRequest(
+            "size는 1~100 범위의 정수여야 합니다.".to_string(),
+        ));
+    }
+
+    let member_id = user.user_id()?;
+    let result = MemberService::list_mentions(
+        &state,
+        member_id,
+        query.cursor,
+        size,
+        query.unread_only.unwrap_or(false),
+    )
+    .await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "멘션 목록 조회를 성공했습니다.",
+    )))
+}
+
+/// 멘션 읽음 처리 API
+///
+/// 본인에게 온 멘션을 읽음 상태로 변경합니다. 이미 읽은 멘션도 성공으로 처리됩니다.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/members/me/mentions/{mentionId}/read",
+    params(
+        ("mentionId" = i64, Path, description = "읽음 처리할 멘션 ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "멘션 읽음 처리 성공", body = SuccessEmptyResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 멘션", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Member"
+)]
+pub async fn mark_mention_read(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(mention_id): Path<i64>,
+) -> Result<Json<BaseResponse<()>>, AppError> {
+    if mention_id < 1 {
+        return Err(AppError::BadRequest(
+            "mentionId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let member_id = user.user_id()?;
+    MemberService::mark_mention_read(&state, member_id, mention_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        (),
+        "멘션을 읽음 처리했습니다.",
+    )))
+}
+
+/// 알림 일괄 읽음 처리 API
+///
+/// 읽지 않은 멘션과 알림(좋아요, 댓글 등)을 한 번에 읽음 처리하고 처리된 개수를 반환합니다.
+/// - type을 지정하면 해당 유형만 읽음 처리합니다. (MENTION은 멘션만)
+/// - 방해금지로 아직 전달되지 않은 알림은 제외됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/members/me/notifications/read-all",
+    params(ReadAllNotificationsQuery),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "알림 일괄 읽음 처리 성공", body = SuccessReadAllNotificationsResponse),
+        (status = 400, description = "잘못된 요청 (지원하지 않는 type)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Member"
+)]
+pub async fn read_all_notifications(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Query(query): Query<ReadAllNotificationsQuery>,
+) -> Result<Json<BaseResponse<ReadAllNotificationsResponse>>, AppError> {
+    let member_id = user.user_id()?;
+    let result = MemberService::read_all_notifications(&state, member_id, query.read_type).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "알림을 모두 읽음 처리했습니다.",
+    )))
+}
+
+/// 방해금지 시간대 설정 API
+///
+/// 알림을 받지 않을 시간대(HH:mm)와 기준 타임존을 설정합니다.
+/// - 방해금지 시간대에 발생한 알림은 종료 시각까지 전달이 보류됩니다.
+/// - dndStart, dndEnd를 모두 null로 보내면 방해금지를 해제합니다.
+#[utoipa::path(
+    put,
+    path = "/api/v1/members/me/dnd",
+    request_body = UpdateDndRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "방해금지 설정 성공", body = SuccessDndSettingsResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 사용자", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Member"
+)]
+pub async fn update_dnd(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(req): Json<UpdateDndRequest>,
+) -> Result<Json<BaseResponse<DndSettingsResponse>>, AppError> {
+    let member_id = user.user_id()?;
+    let result = MemberService::update_dnd(&state, member_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "방해금지 시간대를 설정했습니다.",
+    )))
+}
+
+/// 이메일 공유 설정 API
+///
+/// 회고방 Owner의 멤버 이메일 목록에 내 이메일을 포함할지 설정합니다. (기본값: 비공개)
+#[utoipa::path(
+    put,
+    path = "/api/v1/members/me/email-sharing",
+    request_body = UpdateEmailSharingRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "이메일 공유 설정 성공", body = SuccessEmailSharingResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 사용자", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Member"
+)]
+pub async fn update_email_sharing(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(req): Json<UpdateEmailSharingRequest>,
+) -> Result<Json<BaseResponse<EmailSharingResponse>>, AppError> {
+    let member_id = user.user_id()?;
+    let result = MemberService::update_email_sharing(&state, member_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "이메일 공유 설정을 변경했습니다.",
+    )))
+}
+
+/// 알림 수신 설정 API
+///
+/// 회고 생성 구독 알림, 회고 제출 독려, 분석 요약 메일의 수신 여부를 설정합니다. (기본값: 수신)
+#[utoipa::path(
+    put,
+    path = "/api/v1/members/me/notification-settings",
+    request_body = UpdateNotificationSettingsRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "알림 수신 설정 성공", body = SuccessNotificationSettingsResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 사용자", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Member"
+)]
+pub async fn update_notification_settings(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(req): Json<UpdateNotificationSettingsRequest>,
+) -> Result<Json<BaseResponse<NotificationSettingsResponse>>, AppError> {
+    let member_id = user.user_id()?;
+    let result = MemberService::update_notification_settings(&state, member_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "알림 수신 설정을 변경했습니다.",
+    )))
+}
+
+/// 내 뱃지 목록 조회 API
+///
+/// 회고 제출, 좋아요 등 활동으로 획득한 뱃지를 획득순으로 조회합니다.
+/// 뱃지는 조건을 만족하는 이벤트가 발생할 때 자동으로 부여되며, 같은 뱃지는 한 번만 획득합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/members/me/badges",
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "뱃지 목록 조회 성공", body = SuccessBadgesResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Member"
+)]
+pub async fn list_badges(
+    State(state): State<AppState>,
+    user: AuthUser,
+) -> Result<Json<BaseResponse<BadgesResponse>>, AppError> {
+    let member_id = user.user_id()?;
+    let result = MemberService::list_badges(&state, member_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "뱃지 목록 조회를 성공했습니다.",
+    )))
+}
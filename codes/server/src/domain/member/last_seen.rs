@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tracing::warn;
+
+/// 마지막 활동 시각 갱신 간격 (초). 이 간격 안의 요청은 갱신을 생략합니다.
+pub const LAST_SEEN_THROTTLE_SECS: u64 = 300;
+
+/// 마지막 활동 시각 갱신 스로틀 (in-memory)
+///
+/// 회원별로 마지막 갱신 시각을 기억해 `interval` 안의 요청은 DB 쓰기를 생략합니다.
+/// 서버 인스턴스가 여러 대여도 DB 갱신 조건(`last_seen_at`이 간격보다 오래됨)으로 한 번 더 거릅니다.
+/// Clone 시 내부 상태를 `Arc`로 공유하므로 AppState에 넣어 전 요청에서 같은 기록을 사용합니다.
+#[derive(Debug, Clone)]
+pub struct LastSeenThrottle {
+    interval: Duration,
+    touched: Arc<Mutex<HashMap<i64, Instant>>>,
+}
+
+impl LastSeenThrottle {
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            touched: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// 갱신 간격
+    pub fn interval(&self) -> Duration {
+        self.interval
+    }
+
+    /// 이번 요청에서 마지막 활동 시각을 갱신해야 하는지 확인 (갱신 대상이면 시각 기록)
+    pub fn should_touch(&self, member_id: i64) -> bool {
+        self.should_touch_at(member_id, Instant::now())
+    }
+
+    fn should_touch_at(&self, member_id: i64, now: Instant) -> bool {
+        let mut touched = self.lock_touched();
+        let interval = self.interval;
+
+        if touched
+            .get(&member_id)
+            .is_some_and(|last| now.duration_since(*last) < interval)
+        {
+            return false;
+        }
+
+        // 갱신할 때만 오래된 기록을 정리 (회원당 간격마다 한 번이므로 요청마다 순회하지 않음)
+        touched.retain(|_, last| now.duration_since(*last) < interval);
+        touched.insert(member_id, now);
+        true
+    }
+
+    fn lock_touched(&self) -> std::sync::MutexGuard<'_, HashMap<i64, Instant>> {
+        match self.touched.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("마지막 활동 갱신 기록 mutex poisoned, 복구합니다");
+                poisoned.into_inner()
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INTERVAL: Duration = Duration::from_secs(300);
+
+    #[test]
+    fn should_touch_first_request() {
+        let throttle = LastSeenThrottle::new(INTERVAL);
+
+        assert!(throttle.should_touch_at(1, Instant::now()));
+    }
+
+    #[test]
+    fn should_skip_within_interval() {
+        // Arrange
+        let throttle = LastSeenThrottle::new(INTERVAL);
+        let start = Instant::now();
+        throttle.should_touch_at(1, start);
+
+        // Act & Assert
+        assert!(!throttle.should_touch_at(1, start + Duration::from_secs(299)));
+        assert!(throttle.should_touch_at(1, start + INTERVAL));
+    }
+
+    #[test]
+    fn should_throttle_each_member_separately() {
+        // Arrange
+        let throttle = LastSeenThrottle::new(INTERVAL);
+        let start = Instant::now();
+        throttle.should_touch_at(1, start);
+
+        // Act & Assert
+        assert!(throttle.should_touch_at(2, start + Duration::from_secs(1)));
+        assert!(!throttle.should_touch_at(1, start + Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn should_prune_expired_entries_on_touch() {
+        // Arrange
+        let throttle = LastSeenThrottle::new(INTERVAL);
+        let start = Instant::now();
+        throttle.should_touch_at(1, start);
+
+        // Act
+        throttle.should_touch_at(2, start + INTERVAL);
+
+        // Assert
+        let touched = throttle.lock_touched();
+        assert!(!touched.contains_key(&1));
+        assert!(touched.contains_key(&2));
+    }
+}
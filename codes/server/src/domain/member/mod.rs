@@ -1,4 +1,7 @@
+pub mod badge;
+pub mod display_name;
 pub mod dto;
 pub mod entity;
 pub mod handler;
+pub mod last_seen;
 pub mod service;
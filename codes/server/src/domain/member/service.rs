@@ -1,11 +1,67 @@
-use chrono::{TimeZone, Utc};
-use sea_orm::EntityTrait;
-use tracing::info;
+use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
-use super::dto::MemberProfileResponse;
-use crate::domain::member::entity::member;
+use chrono::{NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use sea_orm::sea_query::{Expr, Query};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use tracing::{info, warn};
+
+use super::badge::{
+    badge_rule, earned_badges, leading_submit_streak, max_streak_threshold, BadgeEvent, BadgeStats,
+};
+use super::dto::{
+    BadgeItem, BadgesResponse, DndSettingsResponse, EmailSharingResponse, MemberProfileResponse,
+    MentionItem, MentionSourceType, MentionsResponse, NotificationReadType,
+    NotificationSettingsResponse, ReadAllNotificationsResponse, RecentResponseItem,
+    RecentResponsesResponse, UpdateEmailSharingRequest, UpdateNotificationSettingsRequest,
+};
+use crate::domain::member::entity::member_badge::{self, BadgeType};
+use crate::domain::member::entity::member_retro::{self, RetrospectStatus};
+use crate::domain::member::entity::{member, member_response};
+use crate::domain::notification::entity::notification;
+use crate::domain::retrospect::entity::{
+    mention, response, response_comment, response_like, retrospect,
+};
 use crate::state::AppState;
 use crate::utils::error::AppError;
+use crate::utils::timezone::{parse_timezone, resolve_timezone, utc_to_local, DEFAULT_TIMEZONE};
+
+/// 멘션 본문 미리보기 최대 글자 수
+const MENTION_SNIPPET_MAX_CHARS: usize = 50;
+
+/// 방해금지 시각 파싱 (HH:mm)
+fn parse_dnd_time(field: &str, value: &str) -> Result<NaiveTime, AppError> {
+    NaiveTime::parse_from_str(value.trim(), "%H:%M").map_err(|_| {
+        AppError::BadRequest(format!(
+            "{} 형식이 올바르지 않습니다. (HH:mm 형식 필요)",
+            field
+        ))
+    })
+}
+
+/// 회원 엔티티 → 방해금지 설정 응답
+fn dnd_settings_response(member: &member::Model) -> DndSettingsResponse {
+    DndSettingsResponse {
+        enabled: member.dnd_start.is_some() && member.dnd_end.is_some(),
+        dnd_start: member.dnd_start.map(|t| t.format("%H:%M").to_string()),
+        dnd_end: member.dnd_end.map(|t| t.format("%H:%M").to_string()),
+        timezone: member.dnd_timezone.clone(),
+    }
+}
+
+/// 멘션 본문 미리보기 (최대 글자 수 초과 시 말줄임)
+fn mention_snippet(content: &str) -> String {
+    if content.chars().count() <= MENTION_SNIPPET_MAX_CHARS {
+        content.to_string()
+    } else {
+        let truncated: String = content.chars().take(MENTION_SNIPPET_MAX_CHARS).collect();
+        format!("{}...", truncated)
+    }
+}
 
 pub struct MemberService;
 
@@ -27,6 +83,7 @@ impl MemberService {
             nickname: member.nickname,
             insight_count: member.insight_count,
             social_type: member.social_type,
+            share_email: member.share_email,
             created_at: Utc.from_utc_datetime(&member.created_at),
         })
     }
@@ -49,9 +106,39 @@ impl MemberService {
             ));
         }
 
+        let txn = state
+            .db
+            .begin()
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        // 누른 좋아요는 member 삭제 시 CASCADE로 함께 삭제되므로 좋아요 수 캐시를 먼저 차감
+        response::Entity::update_many()
+            .col_expr(
+                response::Column::LikeCount,
+                Expr::col(response::Column::LikeCount).sub(1),
+            )
+            .filter(
+                response::Column::ResponseId.in_subquery(
+                    Query::select()
+                        .column(response_like::Column::ResponseId)
+                        .from(response_like::Entity)
+                        .and_where(response_like::Column::MemberId.eq(member_id))
+                        .to_owned(),
+                ),
+            )
+            .filter(response::Column::LikeCount.gt(0))
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
         // member 삭제 (연관 테이블의 member_id는 ON DELETE SET NULL로 자동 NULL 처리)
         member::Entity::delete_by_id(member_id)
-            .exec(&state.db)
+            .exec(&txn)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        txn.commit()
             .await
             .map_err(|e| AppError::InternalError(e.to_string()))?;
 
@@ -59,4 +146,692 @@ impl MemberService {
 
         Ok(())
     }
+
+    /// 최근 수정한 답변 조회
+    ///
+    /// (updated_at, response_id) 내림차순으로 정렬하며, 커서는 마지막으로 조회된 답변 ID입니다.
+    pub async fn list_recent_responses(
+        state: &AppState,
+        member_id: i64,
+        cursor: Option<i64>,
+        size: i32,
+    ) -> Result<RecentResponsesResponse, AppError> {
+        info!(member_id = member_id, "최근 수정한 답변 조회 요청");
+
+        // 1. 본인이 작성한 비어 있지 않은 답변 조회
+        let mut query = response::Entity::find()
+            .inner_join(member_response::Entity)
+            .filter(member_response::Column::MemberId.eq(member_id))
+            .filter(response::Column::Content.ne(""));
+
+        // 2. 커서 위치 이후만 조회 (같은 수정 시각은 ID로 구분)
+        //    커서는 본인 답변으로 한정 (다른 멤버의 답변 ID로 수정 시각을 알아내지 못하도록)
+        if let Some(cursor_id) = cursor {
+            let cursor_response = response::Entity::find_by_id(cursor_id)
+                .inner_join(member_response::Entity)
+                .filter(member_response::Column::MemberId.eq(member_id))
+                .one(&state.db)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+                .ok_or_else(|| AppError::BadRequest("유효하지 않은 cursor입니다.".to_string()))?;
+
+            query = query.filter(
+                Condition::any()
+                    .add(response::Column::UpdatedAt.lt(cursor_response.updated_at))
+                    .add(
+                        Condition::all()
+                            .add(response::Column::UpdatedAt.eq(cursor_response.updated_at))
+                            .add(response::Column::ResponseId.lt(cursor_id)),
+                    ),
+            );
+        }
+
+        let responses = query
+            .order_by_desc(response::Column::UpdatedAt)
+            .order_by_desc(response::Column::ResponseId)
+            .limit((size + 1) as u64)
+            .all(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        let has_next = responses.len() > size as usize;
+        let responses: Vec<response::Model> = responses.into_iter().take(size as usize).collect();
+
+        // 3. 회고 제목 배치 조회
+        let retrospect_ids: Vec<i64> = responses.iter().map(|r| r.retrospect_id).collect();
+        let retrospect_map: HashMap<i64, (String, Tz)> = if retrospect_ids.is_empty() {
+            HashMap::new()
+        } else {
+            retrospect::Entity::find()
+                .filter(retrospect::Column::RetrospectId.is_in(retrospect_ids))
+                .all(&state.db)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+                .into_iter()
+                .map(|r| (r.retrospect_id, (r.title, resolve_timezone(&r.timezone))))
+                .collect()
+        };
+
+        let next_cursor = if has_next {
+            responses.last().map(|r| r.response_id)
+        } else {
+            None
+        };
+
+        let items = responses
+            .into_iter()
+            .map(|r| {
+                let (retrospect_title, tz) = retrospect_map
+                    .get(&r.retrospect_id)
+                    .cloned()
+                    .unwrap_or_else(|| (String::new(), resolve_timezone(DEFAULT_TIMEZONE)));
+                RecentResponseItem {
+                    response_id: r.response_id,
+                    retrospect_id: r.retrospect_id,
+                    retrospect_title,
+                    question: r.question,
+                    content: r.content,
+                    updated_at: utc_to_local(r.updated_at, tz)
+                        .format("%Y-%m-%dT%H:%M:%S")
+                        .to_string(),
+                }
+            })
+            .collect();
+
+        Ok(RecentResponsesResponse {
+            responses: items,
+            has_next,
+            next_cursor,
+        })
+    }
+
+    /// 내가 멘션된 답변/댓글 목록 조회
+    ///
+    /// (created_at, mention_id) 내림차순으로 정렬하며, 커서는 마지막으로 조회된 멘션 ID입니다.
+    pub async fn list_mentions(
+        state: &AppState,
+        member_id: i64,
+        cursor: Option<i64>,
+        size: i32,
+        unread_only: bool,
+    ) -> Result<MentionsResponse, AppError> {
+        info!(member_id = member_id, "내 멘션 목록 조회 요청");
+
+        // 1. 나를 대상으로 한 멘션 조회
+        let mut query = mention::Entity::find().filter(mention::Column::MemberId.eq(member_id));
+        if unread_only {
+            query = query.filter(mention::Column::IsRead.eq(false));
+        }
+
+        // 2. 커서 위치 이후만 조회 (같은 시각은 ID로 구분)
+        if let Some(cursor_id) = cursor {
+            let cursor_mention = mention::Entity::find_by_id(cursor_id)
+                .filter(mention::Column::MemberId.eq(member_id))
+                .one(&state.db)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+                .ok_or_else(|| AppError::BadRequest("유효하지 않은 cursor입니다.".to_string()))?;
+
+            query = query.filter(
+                Condition::any()
+                    .add(mention::Column::CreatedAt.lt(cursor_mention.created_at))
+                    .add(
+                        Condition::all()
+                            .add(mention::Column::CreatedAt.eq(cursor_mention.created_at))
+                            .add(mention::Column::MentionId.lt(cursor_id)),
+                    ),
+            );
+        }
+
+        let mentions = query
+            .order_by_desc(mention::Column::CreatedAt)
+            .order_by_desc(mention::Column::MentionId)
+            .limit((size + 1) as u64)
+            .all(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        let has_next = mentions.len() > size as usize;
+        let mentions: Vec<mention::Model> = mentions.into_iter().take(size as usize).collect();
+
+        // 3. 컨텍스트 배치 조회 (회고 제목, 작성자 닉네임, 답변/댓글 본문)
+        let retrospect_ids: Vec<i64> = mentions.iter().map(|m| m.retrospect_id).collect();
+        let retrospect_map: HashMap<i64, (String, Tz)> = if retrospect_ids.is_empty() {
+            HashMap::new()
+        } else {
+            retrospect::Entity::find()
+                .filter(retrospect::Column::RetrospectId.is_in(retrospect_ids))
+                .all(&state.db)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+                .into_iter()
+                .map(|r| (r.retrospect_id, (r.title, resolve_timezone(&r.timezone))))
+                .collect()
+        };
+
+        let author_ids: Vec<i64> = mentions.iter().filter_map(|m| m.author_id).collect();
+        let nickname_map: HashMap<i64, String> = if author_ids.is_empty() {
+            HashMap::new()
+        } else {
+            member::Entity::find()
+                .filter(member::Column::MemberId.is_in(author_ids))
+                .all(&state.db)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+                .into_iter()
+                .map(|m| (m.member_id, m.nickname.unwrap_or_default()))
+                .collect()
+        };
+
+        let response_ids: Vec<i64> = mentions
+            .iter()
+            .filter(|m| m.response_comment_id.is_none())
+            .map(|m| m.response_id)
+            .collect();
+        let response_content_map: HashMap<i64, String> = if response_ids.is_empty() {
+            HashMap::new()
+        } else {
+            response::Entity::find()
+                .filter(response::Column::ResponseId.is_in(response_ids))
+                .all(&state.db)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+                .into_iter()
+                .map(|r| (r.response_id, r.content))
+                .collect()
+        };
+
+        let comment_ids: Vec<i64> = mentions
+            .iter()
+            .filter_map(|m| m.response_comment_id)
+            .collect();
+        let comment_content_map: HashMap<i64, String> = if comment_ids.is_empty() {
+            HashMap::new()
+        } else {
+            response_comment::Entity::find()
+                .filter(response_comment::Column::ResponseCommentId.is_in(comment_ids))
+                .all(&state.db)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+                .into_iter()
+                .map(|c| (c.response_comment_id, c.content))
+                .collect()
+        };
+
+        let next_cursor = if has_next {
+            mentions.last().map(|m| m.mention_id)
+        } else {
+            None
+        };
+
+        let items = mentions
+            .into_iter()
+            .map(|m| {
+                let (source_type, content) = match m.response_comment_id {
+                    Some(comment_id) => (
+                        MentionSourceType::Comment,
+                        comment_content_map.get(&comment_id),
+                    ),
+                    None => (
+                        MentionSourceType::Response,
+                        response_content_map.get(&m.response_id),
+                    ),
+                };
+                let (retrospect_title, tz) = retrospect_map
+                    .get(&m.retrospect_id)
+                    .cloned()
+                    .unwrap_or_else(|| (String::new(), resolve_timezone(DEFAULT_TIMEZONE)));
+                MentionItem {
+                    mention_id: m.mention_id,
+                    source_type,
+                    retrospect_id: m.retrospect_id,
+                    retrospect_title,
+                    response_id: m.response_id,
+                    comment_id: m.response_comment_id,
+                    author_nickname: m
+                        .author_id
+                        .and_then(|id| nickname_map.get(&id).cloned())
+                        .unwrap_or_else(|| "탈퇴한 멤버".to_string()),
+                    snippet: content.map(|c| mention_snippet(c)).unwrap_or_default(),
+                    is_read: m.is_read,
+                    created_at: utc_to_local(m.created_at, tz)
+                        .format("%Y-%m-%dT%H:%M:%S")
+                        .to_string(),
+                }
+            })
+            .collect();
+
+        Ok(MentionsResponse {
+            mentions: items,
+            has_next,
+            next_cursor,
+        })
+    }
+
+    /// 멘션 읽음 처리 (본인 멘션만 가능)
+    pub async fn mark_mention_read(
+        state: &AppState,
+        member_id: i64,
+        mention_id: i64,
+    ) -> Result<(), AppError> {
+        let result = mention::Entity::update_many()
+            .col_expr(mention::Column::IsRead, Expr::value(true))
+            .filter(mention::Column::MentionId.eq(mention_id))
+            .filter(mention::Column::MemberId.eq(member_id))
+            .exec(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        // 이미 읽은 멘션은 MySQL에서 변경 행 수가 0이므로 존재 여부를 다시 확인
+        if result.rows_affected == 0 {
+            let exists = mention::Entity::find_by_id(mention_id)
+                .filter(mention::Column::MemberId.eq(member_id))
+                .one(&state.db)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+                .is_some();
+            if !exists {
+                return Err(AppError::MentionNotFound(
+                    "존재하지 않는 멘션입니다.".to_string(),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 알림 일괄 읽음 처리
+    ///
+    /// 미읽은 멘션과 알림을 조건부 배치 UPDATE로 읽음 처리하고 변경된 개수를 반환합니다.
+    /// 이미 읽은 항목은 조건에서 빠지므로 동시 요청이 겹쳐도 같은 항목이 두 번 집계되지 않습니다.
+    /// 철회된 알림과 방해금지로 전달이 보류 중인 알림은 제외합니다.
+    pub async fn read_all_notifications(
+        state: &AppState,
+        member_id: i64,
+        read_type: Option<NotificationReadType>,
+    ) -> Result<ReadAllNotificationsResponse, AppError> {
+        let include_mentions = matches!(read_type, None | Some(NotificationReadType::Mention));
+        let include_notifications = read_type != Some(NotificationReadType::Mention);
+        let now = Utc::now().naive_utc();
+
+        let txn = state
+            .db
+            .begin()
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        let mut read_count = 0;
+
+        // 1. 멘션 읽음 처리
+        if include_mentions {
+            read_count += mention::Entity::update_many()
+                .col_expr(mention::Column::IsRead, Expr::value(true))
+                .filter(mention::Column::MemberId.eq(member_id))
+                .filter(mention::Column::IsRead.eq(false))
+                .exec(&txn)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+                .rows_affected;
+        }
+
+        // 2. 알림 읽음 처리 (유형 지정 시 해당 유형만)
+        if include_notifications {
+            let mut update = notification::Entity::update_many()
+                .col_expr(notification::Column::IsRead, Expr::value(true))
+                .filter(notification::Column::MemberId.eq(member_id))
+                .filter(notification::Column::IsRead.eq(false))
+                .filter(notification::Column::DeletedAt.is_null())
+                .filter(
+                    Condition::any()
+                        .add(notification::Column::DeliverAt.is_null())
+                        .add(notification::Column::DeliverAt.lte(now)),
+                );
+            if let Some(notification_type) =
+                read_type.and_then(NotificationReadType::notification_type)
+            {
+                update =
+                    update.filter(notification::Column::NotificationType.eq(notification_type));
+            }
+            read_count += update
+                .exec(&txn)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+                .rows_affected;
+        }
+
+        txn.commit()
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        info!(
+            member_id = member_id,
+            read_type = ?read_type,
+            read_count = read_count,
+            "알림 일괄 읽음 처리"
+        );
+
+        Ok(ReadAllNotificationsResponse { read_count })
+    }
+
+    /// 방해금지 시간대 설정
+    ///
+    /// 시작/종료를 모두 지정하면 설정하고, 모두 비우면 해제합니다.
+    /// 방해금지 시간대에 발행된 알림은 종료 시각까지 전달이 보류됩니다.
+    pub async fn update_dnd(
+        state: &AppState,
+        member_id: i64,
+        req: UpdateDndRequest,
+    ) -> Result<DndSettingsResponse, AppError> {
+        // 1. 입력 검증 (시작/종료는 함께 지정하거나 함께 비워야 함)
+        let window = match (req.dnd_start.as_deref(), req.dnd_end.as_deref()) {
+            (Some(start), Some(end)) => {
+                let start = parse_dnd_time("dndStart", start)?;
+                let end = parse_dnd_time("dndEnd", end)?;
+                if start == end {
+                    return Err(AppError::BadRequest(
+                        "방해금지 시작 시각과 종료 시각은 같을 수 없습니다.".to_string(),
+                    ));
+                }
+                Some((start, end))
+            }
+            (None, None) => None,
+            _ => {
+                return Err(AppError::BadRequest(
+                    "dndStart와 dndEnd는 함께 지정해야 합니다.".to_string(),
+                ))
+            }
+        };
+        let timezone = parse_timezone(req.timezone.as_deref())?;
+
+        // 2. 회원 조회
+        let member = member::Entity::find_by_id(member_id)
+            .one(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?
+            .ok_or_else(|| AppError::MemberNotFound("존재하지 않는 사용자입니다.".to_string()))?;
+
+        // 3. 설정 저장
+        let mut active: member::ActiveModel = member.into();
+        active.dnd_start = Set(window.map(|(start, _)| start));
+        active.dnd_end = Set(window.map(|(_, end)| end));
+        active.dnd_timezone = Set(window.map(|_| timezone.name().to_string()));
+        active.updated_at = Set(Utc::now().naive_utc());
+        let updated = active
+            .update(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        info!(
+            member_id = member_id,
+            enabled = window.is_some(),
+            "방해금지 시간대 설정"
+        );
+
+        Ok(dnd_settings_response(&updated))
+    }
+
+    /// 이메일 공유 설정
+    ///
+    /// 허용한 회원만 회고방 Owner의 멤버 이메일 목록에 포함됩니다.
+    pub async fn update_email_sharing(
+        state: &AppState,
+        member_id: i64,
+        req: UpdateEmailSharingRequest,
+    ) -> Result<EmailSharingResponse, AppError> {
+        // 1. 회원 조회
+        let member = member::Entity::find_by_id(member_id)
+            .one(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?
+            .ok_or_else(|| AppError::MemberNotFound("존재하지 않는 사용자입니다.".to_string()))?;
+
+        // 2. 설정 저장
+        let mut active: member::ActiveModel = member.into();
+        active.share_email = Set(req.share_email);
+        active.updated_at = Set(Utc::now().naive_utc());
+        let updated = active
+            .update(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        info!(
+            member_id = member_id,
+            share_email = updated.share_email,
+            "이메일 공유 설정"
+        );
+
+        Ok(EmailSharingResponse {
+            share_email: updated.share_email,
+        })
+    }
+
+    /// 알림 수신 설정 변경
+    pub async fn update_notification_settings(
+        state: &AppState,
+        member_id: i64,
+        req: UpdateNotificationSettingsRequest,
+    ) -> Result<NotificationSettingsResponse, AppError> {
+        // 1. 회원 조회
+        let member = member::Entity::find_by_id(member_id)
+            .one(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?
+            .ok_or_else(|| AppError::MemberNotFound("존재하지 않는 사용자입니다.".to_string()))?;
+
+        // 2. 설정 저장
+        let mut active: member::ActiveModel = member.into();
+        active.notification_enabled = Set(req.notification_enabled);
+        active.updated_at = Set(Utc::now().naive_utc());
+        let updated = active
+            .update(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        info!(
+            member_id = member_id,
+            notification_enabled = updated.notification_enabled,
+            "알림 수신 설정"
+        );
+
+        Ok(NotificationSettingsResponse {
+            notification_enabled: updated.notification_enabled,
+        })
+    }
+
+    /// 마지막 활동 시각 갱신
+    ///
+    /// 기록이 `interval`보다 오래된 경우에만 갱신해 여러 서버 인스턴스에서도 쓰기를 제한합니다.
+    /// 활동 표시는 부가 정보이므로 실패해도 요청을 막지 않고 경고 로그만 남깁니다.
+    pub async fn touch_last_seen(db: &DatabaseConnection, member_id: i64, interval: Duration) {
+        let now = Utc::now().naive_utc();
+        let cutoff = now - chrono::Duration::seconds(interval.as_secs() as i64);
+
+        let result = member::Entity::update_many()
+            .col_expr(member::Column::LastSeenAt, Expr::value(now))
+            .filter(member::Column::MemberId.eq(member_id))
+            .filter(
+                Condition::any()
+                    .add(member::Column::LastSeenAt.is_null())
+                    .add(member::Column::LastSeenAt.lte(cutoff)),
+            )
+            .exec(db)
+            .await;
+
+        if let Err(e) = result {
+            warn!(member_id = member_id, error = %e, "마지막 활동 시각 갱신 실패");
+        }
+    }
+
+    /// 내 뱃지 목록 조회 (획득순)
+    ///
+    /// 뱃지는 특정 회고에 속하지 않으므로 획득 시각은 기본 타임존으로 변환합니다.
+    pub async fn list_badges(state: &AppState, member_id: i64) -> Result<BadgesResponse, AppError> {
+        let tz = resolve_timezone(DEFAULT_TIMEZONE);
+        let badges = member_badge::Entity::find()
+            .filter(member_badge::Column::MemberId.eq(member_id))
+            .order_by_asc(member_badge::Column::EarnedAt)
+            .order_by_asc(member_badge::Column::MemberBadgeId)
+            .all(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?
+            .into_iter()
+            .filter_map(|badge| {
+                let rule = badge_rule(badge.badge_type)?;
+                Some(BadgeItem {
+                    badge_type: badge.badge_type,
+                    name: rule.name.to_string(),
+                    description: rule.description.to_string(),
+                    earned_at: utc_to_local(badge.earned_at, tz)
+                        .format("%Y-%m-%dT%H:%M:%S")
+                        .to_string(),
+                })
+            })
+            .collect();
+
+        Ok(BadgesResponse { badges })
+    }
+
+    /// 회고 제출 시 뱃지 평가 및 부여
+    ///
+    /// 뱃지는 부가 기능이므로 실패하더라도 제출을 막지 않고 경고 로그만 남깁니다.
+    pub async fn award_submit_badges(state: &AppState, member_id: i64, retrospect_id: i64) {
+        let result = async {
+            let stats = Self::load_submit_badge_stats(state, member_id, retrospect_id).await?;
+            Self::grant_badges(state, member_id, BadgeEvent::RetrospectSubmitted, &stats).await
+        }
+        .await;
+
+        if let Err(e) = result {
+            warn!(
+                member_id = member_id,
+                retrospect_id = retrospect_id,
+                error = %e,
+                "회고 제출 뱃지 평가 실패"
+            );
+        }
+    }
+
+    /// 답변 좋아요 수신 시 작성자 뱃지 평가 및 부여 (실패 시 경고 로그만 남김)
+    pub async fn award_like_badges(state: &AppState, author_id: i64, response_like_count: i32) {
+        let stats = BadgeStats {
+            response_like_count,
+            ..Default::default()
+        };
+
+        if let Err(e) = Self::grant_badges(state, author_id, BadgeEvent::LikeReceived, &stats).await
+        {
+            warn!(
+                member_id = author_id,
+                error = %e,
+                "좋아요 뱃지 평가 실패"
+            );
+        }
+    }
+
+    /// 회고 제출 뱃지 지표 조회 (누적 제출 수, 같은 회고방 연속 제출 횟수)
+    async fn load_submit_badge_stats(
+        state: &AppState,
+        member_id: i64,
+        retrospect_id: i64,
+    ) -> Result<BadgeStats, AppError> {
+        let submitted_statuses = [RetrospectStatus::Submitted, RetrospectStatus::Analyzed];
+
+        // 1. 누적 제출 수
+        let submitted_count = member_retro::Entity::find()
+            .filter(member_retro::Column::MemberId.eq(member_id))
+            .filter(member_retro::Column::Status.is_in(submitted_statuses.clone()))
+            .count(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        // 2. 제출한 회고 기준으로 같은 회고방의 최근 회고 조회 (최신순)
+        let retrospect_model = retrospect::Entity::find_by_id(retrospect_id)
+            .one(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?
+            .ok_or_else(|| AppError::RetrospectNotFound("존재하지 않는 회고입니다.".to_string()))?;
+        let recent_ids: Vec<i64> = retrospect::Entity::find()
+            .filter(retrospect::Column::RetrospectRoomId.eq(retrospect_model.retrospect_room_id))
+            .filter(retrospect::Column::StartTime.lte(retrospect_model.start_time))
+            .order_by_desc(retrospect::Column::StartTime)
+            .order_by_desc(retrospect::Column::RetrospectId)
+            .limit(max_streak_threshold())
+            .select_only()
+            .column(retrospect::Column::RetrospectId)
+            .into_tuple()
+            .all(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        // 3. 최근 회고 중 제출한 회고로 연속 제출 횟수 계산
+        let submitted_ids: HashSet<i64> = if recent_ids.is_empty() {
+            HashSet::new()
+        } else {
+            member_retro::Entity::find()
+                .filter(member_retro::Column::MemberId.eq(member_id))
+                .filter(member_retro::Column::RetrospectId.is_in(recent_ids.clone()))
+                .filter(member_retro::Column::Status.is_in(submitted_statuses))
+                .all(&state.db)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?
+                .into_iter()
+                .map(|mr| mr.retrospect_id)
+                .collect()
+        };
+
+        Ok(BadgeStats {
+            submitted_count,
+            submit_streak: leading_submit_streak(&recent_ids, &submitted_ids),
+            ..Default::default()
+        })
+    }
+
+    /// 이벤트 조건을 만족한 미보유 뱃지 부여
+    ///
+    /// (member_id, badge_type) 유니크 인덱스로 동시 요청에서도 중복 부여되지 않습니다.
+    async fn grant_badges(
+        state: &AppState,
+        member_id: i64,
+        event: BadgeEvent,
+        stats: &BadgeStats,
+    ) -> Result<Vec<BadgeType>, AppError> {
+        let owned: HashSet<BadgeType> = member_badge::Entity::find()
+            .filter(member_badge::Column::MemberId.eq(member_id))
+            .all(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?
+            .into_iter()
+            .map(|badge| badge.badge_type)
+            .collect();
+
+        let now = Utc::now().naive_utc();
+        let mut granted = Vec::new();
+        for badge_type in earned_badges(event, stats, &owned) {
+            let inserted = member_badge::ActiveModel {
+                member_id: Set(member_id),
+                badge_type: Set(badge_type),
+                earned_at: Set(now),
+                ..Default::default()
+            }
+            .insert(&state.db)
+            .await;
+
+            // 동시 요청으로 이미 부여된 경우 유니크 제약 위반은 무시
+            match inserted {
+                Ok(_) => granted.push(badge_type),
+                Err(e) => warn!(
+                    member_id = member_id,
+                    badge_type = ?badge_type,
+                    error = %e,
+                    "뱃지 부여 건너뜀"
+                ),
+            }
+        }
+
+        if !granted.is_empty() {
+            info!(member_id = member_id, badges = ?granted, "뱃지 부여");
+        }
+
+        Ok(granted)
+    }
 }
@@ -1,6 +1,9 @@
+pub mod admin;
 #[allow(dead_code)]
 pub mod ai;
 pub mod auth;
 pub mod member;
+pub mod notification;
 pub mod retrospect;
+pub mod upload;
 pub mod webhook;
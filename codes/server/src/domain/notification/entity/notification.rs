@@ -0,0 +1,86 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 알림 유형 Enum
+///
+/// 유형 추가 시 ENUM 컬럼 마이그레이션이 필요 없도록 문자열 컬럼으로 저장합니다.
+#[derive(
+    Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(50))")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NotificationType {
+    /// 오래된 임시저장 답변 정리 예고 (Owner 대상)
+    #[sea_orm(string_value = "DRAFT_EXPIRY_WARNING")]
+    DraftExpiryWarning,
+    /// 내 회고 답변에 좋아요가 눌림
+    #[sea_orm(string_value = "RESPONSE_LIKED")]
+    ResponseLiked,
+    /// 회고방에 새 회고가 생성됨
+    #[sea_orm(string_value = "RETROSPECT_CREATED")]
+    RetrospectCreated,
+    /// 회고 참여자가 답변을 제출함
+    #[sea_orm(string_value = "RETROSPECT_SUBMITTED")]
+    RetrospectSubmitted,
+    /// 내 회고 답변에 댓글이 달림
+    #[sea_orm(string_value = "RESPONSE_COMMENTED")]
+    ResponseCommented,
+    /// 미제출 참여자 대상 제출 독려
+    #[sea_orm(string_value = "SUBMISSION_REMINDER")]
+    SubmissionReminder,
+    /// 회고방에서 내 역할이 변경됨
+    #[sea_orm(string_value = "ROLE_CHANGED")]
+    RoleChanged,
+}
+
+/// 사용자 알림 엔티티
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "notification")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub notification_id: i64,
+    /// 알림 수신자 ID
+    pub member_id: i64,
+    /// 알림 유형
+    pub notification_type: NotificationType,
+    /// 알림 메시지
+    pub message: String,
+    /// 관련 회고방 ID
+    pub retrospect_room_id: Option<i64>,
+    /// 관련 회고 ID
+    pub retrospect_id: Option<i64>,
+    /// 읽음 여부
+    #[sea_orm(default_value = "false")]
+    pub is_read: bool,
+    /// 생성 일시
+    pub created_at: DateTime,
+    /// 클라이언트 이동용 딥링크 (웹/앱 공통 상대 경로, 예: /retrospects/1)
+    pub deep_link: Option<String>,
+    /// 알림을 발생시킨 원본 이벤트 식별 키 (알림 철회 시 대상 식별용)
+    pub source_key: Option<String>,
+    /// 철회(soft delete) 일시
+    pub deleted_at: Option<DateTime>,
+    /// 전달 보류 해제 시각 (수신자 방해금지 시간대에 발행된 알림, NULL이면 즉시 전달)
+    pub deliver_at: Option<DateTime>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::domain::member::entity::member::Entity",
+        from = "Column::MemberId",
+        to = "crate::domain::member::entity::member::Column::MemberId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Member,
+}
+
+impl Related<crate::domain::member::entity::member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Member.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,331 @@
+use chrono::{NaiveDateTime, NaiveTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, Set};
+use tracing::info;
+
+use crate::domain::member::entity::member;
+use crate::utils::error::AppError;
+use crate::utils::timezone::{resolve_timezone, utc_to_local};
+
+use super::entity::notification::{self, NotificationType};
+
+/// 알림 발행 대상 정보
+#[derive(Debug, Clone)]
+pub struct NewNotification {
+    /// 알림 수신자 ID
+    pub member_id: i64,
+    /// 알림 유형
+    pub notification_type: NotificationType,
+    /// 알림 메시지
+    pub message: String,
+    /// 관련 회고방 ID
+    pub retrospect_room_id: Option<i64>,
+    /// 관련 회고 ID
+    pub retrospect_id: Option<i64>,
+    /// 원본 이벤트 식별 키 (철회 가능한 알림에만 지정)
+    pub source_key: Option<String>,
+    /// 클라이언트 이동용 딥링크 (`build_deep_link`로 생성)
+    pub deep_link: Option<String>,
+}
+
+/// 딥링크가 가리키는 대상 식별자
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DeepLinkTarget {
+    pub retrospect_room_id: Option<i64>,
+    pub retrospect_id: Option<i64>,
+    pub response_id: Option<i64>,
+}
+
+/// 알림 유형별 딥링크 경로 생성
+///
+/// 웹/앱 양쪽에서 해석할 수 있도록 도메인 없이 `base_path`로 시작하는 상대 경로를 반환합니다.
+/// 유형에 필요한 식별자가 없으면 상위 대상(회고 → 회고방) 경로로 대체하고, 모두 없으면 None입니다.
+pub fn build_deep_link(
+    base_path: &str,
+    notification_type: &NotificationType,
+    target: DeepLinkTarget,
+) -> Option<String> {
+    let path = match (notification_type, target.retrospect_id, target.response_id) {
+        (
+            NotificationType::ResponseLiked | NotificationType::ResponseCommented,
+            Some(retrospect_id),
+            Some(response_id),
+        ) => format!("/retrospects/{}/responses/{}", retrospect_id, response_id),
+        (_, Some(retrospect_id), _) => format!("/retrospects/{}", retrospect_id),
+        (_, None, _) => format!("/retro-rooms/{}", target.retrospect_room_id?),
+    };
+
+    Some(format!("{}{}", base_path, path))
+}
+
+/// 답변 좋아요 알림의 원본 이벤트 키
+pub fn response_like_source_key(response_id: i64, liker_id: i64) -> String {
+    format!("RESPONSE_LIKE:{}:{}", response_id, liker_id)
+}
+
+/// 방해금지 시간대에 포함되는지 판단 (시작 포함, 종료 제외)
+///
+/// 시작이 종료보다 늦으면 자정을 넘기는 구간(예: 23:00~07:00)으로 해석합니다.
+/// 시작과 종료가 같으면 빈 구간으로 보고 항상 false입니다.
+pub fn is_within_dnd(local_time: NaiveTime, start: NaiveTime, end: NaiveTime) -> bool {
+    if start <= end {
+        start <= local_time && local_time < end
+    } else {
+        local_time >= start || local_time < end
+    }
+}
+
+/// 방해금지 시간대라면 알림 보류 해제 시각(UTC)을 반환
+///
+/// `now_utc`가 수신자 타임존 기준 방해금지 구간이 아니면 None(즉시 전달)입니다.
+/// 종료 시각이 DST 전환으로 존재하지 않으면 그 직후 유효한 시각으로 보정합니다.
+pub fn dnd_release_at(
+    now_utc: NaiveDateTime,
+    start: NaiveTime,
+    end: NaiveTime,
+    tz: Tz,
+) -> Option<NaiveDateTime> {
+    let now_local = utc_to_local(now_utc, tz);
+    if !is_within_dnd(now_local.time(), start, end) {
+        return None;
+    }
+
+    let release_date = if now_local.time() < end {
+        now_local.date()
+    } else {
+        now_local.date().succ_opt()?
+    };
+    let mut release_local = release_date.and_time(end);
+    for _ in 0..4 {
+        if let Some(release) = tz.from_local_datetime(&release_local).earliest() {
+            return Some(release.naive_utc());
+        }
+        release_local += chrono::Duration::minutes(30);
+    }
+    None
+}
+
+/// 수신자의 방해금지 설정 기준 알림 보류 해제 시각
+fn member_dnd_release_at(member: &member::Model, now_utc: NaiveDateTime) -> Option<NaiveDateTime> {
+    let (start, end) = (member.dnd_start?, member.dnd_end?);
+    let tz = resolve_timezone(member.dnd_timezone.as_deref().unwrap_or_default());
+    dnd_release_at(now_utc, start, end, tz)
+}
+
+pub struct NotificationService;
+
+impl NotificationService {
+    /// 알림 발행 (저장)
+    ///
+    /// 호출 측 트랜잭션에 포함될 수 있도록 커넥션을 인자로 받습니다.
+    /// 수신자가 방해금지 시간대이면 `deliver_at`에 보류 해제 시각을 기록해 그때까지 전달을 미룹니다.
+    pub async fn publish<C: ConnectionTrait>(
+        db: &C,
+        new: NewNotification,
+    ) -> Result<notification::Model, AppError> {
+        let now = Utc::now().naive_utc();
+        let deliver_at = member::Entity::find_by_id(new.member_id)
+            .one(db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?
+            .and_then(|m| member_dnd_release_at(&m, now));
+
+        let model = notification::ActiveModel {
+            member_id: Set(new.member_id),
+            notification_type: Set(new.notification_type.clone()),
+            message: Set(new.message),
+            retrospect_room_id: Set(new.retrospect_room_id),
+            retrospect_id: Set(new.retrospect_id),
+            is_read: Set(false),
+            created_at: Set(now),
+            source_key: Set(new.source_key),
+            deep_link: Set(new.deep_link),
+            deliver_at: Set(deliver_at),
+            ..Default::default()
+        }
+        .insert(db)
+        .await
+        .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        info!(
+            notification_id = model.notification_id,
+            member_id = model.member_id,
+            notification_type = ?new.notification_type,
+            deliver_at = ?model.deliver_at,
+            "알림 발행"
+        );
+
+        Ok(model)
+    }
+
+    /// 원본 이벤트 키로 발행된 알림 철회 (soft delete)
+    ///
+    /// 이미 읽은 알림은 철회하지 않습니다. 철회된 알림 수를 반환합니다.
+    pub async fn withdraw_by_source_key<C: ConnectionTrait>(
+        db: &C,
+        source_key: &str,
+    ) -> Result<u64, AppError> {
+        let result = notification::Entity::update_many()
+            .col_expr(
+                notification::Column::DeletedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(notification::Column::SourceKey.eq(source_key))
+            .filter(notification::Column::IsRead.eq(false))
+            .filter(notification::Column::DeletedAt.is_null())
+            .exec(db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+        if result.rows_affected > 0 {
+            info!(
+                source_key = source_key,
+                withdrawn = result.rows_affected,
+                "알림 철회"
+            );
+        }
+
+        Ok(result.rows_affected)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_build_response_like_source_key_per_liker() {
+        assert_eq!(response_like_source_key(10, 3), "RESPONSE_LIKE:10:3");
+        assert_ne!(
+            response_like_source_key(10, 3),
+            response_like_source_key(10, 4)
+        );
+    }
+
+    #[test]
+    fn should_build_response_deep_link_for_like_and_comment() {
+        let target = DeepLinkTarget {
+            retrospect_room_id: Some(1),
+            retrospect_id: Some(2),
+            response_id: Some(3),
+        };
+
+        assert_eq!(
+            build_deep_link("", &NotificationType::ResponseLiked, target).as_deref(),
+            Some("/retrospects/2/responses/3")
+        );
+        assert_eq!(
+            build_deep_link("", &NotificationType::ResponseCommented, target).as_deref(),
+            Some("/retrospects/2/responses/3")
+        );
+    }
+
+    #[test]
+    fn should_build_retrospect_deep_link_for_retrospect_events() {
+        let target = DeepLinkTarget {
+            retrospect_room_id: Some(1),
+            retrospect_id: Some(2),
+            response_id: None,
+        };
+
+        for notification_type in [
+            NotificationType::RetrospectCreated,
+            NotificationType::RetrospectSubmitted,
+            NotificationType::DraftExpiryWarning,
+        ] {
+            assert_eq!(
+                build_deep_link("", &notification_type, target).as_deref(),
+                Some("/retrospects/2")
+            );
+        }
+    }
+
+    #[test]
+    fn should_fall_back_to_parent_target_and_apply_base_path() {
+        let room_only = DeepLinkTarget {
+            retrospect_room_id: Some(1),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            build_deep_link("/app", &NotificationType::ResponseLiked, room_only).as_deref(),
+            Some("/app/retro-rooms/1")
+        );
+        assert_eq!(
+            build_deep_link(
+                "",
+                &NotificationType::ResponseLiked,
+                DeepLinkTarget::default()
+            ),
+            None
+        );
+    }
+
+    fn time(h: u32, m: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(h, m, 0).unwrap()
+    }
+
+    fn utc(y: i32, mo: u32, d: u32, h: u32, m: u32) -> NaiveDateTime {
+        chrono::NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_detect_dnd_window_crossing_midnight() {
+        let (start, end) = (time(23, 0), time(7, 0));
+
+        assert!(is_within_dnd(time(23, 0), start, end));
+        assert!(is_within_dnd(time(2, 30), start, end));
+        assert!(!is_within_dnd(time(7, 0), start, end));
+        assert!(!is_within_dnd(time(12, 0), start, end));
+    }
+
+    #[test]
+    fn should_treat_equal_start_and_end_as_empty_window() {
+        assert!(!is_within_dnd(time(9, 0), time(9, 0), time(9, 0)));
+    }
+
+    #[test]
+    fn should_release_at_next_end_in_member_timezone() {
+        // 2026-10-16 15:00 UTC = 2026-10-17 00:00 KST (23:00~07:00 구간)
+        let release = dnd_release_at(
+            utc(2026, 10, 16, 15, 0),
+            time(23, 0),
+            time(7, 0),
+            chrono_tz::Asia::Seoul,
+        );
+
+        // 2026-10-17 07:00 KST = 2026-10-16 22:00 UTC
+        assert_eq!(release, Some(utc(2026, 10, 16, 22, 0)));
+    }
+
+    #[test]
+    fn should_release_next_day_when_dnd_started_before_midnight() {
+        // 2026-10-16 14:30 UTC = 2026-10-16 23:30 KST
+        let release = dnd_release_at(
+            utc(2026, 10, 16, 14, 30),
+            time(23, 0),
+            time(7, 0),
+            chrono_tz::Asia::Seoul,
+        );
+
+        assert_eq!(release, Some(utc(2026, 10, 16, 22, 0)));
+    }
+
+    #[test]
+    fn should_deliver_immediately_outside_dnd_window() {
+        // 2026-10-16 03:00 UTC = 2026-10-16 12:00 KST
+        assert_eq!(
+            dnd_release_at(
+                utc(2026, 10, 16, 3, 0),
+                time(23, 0),
+                time(7, 0),
+                chrono_tz::Asia::Seoul
+            ),
+            None
+        );
+    }
+}
@@ -0,0 +1,288 @@
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use axum::response::sse::Event;
+use futures_util::stream::{self, Stream};
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use crate::utils::error::AppError;
+
+use super::dto::{AnalysisProgressEvent, AnalysisResponse, AnalysisStage};
+
+/// 회고별 분석 진행 이벤트 broadcast 채널 버퍼 크기
+const ANALYSIS_PROGRESS_CHANNEL_CAPACITY: usize = 16;
+
+/// 진행 중인 분석 하나의 채널
+#[derive(Debug)]
+struct ProgressChannel {
+    sender: broadcast::Sender<AnalysisProgressEvent>,
+    stage: AnalysisStage,
+}
+
+/// 회고 분석 진행 상태 허브 (in-memory)
+///
+/// 분석 작업이 단계(데이터 수집/AI 호출/저장)를 넘어갈 때마다 같은 회고의 SSE 구독자에게 이벤트를 보내고,
+/// 종료 시 최종 결과(또는 실패)를 마지막 이벤트로 보낸 뒤 채널을 정리합니다.
+/// 진행 중인 분석만 기록하며, Clone 시 내부 상태를 `Arc`로 공유하므로 AppState에 넣어 사용합니다.
+#[derive(Debug, Clone, Default)]
+pub struct AnalysisProgressHub {
+    channels: Arc<Mutex<HashMap<i64, ProgressChannel>>>,
+}
+
+impl AnalysisProgressHub {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 분석 1회의 진행 상태 보고자 생성
+    pub fn reporter(&self, retrospect_id: i64) -> AnalysisProgressReporter {
+        AnalysisProgressReporter {
+            hub: self.clone(),
+            retrospect_id,
+            active: false,
+        }
+    }
+
+    /// 진행 중인 분석 구독 (현재 단계 이벤트와 수신기 반환, 진행 중이 아니면 None)
+    pub fn subscribe(
+        &self,
+        retrospect_id: i64,
+    ) -> Option<(
+        AnalysisProgressEvent,
+        broadcast::Receiver<AnalysisProgressEvent>,
+    )> {
+        let channels = self.lock_channels();
+        let channel = channels.get(&retrospect_id)?;
+        Some((
+            stage_event(retrospect_id, channel.stage),
+            channel.sender.subscribe(),
+        ))
+    }
+
+    /// 분석 시작 (이미 같은 회고의 분석이 진행 중이면 false)
+    fn begin(&self, retrospect_id: i64) -> bool {
+        let mut channels = self.lock_channels();
+        if channels.contains_key(&retrospect_id) {
+            return false;
+        }
+
+        let (sender, _) = broadcast::channel(ANALYSIS_PROGRESS_CHANNEL_CAPACITY);
+        channels.insert(
+            retrospect_id,
+            ProgressChannel {
+                sender,
+                stage: AnalysisStage::Collecting,
+            },
+        );
+        true
+    }
+
+    fn advance(&self, retrospect_id: i64, stage: AnalysisStage) {
+        let mut channels = self.lock_channels();
+        let Some(channel) = channels.get_mut(&retrospect_id) else {
+            return;
+        };
+
+        channel.stage = stage;
+        // 구독자가 없으면 전송 실패는 무시
+        let _ = channel.sender.send(stage_event(retrospect_id, stage));
+    }
+
+    fn finish(&self, event: AnalysisProgressEvent) {
+        if let Some(channel) = self.lock_channels().remove(&event.retrospect_id) {
+            let _ = channel.sender.send(event);
+        }
+    }
+
+    fn lock_channels(&self) -> std::sync::MutexGuard<'_, HashMap<i64, ProgressChannel>> {
+        match self.channels.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("분석 진행 상태 mutex poisoned, 복구합니다");
+                poisoned.into_inner()
+            }
+        }
+    }
+}
+
+/// 분석 1회의 진행 상태 보고자
+///
+/// `begin`으로 진행 중 분석을 선점한 경우에만 이벤트를 보내므로,
+/// 같은 회고에 대한 중복 요청이 먼저 시작된 분석의 스트림을 종료시키지 않습니다.
+#[derive(Debug)]
+pub struct AnalysisProgressReporter {
+    hub: AnalysisProgressHub,
+    retrospect_id: i64,
+    active: bool,
+}
+
+impl AnalysisProgressReporter {
+    /// 데이터 수집 단계로 분석 시작
+    pub fn begin(&mut self) {
+        self.active = self.hub.begin(self.retrospect_id);
+    }
+
+    /// 다음 진행 단계로 이동
+    pub fn advance(&self, stage: AnalysisStage) {
+        if self.active {
+            self.hub.advance(self.retrospect_id, stage);
+        }
+    }
+
+    /// 분석 종료 (성공 시 최종 결과, 실패 시 사유를 마지막 이벤트로 전송)
+    pub fn finish(self, result: &Result<AnalysisResponse, AppError>) {
+        if !self.active {
+            return;
+        }
+
+        let event = match result {
+            Ok(analysis) => AnalysisProgressEvent {
+                result: Some(analysis.clone()),
+                ..stage_event(self.retrospect_id, AnalysisStage::Completed)
+            },
+            Err(e) => AnalysisProgressEvent {
+                message: Some(e.to_string()),
+                ..stage_event(self.retrospect_id, AnalysisStage::Failed)
+            },
+        };
+        self.hub.finish(event);
+    }
+}
+
+/// 결과/사유 없이 단계만 담은 이벤트
+pub fn stage_event(retrospect_id: i64, stage: AnalysisStage) -> AnalysisProgressEvent {
+    AnalysisProgressEvent {
+        retrospect_id,
+        stage,
+        message: None,
+        result: None,
+    }
+}
+
+/// 분석 진행 SSE 스트림
+///
+/// 현재 상태를 첫 이벤트로 보내고, 진행 중이면 종료 이벤트(완료/실패)를 받을 때까지 이어서 전달합니다.
+pub fn analysis_progress_stream(
+    initial: AnalysisProgressEvent,
+    receiver: Option<broadcast::Receiver<AnalysisProgressEvent>>,
+) -> impl Stream<Item = Result<Event, Infallible>> {
+    stream::unfold(
+        (Some(initial), receiver),
+        |(pending, mut receiver)| async move {
+            if let Some(event) = pending {
+                // 진행 중이 아니면 현재 상태만 보내고 종료
+                if !event.stage.is_running() {
+                    receiver = None;
+                }
+                return Some((Ok(to_sse_event(&event)), (None, receiver)));
+            }
+
+            let events = receiver.as_mut()?;
+            loop {
+                match events.recv().await {
+                    Ok(event) => {
+                        if !event.stage.is_running() {
+                            receiver = None;
+                        }
+                        return Some((Ok(to_sse_event(&event)), (None, receiver)));
+                    }
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => warn!(
+                        skipped = skipped,
+                        "분석 진행 이벤트 수신 지연으로 일부 이벤트 누락"
+                    ),
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        },
+    )
+}
+
+fn to_sse_event(event: &AnalysisProgressEvent) -> Event {
+    Event::default()
+        .event("progress")
+        .data(serde_json::to_string(event).unwrap_or_default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_broadcast_stages_and_final_result() {
+        // Arrange
+        let hub = AnalysisProgressHub::new();
+        let mut reporter = hub.reporter(1);
+        reporter.begin();
+        let (current, mut events) = hub.subscribe(1).unwrap();
+
+        // Act
+        reporter.advance(AnalysisStage::CallingAi);
+        reporter.advance(AnalysisStage::Saving);
+        reporter.finish(&Ok(AnalysisResponse {
+            insight: "좋은 회고였습니다.".to_string(),
+            emotion_rank: vec![],
+            personal_missions: vec![],
+        }));
+
+        // Assert
+        assert_eq!(current.stage, AnalysisStage::Collecting);
+        assert_eq!(events.try_recv().unwrap().stage, AnalysisStage::CallingAi);
+        assert_eq!(events.try_recv().unwrap().stage, AnalysisStage::Saving);
+        let last = events.try_recv().unwrap();
+        assert_eq!(last.stage, AnalysisStage::Completed);
+        assert_eq!(last.result.unwrap().insight, "좋은 회고였습니다.");
+        assert!(hub.subscribe(1).is_none());
+    }
+
+    #[test]
+    fn should_send_failure_reason_as_last_event() {
+        // Arrange
+        let hub = AnalysisProgressHub::new();
+        let mut reporter = hub.reporter(1);
+        reporter.begin();
+        let (_, mut events) = hub.subscribe(1).unwrap();
+
+        // Act
+        reporter.finish(&Err(AppError::InternalError("AI 오류".to_string())));
+
+        // Assert
+        let last = events.try_recv().unwrap();
+        assert_eq!(last.stage, AnalysisStage::Failed);
+        assert!(last.message.is_some());
+        assert!(last.result.is_none());
+    }
+
+    #[test]
+    fn should_not_interrupt_running_analysis_on_duplicate_request() {
+        // Arrange
+        let hub = AnalysisProgressHub::new();
+        let mut first = hub.reporter(1);
+        first.begin();
+        let (_, mut events) = hub.subscribe(1).unwrap();
+
+        // Act
+        let mut duplicate = hub.reporter(1);
+        duplicate.begin();
+        duplicate.finish(&Err(AppError::RetroAlreadyAnalyzed(
+            "이미 분석이 완료된 회고입니다.".to_string(),
+        )));
+
+        // Assert
+        assert!(events.try_recv().is_err());
+        assert!(hub.subscribe(1).is_some());
+    }
+
+    #[test]
+    fn should_return_none_when_analysis_not_running() {
+        // Arrange
+        let hub = AnalysisProgressHub::new();
+
+        // Act
+        let subscription = hub.subscribe(1);
+
+        // Assert
+        assert!(subscription.is_none());
+    }
+}
@@ -0,0 +1,220 @@
+use chrono::{NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+
+/// iCalendar 한 줄 최대 길이 (RFC 5545, CRLF 제외 75 octets)
+const ICS_LINE_MAX_OCTETS: usize = 75;
+
+/// 캘린더 이벤트를 만든 제품 식별자
+const ICS_PRODUCT_ID: &str = "-//Moalog//Retrospect Calendar//KO";
+
+/// 회고 일정 캘린더 이벤트
+#[derive(Debug, Clone)]
+pub struct CalendarEvent {
+    /// 이벤트 고유 식별자 (같은 회고는 항상 같은 값)
+    pub uid: String,
+    /// 이벤트 제목
+    pub summary: String,
+    /// 이벤트 설명 (줄바꿈 허용)
+    pub description: String,
+    /// 장소 (회고방 이름)
+    pub location: String,
+    /// 시작 시각 (timezone 기준 벽시계 시간)
+    pub start_local: NaiveDateTime,
+    /// 진행 시간 (분)
+    pub duration_minutes: i64,
+    /// 기준 타임존
+    pub timezone: Tz,
+    /// 생성 시각 (UTC)
+    pub stamp_utc: NaiveDateTime,
+}
+
+/// iCalendar TEXT 값 이스케이프 (RFC 5545 3.3.11)
+///
+/// 역슬래시, 세미콜론, 쉼표를 이스케이프하고 줄바꿈은 `\n`으로 바꿉니다.
+pub fn escape_ics_text(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\r' => {
+                if chars.peek() != Some(&'\n') {
+                    escaped.push_str("\\n");
+                }
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 75 octets를 넘는 콘텐츠 라인 접기 (UTF-8 문자 중간에서 자르지 않음)
+pub fn fold_ics_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + line.len() / ICS_LINE_MAX_OCTETS * 3);
+    let mut line_octets = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if line_octets + len > ICS_LINE_MAX_OCTETS {
+            folded.push_str("\r\n ");
+            // 접힌 줄은 선행 공백 1 octet을 포함
+            line_octets = 1;
+        }
+        folded.push(c);
+        line_octets += len;
+    }
+    folded
+}
+
+/// UTC 오프셋 표기 (예: +0900, -0430)
+fn format_utc_offset(offset_secs: i32) -> String {
+    let sign = if offset_secs < 0 { '-' } else { '+' };
+    let abs = offset_secs.abs();
+    format!("{}{:02}{:02}", sign, abs / 3600, (abs % 3600) / 60)
+}
+
+/// 시작 시각 기준 타임존 오프셋 (초)
+///
+/// DST 전환으로 존재하지 않는 벽시계 시간이면 1시간 뒤의 오프셋을 사용합니다.
+fn offset_at(tz: Tz, local: NaiveDateTime) -> i32 {
+    tz.from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            tz.from_local_datetime(&(local + chrono::Duration::hours(1)))
+                .earliest()
+        })
+        .map(|dt| dt.offset().fix().local_minus_utc())
+        .unwrap_or(0)
+}
+
+/// 회고 일정 iCalendar(.ics) 문서 생성
+///
+/// 시작/종료 시각은 TZID로 회고 타임존을 지정하고, 해당 일정 시점의 오프셋을 담은 VTIMEZONE을 함께 포함합니다.
+pub fn build_ics(event: &CalendarEvent) -> String {
+    const ICS_DATETIME: &str = "%Y%m%dT%H%M%S";
+
+    let tzid = event.timezone.name();
+    let offset = format_utc_offset(offset_at(event.timezone, event.start_local));
+    let end_local = event.start_local + chrono::Duration::minutes(event.duration_minutes);
+
+    let lines = [
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        format!("PRODID:{}", ICS_PRODUCT_ID),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        "BEGIN:VTIMEZONE".to_string(),
+        format!("TZID:{}", tzid),
+        "BEGIN:STANDARD".to_string(),
+        "DTSTART:19700101T000000".to_string(),
+        format!("TZOFFSETFROM:{}", offset),
+        format!("TZOFFSETTO:{}", offset),
+        "END:STANDARD".to_string(),
+        "END:VTIMEZONE".to_string(),
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:{}", event.uid),
+        format!("DTSTAMP:{}Z", event.stamp_utc.format(ICS_DATETIME)),
+        format!(
+            "DTSTART;TZID={}:{}",
+            tzid,
+            event.start_local.format(ICS_DATETIME)
+        ),
+        format!("DTEND;TZID={}:{}", tzid, end_local.format(ICS_DATETIME)),
+        format!("SUMMARY:{}", escape_ics_text(&event.summary)),
+        format!("DESCRIPTION:{}", escape_ics_text(&event.description)),
+        format!("LOCATION:{}", escape_ics_text(&event.location)),
+        "END:VEVENT".to_string(),
+        "END:VCALENDAR".to_string(),
+    ];
+
+    lines
+        .iter()
+        .map(|line| fold_ics_line(line))
+        .collect::<Vec<_>>()
+        .join("\r\n")
+        + "\r\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn datetime(y: i32, mo: u32, d: u32, h: u32, m: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, mo, d)
+            .unwrap()
+            .and_hms_opt(h, m, 0)
+            .unwrap()
+    }
+
+    fn sample_event() -> CalendarEvent {
+        CalendarEvent {
+            uid: "retrospect-1@moalog.me".to_string(),
+            summary: "스프린트 3 회고".to_string(),
+            description: "회고방: 모아로그\n회고 방식: KPT".to_string(),
+            location: "모아로그".to_string(),
+            start_local: datetime(2026, 10, 20, 14, 0),
+            duration_minutes: 60,
+            timezone: chrono_tz::Asia::Seoul,
+            stamp_utc: datetime(2026, 10, 16, 0, 0),
+        }
+    }
+
+    #[test]
+    fn should_escape_special_characters_and_newlines() {
+        assert_eq!(
+            escape_ics_text("a\\b;c,d\ne\r\nf"),
+            "a\\\\b\\;c\\,d\\ne\\nf"
+        );
+    }
+
+    #[test]
+    fn should_fold_long_lines_without_splitting_characters() {
+        // Arrange: 한글은 UTF-8 3 octets
+        let line = format!("SUMMARY:{}", "가".repeat(40));
+
+        // Act
+        let folded = fold_ics_line(&line);
+
+        // Assert
+        for part in folded.split("\r\n") {
+            assert!(part.len() <= ICS_LINE_MAX_OCTETS);
+        }
+        assert_eq!(folded.replace("\r\n ", ""), line);
+    }
+
+    #[test]
+    fn should_build_event_with_timezone() {
+        // Act
+        let ics = build_ics(&sample_event());
+
+        // Assert
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics.contains("TZID:Asia/Seoul\r\n"));
+        assert!(ics.contains("TZOFFSETTO:+0900\r\n"));
+        assert!(ics.contains("DTSTART;TZID=Asia/Seoul:20261020T140000\r\n"));
+        assert!(ics.contains("DTEND;TZID=Asia/Seoul:20261020T150000\r\n"));
+        assert!(ics.contains("DTSTAMP:20261016T000000Z\r\n"));
+        assert!(ics.contains("DESCRIPTION:회고방: 모아로그\\n회고 방식: KPT\r\n"));
+    }
+
+    #[test]
+    fn should_use_offset_at_event_time_for_dst_timezone() {
+        // Arrange: 뉴욕은 7월 EDT(-0400), 12월 EST(-0500)
+        let mut event = sample_event();
+        event.timezone = chrono_tz::America::New_York;
+
+        // Act
+        event.start_local = datetime(2026, 7, 1, 9, 0);
+        let summer = build_ics(&event);
+        event.start_local = datetime(2026, 12, 1, 9, 0);
+        let winter = build_ics(&event);
+
+        // Assert
+        assert!(summer.contains("TZOFFSETTO:-0400\r\n"));
+        assert!(winter.contains("TZOFFSETTO:-0500\r\n"));
+    }
+}
@@ -5,8 +5,13 @@ use serde::{Deserialize, Serialize};
 use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
+use super::entity::response::AnswerCategory;
 use super::entity::retrospect::RetrospectMethod;
+use super::entity::room_subscription::SubscriptionChannel;
 use crate::domain::member::entity::member_retro::RetrospectStatus;
+use crate::domain::member::entity::member_retro_room::RoomRole;
+use crate::domain::member::entity::room_membership_history::MembershipEvent;
+use crate::utils::response::FieldErrorItem;
 
 // ============================================
 // RetroRoom DTOs (API-004 ~ API-010)
@@ -51,6 +56,8 @@ pub struct JoinRetroRoomRequest {
 pub struct JoinRetroRoomResponse {
     pub retro_room_id: i64,
     pub title: String,
+    /// 참여 후 부여된 역할 (MEMBER, MODERATOR)
+    pub role: String,
     pub joined_at: String,
 }
 
@@ -63,6 +70,71 @@ pub struct SuccessJoinRetroRoomResponse {
     pub result: JoinRetroRoomResponse,
 }
 
+// ============== 회고방 초대 미리보기 ==============
+
+/// 초대 미리보기 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+pub struct InvitePreviewQueryParams {
+    /// 초대 코드 (INV-XXXX-XXXX) 또는 초대 링크
+    pub code: String,
+}
+
+/// 초대 미리보기 응답 (참여 전 공개 정보만 포함)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RetroRoomPreviewResponse {
+    /// 회고방 이름
+    pub retro_room_name: String,
+    /// 회고방 설명
+    pub description: Option<String>,
+    /// 현재 멤버 수
+    pub member_count: i64,
+    /// 회고방의 회고 수
+    pub retrospect_count: i64,
+    /// 최근 활동 일시 (마지막 회고 생성 시각, ISO 8601 형식 / 회고가 없으면 null)
+    pub last_activity_at: Option<String>,
+    /// 초대 코드 만료 여부 (만료되었으면 참여 불가)
+    pub is_expired: bool,
+    /// 초대 코드 만료 일시 (ISO 8601 형식)
+    pub expires_at: String,
+}
+
+/// Swagger용 초대 미리보기 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRetroRoomPreviewResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: RetroRoomPreviewResponse,
+}
+
+/// 초대 링크 OpenGraph 메타 응답 (링크 공유 썸네일/미리보기용)
+///
+/// 방 이름과 멤버 수만 담으며, 만료·유효하지 않은 코드는 같은 일반 메타로 응답합니다.
+#[derive(Debug, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteMetaResponse {
+    /// og:title
+    pub og_title: String,
+    /// og:description
+    pub og_description: String,
+    /// og:type (항상 website)
+    pub og_type: String,
+    /// 참여 가능한 초대 코드인지 여부 (false면 일반 메타로 폴백된 응답)
+    pub valid: bool,
+}
+
+/// Swagger용 초대 링크 메타 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessInviteMetaResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: InviteMetaResponse,
+}
+
 // ============== API-006: 회고방 목록 조회 ==============
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -71,6 +143,8 @@ pub struct RetroRoomListItem {
     pub retro_room_id: i64,
     pub retro_room_name: String,
     pub order_index: i32,
+    /// 마지막 확인 이후 미확인 활동 수 (새 회고 + 다른 멤버의 새 댓글)
+    pub unread_count: i64,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -92,10 +166,12 @@ pub struct RetroRoomMemberItem {
     pub member_id: i64,
     /// 멤버 닉네임
     pub nickname: String,
-    /// 회고방 내 역할 ("OWNER" 또는 "MEMBER")
+    /// 회고방 내 역할 ("OWNER", "MODERATOR", "MEMBER")
     pub role: String,
     /// 회고방 참여 일시 (ISO 8601 형식)
     pub joined_at: String,
+    /// 마지막 활동 일시 (ISO 8601 형식, 최대 5분 지연, 기록이 없으면 null)
+    pub last_seen_at: Option<String>,
 }
 
 /// Swagger용 회고방 멤버 목록 조회 성공 응답 타입
@@ -108,296 +184,1689 @@ pub struct SuccessRetroRoomMembersResponse {
     pub result: Vec<RetroRoomMemberItem>,
 }
 
-// ============== API-007: 회고방 순서 변경 ==============
+// ============== 회고방 멤버 이메일 목록 조회 ==============
 
-#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+/// 멤버 이메일 아이템
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct RetroRoomOrderItem {
-    #[validate(range(min = 1, message = "retroRoomId는 1 이상이어야 합니다."))]
-    pub retro_room_id: i64,
-    #[validate(range(min = 1, message = "orderIndex는 1 이상이어야 합니다."))]
-    pub order_index: i32,
+pub struct RoomMemberEmailItem {
+    /// 멤버 고유 식별자
+    pub member_id: i64,
+    /// 멤버 닉네임 (회고방 표시 이름 우선)
+    pub nickname: String,
+    /// 이메일 주소
+    pub email: String,
 }
 
-#[derive(Debug, Deserialize, Validate, ToSchema)]
+/// 회고방 멤버 이메일 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct UpdateRetroRoomOrderRequest {
-    #[validate(length(min = 1, message = "최소 1개 이상의 순서 정보가 필요합니다."))]
-    #[validate(nested)]
-    pub retro_room_orders: Vec<RetroRoomOrderItem>,
+pub struct RoomMemberEmailsResponse {
+    pub retro_room_id: i64,
+    /// 이메일 공유를 허용한 멤버 목록 (멤버 표시 순서)
+    pub members: Vec<RoomMemberEmailItem>,
+    /// 이메일 공유를 허용하지 않아 제외된 멤버 수
+    pub excluded_count: i32,
 }
 
+/// Swagger용 회고방 멤버 이메일 목록 조회 성공 응답 타입
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SuccessEmptyResponse {
+pub struct SuccessRoomMemberEmailsResponse {
     pub is_success: bool,
     pub code: String,
     pub message: String,
-    pub result: Option<()>,
+    pub result: RoomMemberEmailsResponse,
 }
 
-// ============== API-008: 회고방 이름 변경 ==============
+// ============== 회고방 멤버 표시 순서 변경 ==============
+
+/// 멤버 표시 순서 아이템
+#[derive(Debug, Clone, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MemberOrderItem {
+    #[validate(range(min = 1, message = "memberId는 1 이상이어야 합니다."))]
+    pub member_id: i64,
+    #[validate(range(min = 1, message = "displayOrder는 1 이상이어야 합니다."))]
+    pub display_order: i32,
+}
 
+/// 멤버 표시 순서 변경 요청 (Owner 전용)
+///
+/// 목록에 없는 멤버의 지정 순서는 해제되며, 빈 목록이면 모든 멤버가 기본 정렬로 돌아갑니다.
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct UpdateRetroRoomNameRequest {
-    #[validate(length(min = 1, max = 20, message = "룸 이름은 1~20자여야 합니다."))]
-    pub name: String,
+pub struct UpdateMemberOrderRequest {
+    #[validate(nested)]
+    pub member_orders: Vec<MemberOrderItem>,
+}
+
+// ============== 회고방 멤버 역할 변경 ==============
+
+/// 역할 변경으로 부여 가능한 역할 (Owner 이전은 별도 API)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AssignableRoomRole {
+    Member,
+    Moderator,
 }
 
+impl From<AssignableRoomRole> for RoomRole {
+    fn from(role: AssignableRoomRole) -> Self {
+        match role {
+            AssignableRoomRole::Member => RoomRole::Member,
+            AssignableRoomRole::Moderator => RoomRole::Moderator,
+        }
+    }
+}
+
+/// 회고방 멤버 역할 변경 요청 (Owner 전용)
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateMemberRoleRequest {
+    /// 변경할 역할
+    pub role: AssignableRoomRole,
+}
+
+/// 회고방 멤버 역할 변경 응답
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct UpdateRetroRoomNameResponse {
+pub struct UpdateMemberRoleResponse {
     pub retro_room_id: i64,
-    pub retro_room_name: String,
-    pub updated_at: String,
+    pub member_id: i64,
+    /// 변경 후 역할 (OWNER, MODERATOR, MEMBER)
+    pub role: String,
+    /// 실제로 역할이 바뀌었는지 여부 (동일 역할 요청이면 false, 알림도 생략)
+    pub changed: bool,
 }
 
+/// Swagger용 회고방 멤버 역할 변경 성공 응답 타입
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SuccessUpdateRetroRoomNameResponse {
+pub struct SuccessUpdateMemberRoleResponse {
     pub is_success: bool,
     pub code: String,
     pub message: String,
-    pub result: UpdateRetroRoomNameResponse,
+    pub result: UpdateMemberRoleResponse,
 }
 
-// ============== API-009: 회고방 삭제 ==============
+// ============== 회고방 Owner 위임 ==============
 
+/// 회고방 Owner 위임 요청 (Owner 전용)
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TransferOwnershipRequest {
+    /// 새 Owner가 될 멤버 ID (같은 회고방 멤버)
+    pub member_id: i64,
+}
+
+/// 회고방 Owner 위임 응답
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct DeleteRetroRoomResponse {
+pub struct TransferOwnershipResponse {
     pub retro_room_id: i64,
-    pub deleted_at: String,
+    /// 이전 Owner ID (위임 후 MEMBER)
+    pub previous_owner_id: i64,
+    /// 새 Owner ID
+    pub new_owner_id: i64,
 }
 
+/// Swagger용 회고방 Owner 위임 성공 응답 타입
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SuccessDeleteRetroRoomResponse {
+pub struct SuccessTransferOwnershipResponse {
     pub is_success: bool,
     pub code: String,
     pub message: String,
-    pub result: DeleteRetroRoomResponse,
+    pub result: TransferOwnershipResponse,
 }
 
-// ============== API-010: 회고방 내 회고 목록 조회 ==============
+// ============== 회고방 멤버 강퇴 ==============
 
+/// 회고방 멤버 강퇴 응답
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct RetrospectListItem {
-    pub retrospect_id: i64,
-    pub project_name: String,
-    pub retrospect_method: String,
-    pub retrospect_date: String,
-    pub retrospect_time: String,
-    /// 해당 회고의 참여자 수
-    pub participant_count: i64,
+pub struct KickMemberResponse {
+    pub retro_room_id: i64,
+    /// 강퇴된 멤버 ID
+    pub member_id: i64,
+    pub kicked_at: String,
 }
 
+/// Swagger용 회고방 멤버 강퇴 성공 응답 타입
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SuccessRetrospectListResponse {
+pub struct SuccessKickMemberResponse {
     pub is_success: bool,
     pub code: String,
     pub message: String,
-    pub result: Vec<RetrospectListItem>,
+    pub result: KickMemberResponse,
 }
 
-// ============================================
-// Retrospect DTOs
-// ============================================
+// ============== 회고방 멤버 mention 자동완성 ==============
 
-/// 참고 URL 최대 길이 (개별 URL당)
-pub const REFERENCE_URL_MAX_LENGTH: usize = 2048;
+/// mention 자동완성 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct MentionQueryParams {
+    /// 닉네임 접두사 (비어 있으면 최근 활동 멤버 순으로 반환)
+    pub q: Option<String>,
+    /// 최대 반환 개수 (1~20, 기본값 10)
+    pub limit: Option<u64>,
+}
 
-/// 참고 URL 개별 길이 검증
-fn validate_reference_url_items(urls: &[String]) -> Result<(), validator::ValidationError> {
-    for url in urls {
-        if url.len() > REFERENCE_URL_MAX_LENGTH {
-            let mut err = validator::ValidationError::new("url_too_long");
-            err.message = Some(Cow::Borrowed("각 URL은 최대 2048자까지 허용됩니다"));
-            return Err(err);
-        }
-    }
-    Ok(())
+/// mention 자동완성 후보 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MentionCandidateItem {
+    /// 멤버 고유 식별자
+    pub member_id: i64,
+    /// 멤버 닉네임
+    pub nickname: String,
 }
 
-/// 회고 생성 요청 DTO
-#[derive(Debug, Deserialize, Validate, ToSchema)]
+/// Swagger용 mention 자동완성 목록 조회 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct CreateRetrospectRequest {
-    /// 회고가 속한 회고방의 고유 ID
-    #[validate(range(min = 1, message = "회고방 ID는 1 이상이어야 합니다"))]
-    pub retro_room_id: i64,
+pub struct SuccessMentionCandidatesResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: Vec<MentionCandidateItem>,
+}
 
-    /// 프로젝트 이름 (최소 1자, 최대 20자)
-    #[validate(length(
-        min = 1,
-        max = 20,
-        message = "프로젝트 이름은 1자 이상 20자 이하여야 합니다"
-    ))]
-    pub project_name: String,
+// ============== 회고방 Slack 연동 ==============
 
-    /// 회고 날짜 (ISO 8601 형식: YYYY-MM-DD)
-    #[validate(length(
-        min = 10,
-        max = 10,
-        message = "날짜 형식이 올바르지 않습니다. (YYYY-MM-DD 형식 필요)"
-    ))]
-    pub retrospect_date: String,
+/// Slack 연동 설정 요청 (Owner 전용)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSlackIntegrationRequest {
+    /// Slack Incoming Webhook URL (https://hooks.slack.com/...), null이면 연동 해제
+    /// (최대 길이는 retro_room.slack_webhook_url 컬럼 VARCHAR(255)와 동일, 초과 시 DB 오류 대신 400)
+    #[validate(length(max = 255, message = "webhookUrl은 최대 255자까지 입력할 수 있습니다."))]
+    pub webhook_url: Option<String>,
+}
 
-    /// 회고 시간 (HH:mm 형식, 한국 시간 기준)
-    #[validate(length(
-        min = 5,
-        max = 5,
-        message = "시간 형식이 올바르지 않습니다. (HH:mm 형식 필요)"
-    ))]
-    pub retrospect_time: String,
+/// Slack 연동 설정 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SlackIntegrationResponse {
+    pub retro_room_id: i64,
+    /// Slack 연동 여부
+    pub connected: bool,
+    pub updated_at: String,
+}
 
-    /// 회고 방식
-    pub retrospect_method: RetrospectMethod,
+/// Swagger용 Slack 연동 설정 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessSlackIntegrationResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: SlackIntegrationResponse,
+}
 
-    /// 참고 자료 URL 리스트 (최대 10개, 각 URL 최대 2048자)
-    #[validate(
-        length(max = 10, message = "참고 URL은 최대 10개까지 등록 가능합니다"),
-        custom(function = "validate_reference_url_items")
-    )]
-    #[serde(default)]
-    pub reference_urls: Vec<String>,
+// ============== 회고방 스냅샷 복원 ==============
+
+/// 회고방 스냅샷 복원 요청
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RestoreRetroRoomRequest {
+    /// 스냅샷 JSON 문서 (회고방 스냅샷 API로 내려받은 파일 내용)
+    #[schema(value_type = Object)]
+    pub snapshot: serde_json::Value,
+    /// 복원할 회고방 이름 (미지정 시 스냅샷의 방 이름)
+    #[validate(length(min = 1, max = 20, message = "회고방 이름은 1~20자여야 합니다."))]
+    pub title: Option<String>,
+    /// 댓글 복원 여부 (기본값: true)
+    pub include_comments: Option<bool>,
+    /// 좋아요 복원 여부 (기본값: true)
+    pub include_likes: Option<bool>,
 }
 
-/// 회고 생성 응답 DTO
+/// 회고방 스냅샷 복원 응답
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct CreateRetrospectResponse {
-    /// 생성된 회고 고유 ID
-    pub retrospect_id: i64,
-    /// 회고가 속한 회고방의 고유 ID
+pub struct RestoreRetroRoomResponse {
+    /// 새로 생성된 회고방 ID
     pub retro_room_id: i64,
-    /// 저장된 프로젝트 이름
-    pub project_name: String,
+    pub title: String,
+    /// 새 초대 코드
+    pub invite_code: String,
+    /// 복원된 회고 수
+    pub retrospect_count: i64,
+    /// 복원된 답변 수
+    pub response_count: i64,
+    /// 복원된 댓글 수 (작성자가 매칭되지 않은 댓글은 제외)
+    pub comment_count: i64,
+    /// 복원된 좋아요 수 (누른 사람이 매칭되지 않은 좋아요는 제외)
+    pub like_count: i64,
+    /// 기존 회원과 매칭되지 않아 작성자 없이 복원된 스냅샷 멤버 수
+    pub unmatched_member_count: i64,
 }
 
-/// Swagger용 성공 응답 타입
+/// 회고방 스냅샷 복원 성공 응답 (Swagger 문서용)
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SuccessCreateRetrospectResponse {
+pub struct SuccessRestoreRetroRoomResponse {
     pub is_success: bool,
     pub code: String,
     pub message: String,
-    pub result: CreateRetrospectResponse,
+    pub result: RestoreRetroRoomResponse,
 }
 
-// ============================================
-// API-016: 회고 답변 임시 저장 DTO
-// ============================================
+// ============== 회고방 좋아요 급상승 답변 ==============
 
-/// 회고 답변 임시 저장 요청 DTO
-#[derive(Debug, Deserialize, ToSchema)]
+/// 좋아요 급상승 답변 조회 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
 #[serde(rename_all = "camelCase")]
-pub struct DraftSaveRequest {
-    /// 임시 저장할 답변 데이터 리스트 (최소 1개, 최대 5개)
-    pub drafts: Vec<DraftItem>,
+pub struct TrendingResponsesQuery {
+    /// 집계 기간 (최근 N시간, 1~168, 기본값 24)
+    pub hours: Option<i64>,
+    /// 최대 반환 개수 (1~20, 기본값 10)
+    pub limit: Option<u64>,
 }
 
-/// 임시 저장 답변 아이템
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+/// 좋아요 급상승 답변 아이템
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct DraftItem {
-    /// 질문 번호 (1~5)
-    pub question_number: i32,
-    /// 답변 내용 (최대 1,000자, null 또는 빈 문자열 허용)
-    pub content: Option<String>,
+pub struct TrendingResponseItem {
+    /// 답변 고유 식별자
+    pub response_id: i64,
+    /// 답변이 속한 회고 ID
+    pub retrospect_id: i64,
+    /// 회고 제목 (프로젝트 이름)
+    pub retrospect_title: String,
+    /// 질문 내용
+    pub question: String,
+    /// 작성자 이름(닉네임)
+    pub user_name: String,
+    /// 답변 내용
+    pub content: String,
+    /// 트렌드 점수 (집계 기간 내 받은 좋아요 수)
+    pub recent_like_count: i64,
+    /// 전체 좋아요 수
+    pub like_count: i64,
 }
 
-/// 회고 답변 임시 저장 응답 DTO
+/// 좋아요 급상승 답변 목록 응답
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct DraftSaveResponse {
-    /// 해당 회고의 고유 ID
-    pub retrospect_id: i64,
-    /// 최종 저장 날짜 (YYYY-MM-DD)
-    pub updated_at: String,
+pub struct TrendingResponsesResponse {
+    /// 집계 기간 (시간)
+    pub hours: i64,
+    /// 답변 리스트 (트렌드 점수 내림차순)
+    pub responses: Vec<TrendingResponseItem>,
 }
 
-/// Swagger용 회고 답변 임시 저장 성공 응답 타입
+/// Swagger용 좋아요 급상승 답변 조회 성공 응답 타입
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SuccessDraftSaveResponse {
+pub struct SuccessTrendingResponsesResponse {
     pub is_success: bool,
     pub code: String,
     pub message: String,
-    pub result: DraftSaveResponse,
+    pub result: TrendingResponsesResponse,
 }
 
-// ============================================
-// API-017: 회고 최종 제출 DTO
-// ============================================
-
-/// 회고 제출 요청 DTO
-#[derive(Debug, Deserialize, ToSchema)]
+/// 명예의 전당 조회 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
 #[serde(rename_all = "camelCase")]
-pub struct SubmitRetrospectRequest {
-    /// 제출할 답변 리스트 (정확히 5개, 서비스 레이어에서 검증)
-    pub answers: Vec<SubmitAnswerItem>,
+pub struct HallOfFameQuery {
+    /// 최대 반환 개수 (1~50, 기본값 10)
+    pub limit: Option<u64>,
 }
 
-/// 제출 답변 아이템
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+/// 명예의 전당 답변 아이템
+#[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SubmitAnswerItem {
-    /// 질문 번호 (1~5)
-    pub question_number: i32,
-    /// 답변 내용 (1~1,000자)
-    pub content: String,
+pub struct HallOfFameItem {
+    /// 순위 (1부터 시작, 동점이어도 순서대로 부여)
+    pub rank: i32,
+    /// 답변 고유 식별자
+    pub response_id: i64,
+    /// 답변이 속한 회고 ID
+    pub retrospect_id: i64,
+    /// 회고 제목 (프로젝트 이름)
+    pub retrospect_title: String,
+    /// 질문 내용
+    pub question: String,
+    /// 작성자 이름 (회고방을 떠난 작성자는 첫 글자만 노출)
+    pub user_name: String,
+    /// 답변 내용
+    pub content: String,
+    /// 받은 좋아요 수
+    pub like_count: i64,
+}
+
+/// 명예의 전당 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HallOfFameResponse {
+    /// 답변 리스트 (좋아요 수 내림차순)
+    pub responses: Vec<HallOfFameItem>,
+}
+
+/// Swagger용 명예의 전당 조회 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessHallOfFameResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: HallOfFameResponse,
+}
+
+// ============== 회고방 새 회고 알림 구독 ==============
+
+/// 회고방 구독 설정 요청
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRoomSubscriptionRequest {
+    /// 알림을 받을 채널 (EMAIL, PUSH)
+    pub channel: SubscriptionChannel,
+}
+
+/// 회고방 구독 상태 응답
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomSubscriptionResponse {
+    pub retro_room_id: i64,
+    /// 구독 여부
+    pub subscribed: bool,
+    /// 구독 채널 (구독하지 않았으면 null)
+    pub channel: Option<SubscriptionChannel>,
+}
+
+/// Swagger용 회고방 구독 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRoomSubscriptionResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: RoomSubscriptionResponse,
+}
+
+// ============== API-007: 회고방 순서 변경 ==============
+
+#[derive(Debug, Clone, Serialize, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RetroRoomOrderItem {
+    #[validate(range(min = 1, message = "retroRoomId는 1 이상이어야 합니다."))]
+    pub retro_room_id: i64,
+    #[validate(range(min = 1, message = "orderIndex는 1 이상이어야 합니다."))]
+    pub order_index: i32,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRetroRoomOrderRequest {
+    #[validate(length(min = 1, message = "최소 1개 이상의 순서 정보가 필요합니다."))]
+    #[validate(nested)]
+    pub retro_room_orders: Vec<RetroRoomOrderItem>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessEmptyResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: Option<()>,
+}
+
+// ============== API-008: 회고방 이름 변경 ==============
+
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRetroRoomNameRequest {
+    #[validate(length(min = 1, max = 20, message = "룸 이름은 1~20자여야 합니다."))]
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRetroRoomNameResponse {
+    pub retro_room_id: i64,
+    pub retro_room_name: String,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessUpdateRetroRoomNameResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: UpdateRetroRoomNameResponse,
+}
+
+// ============== 회고방 설정 변경 ==============
+
+/// 회고방 설정 변경 요청 (Owner 전용)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRetroRoomSettingsRequest {
+    /// 동시에 진행 중인 회고 최대 개수 (null이면 제한 해제)
+    #[validate(range(
+        min = 1,
+        max = 100,
+        message = "진행 중 회고 제한은 1~100 사이여야 합니다."
+    ))]
+    pub max_active_retrospects: Option<i32>,
+    /// 같은 방 내 회고 제목 중복 금지 여부 (생략 시 기존 값 유지)
+    #[serde(default)]
+    pub unique_retrospect_title: Option<bool>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RetroRoomSettingsResponse {
+    pub retro_room_id: i64,
+    /// 동시에 진행 중인 회고 최대 개수 (null이면 무제한)
+    pub max_active_retrospects: Option<i32>,
+    /// 같은 방 내 회고 제목 중복 금지 여부
+    pub unique_retrospect_title: bool,
+    pub updated_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRetroRoomSettingsResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: RetroRoomSettingsResponse,
+}
+
+// ============== 회고방 초대 코드 재발급 ==============
+
+/// 초대 코드로 부여 가능한 역할 (보안상 Owner는 부여 불가)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum InviteGrantRole {
+    Member,
+    Moderator,
+}
+
+impl From<InviteGrantRole> for RoomRole {
+    fn from(role: InviteGrantRole) -> Self {
+        match role {
+            InviteGrantRole::Member => RoomRole::Member,
+            InviteGrantRole::Moderator => RoomRole::Moderator,
+        }
+    }
+}
+
+impl From<RoomRole> for InviteGrantRole {
+    /// Owner 역할이 저장되어 있더라도 초대 코드로는 Member로 노출합니다
+    fn from(role: RoomRole) -> Self {
+        match role {
+            RoomRole::Moderator => InviteGrantRole::Moderator,
+            RoomRole::Owner | RoomRole::Member => InviteGrantRole::Member,
+        }
+    }
+}
+
+/// 초대 코드 재발급 요청 (Owner 전용)
+#[derive(Debug, Default, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RegenerateInviteCodeRequest {
+    /// 새 코드로 참여한 멤버에게 부여할 역할 (미지정 시 MEMBER)
+    #[serde(default)]
+    pub grant_role: Option<InviteGrantRole>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteCodeResponse {
+    pub retro_room_id: i64,
+    pub invite_code: String,
+    /// 이 코드로 참여한 멤버에게 부여되는 역할
+    pub grant_role: InviteGrantRole,
+    /// 만료 예정 시각 (발급 후 7일)
+    pub expires_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessInviteCodeResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: InviteCodeResponse,
+}
+
+// ============== 회고방 초대 코드 조회 ==============
+
+/// 초대 코드 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InviteCodeItem {
+    pub invite_code: String,
+    /// 이 코드로 참여한 멤버에게 부여되는 역할
+    pub grant_role: InviteGrantRole,
+    /// 발급 시각
+    pub created_at: String,
+    /// 만료 예정 시각 (발급 후 7일)
+    pub expires_at: String,
+    /// 만료 여부
+    pub is_expired: bool,
+}
+
+/// 회고방 초대 코드 조회 응답
+///
+/// 현재는 방마다 초대 코드가 하나지만, 다회용 초대 코드를 지원할 수 있도록 목록으로 반환합니다.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomInviteResponse {
+    pub retro_room_id: i64,
+    /// 초대 코드 목록
+    pub invites: Vec<InviteCodeItem>,
+    /// 사용 가능한 초대 코드가 없어 재발급이 필요한지 여부
+    pub needs_regeneration: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRoomInviteResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: RoomInviteResponse,
+}
+
+// ============== 회고방 참여 기록 조회 ==============
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MembershipHistoryItem {
+    pub history_id: i64,
+    /// 대상 멤버 ID (탈퇴한 멤버는 null)
+    pub member_id: Option<i64>,
+    pub nickname: Option<String>,
+    pub event: MembershipEvent,
+    pub created_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MembershipHistoryResponse {
+    pub retro_room_id: i64,
+    pub histories: Vec<MembershipHistoryItem>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessMembershipHistoryResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: MembershipHistoryResponse,
+}
+
+// ============== API-009: 회고방 삭제 ==============
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteRetroRoomResponse {
+    pub retro_room_id: i64,
+    pub deleted_at: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessDeleteRetroRoomResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: DeleteRetroRoomResponse,
+}
+
+// ============== 회고방 나가기 ==============
+
+/// 회고방 나가기 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaveRetroRoomResponse {
+    pub retro_room_id: i64,
+    pub left_at: String,
+}
+
+/// Swagger용 회고방 나가기 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessLeaveRetroRoomResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: LeaveRetroRoomResponse,
+}
+
+// ============== API-010: 회고방 내 회고 목록 조회 ==============
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RetrospectListItem {
+    pub retrospect_id: i64,
+    pub project_name: String,
+    pub retrospect_method: String,
+    pub retrospect_date: String,
+    pub retrospect_time: String,
+    /// 해당 회고의 참여자 수
+    pub participant_count: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRetrospectListResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: Vec<RetrospectListItem>,
+}
+
+// ============================================
+// Retrospect DTOs
+// ============================================
+
+/// 참고 URL 최대 길이 (개별 URL당)
+pub const REFERENCE_URL_MAX_LENGTH: usize = 2048;
+
+/// 참고 URL 개별 길이 검증
+fn validate_reference_url_items(urls: &[String]) -> Result<(), validator::ValidationError> {
+    for url in urls {
+        if url.len() > REFERENCE_URL_MAX_LENGTH {
+            let mut err = validator::ValidationError::new("url_too_long");
+            err.message = Some(Cow::Borrowed("각 URL은 최대 2048자까지 허용됩니다"));
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+/// 커스텀 방식 질문 최대 개수 (임시 저장/제출의 질문 번호 범위 1~5와 동일)
+pub const CUSTOM_METHOD_MAX_QUESTIONS: usize = 5;
+
+/// 커스텀 방식 질문 최대 길이
+pub const CUSTOM_METHOD_QUESTION_MAX_LENGTH: usize = 200;
+
+/// 질문별 답변 작성 힌트(placeholder) 최대 길이
+pub const QUESTION_PLACEHOLDER_MAX_LENGTH: usize = 100;
+
+/// 질문별 가중치 최솟값
+pub const QUESTION_WEIGHT_MIN: i32 = 1;
+
+/// 질문별 가중치 최댓값
+pub const QUESTION_WEIGHT_MAX: i32 = 5;
+
+/// 커스텀 질문 검증 실패 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuestionIssue {
+    /// 질문 개수가 1~5개 범위를 벗어남
+    InvalidCount,
+    /// 공백만 있는 질문
+    Blank,
+    /// 최대 길이 초과
+    TooLong,
+}
+
+impl QuestionIssue {
+    /// 검증 실패 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            QuestionIssue::InvalidCount => "INVALID_QUESTION_COUNT",
+            QuestionIssue::Blank => "BLANK_QUESTION",
+            QuestionIssue::TooLong => "QUESTION_TOO_LONG",
+        }
+    }
+
+    /// 검증 실패 메시지 (회고 방식 생성 시 검증 메시지와 동일)
+    pub fn message(&self) -> &'static str {
+        match self {
+            QuestionIssue::InvalidCount => "질문은 1개 이상 5개 이하로 등록해야 합니다",
+            QuestionIssue::Blank | QuestionIssue::TooLong => {
+                "각 질문은 1자 이상 200자 이하여야 합니다"
+            }
+        }
+    }
+}
+
+/// 커스텀 질문 목록 검증 (개수, 공백만 있는 질문, 길이 초과)
+///
+/// 회고 방식 생성 검증과 사전 검증 API가 같은 규칙을 쓰도록 공용으로 사용합니다.
+/// 결과는 (질문 번호, 실패 종류) 목록이며, 개수 오류처럼 목록 전체에 대한 실패는 질문 번호가 없습니다.
+pub fn check_custom_questions(questions: &[String]) -> Vec<(Option<i32>, QuestionIssue)> {
+    let mut issues = Vec::new();
+
+    if questions.is_empty() || questions.len() > CUSTOM_METHOD_MAX_QUESTIONS {
+        issues.push((None, QuestionIssue::InvalidCount));
+    }
+
+    for (index, question) in questions.iter().enumerate() {
+        let length = question.trim().chars().count();
+        let issue = if length == 0 {
+            QuestionIssue::Blank
+        } else if length > CUSTOM_METHOD_QUESTION_MAX_LENGTH {
+            QuestionIssue::TooLong
+        } else {
+            continue;
+        };
+        issues.push((Some(index as i32 + 1), issue));
+    }
+
+    issues
+}
+
+/// 커스텀 방식 질문 목록 검증 (개수, 공백만 있는 질문, 길이 초과 금지)
+fn validate_custom_method_questions(
+    questions: &[String],
+) -> Result<(), validator::ValidationError> {
+    match check_custom_questions(questions).first() {
+        None => Ok(()),
+        Some((_, issue)) => {
+            let mut err = validator::ValidationError::new("invalid_question");
+            err.message = Some(Cow::Borrowed(issue.message()));
+            Err(err)
+        }
+    }
+}
+
+/// 커스텀 방식 placeholder 목록 검증 (최대 5개, 항목별 최대 100자)
+pub(crate) fn validate_custom_method_placeholders(
+    placeholders: &[Option<String>],
+) -> Result<(), validator::ValidationError> {
+    let too_long = placeholders
+        .iter()
+        .flatten()
+        .any(|p| p.trim().chars().count() > QUESTION_PLACEHOLDER_MAX_LENGTH);
+    if placeholders.len() > CUSTOM_METHOD_MAX_QUESTIONS || too_long {
+        let mut err = validator::ValidationError::new("invalid_placeholder");
+        err.message = Some(Cow::Borrowed(
+            "placeholder는 질문 수 이하로, 각 100자 이하여야 합니다",
+        ));
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// 커스텀 방식 가중치 목록 검증 (최대 5개, 항목별 1~5)
+pub(crate) fn validate_custom_method_weights(
+    weights: &[Option<i32>],
+) -> Result<(), validator::ValidationError> {
+    let out_of_range = weights
+        .iter()
+        .flatten()
+        .any(|w| !(QUESTION_WEIGHT_MIN..=QUESTION_WEIGHT_MAX).contains(w));
+    if weights.len() > CUSTOM_METHOD_MAX_QUESTIONS || out_of_range {
+        let mut err = validator::ValidationError::new("invalid_weight");
+        err.message = Some(Cow::Borrowed(
+            "가중치는 질문 수 이하로, 각 1 이상 5 이하여야 합니다",
+        ));
+        return Err(err);
+    }
+    Ok(())
+}
+
+/// 커스텀 회고 방식 생성 요청 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateCustomMethodRequest {
+    /// 방식 이름 (최소 1자, 최대 20자)
+    #[validate(length(
+        min = 1,
+        max = 20,
+        message = "방식 이름은 1자 이상 20자 이하여야 합니다"
+    ))]
+    pub name: String,
+
+    /// 질문 목록 (순서대로 질문 번호 1부터 부여, 1~5개)
+    #[validate(custom(function = "validate_custom_method_questions"))]
+    pub questions: Vec<String>,
+
+    /// 질문 순서에 대응하는 답변 작성 힌트 (선택, 질문 수 이하, 미설정 항목은 null)
+    #[serde(default)]
+    #[validate(custom(function = "validate_custom_method_placeholders"))]
+    pub placeholders: Vec<Option<String>>,
+
+    /// 질문 순서에 대응하는 가중치 (선택, 질문 수 이하, 1~5, 미설정 항목은 null이며 모두 미설정 시 동일 가중치)
+    #[serde(default)]
+    #[validate(custom(function = "validate_custom_method_weights"))]
+    pub weights: Vec<Option<i32>>,
+}
+
+/// 커스텀 질문 사전 검증 요청 DTO
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateQuestionsRequest {
+    /// 검증할 질문 목록 (순서대로 질문 번호 1부터)
+    pub questions: Vec<String>,
+}
+
+/// 커스텀 질문 검증 실패 항목 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionValidationErrorItem {
+    /// 실패한 질문 번호 (질문 개수처럼 목록 전체에 대한 실패는 null)
+    pub question_number: Option<i32>,
+    /// 실패 코드 (INVALID_QUESTION_COUNT, BLANK_QUESTION, QUESTION_TOO_LONG)
+    pub code: String,
+    /// 실패 메시지
+    pub message: String,
+}
+
+/// 커스텀 질문 사전 검증 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidateQuestionsResponse {
+    /// 검증 통과 여부
+    pub valid: bool,
+    /// 질문 개수
+    pub question_count: i32,
+    /// 검증 실패 목록 (통과 시 빈 배열)
+    pub errors: Vec<QuestionValidationErrorItem>,
+}
+
+/// Swagger용 커스텀 질문 사전 검증 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessValidateQuestionsResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: ValidateQuestionsResponse,
+}
+
+/// 커스텀 회고 방식 항목 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CustomMethodItem {
+    pub custom_method_id: i64,
+    pub retro_room_id: i64,
+    /// 방식 이름
+    pub name: String,
+    /// 질문 목록
+    pub questions: Vec<String>,
+    /// 질문별 답변 작성 힌트 (질문 순서 대응, 미설정 항목은 null)
+    pub placeholders: Vec<Option<String>>,
+    /// 질문별 가중치 (질문 순서 대응, 미설정 항목은 null)
+    pub weights: Vec<Option<i32>>,
+    /// 질문 개수
+    pub question_count: i32,
+    /// 생성 일시
+    pub created_at: String,
+}
+
+/// Swagger용 커스텀 회고 방식 생성 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessCustomMethodResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: CustomMethodItem,
+}
+
+/// Swagger용 커스텀 회고 방식 목록 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessCustomMethodListResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: Vec<CustomMethodItem>,
+}
+
+/// 회고 생성 요청 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRetrospectRequest {
+    /// 회고가 속한 회고방의 고유 ID
+    #[validate(range(min = 1, message = "회고방 ID는 1 이상이어야 합니다"))]
+    pub retro_room_id: i64,
+
+    /// 프로젝트 이름 (최소 1자, 최대 20자)
+    #[validate(length(
+        min = 1,
+        max = 20,
+        message = "프로젝트 이름은 1자 이상 20자 이하여야 합니다"
+    ))]
+    pub project_name: String,
+
+    /// 회고 날짜 (ISO 8601 형식: YYYY-MM-DD). startAt 미지정 시 필수
+    #[validate(length(
+        min = 10,
+        max = 10,
+        message = "날짜 형식이 올바르지 않습니다. (YYYY-MM-DD 형식 필요)"
+    ))]
+    #[serde(default)]
+    pub retrospect_date: Option<String>,
+
+    /// 회고 시간 (HH:mm 형식, timezone 기준). startAt 미지정 시 필수
+    #[validate(length(
+        min = 5,
+        max = 5,
+        message = "시간 형식이 올바르지 않습니다. (HH:mm 형식 필요)"
+    ))]
+    #[serde(default)]
+    pub retrospect_time: Option<String>,
+
+    /// 회고 시작 일시 (ISO 8601, 예: "2026-01-26T10:00:00+09:00", 분 단위)
+    /// 오프셋이 없으면 timezone 기준 시각으로 해석하며, 분리 필드와 함께 보내면 startAt이 우선하고 두 값이 일치해야 함
+    #[validate(length(max = 40, message = "startAt 형식이 올바르지 않습니다."))]
+    #[serde(default)]
+    pub start_at: Option<String>,
+
+    /// 회고 기준 타임존 (IANA 이름, 예: "Asia/Seoul"). 미지정 시 KST
+    #[validate(length(max = 64, message = "타임존은 최대 64자까지 입력할 수 있습니다"))]
+    #[serde(default)]
+    pub timezone: Option<String>,
+
+    /// 모든 참여자가 제출하면 자동으로 AI 분석 실행 여부 (미지정 시 false)
+    #[serde(default)]
+    pub auto_analyze: Option<bool>,
+
+    /// 제출 시 답변별 최소 글자 수 (미지정 시 1자)
+    #[validate(range(
+        min = 1,
+        max = 1000,
+        message = "답변 최소 글자 수는 1~1000 사이여야 합니다"
+    ))]
+    #[serde(default)]
+    pub min_answer_length: Option<i32>,
+
+    /// 질문 지연 생성 모드 여부 (true면 참여 시 질문을 만들지 않고 질문 확정 시 일괄 생성, 미지정 시 false)
+    #[serde(default)]
+    pub defer_questions: Option<bool>,
+
+    /// 회고 방식
+    pub retrospect_method: RetrospectMethod,
+
+    /// 커스텀 방식 ID (retrospectMethod가 CUSTOM일 때 필수, 같은 회고방의 방식만 가능)
+    #[validate(range(min = 1, message = "커스텀 방식 ID는 1 이상이어야 합니다"))]
+    #[serde(default)]
+    pub custom_method_id: Option<i64>,
+
+    /// 참고 자료 URL 리스트 (최대 10개, 각 URL 최대 2048자)
+    #[validate(
+        length(max = 10, message = "참고 URL은 최대 10개까지 등록 가능합니다"),
+        custom(function = "validate_reference_url_items")
+    )]
+    #[serde(default)]
+    pub reference_urls: Vec<String>,
+}
+
+/// 회고 생성 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRetrospectResponse {
+    /// 생성된 회고 고유 ID
+    pub retrospect_id: i64,
+    /// 회고가 속한 회고방의 고유 ID
+    pub retro_room_id: i64,
+    /// 저장된 프로젝트 이름
+    pub project_name: String,
+}
+
+/// Swagger용 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessCreateRetrospectResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: CreateRetrospectResponse,
+}
+
+// ============================================
+// 회고 일괄 생성 DTO
+// ============================================
+
+/// 여러 회고방에 같은 회고를 일괄 생성하는 요청 DTO
+///
+/// 회고방 ID 목록 외의 필드는 회고 생성 요청과 같습니다. (커스텀 방식은 지원하지 않음)
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCreateRetrospectRequest {
+    /// 회고를 생성할 회고방 ID 목록 (1~20개, 중복은 한 번만 처리)
+    #[validate(length(min = 1, max = 20, message = "회고방은 1~20개까지 지정할 수 있습니다"))]
+    pub room_ids: Vec<i64>,
+
+    /// true면 생성할 수 없는 방이 하나라도 있을 때 전체를 생성하지 않음 (미지정 시 false: 가능한 방만 생성)
+    #[serde(default)]
+    pub atomic: Option<bool>,
+
+    /// 프로젝트 이름 (최소 1자, 최대 20자)
+    #[validate(length(
+        min = 1,
+        max = 20,
+        message = "프로젝트 이름은 1자 이상 20자 이하여야 합니다"
+    ))]
+    pub project_name: String,
+
+    /// 회고 날짜 (ISO 8601 형식: YYYY-MM-DD)
+    #[validate(length(
+        min = 10,
+        max = 10,
+        message = "날짜 형식이 올바르지 않습니다. (YYYY-MM-DD 형식 필요)"
+    ))]
+    pub retrospect_date: String,
+
+    /// 회고 시간 (HH:mm 형식, timezone 기준)
+    #[validate(length(
+        min = 5,
+        max = 5,
+        message = "시간 형식이 올바르지 않습니다. (HH:mm 형식 필요)"
+    ))]
+    pub retrospect_time: String,
+
+    /// 회고 기준 타임존 (IANA 이름, 예: "Asia/Seoul"). 미지정 시 KST
+    #[validate(length(max = 64, message = "타임존은 최대 64자까지 입력할 수 있습니다"))]
+    #[serde(default)]
+    pub timezone: Option<String>,
+
+    /// 모든 참여자가 제출하면 자동으로 AI 분석 실행 여부 (미지정 시 false)
+    #[serde(default)]
+    pub auto_analyze: Option<bool>,
+
+    /// 제출 시 답변별 최소 글자 수 (미지정 시 1자)
+    #[validate(range(
+        min = 1,
+        max = 1000,
+        message = "답변 최소 글자 수는 1~1000 사이여야 합니다"
+    ))]
+    #[serde(default)]
+    pub min_answer_length: Option<i32>,
+
+    /// 질문 지연 생성 모드 여부 (미지정 시 false)
+    #[serde(default)]
+    pub defer_questions: Option<bool>,
+
+    /// 회고 방식 (CUSTOM 불가)
+    pub retrospect_method: RetrospectMethod,
+
+    /// 참고 자료 URL 리스트 (최대 10개, 각 URL 최대 2048자)
+    #[validate(
+        length(max = 10, message = "참고 URL은 최대 10개까지 등록 가능합니다"),
+        custom(function = "validate_reference_url_items")
+    )]
+    #[serde(default)]
+    pub reference_urls: Vec<String>,
+}
+
+impl BatchCreateRetrospectRequest {
+    /// 특정 회고방용 회고 생성 요청으로 변환
+    pub fn to_room_request(&self, retro_room_id: i64) -> CreateRetrospectRequest {
+        CreateRetrospectRequest {
+            retro_room_id,
+            project_name: self.project_name.clone(),
+            retrospect_date: Some(self.retrospect_date.clone()),
+            retrospect_time: Some(self.retrospect_time.clone()),
+            start_at: None,
+            timezone: self.timezone.clone(),
+            auto_analyze: self.auto_analyze,
+            min_answer_length: self.min_answer_length,
+            defer_questions: self.defer_questions,
+            retrospect_method: self.retrospect_method.clone(),
+            custom_method_id: None,
+            reference_urls: self.reference_urls.clone(),
+        }
+    }
+}
+
+/// 일괄 생성에서 건너뛴 회고방
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchSkippedRoomItem {
+    pub retro_room_id: i64,
+    /// 건너뛴 사유의 에러 코드 (예: RETRO4031)
+    pub code: String,
+    /// 건너뛴 사유
+    pub reason: String,
+}
+
+/// 회고 일괄 생성 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCreateRetrospectResponse {
+    /// 생성된 회고 목록 (요청 순서)
+    pub created: Vec<CreateRetrospectResponse>,
+    /// 건너뛴 회고방 목록 (atomic이면 항상 빈 배열)
+    pub skipped: Vec<BatchSkippedRoomItem>,
+}
+
+/// Swagger용 회고 일괄 생성 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessBatchCreateRetrospectResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: BatchCreateRetrospectResponse,
+}
+
+// ============================================
+// API-016: 회고 답변 임시 저장 DTO
+// ============================================
+
+/// 회고 답변 임시 저장 요청 DTO
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftSaveRequest {
+    /// 임시 저장할 답변 데이터 리스트 (최소 1개, 최대 5개)
+    pub drafts: Vec<DraftItem>,
+    /// 작성 세션 ID (작성 세션 시작 API로 발급, 생략 시 충돌 검사 없이 저장)
+    pub edit_session_id: Option<String>,
+}
+
+/// 임시 저장 답변 아이템
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftItem {
+    /// 질문 번호 (1~5)
+    pub question_number: i32,
+    /// 답변 내용 (최대 1,000자, null 또는 빈 문자열 허용)
+    pub content: Option<String>,
+}
+
+/// 회고 답변 임시 저장 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DraftSaveResponse {
+    /// 해당 회고의 고유 ID
+    pub retrospect_id: i64,
+    /// 최종 저장 날짜 (YYYY-MM-DD)
+    pub updated_at: String,
+}
+
+/// Swagger용 회고 답변 임시 저장 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessDraftSaveResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: DraftSaveResponse,
+}
+
+// ============================================
+// 회고 답변 작성 세션 DTO
+// ============================================
+
+/// 답변 작성 세션 시작 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EditSessionResponse {
+    /// 발급된 작성 세션 ID (임시 저장 시 editSessionId로 전달)
+    pub edit_session_id: String,
+    /// 세션 시작 일시 (ISO 8601 형식)
+    pub started_at: String,
+}
+
+/// Swagger용 작성 세션 시작 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessEditSessionResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: EditSessionResponse,
+}
+
+/// 질문 확정 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalizeQuestionsResponse {
+    pub retrospect_id: i64,
+    /// 질문이 생성된 참여자 수
+    pub participant_count: i64,
+    /// 참여자별 생성된 질문 수
+    pub question_count: i32,
+    /// 확정 일시 (UTC)
+    pub finalized_at: String,
+}
+
+/// Swagger용 질문 확정 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessFinalizeQuestionsResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: FinalizeQuestionsResponse,
+}
+
+/// 회고 답변 편집 잠금/해제 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RetrospectLockResponse {
+    pub retrospect_id: i64,
+    /// 변경 후 잠금 여부
+    pub is_locked: bool,
+    /// 변경 일시 (UTC)
+    pub updated_at: String,
+}
+
+/// Swagger용 회고 잠금/해제 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRetrospectLockResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: RetrospectLockResponse,
+}
+
+/// 회고 이동 요청 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveRetrospectRequest {
+    /// 옮길 대상 회고방 ID
+    #[validate(range(min = 1, message = "회고방 ID는 1 이상이어야 합니다"))]
+    pub target_room_id: i64,
+}
+
+/// 회고 이동 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveRetrospectResponse {
+    pub retrospect_id: i64,
+    /// 이동 전 회고방 ID
+    pub source_room_id: i64,
+    /// 이동 후 회고방 ID
+    pub target_room_id: i64,
+    /// 대상 회고방 멤버가 아닌 참여자 (답변은 유지되지만 대상 방 기준 접근 권한이 없음)
+    pub non_member_participants: Vec<RetrospectMemberItem>,
+    /// 이동 일시 (UTC)
+    pub moved_at: String,
+}
+
+/// Swagger용 회고 이동 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessMoveRetrospectResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: MoveRetrospectResponse,
+}
+
+/// 회고 복제 요청 DTO
+///
+/// 시작 일시는 회고 생성과 같은 규칙으로 startAt 또는 retrospectDate/retrospectTime으로 받습니다.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateRetrospectRequest {
+    /// 새 회고의 프로젝트 이름 (미지정 시 원본 회고 제목, 최소 1자, 최대 20자)
+    #[validate(length(
+        min = 1,
+        max = 20,
+        message = "프로젝트 이름은 1자 이상 20자 이하여야 합니다"
+    ))]
+    #[serde(default)]
+    pub project_name: Option<String>,
+
+    /// 회고 날짜 (ISO 8601 형식: YYYY-MM-DD). startAt 미지정 시 필수
+    #[validate(length(
+        min = 10,
+        max = 10,
+        message = "날짜 형식이 올바르지 않습니다. (YYYY-MM-DD 형식 필요)"
+    ))]
+    #[serde(default)]
+    pub retrospect_date: Option<String>,
+
+    /// 회고 시간 (HH:mm 형식, 원본 회고 timezone 기준). startAt 미지정 시 필수
+    #[validate(length(
+        min = 5,
+        max = 5,
+        message = "시간 형식이 올바르지 않습니다. (HH:mm 형식 필요)"
+    ))]
+    #[serde(default)]
+    pub retrospect_time: Option<String>,
+
+    /// 회고 시작 일시 (ISO 8601, 예: "2026-01-26T10:00:00+09:00", 분 단위, 오프셋이 없으면 원본 회고 timezone 기준)
+    #[validate(length(max = 40, message = "startAt 형식이 올바르지 않습니다."))]
+    #[serde(default)]
+    pub start_at: Option<String>,
+}
+
+/// 타이핑 이벤트 종류
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TypingEventType {
+    /// 입력 시작
+    TypingStart,
+    /// 입력 중지
+    TypingStop,
+}
+
+/// 타이핑 WebSocket 클라이언트 메시지 (클라이언트 → 서버)
+#[derive(Debug, Deserialize, ToSchema)]
+pub struct TypingClientMessage {
+    #[serde(rename = "type")]
+    pub event_type: TypingEventType,
+}
+
+/// 타이핑 WebSocket 이벤트 (서버 → 같은 회고 구독자)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct TypingEvent {
+    #[serde(rename = "type")]
+    pub event_type: TypingEventType,
+    pub retrospect_id: i64,
+    /// 입력 중인 멤버 ID
+    pub member_id: i64,
+    /// 입력 중인 멤버 닉네임
+    pub user_name: String,
+}
+
+/// 답변 작성 시작 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct StartWritingResponse {
+    pub retrospect_id: i64,
+    /// 최초 작성 시작 일시 (UTC, 이미 기록된 경우 기존 값)
+    pub writing_started_at: String,
+}
+
+/// Swagger용 작성 시작 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessStartWritingResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: StartWritingResponse,
+}
+
+/// 답변 작성 하트비트 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct HeartbeatResponse {
+    pub retrospect_id: i64,
+    /// 하트비트 반영 일시 (UTC)
+    pub last_active_at: String,
+    /// 이 시간(초) 동안 하트비트가 없으면 작성 비활성으로 처리
+    pub active_timeout_secs: i64,
+}
+
+/// Swagger용 작성 하트비트 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessHeartbeatResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: HeartbeatResponse,
+}
+
+// ============================================
+// API-017: 회고 최종 제출 DTO
+// ============================================
+
+/// 회고 제출 요청 DTO
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitRetrospectRequest {
+    /// 제출할 답변 리스트 (정확히 5개, 서비스 레이어에서 검증)
+    pub answers: Vec<SubmitAnswerItem>,
+    /// 답변 본문의 URL을 참고자료로 자동 등록할지 여부 (기본값 false, 최대 10개)
+    #[serde(default)]
+    pub auto_extract_references: bool,
+}
+
+/// 제출 답변 아이템
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitAnswerItem {
+    /// 질문 번호 (1~5)
+    pub question_number: i32,
+    /// 답변 내용 (1~1,000자)
+    pub content: String,
+}
+
+/// 회고 제출 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitRetrospectResponse {
+    /// 제출된 회고의 고유 ID
+    pub retrospect_id: i64,
+    /// 최종 제출 날짜 (YYYY-MM-DD)
+    pub submitted_at: String,
+    /// 현재 회고 상태
+    pub status: RetrospectStatus,
+    /// 다른 참여자 답변과 유사한 답변이 있는지 여부 (경고일 뿐 제출은 완료됨)
+    pub has_similarity_warning: bool,
+    /// 유사도 경고 목록 (작성자 본인에게만 노출, 다른 참여자 정보는 포함하지 않음)
+    pub similarity_warnings: Vec<SimilarityWarningItem>,
+    /// 답변 본문에서 추출해 참고자료로 새로 등록한 URL 목록 (autoExtractReferences가 false면 빈 배열)
+    pub added_references: Vec<String>,
+}
+
+/// 답변 유사도 경고 아이템
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarityWarningItem {
+    /// 질문 번호 (1부터 시작)
+    pub question_number: i32,
+    /// 가장 유사한 다른 참여자 답변과의 유사도 (0.0~1.0, 소수점 둘째 자리)
+    pub similarity: f64,
+}
+
+/// Swagger용 회고 제출 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessSubmitRetrospectResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: SubmitRetrospectResponse,
+}
+
+/// 회고 제출 미리보기 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitPreviewResponse {
+    /// 그대로 제출해도 검증을 통과하는지 여부
+    pub valid: bool,
+    /// 회고의 질문 개수
+    pub question_count: i32,
+    /// 내용이 있는 답변 수 (양끝 공백 제외 기준)
+    pub answered_count: i32,
+    /// 검증 실패 목록 (제출 시 반환될 에러를 모두 모은 것, 통과 시 빈 배열)
+    pub errors: Vec<FieldErrorItem>,
+    /// 질문별 제출 요약 (질문 순서)
+    pub answers: Vec<SubmitPreviewItem>,
+}
+
+/// 회고 제출 미리보기 질문별 요약
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SubmitPreviewItem {
+    /// 질문 번호 (1부터 시작)
+    pub question_number: i32,
+    /// 질문 내용
+    pub question: String,
+    /// 답변 작성 여부 (요청에 없거나 공백만 있으면 false)
+    pub answered: bool,
+    /// 답변 글자 수 (양끝 공백 제외 기준)
+    pub content_length: i32,
+}
+
+/// Swagger용 회고 제출 미리보기 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessSubmitPreviewResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: SubmitPreviewResponse,
+}
+
+/// 제출 완료 답변 재수정 요청 DTO
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSubmittedAnswersRequest {
+    /// 수정할 답변 리스트 (제출과 동일하게 모든 질문의 답변 필요, 서비스 레이어에서 검증)
+    pub answers: Vec<SubmitAnswerItem>,
+}
+
+/// 제출 완료 답변 재수정 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateSubmittedAnswersResponse {
+    pub retrospect_id: i64,
+    /// 갱신된 제출 날짜 (YYYY-MM-DD)
+    pub submitted_at: String,
+    /// 현재 회고 상태 (항상 SUBMITTED)
+    pub status: RetrospectStatus,
+    /// 수정한 답변 수
+    pub updated_count: i32,
+}
+
+/// Swagger용 제출 완료 답변 재수정 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessUpdateSubmittedAnswersResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: UpdateSubmittedAnswersResponse,
+}
+
+// ============================================
+// API-014: 회고 참석자 등록 DTO
+// ============================================
+
+/// 회고 참석 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateParticipantResponse {
+    /// 참석자 등록 고유 식별자
+    pub participant_id: i64,
+    /// 참석한 유저의 고유 ID
+    pub member_id: i64,
+    /// 참석한 유저의 닉네임
+    pub nickname: String,
+}
+
+/// Swagger용 회고 참석 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessCreateParticipantResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: CreateParticipantResponse,
+}
+
+// ============================================
+// 회고 제출 독려 DTO
+// ============================================
+
+/// 제출 독려 메시지 템플릿
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RemindTemplate {
+    /// 가벼운 안내 (기본값)
+    #[default]
+    Gentle,
+    /// 회고 시작 임박 안내
+    Deadline,
+    /// 마지막 요청
+    LastCall,
+}
+
+impl RemindTemplate {
+    /// 회고 제목을 넣어 템플릿 메시지 생성
+    pub fn render(&self, retrospect_title: &str) -> String {
+        match self {
+            RemindTemplate::Gentle => format!(
+                "'{}' 회고 답변을 아직 제출하지 않으셨어요. 생각을 함께 나눠주세요!",
+                retrospect_title
+            ),
+            RemindTemplate::Deadline => format!(
+                "'{}' 회고가 곧 시작됩니다. 시작 전에 답변을 제출해주세요.",
+                retrospect_title
+            ),
+            RemindTemplate::LastCall => format!(
+                "'{}' 회고에 아직 답변이 없어요. 마지막으로 제출을 부탁드려요!",
+                retrospect_title
+            ),
+        }
+    }
 }
 
-/// 회고 제출 응답 DTO
+/// 제출 독려 발송 요청 DTO
+#[derive(Debug, Default, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RemindRequest {
+    /// 메시지 템플릿 (기본값: GENTLE)
+    pub template: Option<RemindTemplate>,
+    /// 템플릿 뒤에 덧붙일 사용자 정의 메시지 (최대 100자)
+    #[validate(length(max = 100, message = "독려 메시지는 100자 이하여야 합니다."))]
+    pub custom_message: Option<String>,
+}
+
+/// 제출 독려 발송 결과 DTO
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SubmitRetrospectResponse {
-    /// 제출된 회고의 고유 ID
-    pub retrospect_id: i64,
-    /// 최종 제출 날짜 (YYYY-MM-DD)
-    pub submitted_at: String,
-    /// 현재 회고 상태
-    pub status: RetrospectStatus,
+pub struct RemindResponse {
+    /// 미제출 참여자 수 (요청자 본인 제외)
+    pub target_count: i64,
+    /// 알림 발송 성공 수
+    pub sent_count: i64,
+    /// 알림 발송 실패 수
+    pub failed_count: i64,
+    /// 알림을 끈 멤버라 제외된 수
+    pub skipped_count: i64,
 }
 
-/// Swagger용 회고 제출 성공 응답 타입
+/// Swagger용 제출 독려 발송 성공 응답 타입
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SuccessSubmitRetrospectResponse {
+pub struct SuccessRemindResponse {
     pub is_success: bool,
     pub code: String,
     pub message: String,
-    pub result: SubmitRetrospectResponse,
+    pub result: RemindResponse,
 }
 
 // ============================================
-// API-014: 회고 참석자 등록 DTO
+// 회고 QR 체크인 DTO
 // ============================================
 
-/// 회고 참석 응답 DTO
+/// QR 체크인 요청 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckInRequest {
+    /// QR에서 읽은 일회성 체크인 토큰
+    #[validate(length(min = 1, max = 64, message = "체크인 토큰은 1~64자여야 합니다."))]
+    pub token: String,
+}
+
+/// 체크인 QR 발급 응답 DTO
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct CreateParticipantResponse {
-    /// 참석자 등록 고유 식별자
-    pub participant_id: i64,
-    /// 참석한 유저의 고유 ID
-    pub member_id: i64,
-    /// 참석한 유저의 닉네임
-    pub nickname: String,
+pub struct CheckInQrResponse {
+    /// 일회성 체크인 토큰
+    pub token: String,
+    /// QR 코드에 인코딩할 페이로드 (체크인 요청 JSON: retrospectId + token)
+    pub qr_payload: String,
+    /// 만료 일시 (회고 타임존 오프셋 포함 RFC 3339, 예: 2026-01-24T19:03:00+09:00)
+    pub expires_at: String,
 }
 
-/// Swagger용 회고 참석 성공 응답 타입
+/// Swagger용 체크인 QR 발급 성공 응답 타입
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
-pub struct SuccessCreateParticipantResponse {
+pub struct SuccessCheckInQrResponse {
     pub is_success: bool,
     pub code: String,
     pub message: String,
-    pub result: CreateParticipantResponse,
+    pub result: CheckInQrResponse,
 }
 
 // ============================================
@@ -426,6 +1895,41 @@ pub struct SuccessReferencesListResponse {
     pub result: Vec<ReferenceItem>,
 }
 
+/// 참고자료 링크 미리보기 요청 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferencePreviewRequest {
+    /// 미리보기할 URL (http/https)
+    #[validate(length(min = 1, max = 2048, message = "URL은 1~2048자여야 합니다"))]
+    pub url: String,
+}
+
+/// 참고자료 링크 미리보기 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferencePreviewResponse {
+    /// 요청한 URL
+    pub url: String,
+    /// 페이지 제목 (og:title → `<title>`, 크롤링 실패 시 URL 호스트명)
+    pub title: String,
+    /// 페이지 설명 (og:description)
+    pub description: Option<String>,
+    /// 썸네일 이미지 절대 URL (og:image)
+    pub image: Option<String>,
+    /// 메타데이터 크롤링 성공 여부 (false면 호스트명 기본 제목)
+    pub fetched: bool,
+}
+
+/// Swagger용 참고자료 링크 미리보기 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessReferencePreviewResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: ReferencePreviewResponse,
+}
+
 // ============================================
 // API-019: 보관함 조회 DTO
 // ============================================
@@ -535,12 +2039,26 @@ pub struct RetrospectDetailResponse {
     pub retro_room_id: i64,
     /// 회고 제목 (프로젝트명)
     pub title: String,
-    /// 회고 시작 날짜 (YYYY-MM-DD)
+    /// 회고 시작 날짜 (YYYY-MM-DD, timezone 기준)
     pub start_time: String,
+    /// 회고 기준 타임존 (IANA 이름)
+    pub timezone: String,
+    /// 모든 참여자 제출 시 자동 분석 여부
+    pub auto_analyze: bool,
+    /// 제출 시 답변별 최소 글자 수
+    pub min_answer_length: i32,
+    /// 질문 확정 여부 (false면 질문 확정 전이라 답변 저장/제출 불가)
+    pub questions_finalized: bool,
+    /// 답변 편집 잠금 여부 (true면 답변 저장/제출 불가, 조회는 가능)
+    pub is_locked: bool,
+    /// 현재 사용자의 답변 수정 가능 여부 (제출 상태, 잠금, 질문 확정, 회고 시작 시각을 종합)
+    pub editable: bool,
     /// 회고 유형
     pub retro_category: RetrospectMethod,
     /// 참여 멤버 리스트 (참석 등록일 기준 오름차순 정렬)
     pub members: Vec<RetrospectMemberItem>,
+    /// 지금 작성 중인 멤버 ID 목록 (제출 전이며 최근 하트비트가 활성 판단 시간 안에 있는 멤버)
+    pub writing_member_ids: Vec<i64>,
     /// 회고 전체 좋아요 합계
     pub total_like_count: i64,
     /// 회고 전체 댓글 합계
@@ -567,6 +2085,14 @@ pub struct RetrospectQuestionItem {
     pub index: i32,
     /// 질문 내용
     pub content: String,
+    /// 제출 시 답변 필수 여부 (현재 제출은 모든 질문의 답변을 요구하므로 항상 true)
+    pub required: bool,
+    /// 현재 사용자의 답변 작성 여부 (양끝 공백 제외 내용이 회고 최소 글자 수 이상인지 기준, 미참여자는 false)
+    pub answered: bool,
+    /// 답변 작성 예시 힌트 (미설정 시 회고 방식별 기본값, 커스텀 방식은 null 가능)
+    pub placeholder: Option<String>,
+    /// 질문 가중치 (1~5, 미설정 시 null이며 다른 질문과 동일하게 취급)
+    pub weight: Option<i32>,
 }
 
 /// Swagger용 회고 상세 정보 조회 성공 응답 타입
@@ -579,10 +2105,68 @@ pub struct SuccessRetrospectDetailResponse {
     pub result: RetrospectDetailResponse,
 }
 
+// ============================================
+// 질문별 답변 작성률 조회 DTO
+// ============================================
+
+/// 질문별 답변 작성률 아이템
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionCoverageItem {
+    /// 질문 번호 (1부터 시작)
+    pub question_number: i32,
+    /// 질문 내용
+    pub question: String,
+    /// 답변(공백 제외 내용이 있는 답변) 수
+    pub answered_count: i32,
+    /// 참여자 대비 작성률 (0.0~100.0, 소수점 첫째 자리)
+    pub coverage_rate: f64,
+}
+
+/// 질문별 답변 작성률 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct QuestionCoverageResponse {
+    pub retrospect_id: i64,
+    /// 회고 참여자 수
+    pub participant_count: i32,
+    /// 질문별 작성률 (작성률 오름차순, 같으면 질문 번호 순. 참여자가 없으면 빈 배열)
+    pub questions: Vec<QuestionCoverageItem>,
+}
+
+/// Swagger용 질문별 답변 작성률 조회 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessQuestionCoverageResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: QuestionCoverageResponse,
+}
+
 // ============================================
 // API-013: 회고 삭제 DTO
 // ============================================
 
+/// 회고 삭제 쿼리 파라미터
+#[derive(Debug, Default, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteRetrospectQuery {
+    /// 삭제 후 회고방에 남은 회고가 없으면 회고방도 함께 삭제할지 여부 (기본값 false)
+    #[serde(default)]
+    pub delete_empty_room: bool,
+}
+
+/// 회고 삭제 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteRetrospectResponse {
+    /// 삭제된 회고 ID
+    pub retrospect_id: i64,
+    /// 빈 회고방이 함께 삭제되었는지 여부
+    pub room_deleted: bool,
+}
+
 /// Swagger용 회고 삭제 성공 응답 타입
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -590,7 +2174,102 @@ pub struct SuccessDeleteRetrospectResponse {
     pub is_success: bool,
     pub code: String,
     pub message: String,
-    pub result: Option<()>,
+    pub result: DeleteRetrospectResponse,
+}
+
+// ============================================
+// API-021: 회고 내보내기 DTO
+// ============================================
+
+/// 회고 내보내기 형식
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// PDF 파일 다운로드 (기본값)
+    #[default]
+    Pdf,
+    /// 구조화된 JSON 응답
+    Json,
+}
+
+/// 회고 내보내기 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportQueryParams {
+    /// 내보내기 형식 (pdf, json / 기본값: pdf)
+    pub format: Option<ExportFormat>,
+}
+
+/// 내보내기용 댓글 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportCommentItem {
+    /// 작성자 닉네임
+    pub author: String,
+    /// 댓글 내용
+    pub content: String,
+    /// 작성 일시 (ISO 8601 형식)
+    pub created_at: String,
+}
+
+/// 내보내기용 답변 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportAnswerItem {
+    /// 답변 고유 ID
+    pub response_id: i64,
+    /// 작성자 닉네임
+    pub author: String,
+    /// 답변 내용
+    pub content: String,
+    /// 좋아요 수
+    pub like_count: i32,
+    /// 댓글 리스트 (작성 시각 오름차순)
+    pub comments: Vec<ExportCommentItem>,
+}
+
+/// 내보내기용 질문별 답변 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportQuestionItem {
+    /// 질문 순서 (1부터 시작)
+    pub index: i32,
+    /// 질문 내용
+    pub content: String,
+    /// 제출된 답변 리스트
+    pub answers: Vec<ExportAnswerItem>,
+}
+
+/// 회고 내보내기 JSON 응답 (이메일 등 민감 정보 미포함)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RetrospectExportResponse {
+    /// 회고 고유 ID
+    pub retrospect_id: i64,
+    /// 회고 제목
+    pub title: String,
+    /// 회고방 이름
+    pub retro_room_name: String,
+    /// 회고 방식
+    pub retrospect_method: RetrospectMethod,
+    /// 회고 시작 일시 (ISO 8601 형식)
+    pub start_time: String,
+    /// 회고방 전체 AI 인사이트 (분석 전이면 null)
+    pub insight: Option<String>,
+    /// 참여자 닉네임 리스트 (참석 등록 순)
+    pub participants: Vec<String>,
+    /// 질문별 답변 리스트
+    pub questions: Vec<ExportQuestionItem>,
+}
+
+/// Swagger용 회고 내보내기 JSON 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessRetrospectExportResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: RetrospectExportResponse,
 }
 
 // ============================================
@@ -598,7 +2277,7 @@ pub struct SuccessDeleteRetrospectResponse {
 // ============================================
 
 /// 감정 랭킹 아이템
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct EmotionRankItem {
     /// 순위 (1부터 시작, 감정 빈도 기준 내림차순)
@@ -612,7 +2291,7 @@ pub struct EmotionRankItem {
 }
 
 /// 개인 미션 아이템
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct MissionItem {
     /// 개인 미션 제목 (예: "감정 표현 적극적으로 하기")
@@ -622,19 +2301,19 @@ pub struct MissionItem {
 }
 
 /// 사용자별 개인 미션 아이템
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct PersonalMissionItem {
     /// 사용자 고유 ID
     pub user_id: i64,
     /// 사용자 이름
     pub user_name: String,
-    /// 해당 사용자의 개인 미션 리스트 (정확히 3개)
+    /// 해당 사용자의 개인 미션 리스트 (설정 `MISSIONS_PER_USER`개, 기본 3개)
     pub missions: Vec<MissionItem>,
 }
 
 /// 회고 분석 응답 데이터
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
 pub struct AnalysisResponse {
     /// 회고방 전체를 위한 AI 분석 메시지
@@ -655,6 +2334,252 @@ pub struct SuccessAnalysisResponse {
     pub result: AnalysisResponse,
 }
 
+/// 회고 분석 버전 아이템
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisVersionItem {
+    /// 분석 버전 (1부터 증가)
+    pub version: i32,
+    /// 팀 인사이트
+    pub team_insight: String,
+    /// 감정 키워드 순위 리스트
+    pub emotion_rank: Vec<EmotionRankItem>,
+    /// 분석 일시 (회고 타임존 기준, yyyy-MM-ddTHH:mm:ss)
+    pub created_at: String,
+}
+
+/// 회고 분석 버전 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisVersionsResponse {
+    pub retrospect_id: i64,
+    /// 최신 분석 버전
+    pub latest_version: i32,
+    /// 보관 중인 분석 버전 목록 (최신 버전부터)
+    pub versions: Vec<AnalysisVersionItem>,
+}
+
+/// Swagger용 회고 분석 버전 목록 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessAnalysisVersionsResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: AnalysisVersionsResponse,
+}
+
+/// 회고 분석 버전 비교 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisVersionCompareQuery {
+    /// 기준(이전) 버전 (미지정 시 비교 대상 바로 이전 버전)
+    pub base: Option<i32>,
+    /// 비교 대상 버전 (미지정 시 최신 버전)
+    pub target: Option<i32>,
+}
+
+/// 감정 키워드 순위 변화 아이템
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct EmotionRankChangeItem {
+    /// 감정 키워드
+    pub label: String,
+    /// 기준 버전에서의 순위 (없으면 null)
+    pub base_rank: Option<i32>,
+    /// 비교 대상 버전에서의 순위 (없으면 null)
+    pub target_rank: Option<i32>,
+}
+
+/// 회고 분석 버전 비교 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisVersionCompareResponse {
+    pub retrospect_id: i64,
+    /// 기준(이전) 버전
+    pub base: AnalysisVersionItem,
+    /// 비교 대상 버전
+    pub target: AnalysisVersionItem,
+    /// 팀 인사이트 변경 여부
+    pub insight_changed: bool,
+    /// 감정 키워드별 순위 변화 (비교 대상 순위 순, 사라진 키워드는 뒤에 기준 순위 순)
+    pub emotion_rank_changes: Vec<EmotionRankChangeItem>,
+}
+
+/// Swagger용 회고 분석 버전 비교 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessAnalysisVersionCompareResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: AnalysisVersionCompareResponse,
+}
+
+/// 회고 분석 진행 단계
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AnalysisStage {
+    /// 분석 진행 중이 아니며 결과도 없음
+    Idle,
+    /// 참여자 답변 데이터 수집 중
+    Collecting,
+    /// AI 분석 호출 중
+    CallingAi,
+    /// 분석 결과 저장 중
+    Saving,
+    /// AI 호출 실패로 백그라운드 재시도 대기 중
+    PendingRetry,
+    /// 분석 완료
+    Completed,
+    /// 분석 실패
+    Failed,
+}
+
+impl AnalysisStage {
+    /// 분석이 진행 중인 단계인지 여부 (아니면 스트림을 종료)
+    pub fn is_running(self) -> bool {
+        matches!(self, Self::Collecting | Self::CallingAi | Self::Saving)
+    }
+}
+
+/// 회고 분석 진행 상태 SSE 이벤트
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisProgressEvent {
+    pub retrospect_id: i64,
+    pub stage: AnalysisStage,
+    /// 실패 사유 (FAILED, PENDING_RETRY 단계에서만 포함)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    /// 최종 분석 결과 (스트림 중 분석이 완료된 경우 마지막 이벤트에만 포함)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<AnalysisResponse>,
+}
+
+// ============================================
+// 회고 공유 링크 DTO (분석 결과 / 회고 전체)
+// ============================================
+
+/// 공유 링크 발급 요청 (분석 결과·회고 전체 공유 공통)
+#[derive(Debug, Default, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAnalysisShareRequest {
+    /// 만료 기간 (일 단위, 1~30). 생략 시 만료 없음
+    #[validate(range(min = 1, max = 30, message = "만료 기간은 1~30일이어야 합니다."))]
+    pub expires_in_days: Option<i64>,
+    /// 닉네임 마스킹 여부 (기본값 true)
+    pub mask_nickname: Option<bool>,
+}
+
+/// 공유 링크 발급 응답 (분석 결과·회고 전체 공유 공통)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AnalysisShareResponse {
+    /// 공유 토큰
+    pub token: String,
+    /// 공유 조회 경로 (인증 불필요)
+    pub share_path: String,
+    /// 닉네임 마스킹 여부
+    pub mask_nickname: bool,
+    /// 만료 일시 (ISO 8601 형식, 만료 없음이면 null)
+    pub expires_at: Option<String>,
+}
+
+/// Swagger용 공유 링크 발급 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessAnalysisShareResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: AnalysisShareResponse,
+}
+
+/// 공유용 사용자별 개인 미션 아이템 (사용자 ID 비노출)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedPersonalMissionItem {
+    /// 사용자 이름 (마스킹 옵션 적용)
+    pub user_name: String,
+    /// 개인 미션 리스트
+    pub missions: Vec<MissionItem>,
+}
+
+/// 공유 링크로 조회한 회고 분석 결과
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedAnalysisResponse {
+    /// 프로젝트 이름
+    pub project_name: String,
+    /// 회고 방식
+    pub retrospect_method: RetrospectMethod,
+    /// 회고 날짜 (YYYY-MM-DD)
+    pub retrospect_date: String,
+    /// 회고방 전체를 위한 AI 분석 메시지
+    pub insight: String,
+    /// 사용자별 개인 미션 리스트
+    pub personal_missions: Vec<SharedPersonalMissionItem>,
+}
+
+/// Swagger용 공유 분석 결과 조회 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessSharedAnalysisResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: SharedAnalysisResponse,
+}
+
+/// 공유용 답변 아이템 (사용자 ID 비노출)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedAnswerItem {
+    /// 작성자 이름 (마스킹 옵션 적용)
+    pub user_name: String,
+    /// 답변 내용
+    pub content: String,
+}
+
+/// 공유용 질문별 답변 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedQuestionItem {
+    /// 질문 순서 (1부터 시작)
+    pub index: i32,
+    /// 질문 내용
+    pub content: String,
+    /// 제출된 답변 리스트
+    pub answers: Vec<SharedAnswerItem>,
+}
+
+/// 공유 링크로 조회한 회고 전체 (읽기 전용)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedRetrospectResponse {
+    /// 프로젝트 이름
+    pub project_name: String,
+    /// 회고 방식
+    pub retrospect_method: RetrospectMethod,
+    /// 회고 날짜 (YYYY-MM-DD)
+    pub retrospect_date: String,
+    /// 참여자 이름 리스트 (참석 등록일 기준 오름차순, 마스킹 옵션 적용)
+    pub participants: Vec<String>,
+    /// 질문별 답변 리스트
+    pub questions: Vec<SharedQuestionItem>,
+}
+
+/// Swagger용 공유 회고 조회 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessSharedRetrospectResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: SharedRetrospectResponse,
+}
+
 // ============================================
 // API-023: 회고 검색 DTO
 // ============================================
@@ -777,6 +2702,35 @@ pub struct ResponsesQueryParams {
     pub cursor: Option<i64>,
     /// 페이지당 조회 개수 (1~100, 기본값: 10)
     pub size: Option<i64>,
+    /// 라벨 필터 (지정 시 해당 라벨이 붙은 답변만 조회)
+    pub label: Option<String>,
+    /// 응답에 포함할 답변 필드 (쉼표 구분, 예: responseId,content,likeCount). 미지정 시 전체 필드
+    pub fields: Option<String>,
+}
+
+/// `fields` 파라미터로 선택 가능한 답변 필드 (camelCase)
+pub const RESPONSE_LIST_ITEM_FIELDS: [&str; 9] = [
+    "responseId",
+    "userName",
+    "content",
+    "likeCount",
+    "viewCount",
+    "commentCount",
+    "summary",
+    "labels",
+    "questionWeight",
+];
+
+/// `fields` 파라미터 해석 (알 수 없는 필드명은 무시)
+///
+/// 미지정이거나 유효한 필드가 하나도 없으면 None을 반환해 전체 필드를 내려줍니다.
+pub fn parse_response_fields(fields: Option<&str>) -> Option<Vec<&'static str>> {
+    let requested: Vec<&str> = fields?.split(',').map(str::trim).collect();
+    let selected: Vec<&'static str> = RESPONSE_LIST_ITEM_FIELDS
+        .into_iter()
+        .filter(|field| requested.contains(field))
+        .collect();
+    (!selected.is_empty()).then_some(selected)
 }
 
 /// 답변 아이템 응답 DTO
@@ -791,8 +2745,16 @@ pub struct ResponseListItem {
     pub content: String,
     /// 해당 답변의 좋아요 수
     pub like_count: i64,
+    /// 해당 답변의 조회수
+    pub view_count: i64,
     /// 해당 답변의 댓글 수
     pub comment_count: i64,
+    /// AI 한 줄 요약 (아직 생성되지 않았으면 null)
+    pub summary: Option<String>,
+    /// 답변 라벨 리스트 (가나다순)
+    pub labels: Vec<String>,
+    /// 답변한 질문의 가중치 (1~5, 미설정 시 null)
+    pub question_weight: Option<i32>,
 }
 
 /// 답변 카테고리별 조회 응답 DTO
@@ -807,6 +2769,151 @@ pub struct ResponsesListResponse {
     pub next_cursor: Option<i64>,
 }
 
+impl ResponsesListResponse {
+    /// 답변 아이템에서 선택한 필드만 남긴 JSON으로 변환 (페이지 정보는 그대로 유지)
+    pub fn select_fields(self, fields: &[&str]) -> serde_json::Value {
+        let mut value = serde_json::to_value(self).unwrap_or_default();
+        if let Some(items) = value
+            .get_mut("responses")
+            .and_then(serde_json::Value::as_array_mut)
+        {
+            for item in items
+                .iter_mut()
+                .filter_map(serde_json::Value::as_object_mut)
+            {
+                item.retain(|key, _| fields.contains(&key.as_str()));
+            }
+        }
+        value
+    }
+}
+
+// ============================================
+// 회고 답변 AI 한 줄 요약 DTO
+// ============================================
+
+/// 답변 요약 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseSummaryResponse {
+    /// 답변 고유 식별자
+    pub response_id: i64,
+    /// 한 줄 요약
+    pub summary: String,
+}
+
+/// Swagger용 답변 요약 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessResponseSummaryResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: ResponseSummaryResponse,
+}
+
+// ============================================
+// 회고 답변 카테고리 자동 분류 DTO
+// ============================================
+
+/// 답변 자동 분류 쿼리 파라미터
+#[derive(Debug, Deserialize, IntoParams)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCategorizeQuery {
+    /// 분류 결과를 답변에 저장할지 여부 (기본값 false, 저장은 회고방 운영진 이상만 가능)
+    pub save: Option<bool>,
+}
+
+/// 답변 자동 분류 결과 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCategorizeItem {
+    /// 답변 고유 식별자
+    pub response_id: i64,
+    /// 분류된 카테고리 (분류 실패 시 UNCATEGORIZED)
+    pub category: AnswerCategory,
+}
+
+/// 답변 자동 분류 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AutoCategorizeResponse {
+    /// 회고 고유 식별자
+    pub retrospect_id: i64,
+    /// 분류 결과를 답변에 저장했는지 여부
+    pub saved: bool,
+    /// 답변별 분류 결과 (답변 ID 오름차순)
+    pub items: Vec<AutoCategorizeItem>,
+}
+
+/// Swagger용 답변 자동 분류 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessAutoCategorizeResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: AutoCategorizeResponse,
+}
+
+// ============================================
+// 회고 답변 라벨 DTO
+// ============================================
+
+/// 답변당 최대 라벨 수
+pub const RESPONSE_LABEL_MAX_COUNT: usize = 5;
+
+/// 라벨 최대 길이 (양끝 공백 제외)
+pub const RESPONSE_LABEL_MAX_LENGTH: usize = 20;
+
+/// 라벨 개별 길이 검증
+fn validate_label_items(labels: &[String]) -> Result<(), validator::ValidationError> {
+    for label in labels {
+        let length = label.trim().chars().count();
+        if length == 0 || length > RESPONSE_LABEL_MAX_LENGTH {
+            let mut err = validator::ValidationError::new("invalid_label_length");
+            err.message = Some(Cow::Borrowed("라벨은 1자 이상 20자 이하여야 합니다"));
+            return Err(err);
+        }
+    }
+    Ok(())
+}
+
+/// 답변 라벨 설정 요청 DTO
+///
+/// 라벨은 자유 입력이며 요청한 목록으로 전체 교체됩니다. (빈 배열이면 모든 라벨 제거)
+/// 양끝 공백은 제거하고 중복은 하나로 합칩니다.
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateResponseLabelsRequest {
+    /// 라벨 리스트 (최대 5개, 각 1~20자)
+    #[validate(
+        length(max = 5, message = "라벨은 최대 5개까지 설정할 수 있습니다"),
+        custom(function = "validate_label_items")
+    )]
+    pub labels: Vec<String>,
+}
+
+/// 답변 라벨 설정 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseLabelsResponse {
+    /// 답변 고유 식별자
+    pub response_id: i64,
+    /// 설정된 라벨 리스트 (가나다순)
+    pub labels: Vec<String>,
+}
+
+/// Swagger용 답변 라벨 설정 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessResponseLabelsResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: ResponseLabelsResponse,
+}
+
 // ============================================
 // API-025: 회고 답변 좋아요 토글 DTO
 // ============================================
@@ -823,6 +2930,72 @@ pub struct LikeToggleResponse {
     pub total_likes: i64,
 }
 
+/// 좋아요 누른 멤버 목록 조회 쿼리 파라미터
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListLikesQuery {
+    /// 마지막으로 조회된 좋아요 ID (첫 요청 시 생략)
+    pub cursor: Option<i64>,
+    /// 페이지당 조회 개수 (기본값: 20, 최대: 100)
+    pub size: Option<i32>,
+}
+
+/// 좋아요 누른 멤버 아이템
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct LikeMemberItem {
+    /// 좋아요 고유 식별자 (커서로 사용)
+    pub like_id: i64,
+    /// 좋아요를 누른 멤버 ID
+    pub member_id: i64,
+    /// 좋아요를 누른 멤버 닉네임
+    pub nickname: String,
+}
+
+/// 좋아요 누른 멤버 목록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListLikesResponse {
+    /// 좋아요 누른 멤버 리스트 (최근 좋아요 순)
+    pub likes: Vec<LikeMemberItem>,
+    /// 다음 페이지 존재 여부
+    pub has_next: bool,
+    /// 다음 조회를 위한 커서 ID (마지막 페이지면 null)
+    pub next_cursor: Option<i64>,
+}
+
+/// Swagger용 좋아요 누른 멤버 목록 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessListLikesResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: ListLikesResponse,
+}
+
+/// 답변 조회 기록 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResponseViewResponse {
+    /// 대상 답변의 ID
+    pub response_id: i64,
+    /// 반영 후 해당 답변의 조회수
+    pub view_count: i64,
+    /// 이번 조회가 조회수에 반영되었는지 여부 (본인 답변 또는 중복 조회면 false)
+    pub counted: bool,
+}
+
+/// Swagger용 답변 조회 기록 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessResponseViewResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: ResponseViewResponse,
+}
+
 /// Swagger용 좋아요 토글 성공 응답 타입
 #[derive(Debug, Serialize, ToSchema)]
 #[serde(rename_all = "camelCase")]
@@ -833,6 +3006,28 @@ pub struct SuccessLikeToggleResponse {
     pub result: LikeToggleResponse,
 }
 
+/// 답변 좋아요 일괄 취소 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ResetLikesResponse {
+    /// 대상 답변의 ID
+    pub response_id: i64,
+    /// 취소 전 좋아요 개수 (삭제된 좋아요 수)
+    pub previous_like_count: i64,
+    /// 취소 후 총 좋아요 개수 (항상 0)
+    pub total_likes: i64,
+}
+
+/// Swagger용 답변 좋아요 일괄 취소 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessResetLikesResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: ResetLikesResponse,
+}
+
 // ============================================
 // API-026: 회고 답변 댓글 목록 조회 DTO
 // ============================================
@@ -1001,6 +3196,67 @@ pub struct SuccessAssistantResponse {
     pub result: AssistantResponse,
 }
 
+// ============================================
+// 답변 AI 피드백 미리보기 DTO
+// ============================================
+
+/// 답변 피드백 요청 DTO
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AnswerFeedbackRequest {
+    /// 작성 중인 답변 내용 (1~1000자)
+    #[validate(length(max = 1000, message = "content는 1000자를 초과할 수 없습니다."))]
+    pub content: String,
+}
+
+/// 피드백 관점
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FeedbackAspect {
+    /// 구체성 - 상황, 근거, 사례가 충분히 드러나는지
+    Specificity,
+    /// 건설성 - 다음 행동이나 개선 방향으로 이어지는지
+    Constructiveness,
+}
+
+/// 피드백 개선 제안 아이템
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedbackSuggestionItem {
+    /// 피드백 관점 (SPECIFICITY 또는 CONSTRUCTIVENESS)
+    pub aspect: FeedbackAspect,
+    /// 제안 제목
+    pub title: String,
+    /// 제안 상세 설명
+    pub description: String,
+}
+
+/// 답변 피드백 응답 DTO
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct AnswerFeedbackResponse {
+    /// 질문 ID
+    pub question_id: i32,
+    /// 질문 내용
+    pub question_content: String,
+    /// 답변에 대한 한 줄 총평
+    pub summary: String,
+    /// 개선 제안 목록 (최대 3개)
+    pub suggestions: Vec<FeedbackSuggestionItem>,
+    /// 이번 달 남은 피드백 사용 횟수
+    pub remaining_count: i32,
+}
+
+/// Swagger용 답변 피드백 성공 응답 타입
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessAnswerFeedbackResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: AnswerFeedbackResponse,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1010,9 +3266,15 @@ mod tests {
         CreateRetrospectRequest {
             retro_room_id: 1,
             project_name: "테스트 프로젝트".to_string(),
-            retrospect_date: "2025-01-25".to_string(),
-            retrospect_time: "14:00".to_string(),
+            retrospect_date: Some("2025-01-25".to_string()),
+            retrospect_time: Some("14:00".to_string()),
+            start_at: None,
+            timezone: None,
+            auto_analyze: None,
+            min_answer_length: None,
+            defer_questions: None,
             retrospect_method: RetrospectMethod::Kpt,
+            custom_method_id: None,
             reference_urls: vec![],
         }
     }
@@ -1135,7 +3397,7 @@ mod tests {
     fn should_fail_validation_when_retrospect_date_is_too_short() {
         // Arrange
         let request = CreateRetrospectRequest {
-            retrospect_date: "2025-1-1".to_string(), // 8자 (형식 오류)
+            retrospect_date: Some("2025-1-1".to_string()), // 8자 (형식 오류)
             ..create_valid_request()
         };
 
@@ -1153,7 +3415,7 @@ mod tests {
     fn should_fail_validation_when_retrospect_date_is_too_long() {
         // Arrange
         let request = CreateRetrospectRequest {
-            retrospect_date: "2025-01-251".to_string(), // 11자 (형식 오류)
+            retrospect_date: Some("2025-01-251".to_string()), // 11자 (형식 오류)
             ..create_valid_request()
         };
 
@@ -1171,7 +3433,7 @@ mod tests {
     fn should_pass_validation_when_retrospect_date_has_correct_format() {
         // Arrange
         let request = CreateRetrospectRequest {
-            retrospect_date: "2025-01-25".to_string(), // 정확히 10자
+            retrospect_date: Some("2025-01-25".to_string()), // 정확히 10자
             ..create_valid_request()
         };
 
@@ -1283,7 +3545,7 @@ mod tests {
     fn should_fail_validation_when_retrospect_time_is_too_short() {
         // Arrange
         let request = CreateRetrospectRequest {
-            retrospect_time: "9:00".to_string(), // 4자 (형식 오류)
+            retrospect_time: Some("9:00".to_string()), // 4자 (형식 오류)
             ..create_valid_request()
         };
 
@@ -1301,7 +3563,7 @@ mod tests {
     fn should_fail_validation_when_retrospect_time_is_too_long() {
         // Arrange
         let request = CreateRetrospectRequest {
-            retrospect_time: "14:00:00".to_string(), // 8자 (형식 오류)
+            retrospect_time: Some("14:00:00".to_string()), // 8자 (형식 오류)
             ..create_valid_request()
         };
 
@@ -1319,7 +3581,7 @@ mod tests {
     fn should_pass_validation_when_retrospect_time_has_correct_format() {
         // Arrange
         let request = CreateRetrospectRequest {
-            retrospect_time: "14:30".to_string(), // 정확히 5자
+            retrospect_time: Some("14:30".to_string()), // 정확히 5자
             ..create_valid_request()
         };
 
@@ -1330,6 +3592,73 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn should_deserialize_create_request_with_auto_analyze() {
+        // Arrange
+        let json = r#"{
+            "retroRoomId": 1,
+            "projectName": "테스트 프로젝트",
+            "retrospectDate": "2025-01-25",
+            "retrospectTime": "14:00",
+            "retrospectMethod": "KPT",
+            "referenceUrls": [],
+            "autoAnalyze": true
+        }"#;
+
+        // Act
+        let request: CreateRetrospectRequest = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert_eq!(request.auto_analyze, Some(true));
+        assert!(request.timezone.is_none());
+    }
+
+    // ========================================
+    // 회고 일괄 생성 요청 테스트
+    // ========================================
+
+    #[test]
+    fn should_convert_batch_request_into_room_requests() {
+        // Arrange
+        let json = r#"{
+            "roomIds": [3, 5],
+            "projectName": "분기 회고",
+            "retrospectDate": "2025-01-25",
+            "retrospectTime": "14:00",
+            "retrospectMethod": "KPT",
+            "referenceUrls": ["https://github.com/team/repo"]
+        }"#;
+        let request: BatchCreateRetrospectRequest = serde_json::from_str(json).unwrap();
+
+        // Act
+        let room_request = request.to_room_request(5);
+
+        // Assert
+        assert!(request.atomic.is_none());
+        assert!(request.validate().is_ok());
+        assert!(room_request.validate().is_ok());
+        assert_eq!(room_request.retro_room_id, 5);
+        assert_eq!(room_request.project_name, "분기 회고");
+        assert!(room_request.custom_method_id.is_none());
+        assert_eq!(room_request.reference_urls.len(), 1);
+    }
+
+    #[test]
+    fn should_fail_validation_when_batch_room_ids_empty() {
+        // Arrange
+        let json = r#"{
+            "roomIds": [],
+            "projectName": "분기 회고",
+            "retrospectDate": "2025-01-25",
+            "retrospectTime": "14:00",
+            "retrospectMethod": "KPT"
+        }"#;
+        let request: BatchCreateRetrospectRequest = serde_json::from_str(json).unwrap();
+
+        // Act & Assert
+        assert!(request.validate().is_err());
+    }
+
     // ========================================
     // API-016: DraftItem 직렬화/역직렬화 테스트
     // ========================================
@@ -1569,6 +3898,12 @@ mod tests {
             retro_room_id: 789,
             title: "3차 스프린트 회고".to_string(),
             start_time: "2026-01-24".to_string(),
+            timezone: "Asia/Seoul".to_string(),
+            auto_analyze: false,
+            min_answer_length: 1,
+            questions_finalized: true,
+            is_locked: false,
+            editable: true,
             retro_category: RetrospectMethod::Kpt,
             members: vec![
                 RetrospectMemberItem {
@@ -1580,20 +3915,33 @@ mod tests {
                     user_name: "카이".to_string(),
                 },
             ],
+            writing_member_ids: vec![2],
             total_like_count: 156,
             total_comment_count: 42,
             questions: vec![
                 RetrospectQuestionItem {
                     index: 1,
                     content: "계속 유지하고 싶은 좋은 점은 무엇인가요?".to_string(),
+                    required: true,
+                    answered: false,
+                    placeholder: None,
+                    weight: None,
                 },
                 RetrospectQuestionItem {
                     index: 2,
                     content: "개선이 필요한 문제점은 무엇인가요?".to_string(),
+                    required: true,
+                    answered: false,
+                    placeholder: None,
+                    weight: None,
                 },
                 RetrospectQuestionItem {
                     index: 3,
                     content: "다음에 시도해보고 싶은 것은 무엇인가요?".to_string(),
+                    required: true,
+                    answered: false,
+                    placeholder: None,
+                    weight: None,
                 },
             ],
         };
@@ -1605,6 +3953,9 @@ mod tests {
         assert_eq!(json["retroRoomId"], 789);
         assert_eq!(json["title"], "3차 스프린트 회고");
         assert_eq!(json["startTime"], "2026-01-24");
+        assert_eq!(json["timezone"], "Asia/Seoul");
+        assert_eq!(json["autoAnalyze"], false);
+        assert_eq!(json["editable"], true);
         assert_eq!(json["retroCategory"], "KPT");
         assert_eq!(json["totalLikeCount"], 156);
         assert_eq!(json["totalCommentCount"], 42);
@@ -1616,6 +3967,7 @@ mod tests {
         assert_eq!(members[0]["userName"], "김민철");
         assert_eq!(members[1]["memberId"], 2);
         assert_eq!(members[1]["userName"], "카이");
+        assert_eq!(json["writingMemberIds"], serde_json::json!([2]));
 
         // questions 검증
         let questions = json["questions"].as_array().unwrap();
@@ -1633,8 +3985,15 @@ mod tests {
             retro_room_id: 1,
             title: "빈 회고".to_string(),
             start_time: "2026-01-01".to_string(),
+            timezone: "Asia/Seoul".to_string(),
+            auto_analyze: false,
+            min_answer_length: 1,
+            questions_finalized: true,
+            is_locked: false,
+            editable: true,
             retro_category: RetrospectMethod::Free,
             members: vec![],
+            writing_member_ids: vec![],
             total_like_count: 0,
             total_comment_count: 0,
             questions: vec![],
@@ -1660,6 +4019,8 @@ mod tests {
             (RetrospectMethod::FiveF, "FIVE_F"),
             (RetrospectMethod::Pmi, "PMI"),
             (RetrospectMethod::Free, "FREE"),
+            (RetrospectMethod::Sailboat, "SAILBOAT"),
+            (RetrospectMethod::Daki, "DAKI"),
         ];
 
         for (category, expected) in categories {
@@ -1667,8 +4028,15 @@ mod tests {
                 retro_room_id: 1,
                 title: "테스트".to_string(),
                 start_time: "2026-01-01".to_string(),
+                timezone: "Asia/Seoul".to_string(),
+                auto_analyze: false,
+                min_answer_length: 1,
+                questions_finalized: true,
+                is_locked: false,
+                editable: true,
                 retro_category: category,
                 members: vec![],
+                writing_member_ids: vec![],
                 total_like_count: 0,
                 total_comment_count: 0,
                 questions: vec![],
@@ -1704,6 +4072,10 @@ mod tests {
         let question = RetrospectQuestionItem {
             index: 3,
             content: "테스트 질문입니다".to_string(),
+            required: true,
+            answered: false,
+            placeholder: Some("예) 테스트 답변".to_string()),
+            weight: Some(3),
         };
 
         // Act
@@ -1712,6 +4084,10 @@ mod tests {
         // Assert
         assert_eq!(json["index"], 3);
         assert_eq!(json["content"], "테스트 질문입니다");
+        assert_eq!(json["required"], true);
+        assert_eq!(json["answered"], false);
+        assert_eq!(json["placeholder"], "예) 테스트 답변");
+        assert_eq!(json["weight"], 3);
     }
 
     // ========================================
@@ -1758,6 +4134,8 @@ mod tests {
             (RetrospectMethod::FiveF, "FIVE_F"),
             (RetrospectMethod::Pmi, "PMI"),
             (RetrospectMethod::Free, "FREE"),
+            (RetrospectMethod::Sailboat, "SAILBOAT"),
+            (RetrospectMethod::Daki, "DAKI"),
         ];
 
         for (method, expected) in methods {
@@ -1949,7 +4327,11 @@ mod tests {
             user_name: "제이슨".to_string(),
             content: "이번 스프린트에서 테스트 코드를 꼼꼼히 짠 것이 좋았습니다.".to_string(),
             like_count: 12,
+            view_count: 0,
             comment_count: 3,
+            summary: None,
+            labels: vec!["칭찬".to_string()],
+            question_weight: Some(4),
         };
 
         // Act
@@ -1961,6 +4343,9 @@ mod tests {
         assert!(json["content"].as_str().unwrap().contains("테스트 코드"));
         assert_eq!(json["likeCount"], 12);
         assert_eq!(json["commentCount"], 3);
+        assert!(json["summary"].is_null());
+        assert_eq!(json["labels"][0], "칭찬");
+        assert_eq!(json["questionWeight"], 4);
         // snake_case 키가 없는지 확인
         assert!(json.get("response_id").is_none());
         assert!(json.get("user_name").is_none());
@@ -1976,7 +4361,11 @@ mod tests {
             user_name: "테스트유저".to_string(),
             content: "테스트 답변".to_string(),
             like_count: 0,
+            view_count: 0,
             comment_count: 0,
+            summary: None,
+            labels: vec![],
+            question_weight: None,
         };
 
         // Act
@@ -2001,14 +4390,22 @@ mod tests {
                     user_name: "제이슨".to_string(),
                     content: "좋은 점".to_string(),
                     like_count: 12,
+                    view_count: 0,
                     comment_count: 3,
+                    summary: None,
+                    labels: vec![],
+                    question_weight: None,
                 },
                 ResponseListItem {
                     response_id: 456,
                     user_name: "김민수".to_string(),
                     content: "기한 맞춰서".to_string(),
                     like_count: 12,
+                    view_count: 0,
                     comment_count: 21,
+                    summary: None,
+                    labels: vec![],
+                    question_weight: None,
                 },
             ],
             has_next: true,
@@ -2029,6 +4426,54 @@ mod tests {
         assert!(json.get("next_cursor").is_none());
     }
 
+    #[test]
+    fn should_parse_known_response_fields_and_ignore_unknown() {
+        // Act
+        let fields = parse_response_fields(Some("content, responseId,unknown,likeCount"));
+
+        // Assert
+        assert_eq!(fields, Some(vec!["responseId", "content", "likeCount"]));
+    }
+
+    #[test]
+    fn should_return_all_fields_when_no_valid_field_requested() {
+        // Act & Assert
+        assert_eq!(parse_response_fields(None), None);
+        assert_eq!(parse_response_fields(Some("")), None);
+        assert_eq!(parse_response_fields(Some("foo,bar")), None);
+    }
+
+    #[test]
+    fn should_keep_only_selected_fields_in_responses() {
+        // Arrange
+        let response = ResponsesListResponse {
+            responses: vec![ResponseListItem {
+                response_id: 501,
+                user_name: "제이슨".to_string(),
+                content: "좋은 점".to_string(),
+                like_count: 12,
+                view_count: 4,
+                comment_count: 3,
+                summary: None,
+                labels: vec![],
+                question_weight: None,
+            }],
+            has_next: true,
+            next_cursor: Some(501),
+        };
+
+        // Act
+        let json = response.select_fields(&["responseId", "likeCount"]);
+
+        // Assert
+        let item = json["responses"][0].as_object().unwrap();
+        assert_eq!(item.len(), 2);
+        assert_eq!(item["responseId"], 501);
+        assert_eq!(item["likeCount"], 12);
+        assert_eq!(json["hasNext"], true);
+        assert_eq!(json["nextCursor"], 501);
+    }
+
     #[test]
     fn should_serialize_empty_responses_list_response() {
         // Arrange
@@ -2056,7 +4501,11 @@ mod tests {
                 user_name: "유저".to_string(),
                 content: "마지막 답변".to_string(),
                 like_count: 1,
+                view_count: 0,
                 comment_count: 0,
+                summary: None,
+                labels: vec![],
+                question_weight: None,
             }],
             has_next: false,
             next_cursor: None,
@@ -2084,7 +4533,11 @@ mod tests {
                     user_name: "제이슨".to_string(),
                     content: "테스트 답변".to_string(),
                     like_count: 5,
+                    view_count: 0,
                     comment_count: 2,
+                    summary: None,
+                    labels: vec![],
+                    question_weight: None,
                 }],
                 has_next: false,
                 next_cursor: None,
@@ -2459,4 +4912,59 @@ mod tests {
         assert_eq!(json["code"], "COMMON200");
         assert!(json["result"]["questionId"].is_number());
     }
+
+    // ========================================
+    // 답변 AI 피드백 DTO 테스트
+    // ========================================
+
+    #[test]
+    fn should_deserialize_answer_feedback_request() {
+        // Arrange
+        let json = r#"{"content": "일정이 너무 빠듯했다."}"#;
+
+        // Act
+        let req: AnswerFeedbackRequest = serde_json::from_str(json).unwrap();
+
+        // Assert
+        assert_eq!(req.content, "일정이 너무 빠듯했다.");
+    }
+
+    #[test]
+    fn should_fail_validation_when_feedback_content_exceeds_1000_chars() {
+        // Arrange
+        let req = AnswerFeedbackRequest {
+            content: "가".repeat(1001),
+        };
+
+        // Act
+        let result = req.validate();
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn should_serialize_answer_feedback_response_in_camel_case() {
+        // Arrange
+        let response = AnswerFeedbackResponse {
+            question_id: 2,
+            question_content: "질문 내용".to_string(),
+            summary: "경험이 잘 드러나 있어요".to_string(),
+            suggestions: vec![FeedbackSuggestionItem {
+                aspect: FeedbackAspect::Constructiveness,
+                title: "다음 행동 정하기".to_string(),
+                description: "다음 스프린트에서 해볼 일을 적어보면 좋아요".to_string(),
+            }],
+            remaining_count: 9,
+        };
+
+        // Act
+        let json = serde_json::to_value(&response).unwrap();
+
+        // Assert
+        assert_eq!(json["questionId"], 2);
+        assert_eq!(json["summary"], "경험이 잘 드러나 있어요");
+        assert_eq!(json["suggestions"][0]["aspect"], "CONSTRUCTIVENESS");
+        assert_eq!(json["remainingCount"], 9);
+    }
 }
@@ -0,0 +1,70 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 회고 분석 재시도 작업 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AnalysisJobStatus {
+    /// 재시도 대기 중
+    #[sea_orm(string_value = "PENDING")]
+    Pending,
+    /// 재시도 중 분석 성공
+    #[sea_orm(string_value = "SUCCEEDED")]
+    Succeeded,
+    /// 최대 재시도 초과 또는 재시도 불가 오류로 실패 확정
+    #[sea_orm(string_value = "FAILED")]
+    Failed,
+}
+
+/// 회고 분석 재시도 작업 엔티티 (회고당 1건)
+///
+/// AI 호출 실패로 분석이 중단되면 등록되며, 백그라운드 잡이 지수 백오프로 재시도합니다.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "analysis_job")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub analysis_job_id: i64,
+    #[sea_orm(unique)]
+    pub retrospect_id: i64,
+    /// 분석을 요청한 멤버 ID (재시도 시 사용량 기록 기준, 탈퇴 시 NULL)
+    pub requested_by: Option<i64>,
+    pub status: AnalysisJobStatus,
+    /// 지금까지 수행한 재시도 횟수
+    pub retry_count: i32,
+    /// 마지막 실패 사유
+    #[sea_orm(column_type = "Text", nullable)]
+    pub last_error: Option<String>,
+    /// 다음 재시도 예정 시각 (PENDING일 때만 의미 있음)
+    pub next_retry_at: DateTime,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::retrospect::Entity",
+        from = "Column::RetrospectId",
+        to = "super::retrospect::Column::RetrospectId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Retrospect,
+    #[sea_orm(
+        belongs_to = "crate::domain::member::entity::member::Entity",
+        from = "Column::RequestedBy",
+        to = "crate::domain::member::entity::member::Column::MemberId",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Member,
+}
+
+impl Related<super::retrospect::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Retrospect.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,43 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 오프라인 회고 QR 출석 체크인 토큰 엔티티 (일회성)
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "check_in_token")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub check_in_token_id: i64,
+    /// 체크인 토큰 (QR 페이로드에 포함)
+    #[sea_orm(unique)]
+    pub token: String,
+    pub retrospect_id: i64,
+    /// 발급자 ID (탈퇴 시 NULL)
+    pub issued_by: Option<i64>,
+    /// 만료 일시
+    pub expires_at: DateTime,
+    /// 사용 일시 (NULL이면 미사용)
+    pub used_at: Option<DateTime>,
+    /// 사용한 멤버 ID
+    pub used_by: Option<i64>,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::retrospect::Entity",
+        from = "Column::RetrospectId",
+        to = "super::retrospect::Column::RetrospectId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Retrospect,
+}
+
+impl Related<super::retrospect::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Retrospect.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,74 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 회고방 전용 커스텀 회고 방식 엔티티
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "custom_method")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub custom_method_id: i64,
+    pub retro_room_id: i64,
+    /// 방식 이름
+    pub name: String,
+    /// 질문 목록 (JSON 문자열 배열)
+    #[sea_orm(column_type = "Text")]
+    pub questions_json: String,
+    /// 질문별 답변 작성 힌트 (JSON 배열, 질문 순서 대응, 미설정 항목은 null)
+    #[sea_orm(column_type = "Text", nullable)]
+    pub placeholders_json: Option<String>,
+    /// 질문별 가중치 (JSON 배열, 질문 순서 대응, 미설정 항목은 null)
+    #[sea_orm(column_type = "Text", nullable)]
+    pub weights_json: Option<String>,
+    /// 만든 멤버 ID (탈퇴 시 NULL)
+    pub created_by: Option<i64>,
+    pub created_at: DateTime,
+}
+
+impl Model {
+    /// 저장된 질문 목록 (손상된 JSON이면 빈 목록)
+    pub fn questions(&self) -> Vec<String> {
+        serde_json::from_str(&self.questions_json).unwrap_or_default()
+    }
+
+    /// 저장된 질문별 placeholder (질문 수에 맞춰 채우며, 미설정·손상 시 None)
+    pub fn placeholders(&self) -> Vec<Option<String>> {
+        let mut placeholders: Vec<Option<String>> = self
+            .placeholders_json
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        placeholders.resize(self.questions().len(), None);
+        placeholders
+    }
+
+    /// 저장된 질문별 가중치 (질문 수에 맞춰 채우며, 미설정·손상 시 None)
+    pub fn weights(&self) -> Vec<Option<i32>> {
+        let mut weights: Vec<Option<i32>> = self
+            .weights_json
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        weights.resize(self.questions().len(), None);
+        weights
+    }
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::retro_room::Entity",
+        from = "Column::RetroRoomId",
+        to = "super::retro_room::Column::RetrospectRoomId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    RetroRoom,
+}
+
+impl Related<super::retro_room::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RetroRoom.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
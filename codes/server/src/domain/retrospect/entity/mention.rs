@@ -0,0 +1,78 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 답변/댓글 멘션 엔티티 (`@닉네임`으로 언급된 회고방 멤버)
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "mention")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub mention_id: i64,
+    /// 멘션된 회원 ID (수신자)
+    pub member_id: i64,
+    /// 멘션한 회원 ID (탈퇴 시 NULL)
+    pub author_id: Option<i64>,
+    pub retrospect_id: i64,
+    pub response_id: i64,
+    /// 댓글 멘션이면 댓글 ID, 답변 멘션이면 NULL
+    pub response_comment_id: Option<i64>,
+    /// 읽음 여부
+    #[sea_orm(default_value = false)]
+    pub is_read: bool,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::domain::member::entity::member::Entity",
+        from = "Column::MemberId",
+        to = "crate::domain::member::entity::member::Column::MemberId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Member,
+    #[sea_orm(
+        belongs_to = "crate::domain::member::entity::member::Entity",
+        from = "Column::AuthorId",
+        to = "crate::domain::member::entity::member::Column::MemberId",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Author,
+    #[sea_orm(
+        belongs_to = "super::response::Entity",
+        from = "Column::ResponseId",
+        to = "super::response::Column::ResponseId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Response,
+    #[sea_orm(
+        belongs_to = "super::response_comment::Entity",
+        from = "Column::ResponseCommentId",
+        to = "super::response_comment::Column::ResponseCommentId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    ResponseComment,
+}
+
+impl Related<crate::domain::member::entity::member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Member.def()
+    }
+}
+
+impl Related<super::response::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Response.def()
+    }
+}
+
+impl Related<super::response_comment::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::ResponseComment.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
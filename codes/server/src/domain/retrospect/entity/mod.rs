@@ -1,6 +1,17 @@
+pub mod analysis_job;
+pub mod check_in_token;
+pub mod custom_method;
+pub mod mention;
+pub mod question_guide_cache;
 pub mod response;
 pub mod response_comment;
+pub mod response_label;
 pub mod response_like;
+pub mod response_view;
+pub mod retro_analysis;
 pub mod retro_reference;
 pub mod retro_room;
 pub mod retrospect;
+pub mod retrospect_pdf_cache;
+pub mod room_subscription;
+pub mod shared_link;
@@ -0,0 +1,27 @@
+use sea_orm::entity::prelude::*;
+
+/// 질문별 초기 어시스턴트 가이드 캐시 엔티티 (질문 내용당 1건)
+///
+/// 작성 내용이 없는 초기(Initial) 가이드는 질문 내용에만 의존하므로,
+/// 질문 내용의 해시를 키로 회고와 무관하게 공유합니다.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "question_guide_cache")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub question_guide_cache_id: i64,
+    /// 질문 내용의 SHA-256 해시 (hex)
+    #[sea_orm(unique, column_type = "String(StringLen::N(64))")]
+    pub question_hash: String,
+    /// 질문 내용
+    #[sea_orm(column_type = "Text")]
+    pub question_content: String,
+    /// 생성된 가이드 목록 (GuideItem JSON 배열)
+    #[sea_orm(column_type = "Text")]
+    pub guides: String,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -1,5 +1,39 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 답변 자동 분류 카테고리 Enum
+///
+/// AI가 답변을 Keep/Problem/Try 중 하나로 분류하며, 분류할 수 없으면 `Uncategorized`(미분류)로 둡니다.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    EnumIter,
+    DeriveActiveEnum,
+    Serialize,
+    Deserialize,
+    ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AnswerCategory {
+    /// 유지할 점
+    #[sea_orm(string_value = "KEEP")]
+    Keep,
+    /// 문제점
+    #[sea_orm(string_value = "PROBLEM")]
+    Problem,
+    /// 시도할 점
+    #[sea_orm(string_value = "TRY")]
+    Try,
+    /// 미분류
+    #[sea_orm(string_value = "UNCATEGORIZED")]
+    Uncategorized,
+}
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "response")]
@@ -11,6 +45,20 @@ pub struct Model {
     pub content: String,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    /// AI 한 줄 요약 (생성 전이거나 실패 시 None)
+    #[sea_orm(column_type = "String(StringLen::N(200))", nullable)]
+    pub summary: Option<String>,
+    /// 좋아요 수 (response_like 집계 캐시, 좋아요 토글 트랜잭션에서 증감)
+    #[sea_orm(default_value = 0)]
+    pub like_count: i32,
+    /// 조회수 (작성자 본인 조회 제외, 같은 멤버는 일정 시간 내 1회만 반영)
+    #[sea_orm(default_value = 0)]
+    pub view_count: i32,
+    /// 마지막으로 이 답변을 임시 저장한 작성 세션 ID (질문 단위 병합 충돌 감지)
+    #[sea_orm(column_type = "String(StringLen::N(64))", nullable)]
+    pub last_edit_session: Option<String>,
+    /// AI 자동 분류 카테고리 (분류 결과를 저장하지 않았으면 None)
+    pub auto_category: Option<AnswerCategory>,
     pub retrospect_id: i64,
 }
 
@@ -0,0 +1,35 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 회고 답변 라벨 엔티티 (예: "액션아이템", "칭찬")
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "response_label")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub response_label_id: i64,
+    pub response_id: i64,
+    /// 라벨 이름 (양끝 공백 제거, 최대 20자)
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
+    pub label: String,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::response::Entity",
+        from = "Column::ResponseId",
+        to = "super::response::Column::ResponseId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Response,
+}
+
+impl Related<super::response::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Response.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
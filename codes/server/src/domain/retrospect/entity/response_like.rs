@@ -8,6 +8,8 @@ pub struct Model {
     pub response_like_id: i64,
     pub member_id: i64,
     pub response_id: i64,
+    /// 좋아요 시각 (컬럼 추가 이전의 좋아요는 NULL)
+    pub created_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
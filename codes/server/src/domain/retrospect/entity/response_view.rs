@@ -0,0 +1,48 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 답변 조회 기록 (멤버별 마지막으로 조회수에 반영된 시각, 중복 조회 제한용)
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "response_view")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub response_view_id: i64,
+    pub member_id: i64,
+    pub response_id: i64,
+    /// 마지막으로 조회수에 반영된 조회 시각
+    pub counted_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::domain::member::entity::member::Entity",
+        from = "Column::MemberId",
+        to = "crate::domain::member::entity::member::Column::MemberId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Member,
+    #[sea_orm(
+        belongs_to = "super::response::Entity",
+        from = "Column::ResponseId",
+        to = "super::response::Column::ResponseId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Response,
+}
+
+impl Related<crate::domain::member::entity::member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Member.def()
+    }
+}
+
+impl Related<super::response::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Response.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,53 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 회고 분석 결과 버전 엔티티
+///
+/// 분석이 완료될 때마다 새 버전으로 누적되며, 회고당 최근 `ANALYSIS_VERSION_RETENTION`개만 보관합니다.
+/// 최신 결과는 기존과 같이 `retrospects.insight`, `member_retro.personal_insight`에도 반영됩니다.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "retro_analysis")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub retro_analysis_id: i64,
+    pub retrospect_id: i64,
+    /// 회고 내 분석 버전 (1부터 증가, 삭제된 버전 번호는 재사용하지 않음)
+    pub version: i32,
+    /// 팀 인사이트
+    #[sea_orm(column_type = "Text")]
+    pub team_insight: String,
+    /// 전체 분석 결과 (AnalysisResponse JSON)
+    #[sea_orm(column_type = "Text")]
+    pub result_json: String,
+    /// 분석을 요청한 멤버 ID (탈퇴 시 NULL)
+    pub requested_by: Option<i64>,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::retrospect::Entity",
+        from = "Column::RetrospectId",
+        to = "super::retrospect::Column::RetrospectId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Retrospect,
+    #[sea_orm(
+        belongs_to = "crate::domain::member::entity::member::Entity",
+        from = "Column::RequestedBy",
+        to = "crate::domain::member::entity::member::Column::MemberId",
+        on_update = "NoAction",
+        on_delete = "SetNull"
+    )]
+    Member,
+}
+
+impl Related<super::retrospect::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Retrospect.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
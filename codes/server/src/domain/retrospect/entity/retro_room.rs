@@ -1,18 +1,30 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use crate::domain::member::entity::member_retro_room::RoomRole;
+
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "retro_room")]
 pub struct Model {
     #[sea_orm(primary_key)]
     pub retrospect_room_id: i64,
-    #[sea_orm(unique, column_type = "String(StringLen::N(20))")]
+    /// 회고방 이름 (같은 사용자가 소유한 방 사이에서만 중복 불가)
+    #[sea_orm(column_type = "String(StringLen::N(20))")]
     pub title: String,
     #[sea_orm(column_type = "String(StringLen::N(50))", nullable)]
     pub description: Option<String>,
     pub invition_url: String, // Keeping schema spelling
     /// 초대 코드 생성 시각 (만료 기준)
     pub invite_code_created_at: DateTime,
+    /// 초대 코드로 참여한 멤버에게 부여할 역할 (Member 또는 Moderator)
+    pub invite_grant_role: RoomRole,
+    /// 동시에 진행 중인 회고 최대 개수 (None이면 무제한)
+    pub max_active_retrospects: Option<i32>,
+    /// 같은 방 내 회고 제목 중복 금지 여부 (trim + 대소문자 무시 비교)
+    pub unique_retrospect_title: bool,
+    /// 분석 결과를 전송할 Slack Incoming Webhook URL (None이면 연동 해제)
+    #[serde(skip)]
+    pub slack_webhook_url: Option<String>,
     pub created_at: DateTime,
     pub updated_at: DateTime,
 }
@@ -2,8 +2,10 @@ use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use crate::utils::error::AppError;
+
 /// 회고 방식 Enum
-/// API 스펙에 따라 5가지 회고 방식을 지원합니다.
+/// API 스펙에 따른 기본 방식과 회고방 전용 커스텀 방식을 지원합니다.
 #[derive(
     Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
 )]
@@ -25,6 +27,16 @@ pub enum RetrospectMethod {
     /// 자유 형식: 형식 제약 없이 자유롭게 작성
     #[sea_orm(string_value = "FREE")]
     Free,
+    /// 세일보트: 순풍-닻-암초-섬(목표)으로 팀의 항해를 돌아보는 방식
+    #[sea_orm(string_value = "SAILBOAT")]
+    Sailboat,
+    /// DAKI: Drop-Add-Keep-Improve 4단계로 버릴 것, 추가할 것, 유지할 것, 개선할 것을 정리하는 방식
+    #[sea_orm(string_value = "DAKI")]
+    #[serde(rename = "DAKI")]
+    Daki,
+    /// 팀 전용 커스텀 방식: 질문은 회고 생성 시 `custom_questions`에 복사해 둡니다
+    #[sea_orm(string_value = "CUSTOM")]
+    Custom,
 }
 
 impl std::fmt::Display for RetrospectMethod {
@@ -35,6 +47,9 @@ impl std::fmt::Display for RetrospectMethod {
             RetrospectMethod::FiveF => "FIVE_F",
             RetrospectMethod::Pmi => "PMI",
             RetrospectMethod::Free => "FREE",
+            RetrospectMethod::Sailboat => "SAILBOAT",
+            RetrospectMethod::Daki => "DAKI",
+            RetrospectMethod::Custom => "CUSTOM",
         };
         write!(f, "{}", s)
     }
@@ -42,6 +57,7 @@ impl std::fmt::Display for RetrospectMethod {
 
 impl RetrospectMethod {
     /// 회고 방식에 따른 기본 질문 목록을 반환합니다.
+    /// 커스텀 방식은 회고마다 질문이 다르므로 빈 목록이며, `Model::questions`를 사용합니다.
     pub fn default_questions(&self) -> Vec<&'static str> {
         match self {
             RetrospectMethod::Kpt => vec![
@@ -74,6 +90,105 @@ impl RetrospectMethod {
                 "팀원들에게 전하고 싶은 말이 있나요?",
                 "추가로 공유하고 싶은 의견이 있나요?",
             ],
+            RetrospectMethod::Sailboat => vec![
+                "순풍(우리를 앞으로 나아가게 한 것)",
+                "닻(우리를 붙잡은 것)",
+                "암초(위험 요소)",
+                "섬(목표)",
+            ],
+            RetrospectMethod::Daki => vec![
+                "버려야 할 것은 무엇인가요?",
+                "새롭게 추가하고 싶은 것은?",
+                "계속 유지할 것은?",
+                "개선할 것은?",
+            ],
+            RetrospectMethod::Custom => vec![],
+        }
+    }
+
+    /// 기본 질문 순서에 대응하는 질문 카테고리 힌트를 반환합니다.
+    /// AI 분석 시 각 답변이 방식의 어떤 항목에 해당하는지 알려주는 용도이며, 자유 형식은 힌트가 없습니다.
+    pub fn question_categories(&self) -> Vec<&'static str> {
+        match self {
+            RetrospectMethod::Kpt => vec!["Keep(유지할 점)", "Problem(문제점)", "Try(시도할 점)"],
+            RetrospectMethod::FourL => vec![
+                "Liked(좋았던 점)",
+                "Learned(배운 점)",
+                "Lacked(부족했던 점)",
+                "Longed for(바라는 점)",
+            ],
+            RetrospectMethod::FiveF => vec![
+                "Facts(사실)",
+                "Feelings(감정)",
+                "Findings(발견)",
+                "Future(향후 행동)",
+                "Feedback(동료 피드백)",
+            ],
+            RetrospectMethod::Pmi => vec![
+                "Plus(긍정적인 점)",
+                "Minus(부정적인 점)",
+                "Interesting(흥미로운 점)",
+            ],
+            RetrospectMethod::Sailboat => {
+                vec!["Wind(순풍)", "Anchor(닻)", "Rocks(암초)", "Island(목표)"]
+            }
+            RetrospectMethod::Daki => vec![
+                "Drop(버릴 것)",
+                "Add(추가할 것)",
+                "Keep(유지할 것)",
+                "Improve(개선할 것)",
+            ],
+            RetrospectMethod::Free | RetrospectMethod::Custom => vec![],
+        }
+    }
+
+    /// 기본 질문 순서에 대응하는 답변 작성 힌트(placeholder)를 반환합니다.
+    /// 빈 화면 부담을 줄이기 위한 예시 문구이며, 답변 검증에는 사용하지 않습니다.
+    pub fn default_placeholders(&self) -> Vec<&'static str> {
+        match self {
+            RetrospectMethod::Kpt => vec![
+                "예) 매일 짧게 진행한 스탠드업 덕분에 진행 상황을 빠르게 공유할 수 있었어요.",
+                "예) 요구사항 변경이 늦게 공유되어 작업을 다시 해야 했어요.",
+                "예) 다음에는 작업 시작 전에 API 명세를 먼저 맞춰보고 싶어요.",
+            ],
+            RetrospectMethod::FourL => vec![
+                "예) 배포 후 사용자 반응을 함께 확인했던 순간이 좋았어요.",
+                "예) 코드 리뷰를 통해 테스트 작성 방법을 새로 익혔어요.",
+                "예) 디자인 확정 일정이 부족해 충분히 검토하지 못했어요.",
+                "예) 역할 분담을 더 일찍 정하면 좋겠어요.",
+            ],
+            RetrospectMethod::FiveF => vec![
+                "예) 2주 동안 주요 기능 3개를 배포했어요.",
+                "예) 마감 직전 장애 대응이 가장 힘들었고, 해결했을 때 가장 뿌듯했어요.",
+                "예) 생각보다 캐시 적용 효과가 컸어요.",
+                "예) 일정 산정 시 버퍼를 더 두고 싶어요.",
+                "예) 리뷰를 꼼꼼히 봐주셔서 큰 도움이 되었어요.",
+            ],
+            RetrospectMethod::Pmi => vec![
+                "예) 작업 단위를 작게 나눠 PR을 올린 방식이 도움이 되었어요.",
+                "예) 회의가 길어지면서 작업 시간이 부족했어요.",
+                "예) 페어 프로그래밍이 생각보다 효율적이었어요.",
+            ],
+            RetrospectMethod::Free => vec![
+                "예) 첫 배포를 함께 지켜본 순간이 기억에 남아요.",
+                "예) 일정이 빠듯했지만 팀 분위기가 좋아 버틸 수 있었어요.",
+                "예) 다음에는 문서화를 더 꼼꼼히 하고 싶어요.",
+                "예) 늘 먼저 도와주셔서 고마웠어요.",
+                "예) 회고 주기를 조금 더 짧게 가져가면 좋겠어요.",
+            ],
+            RetrospectMethod::Sailboat => vec![
+                "예) 명확한 목표 공유 덕분에 빠르게 결정할 수 있었어요.",
+                "예) 반복되는 수동 배포 작업이 속도를 늦췄어요.",
+                "예) 테스트가 부족한 결제 모듈이 장애로 이어질 수 있어요.",
+                "예) 다음 분기까지 주간 활성 사용자 1,000명을 달성하고 싶어요.",
+            ],
+            RetrospectMethod::Daki => vec![
+                "예) 효과 없이 길어지기만 하는 주간 회의를 없애고 싶어요.",
+                "예) 배포 전 체크리스트를 새로 도입하고 싶어요.",
+                "예) PR을 작게 나눠 올리는 습관은 계속 유지하고 싶어요.",
+                "예) 이슈 우선순위를 정하는 기준을 더 명확히 하고 싶어요.",
+            ],
+            RetrospectMethod::Custom => vec![],
         }
     }
 
@@ -93,10 +208,106 @@ pub struct Model {
     pub retrospect_method: RetrospectMethod,
     pub created_at: DateTime,
     pub updated_at: DateTime,
+    /// 회고 시작 시각 (timezone 기준 벽시계 시간)
     pub start_time: DateTime,
+    /// 회고 시작 시각 (UTC, 진행 중 회고 수 집계용)
+    pub start_time_utc: Option<DateTime>,
+    /// 회고 기준 타임존 (IANA 이름, 예: "Asia/Seoul")
+    #[sea_orm(column_type = "String(StringLen::N(64))", default_value = "Asia/Seoul")]
+    pub timezone: String,
+    /// 모든 참여자 제출 시 자동 분석 여부
+    #[sea_orm(default_value = "false")]
+    pub auto_analyze: bool,
+    /// 자동 분석 트리거 시각 (중복 트리거 방지용, NULL이면 미실행)
+    pub auto_analysis_triggered_at: Option<DateTime>,
+    /// 참여자 요약 메일 발송 시각 (중복 발송 방지용, NULL이면 미발송)
+    pub summary_mail_sent_at: Option<DateTime>,
+    /// 제출 시 답변별 최소 글자 수 (양끝 공백 제외 기준)
+    #[sea_orm(default_value = "1")]
+    pub min_answer_length: i32,
+    /// 질문 확정 여부 (false면 지연 생성 모드: 확정 시 참여자별 response 일괄 생성)
+    #[sea_orm(default_value = "true")]
+    pub questions_finalized: bool,
+    /// 답변 편집 잠금 여부 (true면 답변 임시 저장/제출 불가, Owner만 변경)
+    #[sea_orm(default_value = "false")]
+    pub is_locked: bool,
+    /// 커스텀 방식으로 생성한 경우 원본 커스텀 방식 ID (방식 삭제 후에도 회고는 유지)
+    pub custom_method_id: Option<i64>,
+    /// 커스텀 방식 질문 스냅샷 (JSON 문자열 배열, 기본 방식이면 NULL)
+    #[sea_orm(column_type = "Text", nullable)]
+    pub custom_questions: Option<String>,
+    /// 커스텀 방식 질문별 placeholder 스냅샷 (JSON 배열, 기본 방식이면 NULL)
+    #[sea_orm(column_type = "Text", nullable)]
+    pub custom_placeholders: Option<String>,
+    /// 커스텀 방식 질문별 가중치 스냅샷 (JSON 배열, 기본 방식이거나 미설정이면 NULL)
+    #[sea_orm(column_type = "Text", nullable)]
+    pub custom_weights: Option<String>,
     pub retrospect_room_id: i64,
 }
 
+impl Model {
+    /// 회고의 질문 목록 (커스텀 방식이면 스냅샷, 아니면 방식별 기본 질문)
+    ///
+    /// 커스텀 질문 스냅샷이 손상되어 파싱할 수 없으면 빈 목록으로 대체하지 않고
+    /// 로그를 남긴 뒤 에러를 반환합니다. (질문 0개로 검증/집계가 통과되는 것을 방지)
+    pub fn questions(&self) -> Result<Vec<String>, AppError> {
+        match &self.custom_questions {
+            Some(json) => serde_json::from_str(json).map_err(|e| {
+                tracing::error!(
+                    retrospect_id = self.retrospect_id,
+                    error = %e,
+                    "커스텀 질문 스냅샷 파싱 실패"
+                );
+                AppError::InternalError(format!(
+                    "회고 {}의 커스텀 질문 데이터가 손상되었습니다.",
+                    self.retrospect_id
+                ))
+            }),
+            None => Ok(self
+                .retrospect_method
+                .default_questions()
+                .into_iter()
+                .map(str::to_string)
+                .collect()),
+        }
+    }
+
+    /// 회고의 질문 개수
+    pub fn question_count(&self) -> Result<usize, AppError> {
+        Ok(self.questions()?.len())
+    }
+
+    /// 질문 순서에 대응하는 placeholder (커스텀 스냅샷 우선, 미설정 항목은 방식별 기본값)
+    pub fn placeholders(&self) -> Result<Vec<Option<String>>, AppError> {
+        let custom: Vec<Option<String>> = self
+            .custom_placeholders
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        let defaults = self.retrospect_method.default_placeholders();
+        Ok((0..self.question_count()?)
+            .map(|i| {
+                custom
+                    .get(i)
+                    .cloned()
+                    .flatten()
+                    .or_else(|| defaults.get(i).map(|p| p.to_string()))
+            })
+            .collect())
+    }
+
+    /// 질문 순서에 대응하는 가중치 (미설정 항목은 None, 모두 None이면 동일 가중치)
+    pub fn weights(&self) -> Result<Vec<Option<i32>>, AppError> {
+        let mut weights: Vec<Option<i32>> = self
+            .custom_weights
+            .as_deref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default();
+        weights.resize(self.question_count()?, None);
+        Ok(weights)
+    }
+}
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(
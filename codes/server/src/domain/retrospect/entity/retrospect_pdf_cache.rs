@@ -0,0 +1,41 @@
+use sea_orm::entity::prelude::*;
+
+/// 회고 PDF 캐시 엔티티 (회고당 1건)
+///
+/// 전원 제출 또는 분석 완료 시 미리 생성한 PDF를 저장합니다.
+/// `content_hash`가 현재 회고 내용의 해시와 다르면 무효로 보고 다시 생성합니다.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "retrospect_pdf_cache")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub retrospect_pdf_cache_id: i64,
+    #[sea_orm(unique)]
+    pub retrospect_id: i64,
+    /// PDF 생성에 사용한 회고 내용의 SHA-256 해시 (hex)
+    #[sea_orm(column_type = "String(StringLen::N(64))")]
+    pub content_hash: String,
+    /// 생성된 PDF 바이트
+    #[sea_orm(column_type = "Blob")]
+    pub pdf_data: Vec<u8>,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::retrospect::Entity",
+        from = "Column::RetrospectId",
+        to = "super::retrospect::Column::RetrospectId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Retrospect,
+}
+
+impl Related<super::retrospect::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Retrospect.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,66 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 회고방 구독 알림 채널 Enum
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(10))")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SubscriptionChannel {
+    /// 이메일 알림
+    #[sea_orm(string_value = "EMAIL")]
+    Email,
+    /// 푸시 알림
+    #[sea_orm(string_value = "PUSH")]
+    Push,
+}
+
+/// 회고방 새 회고 알림 구독 (멤버당 회고방별 1개, member_id + retro_room_id 유니크)
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "room_subscription")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub room_subscription_id: i64,
+    pub member_id: i64,
+    pub retro_room_id: i64,
+    /// 알림을 받을 채널
+    pub channel: SubscriptionChannel,
+    pub created_at: DateTime,
+    pub updated_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "crate::domain::member::entity::member::Entity",
+        from = "Column::MemberId",
+        to = "crate::domain::member::entity::member::Column::MemberId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Member,
+    #[sea_orm(
+        belongs_to = "super::retro_room::Entity",
+        from = "Column::RetroRoomId",
+        to = "super::retro_room::Column::RetrospectRoomId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    RetroRoom,
+}
+
+impl Related<crate::domain::member::entity::member::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Member.def()
+    }
+}
+
+impl Related<super::retro_room::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::RetroRoom.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,60 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 공유 범위 Enum
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ShareScope {
+    /// AI 분석 결과만 공유
+    #[sea_orm(string_value = "ANALYSIS")]
+    Analysis,
+    /// 회고 전체(질문/답변/참여자) 읽기 전용 공유
+    #[sea_orm(string_value = "RETROSPECT")]
+    Retrospect,
+}
+
+/// 회고 외부 공유 링크 엔티티 (분석 결과 또는 회고 전체)
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "shared_link")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub shared_link_id: i64,
+    /// 공유 토큰 (URL 경로에 사용)
+    #[sea_orm(unique)]
+    pub token: String,
+    pub retrospect_id: i64,
+    /// 공유 범위
+    #[sea_orm(default_value = "ANALYSIS")]
+    pub scope: ShareScope,
+    /// 발급자 ID (탈퇴 시 NULL)
+    pub created_by: Option<i64>,
+    /// 공유 시 닉네임 마스킹 여부
+    #[sea_orm(default_value = "true")]
+    pub mask_nickname: bool,
+    /// 만료 일시 (NULL이면 만료 없음)
+    pub expires_at: Option<DateTime>,
+    /// 폐기 일시 (NULL이면 유효)
+    pub revoked_at: Option<DateTime>,
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::retrospect::Entity",
+        from = "Column::RetrospectId",
+        to = "super::retrospect::Column::RetrospectId",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Retrospect,
+}
+
+impl Related<super::retrospect::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Retrospect.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
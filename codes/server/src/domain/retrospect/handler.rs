@@ -1,10 +1,16 @@
+use std::convert::Infallible;
+
 use axum::{
-    extract::{Path, Query, State},
+    extract::{ws::WebSocketUpgrade, Path, Query, State},
     http::header,
-    response::IntoResponse,
+    response::{
+        sse::{Event, KeepAlive, Sse},
+        IntoResponse, Response,
+    },
     Json,
 };
 use chrono::Utc;
+use futures_util::stream::Stream;
 use validator::Validate;
 
 use crate::state::AppState;
@@ -12,19 +18,45 @@ use crate::utils::auth::AuthUser;
 use crate::utils::error::AppError;
 use crate::utils::BaseResponse;
 
+use super::analysis_progress::analysis_progress_stream;
 use super::dto::{
-    AnalysisResponse, AssistantRequest, AssistantResponse, CreateCommentRequest,
-    CreateCommentResponse, CreateParticipantResponse, CreateRetrospectRequest,
-    CreateRetrospectResponse, DeleteRetroRoomResponse, DraftSaveRequest, DraftSaveResponse,
-    JoinRetroRoomRequest, JoinRetroRoomResponse, LikeToggleResponse, ListCommentsQuery,
-    ListCommentsResponse, ReferenceItem, ResponseCategory, ResponsesListResponse,
-    ResponsesQueryParams, RetroRoomCreateRequest, RetroRoomCreateResponse, RetroRoomListItem,
-    RetroRoomMemberItem, RetrospectDetailResponse, RetrospectListItem, SearchQueryParams,
-    SearchRetrospectItem, StorageQueryParams, StorageResponse, SubmitRetrospectRequest,
-    SubmitRetrospectResponse, UpdateRetroRoomNameRequest, UpdateRetroRoomNameResponse,
-    UpdateRetroRoomOrderRequest,
+    parse_response_fields, AnalysisResponse, AnalysisShareResponse, AnalysisVersionCompareQuery,
+    AnalysisVersionCompareResponse, AnalysisVersionsResponse, AnswerFeedbackRequest,
+    AnswerFeedbackResponse, AssistantRequest, AssistantResponse, AutoCategorizeQuery,
+    AutoCategorizeResponse, BatchCreateRetrospectRequest, BatchCreateRetrospectResponse,
+    CheckInQrResponse, CheckInRequest, CreateAnalysisShareRequest, CreateCommentRequest,
+    CreateCommentResponse, CreateCustomMethodRequest, CreateParticipantResponse,
+    CreateRetrospectRequest, CreateRetrospectResponse, CustomMethodItem, DeleteRetroRoomResponse,
+    DeleteRetrospectQuery, DeleteRetrospectResponse, DraftSaveRequest, DraftSaveResponse,
+    DuplicateRetrospectRequest, EditSessionResponse, ExportFormat, ExportQueryParams,
+    FinalizeQuestionsResponse, HallOfFameQuery, HallOfFameResponse, HeartbeatResponse,
+    InviteCodeResponse, InviteMetaResponse, InvitePreviewQueryParams, JoinRetroRoomRequest,
+    JoinRetroRoomResponse, KickMemberResponse, LeaveRetroRoomResponse, LikeToggleResponse,
+    ListCommentsQuery, ListCommentsResponse, ListLikesQuery, ListLikesResponse,
+    MembershipHistoryResponse, MentionCandidateItem, MentionQueryParams, MoveRetrospectRequest,
+    MoveRetrospectResponse, QuestionCoverageResponse, ReferenceItem, ReferencePreviewRequest,
+    ReferencePreviewResponse, RegenerateInviteCodeRequest, RemindRequest, RemindResponse,
+    ResetLikesResponse, ResponseCategory, ResponseLabelsResponse, ResponseSummaryResponse,
+    ResponseViewResponse, ResponsesQueryParams, RestoreRetroRoomRequest, RestoreRetroRoomResponse,
+    RetroRoomCreateRequest, RetroRoomCreateResponse, RetroRoomListItem, RetroRoomMemberItem,
+    RetroRoomPreviewResponse, RetroRoomSettingsResponse, RetrospectDetailResponse,
+    RetrospectListItem, RetrospectLockResponse, RoomInviteResponse, RoomMemberEmailsResponse,
+    RoomSubscriptionResponse, SearchQueryParams, SearchRetrospectItem, SharedRetrospectResponse,
+    SlackIntegrationResponse, StartWritingResponse, StorageQueryParams, StorageResponse,
+    SubmitPreviewResponse, SubmitRetrospectRequest, SubmitRetrospectResponse,
+    SuccessCustomMethodListResponse, SuccessCustomMethodResponse, SuccessFinalizeQuestionsResponse,
+    SuccessMoveRetrospectResponse, SuccessResetLikesResponse, SuccessResponseViewResponse,
+    SuccessRestoreRetroRoomResponse, SuccessUpdateSubmittedAnswersResponse,
+    SuccessValidateQuestionsResponse, TransferOwnershipRequest, TransferOwnershipResponse,
+    TrendingResponsesQuery, TrendingResponsesResponse, TypingEvent, UpdateMemberOrderRequest,
+    UpdateMemberRoleRequest, UpdateMemberRoleResponse, UpdateResponseLabelsRequest,
+    UpdateRetroRoomNameRequest, UpdateRetroRoomNameResponse, UpdateRetroRoomOrderRequest,
+    UpdateRetroRoomSettingsRequest, UpdateRoomSubscriptionRequest, UpdateSlackIntegrationRequest,
+    UpdateSubmittedAnswersRequest, UpdateSubmittedAnswersResponse, ValidateQuestionsRequest,
+    ValidateQuestionsResponse,
 };
 use super::service::RetrospectService;
+use super::typing::run_typing_session;
 
 // ============================================
 // RetroRoom Handlers (API-004 ~ API-010)
@@ -65,6 +97,62 @@ pub async fn create_retro_room(
     )))
 }
 
+/// 회고방 초대 미리보기 API
+///
+/// 초대 코드로 참여 전 회고방 정보(이름, 설명, 멤버 수, 최근 활동)를 인증 없이 조회합니다.
+/// 만료된 코드도 조회되며 `isExpired`로 만료 상태를 알려줍니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retro-rooms/preview",
+    params(InvitePreviewQueryParams),
+    responses(
+        (status = 200, description = "초대 미리보기 조회 성공", body = SuccessRetroRoomPreviewResponse),
+        (status = 400, description = "유효하지 않은 초대 코드", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고방", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn preview_retro_room(
+    State(state): State<AppState>,
+    Query(params): Query<InvitePreviewQueryParams>,
+) -> Result<Json<BaseResponse<RetroRoomPreviewResponse>>, AppError> {
+    let result = RetrospectService::preview_retro_room(state, params.code).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "초대 미리보기 조회를 성공했습니다.",
+    )))
+}
+
+/// 초대 링크 OpenGraph 메타 API
+///
+/// 초대 링크 공유 시 썸네일/미리보기에 쓸 OG 태그 데이터(방 이름, 멤버 수)를 인증 없이 조회합니다.
+/// 만료되었거나 유효하지 않은 코드도 에러 없이 일반 메타(`valid: false`)로 응답합니다.
+/// 참여 가능한 코드면 `valid: true`와 방 이름을 내려주므로 링크를 가진 사람에게는 코드 유효 여부가 공개됩니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retro-rooms/invite/{code}/meta",
+    params(
+        ("code" = String, Path, description = "초대 코드 (INV-XXXX-XXXX)")
+    ),
+    responses(
+        (status = 200, description = "초대 링크 메타 조회 성공", body = SuccessInviteMetaResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn get_invite_meta(
+    State(state): State<AppState>,
+    Path(code): Path<String>,
+) -> Result<Json<BaseResponse<InviteMetaResponse>>, AppError> {
+    let result = RetrospectService::get_invite_meta(state, code).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "초대 링크 메타 조회를 성공했습니다.",
+    )))
+}
+
 /// 회고방 참여 API (API-005)
 ///
 /// 초대 링크(코드)를 통해 회고방에 참여합니다.
@@ -103,6 +191,7 @@ pub async fn join_retro_room(
 /// 회고방 목록 조회 API (API-006)
 ///
 /// 현재 로그인한 사용자가 참여 중인 모든 회고방 목록을 조회합니다.
+/// 각 회고방에는 마지막 확인 이후의 미확인 활동 수(unreadCount)가 포함됩니다.
 #[utoipa::path(
     get,
     path = "/api/v1/retro-rooms",
@@ -161,311 +250,1405 @@ pub async fn list_retro_room_members(
     )))
 }
 
-/// 회고방 순서 변경 API (API-007)
+/// 회고방 멤버 이메일 목록 조회 API
 ///
-/// 드래그 앤 드롭으로 변경된 회고방들의 정렬 순서를 서버에 일괄 저장합니다.
+/// Owner가 외부 메일 발송을 위해 멤버 이메일 목록을 조회합니다.
+/// - 이메일 공유를 허용한 멤버만 포함되며, 제외된 인원 수를 함께 반환합니다.
+/// - 조회 이력이 기록되며, 같은 Owner는 60초에 한 번만 조회할 수 있습니다.
 #[utoipa::path(
-    patch,
-    path = "/api/v1/retro-rooms/order",
-    request_body = UpdateRetroRoomOrderRequest,
+    get,
+    path = "/api/v1/retro-rooms/{retro_room_id}/members/emails",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "순서 변경 성공", body = SuccessEmptyResponse),
-        (status = 400, description = "잘못된 순서 데이터", body = ErrorResponse),
+        (status = 200, description = "멤버 이메일 목록 조회 성공", body = SuccessRoomMemberEmailsResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 403, description = "권한 없음", body = ErrorResponse)
+        (status = 403, description = "Owner 권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse),
+        (status = 429, description = "조회 간격 제한", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
     ),
     tag = "RetroRoom"
 )]
-pub async fn update_retro_room_order(
+pub async fn list_member_emails(
     State(state): State<AppState>,
     user: AuthUser,
-    Json(req): Json<UpdateRetroRoomOrderRequest>,
-) -> Result<Json<BaseResponse<()>>, AppError> {
-    req.validate()?;
+    Path(retro_room_id): Path<i64>,
+) -> Result<Json<BaseResponse<RoomMemberEmailsResponse>>, AppError> {
+    if retro_room_id < 1 {
+        return Err(AppError::BadRequest(
+            "retroRoomId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
 
     let member_id = user.user_id()?;
 
-    RetrospectService::update_retro_room_order(state, member_id, req).await?;
+    let result = RetrospectService::list_member_emails(state, member_id, retro_room_id).await?;
 
     Ok(Json(BaseResponse::success_with_message(
-        (),
-        "회고방 순서가 성공적으로 변경되었습니다.",
+        result,
+        "회고방 멤버 이메일 목록 조회를 성공했습니다.",
     )))
 }
 
-/// 회고방 이름 변경 API (API-008)
+/// 회고방 멤버 표시 순서 변경 API
 ///
-/// 기존 회고방의 이름을 새로운 이름으로 변경합니다. (Owner만 가능)
+/// Owner가 멤버 목록의 표시 순서를 지정합니다. 요청에 없는 멤버의 지정 순서는 해제되며,
+/// 순서가 없는 멤버는 지정된 멤버 뒤에 역할(OWNER 우선)·가입일 순으로 표시됩니다.
 #[utoipa::path(
     patch,
-    path = "/api/v1/retro-rooms/{retro_room_id}/name",
-    request_body = UpdateRetroRoomNameRequest,
+    path = "/api/v1/retro-rooms/{retro_room_id}/members/order",
+    request_body = UpdateMemberOrderRequest,
     params(
         ("retro_room_id" = i64, Path, description = "회고방 ID")
     ),
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "이름 변경 성공", body = SuccessUpdateRetroRoomNameResponse),
-        (status = 400, description = "이름 길이 초과", body = ErrorResponse),
+        (status = 200, description = "멤버 순서 변경 성공", body = SuccessEmptyResponse),
+        (status = 400, description = "잘못된 순서 데이터", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 403, description = "권한 없음", body = ErrorResponse),
-        (status = 404, description = "회고방 없음", body = ErrorResponse),
-        (status = 409, description = "이름 중복", body = ErrorResponse)
+        (status = 403, description = "권한 없음 (Owner 전용)", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
     ),
     tag = "RetroRoom"
 )]
-pub async fn update_retro_room_name(
+pub async fn update_member_order(
     State(state): State<AppState>,
     user: AuthUser,
     Path(retro_room_id): Path<i64>,
-    Json(req): Json<UpdateRetroRoomNameRequest>,
-) -> Result<Json<BaseResponse<UpdateRetroRoomNameResponse>>, AppError> {
+    Json(req): Json<UpdateMemberOrderRequest>,
+) -> Result<Json<BaseResponse<()>>, AppError> {
     req.validate()?;
 
     let member_id = user.user_id()?;
 
-    let result =
-        RetrospectService::update_retro_room_name(state, member_id, retro_room_id, req).await?;
+    RetrospectService::update_member_order(state, member_id, retro_room_id, req).await?;
 
     Ok(Json(BaseResponse::success_with_message(
-        result,
-        "회고방 이름 변경에 성공하였습니다.",
+        (),
+        "멤버 순서가 성공적으로 변경되었습니다.",
     )))
 }
 
-/// 회고방 삭제 API (API-009)
+/// 회고방 멤버 역할 변경 API
 ///
-/// 회고방을 완전히 삭제합니다. (Owner만 가능)
+/// Owner가 멤버에게 Moderator 역할을 부여하거나 해제합니다. 역할이 바뀌면 대상 멤버에게 알림을 보냅니다.
+/// 이미 같은 역할이면 변경 없이 `changed: false`를 반환하고 알림도 보내지 않습니다.
 #[utoipa::path(
-    delete,
-    path = "/api/v1/retro-rooms/{retro_room_id}",
+    patch,
+    path = "/api/v1/retro-rooms/{retro_room_id}/members/{member_id}/role",
+    request_body = UpdateMemberRoleRequest,
     params(
-        ("retro_room_id" = i64, Path, description = "회고방 ID")
+        ("retro_room_id" = i64, Path, description = "회고방 ID"),
+        ("member_id" = i64, Path, description = "역할을 변경할 멤버 ID")
     ),
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "삭제 성공", body = SuccessDeleteRetroRoomResponse),
+        (status = 200, description = "멤버 역할 변경 성공", body = SuccessUpdateMemberRoleResponse),
+        (status = 400, description = "본인 역할 변경 시도", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 403, description = "권한 없음", body = ErrorResponse),
-        (status = 404, description = "회고방 없음", body = ErrorResponse)
+        (status = 403, description = "권한 없음 (Owner 전용, Owner 대상 불가)", body = ErrorResponse),
+        (status = 404, description = "회고방 또는 대상 멤버 없음", body = ErrorResponse)
     ),
     tag = "RetroRoom"
 )]
-pub async fn delete_retro_room(
+pub async fn update_member_role(
     State(state): State<AppState>,
     user: AuthUser,
-    Path(retro_room_id): Path<i64>,
-) -> Result<Json<BaseResponse<DeleteRetroRoomResponse>>, AppError> {
+    Path((retro_room_id, target_member_id)): Path<(i64, i64)>,
+    Json(req): Json<UpdateMemberRoleRequest>,
+) -> Result<Json<BaseResponse<UpdateMemberRoleResponse>>, AppError> {
     let member_id = user.user_id()?;
 
-    let result = RetrospectService::delete_retro_room(state, member_id, retro_room_id).await?;
+    let result = RetrospectService::update_member_role(
+        state,
+        member_id,
+        retro_room_id,
+        target_member_id,
+        req,
+    )
+    .await?;
 
     Ok(Json(BaseResponse::success_with_message(
         result,
-        "회고방 삭제에 성공하였습니다.",
+        "멤버 역할 변경에 성공하였습니다.",
     )))
 }
 
-/// 회고방 내 회고 목록 조회 API (API-010)
+/// 회고방 Owner 위임 API
 ///
-/// 특정 회고방에 속한 모든 회고 목록을 조회합니다.
+/// Owner가 같은 회고방의 다른 멤버에게 Owner 역할을 넘깁니다.
+/// 위임 후 요청자는 MEMBER가 되며, 새 Owner에게 알림을 보냅니다.
 #[utoipa::path(
-    get,
-    path = "/api/v1/retro-rooms/{retro_room_id}/retrospects",
+    patch,
+    path = "/api/v1/retro-rooms/{retro_room_id}/owner",
+    request_body = TransferOwnershipRequest,
     params(
         ("retro_room_id" = i64, Path, description = "회고방 ID")
     ),
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "회고 목록 조회 성공", body = SuccessRetrospectListResponse),
+        (status = 200, description = "Owner 위임 성공", body = SuccessTransferOwnershipResponse),
+        (status = 400, description = "잘못된 요청 (본인에게 위임 등)", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 403, description = "권한 없음", body = ErrorResponse),
-        (status = 404, description = "회고방 없음", body = ErrorResponse)
+        (status = 403, description = "권한 없음 (Owner 전용)", body = ErrorResponse),
+        (status = 404, description = "회고방 또는 대상 멤버 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
     ),
     tag = "RetroRoom"
 )]
-pub async fn list_retrospects(
+pub async fn transfer_ownership(
     State(state): State<AppState>,
     user: AuthUser,
     Path(retro_room_id): Path<i64>,
-) -> Result<Json<BaseResponse<Vec<RetrospectListItem>>>, AppError> {
+    Json(req): Json<TransferOwnershipRequest>,
+) -> Result<Json<BaseResponse<TransferOwnershipResponse>>, AppError> {
+    if retro_room_id < 1 || req.member_id < 1 {
+        return Err(AppError::BadRequest(
+            "retroRoomId와 memberId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
     let member_id = user.user_id()?;
 
-    let result = RetrospectService::list_retrospects(state, member_id, retro_room_id).await?;
+    let result =
+        RetrospectService::transfer_ownership(state, member_id, retro_room_id, req).await?;
 
     Ok(Json(BaseResponse::success_with_message(
         result,
-        "회고방 내 전체 회고 목록 조회를 성공했습니다.",
+        "Owner 위임에 성공하였습니다.",
     )))
 }
 
-// ============================================
-// Retrospect Handlers
-// ============================================
-
-/// 회고 생성 API (API-011)
+/// 회고방 멤버 강퇴 API
 ///
-/// 진행한 프로젝트에 대한 회고 세션을 생성합니다.
-/// 프로젝트 정보, 회고 방식, 참고 자료 등을 포함하며 생성된 회고의 고유 식별자를 반환합니다.
+/// Owner가 멤버를 회고방에서 내보냅니다. 강퇴된 멤버의 기존 답변과 댓글은 유지됩니다.
+/// 본인이나 다른 Owner는 강퇴할 수 없습니다.
 #[utoipa::path(
-    post,
-    path = "/api/v1/retrospects",
-    request_body = CreateRetrospectRequest,
-    security(
-        ("bearer_auth" = [])
+    delete,
+    path = "/api/v1/retro-rooms/{retro_room_id}/members/{member_id}",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID"),
+        ("member_id" = i64, Path, description = "강퇴할 멤버 ID")
     ),
+    security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "회고가 성공적으로 생성되었습니다.", body = SuccessCreateRetrospectResponse),
-        (status = 400, description = "잘못된 요청 (프로젝트 이름 길이 초과, 날짜 형식 오류, URL 형식 오류 등)", body = ErrorResponse),
+        (status = 200, description = "멤버 강퇴 성공", body = SuccessKickMemberResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 403, description = "회고방 접근 권한 없음", body = ErrorResponse),
-        (status = 404, description = "존재하지 않는 회고방", body = ErrorResponse),
-        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+        (status = 403, description = "권한 없음 (Owner 전용, 본인·Owner 대상 불가)", body = ErrorResponse),
+        (status = 404, description = "회고방 또는 대상 멤버 없음", body = ErrorResponse)
     ),
-    tag = "Retrospect"
+    tag = "RetroRoom"
 )]
-pub async fn create_retrospect(
-    user: AuthUser,
+pub async fn kick_member(
     State(state): State<AppState>,
-    Json(req): Json<CreateRetrospectRequest>,
-) -> Result<Json<BaseResponse<CreateRetrospectResponse>>, AppError> {
-    // 입력값 검증
-    req.validate()?;
+    user: AuthUser,
+    Path((retro_room_id, target_member_id)): Path<(i64, i64)>,
+) -> Result<Json<BaseResponse<KickMemberResponse>>, AppError> {
+    if retro_room_id < 1 || target_member_id < 1 {
+        return Err(AppError::BadRequest(
+            "retroRoomId와 memberId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
 
-    // 사용자 ID 추출
-    let user_id = user.user_id()?;
+    let member_id = user.user_id()?;
 
-    // 서비스 호출
-    let result = RetrospectService::create_retrospect(state, user_id, req).await?;
+    let result =
+        RetrospectService::kick_member(state, member_id, retro_room_id, target_member_id).await?;
 
     Ok(Json(BaseResponse::success_with_message(
         result,
-        "회고가 성공적으로 생성되었습니다.",
+        "멤버 강퇴에 성공하였습니다.",
     )))
 }
 
-/// 회고 참석자 등록 API (API-014)
+/// 회고방 멤버 mention 자동완성 목록 조회 API
 ///
-/// 진행 예정인 회고에 참석자로 등록합니다.
-/// JWT의 유저 정보를 기반으로 참석을 처리하며, 해당 회고가 속한 회고방의 멤버만 참석이 가능합니다.
+/// 닉네임 접두사(`q`)와 일치하는 회고방 멤버 목록을 반환합니다.
+/// `q`가 비어 있으면 회고방 내 최근 활동 멤버 순으로 반환합니다.
 #[utoipa::path(
-    post,
-    path = "/api/v1/retrospects/{retrospectId}/participants",
+    get,
+    path = "/api/v1/retro-rooms/{retro_room_id}/members/mention",
     params(
-        ("retrospectId" = i64, Path, description = "참여하고자 하는 회고의 고유 ID")
-    ),
-    security(
-        ("bearer_auth" = [])
+        ("retro_room_id" = i64, Path, description = "회고방 ID"),
+        MentionQueryParams
     ),
+    security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "회고 참석자로 성공적으로 등록되었습니다.", body = SuccessCreateParticipantResponse),
-        (status = 400, description = "잘못된 요청 (retrospectId 유효성 오류)", body = ErrorResponse),
+        (status = 200, description = "mention 후보 목록 조회 성공", body = SuccessMentionCandidatesResponse),
+        (status = 400, description = "잘못된 limit 값", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
-        (status = 409, description = "중복 참석", body = ErrorResponse),
-        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
     ),
-    tag = "Retrospect"
+    tag = "RetroRoom"
 )]
-pub async fn create_participant(
-    user: AuthUser,
+pub async fn list_mention_candidates(
     State(state): State<AppState>,
-    Path(retrospect_id): Path<i64>,
-) -> Result<Json<BaseResponse<CreateParticipantResponse>>, AppError> {
-    // retrospectId 검증 (1 이상의 양수)
-    if retrospect_id < 1 {
-        return Err(AppError::BadRequest(
-            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
-        ));
-    }
-
-    // 사용자 ID 추출
-    let user_id = user.user_id()?;
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+    Query(params): Query<MentionQueryParams>,
+) -> Result<Json<BaseResponse<Vec<MentionCandidateItem>>>, AppError> {
+    let member_id = user.user_id()?;
 
-    // 서비스 호출
-    let result = RetrospectService::create_participant(state, user_id, retrospect_id).await?;
+    let result =
+        RetrospectService::list_mention_candidates(state, member_id, retro_room_id, params).await?;
 
     Ok(Json(BaseResponse::success_with_message(
         result,
-        "회고 참석자로 성공적으로 등록되었습니다.",
+        "mention 후보 목록 조회를 성공했습니다.",
     )))
 }
 
-/// 회고 참고자료 목록 조회 API (API-018)
+/// 회고방 좋아요 급상승 답변 조회 API
 ///
-/// 특정 회고에 등록된 모든 참고자료(URL) 목록을 조회합니다.
-/// 회고 생성 시 등록했던 외부 링크들을 확인할 수 있습니다.
+/// 최근 `hours`시간 동안 좋아요가 많이 늘어난 답변을 트렌드 점수(최근 좋아요 수) 순으로 반환합니다.
 #[utoipa::path(
     get,
-    path = "/api/v1/retrospects/{retrospectId}/references",
+    path = "/api/v1/retro-rooms/{retro_room_id}/trending-responses",
     params(
-        ("retrospectId" = i64, Path, description = "조회를 원하는 회고의 고유 ID")
-    ),
-    security(
-        ("bearer_auth" = [])
+        ("retro_room_id" = i64, Path, description = "회고방 ID"),
+        TrendingResponsesQuery
     ),
+    security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "참고자료 목록을 성공적으로 조회했습니다.", body = SuccessReferencesListResponse),
-        (status = 400, description = "잘못된 요청 (retrospectId 유효성 오류)", body = ErrorResponse),
+        (status = 200, description = "좋아요 급상승 답변 조회 성공", body = SuccessTrendingResponsesResponse),
+        (status = 400, description = "잘못된 hours/limit 값", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
-        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
     ),
-    tag = "Retrospect"
+    tag = "RetroRoom"
 )]
-pub async fn list_references(
-    user: AuthUser,
+pub async fn list_trending_responses(
     State(state): State<AppState>,
-    Path(retrospect_id): Path<i64>,
-) -> Result<Json<BaseResponse<Vec<ReferenceItem>>>, AppError> {
-    // retrospectId 검증 (1 이상의 양수)
-    if retrospect_id < 1 {
-        return Err(AppError::BadRequest(
-            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
-        ));
-    }
-
-    // 사용자 ID 추출
-    let user_id = user.user_id()?;
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+    Query(params): Query<TrendingResponsesQuery>,
+) -> Result<Json<BaseResponse<TrendingResponsesResponse>>, AppError> {
+    let member_id = user.user_id()?;
 
-    // 서비스 호출
-    let result = RetrospectService::list_references(state, user_id, retrospect_id).await?;
+    let result =
+        RetrospectService::list_trending_responses(state, member_id, retro_room_id, params).await?;
 
     Ok(Json(BaseResponse::success_with_message(
         result,
-        "참고자료 목록을 성공적으로 조회했습니다.",
+        "좋아요 급상승 답변 조회를 성공했습니다.",
     )))
 }
 
-/// 회고 답변 임시 저장 API (API-016)
+/// 회고방 명예의 전당 조회 API
 ///
-/// 진행 중인 회고의 답변을 임시로 저장합니다.
-/// 기존에 저장된 내용이 있다면 전달받은 내용으로 덮어쓰기 처리됩니다.
+/// 방 내 모든 회고의 답변 중 좋아요를 가장 많이 받은 답변을 상위 `limit`개까지 반환합니다.
+/// 탈퇴한 작성자의 답변은 제외되며, 회고방을 떠난 작성자의 닉네임은 첫 글자만 노출됩니다.
 #[utoipa::path(
-    put,
-    path = "/api/v1/retrospects/{retrospectId}/drafts",
+    get,
+    path = "/api/v1/retro-rooms/{retro_room_id}/hall-of-fame",
     params(
-        ("retrospectId" = i64, Path, description = "임시 저장할 회고의 고유 식별자")
-    ),
-    request_body = DraftSaveRequest,
-    security(
-        ("bearer_auth" = [])
+        ("retro_room_id" = i64, Path, description = "회고방 ID"),
+        HallOfFameQuery
     ),
+    security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "임시 저장이 완료되었습니다.", body = SuccessDraftSaveResponse),
-        (status = 400, description = "잘못된 요청 (답변 길이 초과, 잘못된 질문 번호, 빈 배열, 중복 질문 번호)", body = ErrorResponse),
+        (status = 200, description = "명예의 전당 조회 성공", body = SuccessHallOfFameResponse),
+        (status = 400, description = "잘못된 limit 값", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 403, description = "작성 권한 없음", body = ErrorResponse),
-        (status = 404, description = "존재하지 않는 회고", body = ErrorResponse),
-        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
     ),
-    tag = "Retrospect"
+    tag = "RetroRoom"
 )]
-pub async fn save_draft(
-    user: AuthUser,
+pub async fn get_hall_of_fame(
     State(state): State<AppState>,
-    Path(retrospect_id): Path<i64>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+    Query(params): Query<HallOfFameQuery>,
+) -> Result<Json<BaseResponse<HallOfFameResponse>>, AppError> {
+    let member_id = user.user_id()?;
+
+    let result =
+        RetrospectService::get_hall_of_fame(state, member_id, retro_room_id, params).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "명예의 전당 조회를 성공했습니다.",
+    )))
+}
+
+/// 회고방 활동 확인 처리 API
+///
+/// 회고방의 마지막 확인 시각을 현재로 갱신해 목록의 미확인 활동 수(unreadCount)를 초기화합니다.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/retro-rooms/{retro_room_id}/seen",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "확인 처리 성공", body = SuccessEmptyResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn mark_retro_room_seen(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+) -> Result<Json<BaseResponse<()>>, AppError> {
+    let member_id = user.user_id()?;
+
+    RetrospectService::mark_retro_room_seen(state, member_id, retro_room_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        (),
+        "회고방 활동 확인 처리가 완료되었습니다.",
+    )))
+}
+
+/// 회고방 새 회고 알림 구독 설정 API
+///
+/// 회고방에 새 회고가 열리면 지정한 채널(EMAIL, PUSH)로 알림을 받도록 구독합니다.
+/// 이미 구독 중이면 채널만 변경되며, 회고를 직접 만든 경우에는 알림을 받지 않습니다.
+/// 두 채널 모두 앱 알림을 받고, EMAIL 채널은 메일도 함께 받습니다. 회원 알림 수신 설정을 끄면 어느 채널로도 받지 않습니다.
+#[utoipa::path(
+    put,
+    path = "/api/v1/retro-rooms/{retro_room_id}/subscribe",
+    request_body = UpdateRoomSubscriptionRequest,
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "구독 설정 성공", body = SuccessRoomSubscriptionResponse),
+        (status = 400, description = "잘못된 채널 값", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn subscribe_retro_room(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+    Json(req): Json<UpdateRoomSubscriptionRequest>,
+) -> Result<Json<BaseResponse<RoomSubscriptionResponse>>, AppError> {
+    let member_id = user.user_id()?;
+
+    let result =
+        RetrospectService::subscribe_retro_room(state, member_id, retro_room_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고방 구독 설정이 완료되었습니다.",
+    )))
+}
+
+/// 회고방 새 회고 알림 구독 해제 API
+#[utoipa::path(
+    delete,
+    path = "/api/v1/retro-rooms/{retro_room_id}/subscribe",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "구독 해제 성공", body = SuccessRoomSubscriptionResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn unsubscribe_retro_room(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+) -> Result<Json<BaseResponse<RoomSubscriptionResponse>>, AppError> {
+    let member_id = user.user_id()?;
+
+    let result = RetrospectService::unsubscribe_retro_room(state, member_id, retro_room_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고방 구독이 해제되었습니다.",
+    )))
+}
+
+/// 회고방 순서 변경 API (API-007)
+///
+/// 드래그 앤 드롭으로 변경된 회고방들의 정렬 순서를 서버에 일괄 저장합니다.
+#[utoipa::path(
+    patch,
+    path = "/api/v1/retro-rooms/order",
+    request_body = UpdateRetroRoomOrderRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "순서 변경 성공", body = SuccessEmptyResponse),
+        (status = 400, description = "잘못된 순서 데이터", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn update_retro_room_order(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(req): Json<UpdateRetroRoomOrderRequest>,
+) -> Result<Json<BaseResponse<()>>, AppError> {
+    req.validate()?;
+
+    let member_id = user.user_id()?;
+
+    RetrospectService::update_retro_room_order(state, member_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        (),
+        "회고방 순서가 성공적으로 변경되었습니다.",
+    )))
+}
+
+/// 회고방 이름 변경 API (API-008)
+///
+/// 기존 회고방의 이름을 새로운 이름으로 변경합니다. (Owner만 가능)
+#[utoipa::path(
+    patch,
+    path = "/api/v1/retro-rooms/{retro_room_id}/name",
+    request_body = UpdateRetroRoomNameRequest,
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "이름 변경 성공", body = SuccessUpdateRetroRoomNameResponse),
+        (status = 400, description = "이름 길이 초과", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse),
+        (status = 409, description = "이름 중복", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn update_retro_room_name(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+    Json(req): Json<UpdateRetroRoomNameRequest>,
+) -> Result<Json<BaseResponse<UpdateRetroRoomNameResponse>>, AppError> {
+    req.validate()?;
+
+    let member_id = user.user_id()?;
+
+    let result =
+        RetrospectService::update_retro_room_name(state, member_id, retro_room_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고방 이름 변경에 성공하였습니다.",
+    )))
+}
+
+/// 회고방 설정 변경 API
+///
+/// 진행 중 회고 수 제한 등 회고방 설정을 변경합니다. (Owner만 가능)
+#[utoipa::path(
+    patch,
+    path = "/api/v1/retro-rooms/{retro_room_id}/settings",
+    request_body = UpdateRetroRoomSettingsRequest,
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "설정 변경 성공", body = SuccessRetroRoomSettingsResponse),
+        (status = 400, description = "잘못된 제한값", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn update_retro_room_settings(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+    Json(req): Json<UpdateRetroRoomSettingsRequest>,
+) -> Result<Json<BaseResponse<RetroRoomSettingsResponse>>, AppError> {
+    req.validate()?;
+
+    let member_id = user.user_id()?;
+
+    let result =
+        RetrospectService::update_retro_room_settings(state, member_id, retro_room_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고방 설정 변경에 성공하였습니다.",
+    )))
+}
+
+/// 회고방 Slack 연동 설정 API
+///
+/// 회고 분석이 완료되면 팀 인사이트와 감정 순위를 Slack Block Kit 메시지로 전송할 Webhook을 설정합니다. (Owner만 가능)
+/// `webhookUrl`을 null로 보내면 연동을 해제합니다.
+#[utoipa::path(
+    put,
+    path = "/api/v1/retro-rooms/{retro_room_id}/integrations/slack",
+    request_body = UpdateSlackIntegrationRequest,
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Slack 연동 설정 성공", body = SuccessSlackIntegrationResponse),
+        (status = 400, description = "잘못된 Webhook URL", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn update_slack_integration(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+    Json(req): Json<UpdateSlackIntegrationRequest>,
+) -> Result<Json<BaseResponse<SlackIntegrationResponse>>, AppError> {
+    req.validate()?;
+
+    let member_id = user.user_id()?;
+
+    let result =
+        RetrospectService::update_slack_integration(state, member_id, retro_room_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "Slack 연동 설정에 성공하였습니다.",
+    )))
+}
+
+/// 회고방 데이터 백업 스냅샷 API
+///
+/// 회고방 설정, 멤버, 모든 회고의 참여자·답변·댓글을 JSON 스냅샷 파일로 생성해 내려받습니다. (Owner만 가능)
+/// 문서 최상위 `format`, `version` 필드로 스냅샷 포맷을 식별하며, 스냅샷 복원 API의 입력으로 사용합니다.
+/// 초대 코드, Slack Webhook URL 등 비밀 값은 포함하지 않으며, 멤버 이메일은 복원 시 회원 매칭용으로 포함합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retro-rooms/{retro_room_id}/snapshot",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "스냅샷 JSON 파일 다운로드", content(
+            (String = "application/json")
+        )),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn create_room_snapshot(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+) -> Result<Response, AppError> {
+    let member_id = user.user_id()?;
+
+    let snapshot = RetrospectService::create_room_snapshot(state, member_id, retro_room_id).await?;
+
+    let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
+    let filename = format!("retro_room_snapshot_{}_{}.json", retro_room_id, timestamp);
+
+    let headers = [
+        (
+            header::CONTENT_TYPE,
+            "application/json; charset=utf-8".to_string(),
+        ),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", filename),
+        ),
+        (
+            header::CACHE_CONTROL,
+            "no-cache, no-store, must-revalidate".to_string(),
+        ),
+    ];
+
+    Ok((headers, snapshot).into_response())
+}
+
+/// 회고방 참여 통계 CSV 내보내기 API
+///
+/// 회고별 참여자 수, 제출 수, 제출률, 평균 답변 길이를 행으로 하는 CSV 파일을 내려받습니다. (회고방 멤버만 가능)
+/// Excel 호환을 위해 UTF-8 BOM을 포함하며, 회고가 없으면 헤더만 있는 CSV를 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retro-rooms/{retro_room_id}/participation.csv",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "참여 통계 CSV 파일 다운로드", content(
+            (String = "text/csv")
+        )),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "회고방 멤버가 아님", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn export_participation_csv(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+) -> Result<Response, AppError> {
+    let member_id = user.user_id()?;
+
+    let csv = RetrospectService::export_participation_csv(state, member_id, retro_room_id).await?;
+
+    let headers = [
+        (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+        (
+            header::CONTENT_DISPOSITION,
+            format!(
+                "attachment; filename=\"retro_room_participation_{}.csv\"",
+                retro_room_id
+            ),
+        ),
+        (
+            header::CACHE_CONTROL,
+            "no-cache, no-store, must-revalidate".to_string(),
+        ),
+    ];
+
+    Ok((headers, csv).into_response())
+}
+
+/// 회고방 스냅샷 복원 API
+///
+/// 스냅샷 JSON으로 새 회고방을 만들고 회고, 참여자, 답변을 새 ID로 다시 생성합니다. 요청자가 새 회고방의 Owner가 됩니다.
+/// 스냅샷 멤버는 이메일로 기존 회원과 매칭해 작성자를 연결하며, 요청자와 일치하는 멤버가 없으면 스냅샷의 Owner를 요청자로 매칭합니다.
+/// 매칭된 회원을 회고방 멤버로 추가하지는 않습니다. 복원은 하나의 트랜잭션으로 처리되어 실패 시 아무것도 생성되지 않습니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retro-rooms/restore",
+    request_body = RestoreRetroRoomRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "회고방 복원 성공", body = SuccessRestoreRetroRoomResponse),
+        (status = 400, description = "잘못된 스냅샷 또는 지원하지 않는 스냅샷 버전", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 409, description = "이름 중복", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn restore_retro_room(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(req): Json<RestoreRetroRoomRequest>,
+) -> Result<Json<BaseResponse<RestoreRetroRoomResponse>>, AppError> {
+    req.validate()?;
+
+    let member_id = user.user_id()?;
+
+    let result = RetrospectService::restore_retro_room(state, member_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고방 복원에 성공하였습니다.",
+    )))
+}
+
+/// 회고방 초대 코드 재발급 API
+///
+/// 기존 초대 코드를 무효화하고 새 코드를 발급합니다. (Owner만 가능)
+/// 새 코드로 참여한 멤버는 `grantRole`에 지정한 역할(MEMBER, MODERATOR)로 등록되며, OWNER는 지정할 수 없습니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retro-rooms/{retro_room_id}/invite-code",
+    request_body = RegenerateInviteCodeRequest,
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "초대 코드 재발급 성공", body = SuccessInviteCodeResponse),
+        (status = 400, description = "잘못된 역할 지정", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn regenerate_invite_code(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+    Json(req): Json<RegenerateInviteCodeRequest>,
+) -> Result<Json<BaseResponse<InviteCodeResponse>>, AppError> {
+    let member_id = user.user_id()?;
+
+    let result =
+        RetrospectService::regenerate_invite_code(state, member_id, retro_room_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "초대 코드 재발급에 성공하였습니다.",
+    )))
+}
+
+/// 회고방 초대 코드 조회 API
+///
+/// 현재 초대 코드와 만료 예정 시각, 만료 여부를 조회합니다. (Moderator 이상 가능)
+/// 사용 가능한 코드가 없으면 `needsRegeneration`이 true이며, Owner가 초대 코드 재발급 API로 새 코드를 발급해야 합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retro-rooms/{retro_room_id}/invite",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "초대 코드 조회 성공", body = SuccessRoomInviteResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn get_room_invite(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+) -> Result<Json<BaseResponse<RoomInviteResponse>>, AppError> {
+    let member_id = user.user_id()?;
+
+    let result = RetrospectService::get_room_invite(state, member_id, retro_room_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "초대 코드 조회에 성공하였습니다.",
+    )))
+}
+
+/// 커스텀 회고 방식 생성 API
+///
+/// 기본 5가지 방식 외에 회고방 전용 질문 세트를 정의합니다. (Moderator 이상 가능)
+/// 회고 생성 시 `retrospectMethod: CUSTOM`과 `customMethodId`로 사용합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retro-rooms/{retro_room_id}/custom-methods",
+    request_body = CreateCustomMethodRequest,
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "커스텀 회고 방식 생성 성공", body = SuccessCustomMethodResponse),
+        (status = 400, description = "잘못된 이름 또는 질문", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn create_custom_method(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+    Json(req): Json<CreateCustomMethodRequest>,
+) -> Result<Json<BaseResponse<CustomMethodItem>>, AppError> {
+    req.validate()?;
+    let member_id = user.user_id()?;
+
+    let result =
+        RetrospectService::create_custom_method(state, member_id, retro_room_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "커스텀 회고 방식 생성에 성공하였습니다.",
+    )))
+}
+
+/// 커스텀 질문 사전 검증 API
+///
+/// 저장 없이 질문 개수·공백·길이를 검증해 질문별 실패 목록을 반환합니다.
+/// 커스텀 회고 방식 생성과 같은 검증 규칙을 사용하므로 제출 전 확인에 사용합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/validate-questions",
+    request_body = ValidateQuestionsRequest,
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "질문 검증 완료 (통과 여부는 valid 필드)", body = SuccessValidateQuestionsResponse),
+        (status = 400, description = "잘못된 요청 형식", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn validate_questions(
+    user: AuthUser,
+    Json(req): Json<ValidateQuestionsRequest>,
+) -> Result<Json<BaseResponse<ValidateQuestionsResponse>>, AppError> {
+    user.user_id()?;
+
+    let result = RetrospectService::validate_questions(req);
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "질문 검증을 완료했습니다.",
+    )))
+}
+
+/// 커스텀 회고 방식 목록 조회 API
+///
+/// 회고방에 정의된 커스텀 회고 방식을 생성순으로 조회합니다. (회고방 멤버만 가능)
+#[utoipa::path(
+    get,
+    path = "/api/v1/retro-rooms/{retro_room_id}/custom-methods",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "커스텀 회고 방식 목록 조회 성공", body = SuccessCustomMethodListResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn list_custom_methods(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+) -> Result<Json<BaseResponse<Vec<CustomMethodItem>>>, AppError> {
+    let member_id = user.user_id()?;
+
+    let result = RetrospectService::list_custom_methods(state, member_id, retro_room_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "커스텀 회고 방식 목록 조회에 성공하였습니다.",
+    )))
+}
+
+/// 회고방 참여 기록 조회 API
+///
+/// 회고방 멤버의 참여/나가기/내보내기 기록을 최신순으로 조회합니다. (Moderator 이상 가능)
+#[utoipa::path(
+    get,
+    path = "/api/v1/retro-rooms/{retro_room_id}/membership-history",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "참여 기록 조회 성공", body = SuccessMembershipHistoryResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn list_membership_history(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+) -> Result<Json<BaseResponse<MembershipHistoryResponse>>, AppError> {
+    let member_id = user.user_id()?;
+
+    let result =
+        RetrospectService::list_membership_history(state, member_id, retro_room_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고방 참여 기록 조회에 성공하였습니다.",
+    )))
+}
+
+/// 회고방 삭제 API (API-009)
+///
+/// 회고방을 완전히 삭제합니다. (Owner만 가능)
+#[utoipa::path(
+    delete,
+    path = "/api/v1/retro-rooms/{retro_room_id}",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "삭제 성공", body = SuccessDeleteRetroRoomResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn delete_retro_room(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+) -> Result<Json<BaseResponse<DeleteRetroRoomResponse>>, AppError> {
+    let member_id = user.user_id()?;
+
+    let result = RetrospectService::delete_retro_room(state, member_id, retro_room_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고방 삭제에 성공하였습니다.",
+    )))
+}
+
+/// 회고방 나가기 API
+///
+/// Owner가 아닌 멤버가 회고방을 나갑니다.
+/// - Owner는 다른 멤버에게 방장 권한을 위임한 뒤 나갈 수 있습니다.
+/// - 참여했던 회고의 답변 기록은 유지됩니다.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/retro-rooms/{retro_room_id}/leave",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "회고방 나가기 성공", body = SuccessLeaveRetroRoomResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "멤버가 아니거나 Owner인 경우", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn leave_retro_room(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+) -> Result<Json<BaseResponse<LeaveRetroRoomResponse>>, AppError> {
+    if retro_room_id < 1 {
+        return Err(AppError::BadRequest(
+            "retroRoomId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let member_id = user.user_id()?;
+
+    let result = RetrospectService::leave_retro_room(state, member_id, retro_room_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고방 나가기에 성공하였습니다.",
+    )))
+}
+
+/// 회고방 내 회고 목록 조회 API (API-010)
+///
+/// 특정 회고방에 속한 모든 회고 목록을 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retro-rooms/{retro_room_id}/retrospects",
+    params(
+        ("retro_room_id" = i64, Path, description = "회고방 ID")
+    ),
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "회고 목록 조회 성공", body = SuccessRetrospectListResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고방 없음", body = ErrorResponse)
+    ),
+    tag = "RetroRoom"
+)]
+pub async fn list_retrospects(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Path(retro_room_id): Path<i64>,
+) -> Result<Json<BaseResponse<Vec<RetrospectListItem>>>, AppError> {
+    let member_id = user.user_id()?;
+
+    let result = RetrospectService::list_retrospects(state, member_id, retro_room_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고방 내 전체 회고 목록 조회를 성공했습니다.",
+    )))
+}
+
+// ============================================
+// Retrospect Handlers
+// ============================================
+
+/// 회고 생성 API (API-011)
+///
+/// 진행한 프로젝트에 대한 회고 세션을 생성합니다.
+/// 프로젝트 정보, 회고 방식, 참고 자료 등을 포함하며 생성된 회고의 고유 식별자를 반환합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects",
+    request_body = CreateRetrospectRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "회고가 성공적으로 생성되었습니다.", body = SuccessCreateRetrospectResponse),
+        (status = 400, description = "잘못된 요청 (프로젝트 이름 길이 초과, 날짜 형식 오류, URL 형식 오류 등)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "회고방 접근 권한 없음", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고방", body = ErrorResponse),
+        (status = 409, description = "진행 중 회고 수 제한 초과 또는 회고 제목 중복", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn create_retrospect(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Json(req): Json<CreateRetrospectRequest>,
+) -> Result<Json<BaseResponse<CreateRetrospectResponse>>, AppError> {
+    // 입력값 검증
+    req.validate()?;
+
+    // 사용자 ID 추출
+    let user_id = user.user_id()?;
+
+    // 서비스 호출
+    let result = RetrospectService::create_retrospect(state, user_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고가 성공적으로 생성되었습니다.",
+    )))
+}
+
+/// 회고 일괄 생성 API
+///
+/// 같은 회고를 여러 회고방에 한 번에 생성합니다. 요청자가 멤버인 회고방만 생성하며,
+/// `atomic`이 true면 생성할 수 없는 방이 하나라도 있을 때 아무것도 만들지 않고 해당 에러를 반환합니다.
+/// false(기본값)면 가능한 방만 생성하고 건너뛴 방과 사유를 `skipped`로 반환합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/batch",
+    request_body = BatchCreateRetrospectRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "회고 일괄 생성 성공", body = SuccessBatchCreateRetrospectResponse),
+        (status = 400, description = "잘못된 요청 (날짜 형식 오류, 커스텀 방식 지정 등)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "회고방 접근 권한 없음 (atomic)", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고방 (atomic)", body = ErrorResponse),
+        (status = 409, description = "진행 중 회고 수 제한 초과 또는 회고 제목 중복 (atomic)", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn batch_create_retrospects(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Json(req): Json<BatchCreateRetrospectRequest>,
+) -> Result<Json<BaseResponse<BatchCreateRetrospectResponse>>, AppError> {
+    req.validate()?;
+
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::batch_create_retrospects(state, user_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고 일괄 생성을 완료했습니다.",
+    )))
+}
+
+/// 회고 참석자 등록 API (API-014)
+///
+/// 진행 예정인 회고에 참석자로 등록합니다.
+/// JWT의 유저 정보를 기반으로 참석을 처리하며, 해당 회고가 속한 회고방의 멤버만 참석이 가능합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/participants",
+    params(
+        ("retrospectId" = i64, Path, description = "참여하고자 하는 회고의 고유 ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "회고 참석자로 성공적으로 등록되었습니다.", body = SuccessCreateParticipantResponse),
+        (status = 400, description = "잘못된 요청 (retrospectId 유효성 오류)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
+        (status = 409, description = "중복 참석", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn create_participant(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Json<BaseResponse<CreateParticipantResponse>>, AppError> {
+    // retrospectId 검증 (1 이상의 양수)
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    // 사용자 ID 추출
+    let user_id = user.user_id()?;
+
+    // 서비스 호출
+    let result = RetrospectService::create_participant(state, user_id, retrospect_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고 참석자로 성공적으로 등록되었습니다.",
+    )))
+}
+
+/// 회고 제출 독려 API
+///
+/// 아직 답변을 제출하지 않은 참여자에게 제출 독려 알림을 발송합니다.
+/// 회고방 Owner만 발송할 수 있으며, 알림을 끈 멤버는 제외되고 같은 회고에는 60분에 한 번만 발송할 수 있습니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/remind",
+    params(
+        ("retrospectId" = i64, Path, description = "독려할 회고 ID")
+    ),
+    request_body = RemindRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "제출 독려 발송 성공", body = SuccessRemindResponse),
+        (status = 400, description = "잘못된 요청 (사용자 정의 메시지 길이 초과)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "회고방 Owner 권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고 없음", body = ErrorResponse),
+        (status = 429, description = "발송 간격 제한 초과", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn remind_participants(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+    Json(req): Json<RemindRequest>,
+) -> Result<Json<BaseResponse<RemindResponse>>, AppError> {
+    req.validate()?;
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::remind_participants(state, user_id, retrospect_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "제출 독려 알림이 발송되었습니다.",
+    )))
+}
+
+/// 회고 체크인 QR 발급 API
+///
+/// 오프라인 회고 현장에서 띄울 시간 제한 일회성 체크인 토큰을 발급합니다.
+/// 회고방 Moderator 이상만 발급할 수 있으며, 시작 전인 회고에만 발급됩니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retrospects/{retrospectId}/check-in/qr",
+    params(
+        ("retrospectId" = i64, Path, description = "체크인할 회고 ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "체크인 QR 발급 성공", body = SuccessCheckInQrResponse),
+        (status = 400, description = "이미 시작된 회고", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "Moderator 이상 권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고 없음", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn issue_check_in_qr(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Json<BaseResponse<CheckInQrResponse>>, AppError> {
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::issue_check_in_qr(state, user_id, retrospect_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "체크인 QR이 발급되었습니다.",
+    )))
+}
+
+/// 회고 QR 체크인 API
+///
+/// QR에서 읽은 일회성 토큰을 검증한 뒤 회고 참석자로 등록합니다.
+/// 토큰은 한 번만 사용할 수 있으며, 참석 등록 조건은 참석자 등록 API(API-014)와 동일합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/check-in",
+    params(
+        ("retrospectId" = i64, Path, description = "체크인할 회고 ID")
+    ),
+    request_body = CheckInRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "체크인 및 참석 등록 성공", body = SuccessCreateParticipantResponse),
+        (status = 400, description = "유효하지 않거나 만료·사용된 토큰, 이미 시작된 회고", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
+        (status = 409, description = "중복 참석", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn check_in(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+    Json(req): Json<CheckInRequest>,
+) -> Result<Json<BaseResponse<CreateParticipantResponse>>, AppError> {
+    req.validate()?;
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::check_in(state, user_id, retrospect_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "체크인이 완료되어 회고 참석자로 등록되었습니다.",
+    )))
+}
+
+/// 참고자료 링크 미리보기 API
+///
+/// URL의 OpenGraph 메타데이터(title, description, image)를 서버가 가져와 반환합니다.
+/// 사설 IP/localhost 등 내부망 주소는 거부하며, 크롤링에 실패하면 URL 호스트명을 기본 제목으로 반환합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/references/preview",
+    request_body = ReferencePreviewRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "링크 미리보기를 성공적으로 조회했습니다.", body = SuccessReferencePreviewResponse),
+        (status = 400, description = "잘못된 URL 또는 내부망 주소", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn preview_reference(
+    user: AuthUser,
+    Json(req): Json<ReferencePreviewRequest>,
+) -> Result<Json<BaseResponse<ReferencePreviewResponse>>, AppError> {
+    req.validate()?;
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::preview_reference(user_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "링크 미리보기를 성공적으로 조회했습니다.",
+    )))
+}
+
+/// 회고 참고자료 목록 조회 API (API-018)
+///
+/// 특정 회고에 등록된 모든 참고자료(URL) 목록을 조회합니다.
+/// 회고 생성 시 등록했던 외부 링크들을 확인할 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retrospects/{retrospectId}/references",
+    params(
+        ("retrospectId" = i64, Path, description = "조회를 원하는 회고의 고유 ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "참고자료 목록을 성공적으로 조회했습니다.", body = SuccessReferencesListResponse),
+        (status = 400, description = "잘못된 요청 (retrospectId 유효성 오류)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn list_references(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Json<BaseResponse<Vec<ReferenceItem>>>, AppError> {
+    // retrospectId 검증 (1 이상의 양수)
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    // 사용자 ID 추출
+    let user_id = user.user_id()?;
+
+    // 서비스 호출
+    let result = RetrospectService::list_references(state, user_id, retrospect_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "참고자료 목록을 성공적으로 조회했습니다.",
+    )))
+}
+
+/// 회고 답변 임시 저장 API (API-016)
+///
+/// 진행 중인 회고의 답변을 임시로 저장합니다.
+/// 기존에 저장된 내용이 있다면 전달받은 내용으로 덮어쓰기 처리됩니다.
+/// 질문 단위로 저장되므로 여러 기기에서 서로 다른 질문을 편집해도 모두 반영되며,
+/// 같은 질문을 동시에 편집한 경우에만 409(RETRO4093)로 거부됩니다.
+#[utoipa::path(
+    put,
+    path = "/api/v1/retrospects/{retrospectId}/drafts",
+    params(
+        ("retrospectId" = i64, Path, description = "임시 저장할 회고의 고유 식별자")
+    ),
+    request_body = DraftSaveRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "임시 저장이 완료되었습니다.", body = SuccessDraftSaveResponse),
+        (status = 400, description = "잘못된 요청 (답변 길이 초과, 잘못된 질문 번호, 빈 배열, 중복 질문 번호)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "작성 권한 없음", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고", body = ErrorResponse),
+        (status = 409, description = "다른 기기에서 편집한 질문과 충돌", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn save_draft(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
     Json(req): Json<DraftSaveRequest>,
 ) -> Result<Json<BaseResponse<DraftSaveResponse>>, AppError> {
     // retrospectId 검증 (1 이상의 양수)
@@ -475,33 +1658,1058 @@ pub async fn save_draft(
         ));
     }
 
-    // 사용자 ID 추출
+    // 사용자 ID 추출
+    let user_id = user.user_id()?;
+
+    // 서비스 호출
+    let result = RetrospectService::save_draft(state, user_id, retrospect_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "임시 저장이 완료되었습니다.",
+    )))
+}
+
+/// 회고 답변 작성 세션 시작 API
+///
+/// 새 작성 세션 ID를 발급합니다. 다른 기기에서 새 세션이 시작되면
+/// 새 세션이 저장한 질문을 기존 세션 ID로 덮어쓰는 임시 저장은 409(RETRO4093)로 거부됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/drafts/session",
+    params(
+        ("retrospectId" = i64, Path, description = "작성할 회고의 고유 식별자")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "작성 세션이 시작되었습니다.", body = SuccessEditSessionResponse),
+        (status = 400, description = "잘못된 Path Parameter", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "작성 권한 없음 또는 이미 제출된 회고", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn start_edit_session(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Json<BaseResponse<EditSessionResponse>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::start_edit_session(state, user_id, retrospect_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "작성 세션이 시작되었습니다.",
+    )))
+}
+
+/// 회고 답변 작성 시작 API
+///
+/// 답변 작성을 시작한 시각을 기록합니다. 제출 시 이 시각부터의 소요 시간이 저장되며,
+/// 호출하지 않고 제출하면 소요 시간은 기록되지 않습니다. 재호출해도 최초 시각을 유지합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/start-writing",
+    params(
+        ("retrospectId" = i64, Path, description = "작성할 회고의 고유 식별자")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "작성 시작이 기록되었습니다.", body = SuccessStartWritingResponse),
+        (status = 400, description = "잘못된 Path Parameter", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "작성 권한 없음 또는 이미 제출된 회고", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn start_writing(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Json<BaseResponse<StartWritingResponse>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::start_writing(state, user_id, retrospect_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "작성 시작이 기록되었습니다.",
+    )))
+}
+
+/// 회고 답변 작성 하트비트 API
+///
+/// 작성 화면이 열려 있는 동안 주기적으로 호출해 작성 중 상태를 유지합니다.
+/// 마지막 하트비트 후 activeTimeoutSecs가 지나면 작성 비활성으로 처리되며 (회고 상세의 writingMemberIds),
+/// 최소 간격(10초)보다 짧게 호출하면 429(RETRO4292)를 반환합니다.
+/// 회고방 멤버가 아니면 회고 존재 여부를 노출하지 않도록 404로 응답합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/heartbeat",
+    params(
+        ("retrospectId" = i64, Path, description = "작성 중인 회고의 고유 식별자")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "하트비트가 반영되었습니다.", body = SuccessHeartbeatResponse),
+        (status = 400, description = "잘못된 Path Parameter", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "작성 권한 없음 또는 이미 제출된 회고", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 회고방 멤버가 아님", body = ErrorResponse),
+        (status = 429, description = "하트비트 최소 간격 제한", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn heartbeat(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Json<BaseResponse<HeartbeatResponse>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::heartbeat(state, user_id, retrospect_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "하트비트가 반영되었습니다.",
+    )))
+}
+
+/// 회고 상세 정보 조회 API (API-012)
+///
+/// 특정 회고 세션의 상세 정보(제목, 일시, 유형, 참여 멤버, 질문 리스트 및 전체 통계)를 조회합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retrospects/{retrospectId}",
+    params(
+        ("retrospectId" = i64, Path, description = "조회할 회고의 고유 식별자")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "회고 상세 정보 조회를 성공했습니다.", body = SuccessRetrospectDetailResponse),
+        (status = 400, description = "잘못된 Path Parameter", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "접근 권한 없음", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn get_retrospect_detail(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Json<BaseResponse<RetrospectDetailResponse>>, AppError> {
+    // retrospectId 검증 (1 이상의 양수)
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    // 사용자 ID 추출
+    let user_id = user.user_id()?;
+
+    // 서비스 호출
+    let result = RetrospectService::get_retrospect_detail(state, user_id, retrospect_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고 상세 정보 조회를 성공했습니다.",
+    )))
+}
+
+/// 질문별 답변 작성률 조회 API
+///
+/// 질문별로 내용이 있는 답변 수와 참여자 대비 작성률을 집계해 작성률이 낮은 질문부터 반환합니다.
+/// 회고방 멤버만 조회할 수 있으며, 참여자가 없으면 빈 목록을 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retrospects/{retrospectId}/question-coverage",
+    params(
+        ("retrospectId" = i64, Path, description = "조회할 회고의 고유 식별자")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "질문별 답변 작성률 조회를 성공했습니다.", body = SuccessQuestionCoverageResponse),
+        (status = 400, description = "잘못된 Path Parameter", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn get_question_coverage(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Json<BaseResponse<QuestionCoverageResponse>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::get_question_coverage(state, user_id, retrospect_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "질문별 답변 작성률 조회를 성공했습니다.",
+    )))
+}
+
+/// 회고 최종 제출 API (API-017)
+///
+/// 작성한 모든 답변(총 5개)을 최종 제출합니다.
+/// 각 답변은 최대 1,000자까지 입력 가능하며, 제출 완료 시 회고 상태가 SUBMITTED로 변경됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/submit",
+    params(
+        ("retrospectId" = i64, Path, description = "제출할 회고의 고유 식별자")
+    ),
+    request_body = SubmitRetrospectRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "회고 제출이 성공적으로 완료되었습니다.", body = SuccessSubmitRetrospectResponse),
+        (status = 400, description = "잘못된 요청 (답변 누락, 답변 길이 초과, 공백만 입력 등)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "이미 제출 완료된 회고", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn submit_retrospect(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+    Json(req): Json<SubmitRetrospectRequest>,
+) -> Result<Json<BaseResponse<SubmitRetrospectResponse>>, AppError> {
+    // retrospectId 검증 (1 이상의 양수)
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    // 사용자 ID 추출
+    let user_id = user.user_id()?;
+
+    // 서비스 호출
+    let result = RetrospectService::submit_retrospect(state, user_id, retrospect_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고 제출이 성공적으로 완료되었습니다.",
+    )))
+}
+
+/// 회고 제출 미리보기 API
+///
+/// 제출할 답변을 저장하지 않고 검증만 수행해 문제점(누락·공백·길이 초과)을 모두 모아 질문별 요약과 함께 반환합니다.
+/// 확인 후 최종 제출(API-017)로 확정합니다. 작성 중인 참석자 본인만 호출할 수 있습니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/submit/preview",
+    params(
+        ("retrospectId" = i64, Path, description = "제출할 회고의 고유 식별자")
+    ),
+    request_body = SubmitRetrospectRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "회고 제출 미리보기를 성공했습니다.", body = SuccessSubmitPreviewResponse),
+        (status = 400, description = "잘못된 요청 (질문 미확정 등)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "이미 제출 완료된 회고", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 참석자가 아님", body = ErrorResponse),
+        (status = 409, description = "잠긴 회고", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn preview_submit(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+    Json(req): Json<SubmitRetrospectRequest>,
+) -> Result<Json<BaseResponse<SubmitPreviewResponse>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::preview_submit(state, user_id, retrospect_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고 제출 미리보기를 성공했습니다.",
+    )))
+}
+
+/// 제출 완료 답변 재수정 API
+///
+/// 이미 제출한 회고의 답변을 회고 시작 시각 전까지 다시 수정합니다.
+/// 제출과 동일한 답변 검증 규칙이 적용되며, 수정 시 제출 시각이 현재 시각으로 갱신됩니다.
+#[utoipa::path(
+    put,
+    path = "/api/v1/retrospects/{retrospectId}/answers",
+    params(
+        ("retrospectId" = i64, Path, description = "답변을 수정할 회고의 고유 식별자")
+    ),
+    request_body = UpdateSubmittedAnswersRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "제출한 답변 수정이 완료되었습니다.", body = SuccessUpdateSubmittedAnswersResponse),
+        (status = 400, description = "잘못된 요청 (답변 누락·길이 초과, 미제출 회고, 이미 시작된 회고 등)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고", body = ErrorResponse),
+        (status = 409, description = "분석이 완료된 회고", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn update_submitted_answers(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+    Json(req): Json<UpdateSubmittedAnswersRequest>,
+) -> Result<Json<BaseResponse<UpdateSubmittedAnswersResponse>>, AppError> {
+    // retrospectId 검증 (1 이상의 양수)
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    // 사용자 ID 추출
+    let user_id = user.user_id()?;
+
+    // 서비스 호출
+    let result =
+        RetrospectService::update_submitted_answers(state, user_id, retrospect_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "제출한 답변 수정이 완료되었습니다.",
+    )))
+}
+
+/// 보관함 조회 API (API-019)
+///
+/// 완료된 회고 목록을 연도별로 그룹화하여 조회합니다.
+/// 기간 필터를 통해 특정 기간의 회고만 조회할 수 있습니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retrospects/storage",
+    params(StorageQueryParams),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "보관함 조회를 성공했습니다.", body = SuccessStorageResponse),
+        (status = 400, description = "유효하지 않은 기간 필터", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn get_storage(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Query(params): Query<StorageQueryParams>,
+) -> Result<Json<BaseResponse<StorageResponse>>, AppError> {
+    // 사용자 ID 추출
+    let user_id = user.user_id()?;
+
+    // 서비스 호출
+    let result = RetrospectService::get_storage(state, user_id, params).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "보관함 조회를 성공했습니다.",
+    )))
+}
+
+/// 회고 분석 API (API-022)
+///
+/// 특정 회고 세션에 쌓인 모든 회고방 멤버의 답변을 종합 분석하여 AI 인사이트, 감정 통계, 맞춤형 미션을 생성합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/analysis",
+    params(
+        ("retrospectId" = i64, Path, description = "분석할 회고 ID")
+    ),
+    request_body = (),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "회고 분석 성공", body = SuccessAnalysisResponse),
+        (status = 400, description = "잘못된 Path Parameter", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "월간 한도 초과 또는 접근 권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고 없음", body = ErrorResponse),
+        (status = 409, description = "이미 분석 완료된 회고", body = ErrorResponse),
+        (status = 422, description = "분석 데이터 부족", body = ErrorResponse),
+        (status = 500, description = "AI 분석 실패", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn analyze_retrospective_handler(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Json<BaseResponse<AnalysisResponse>>, AppError> {
+    // retrospectId 검증 (1 이상의 양수)
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    // 서비스 호출
+    let result = RetrospectService::analyze_retrospective(state, user_id, retrospect_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고 분석이 성공적으로 완료되었습니다.",
+    )))
+}
+
+/// 회고 분석 진행 상태 스트리밍 API (SSE)
+///
+/// 분석 단계(COLLECTING → CALLING_AI → SAVING)별 진행 이벤트를 `progress` 이벤트로 스트리밍하고,
+/// 완료(COMPLETED, 최종 결과 포함) 또는 실패(FAILED) 이벤트를 마지막으로 보낸 뒤 연결을 종료합니다.
+/// 분석이 진행 중이 아니면 현재 상태(IDLE/PENDING_RETRY/COMPLETED/FAILED)만 보내고 종료합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retrospects/{retrospectId}/analysis/stream",
+    params(
+        ("retrospectId" = i64, Path, description = "회고 고유 식별자")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "분석 진행 이벤트 스트림 (text/event-stream)", body = AnalysisProgressEvent, content_type = "text/event-stream"),
+        (status = 400, description = "잘못된 Path Parameter", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn stream_analysis_progress(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Sse<impl Stream<Item = Result<Event, Infallible>>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let (current, receiver) =
+        RetrospectService::subscribe_analysis_progress(&state, user_id, retrospect_id).await?;
+
+    Ok(Sse::new(analysis_progress_stream(current, receiver)).keep_alive(KeepAlive::default()))
+}
+
+/// 회고 분석 버전 목록 조회 API
+///
+/// 분석할 때마다 누적된 분석 결과를 최신 버전부터 반환합니다. 회고당 최근 10개 버전만 보관합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retrospects/{retrospectId}/analysis/versions",
+    params(
+        ("retrospectId" = i64, Path, description = "회고 고유 식별자")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "분석 버전 목록 조회 성공", body = SuccessAnalysisVersionsResponse),
+        (status = 400, description = "잘못된 Path Parameter 또는 분석이 완료되지 않은 회고", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn list_analysis_versions(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Json<BaseResponse<AnalysisVersionsResponse>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::list_analysis_versions(state, user_id, retrospect_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "분석 버전 목록 조회를 성공했습니다.",
+    )))
+}
+
+/// 회고 분석 버전 비교 API
+///
+/// 두 분석 버전의 팀 인사이트와 감정 순위를 나란히 반환합니다.
+/// `target` 미지정 시 최신 버전, `base` 미지정 시 비교 대상 바로 이전 버전과 비교합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retrospects/{retrospectId}/analysis/versions/compare",
+    params(
+        ("retrospectId" = i64, Path, description = "회고 고유 식별자"),
+        AnalysisVersionCompareQuery
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "분석 버전 비교 성공", body = SuccessAnalysisVersionCompareResponse),
+        (status = 400, description = "잘못된 파라미터, 분석이 완료되지 않은 회고 또는 비교할 이전 버전 없음", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고·접근 권한 없음 또는 보관되지 않은 버전", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn compare_analysis_versions(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+    Query(query): Query<AnalysisVersionCompareQuery>,
+) -> Result<Json<BaseResponse<AnalysisVersionCompareResponse>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result =
+        RetrospectService::compare_analysis_versions(state, user_id, retrospect_id, query).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "분석 버전 비교를 성공했습니다.",
+    )))
+}
+
+/// 회고 답변 카테고리 자동 분류 API
+///
+/// AI가 회고의 각 답변을 Keep/Problem/Try로 분류한 결과를 반환합니다.
+/// `save=true`이면 분류 결과를 답변에 저장하며(운영진 이상), AI 분류에 실패하면 모든 답변을 미분류로 반환합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/auto-categorize",
+    params(
+        ("retrospectId" = i64, Path, description = "분류할 회고 ID"),
+        AutoCategorizeQuery
+    ),
+    request_body = (),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "답변 자동 분류 성공", body = SuccessAutoCategorizeResponse),
+        (status = 400, description = "잘못된 Path Parameter", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "접근 권한 없음 또는 월간 자동 분류 한도 초과", body = ErrorResponse),
+        (status = 404, description = "회고 없음", body = ErrorResponse),
+        (status = 422, description = "분류할 답변 없음", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn auto_categorize_responses(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+    Query(params): Query<AutoCategorizeQuery>,
+) -> Result<Json<BaseResponse<AutoCategorizeResponse>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::auto_categorize_responses(
+        state,
+        user_id,
+        retrospect_id,
+        params.save.unwrap_or(false),
+    )
+    .await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "답변 자동 분류를 완료했습니다.",
+    )))
+}
+
+/// 회고 분석 결과 공유 링크 발급 API
+///
+/// 분석이 완료된 회고의 결과를 회고방 외부에 공유할 수 있는 읽기 전용 토큰을 발급합니다.
+/// 회고방 Owner만 발급할 수 있으며, 닉네임 마스킹 여부와 만료 기간을 지정할 수 있습니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/analysis/share",
+    params(
+        ("retrospectId" = i64, Path, description = "공유할 회고 ID")
+    ),
+    request_body = CreateAnalysisShareRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "공유 링크 발급 성공", body = SuccessAnalysisShareResponse),
+        (status = 400, description = "잘못된 요청 또는 분석 미완료 회고", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "Owner 권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고 없음", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn create_analysis_share(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+    Json(req): Json<CreateAnalysisShareRequest>,
+) -> Result<Json<BaseResponse<AnalysisShareResponse>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    req.validate()?;
+
+    let user_id = user.user_id()?;
+
+    let result =
+        RetrospectService::create_analysis_share(state, user_id, retrospect_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "공유 링크가 발급되었습니다.",
+    )))
+}
+
+/// 회고 분석 결과 공유 링크 폐기 API
+///
+/// 발급된 공유 토큰을 폐기합니다. 폐기된 토큰으로는 더 이상 조회할 수 없습니다.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/retrospects/{retrospectId}/analysis/share/{token}",
+    params(
+        ("retrospectId" = i64, Path, description = "회고 ID"),
+        ("token" = String, Path, description = "폐기할 공유 토큰")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "공유 링크 폐기 성공", body = SuccessEmptyResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "Owner 권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고 또는 공유 링크 없음", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn revoke_analysis_share(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path((retrospect_id, token)): Path<(i64, String)>,
+) -> Result<Json<BaseResponse<()>>, AppError> {
+    let user_id = user.user_id()?;
+
+    RetrospectService::revoke_analysis_share(state, user_id, retrospect_id, token).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        (),
+        "공유 링크가 폐기되었습니다.",
+    )))
+}
+
+/// 공유 링크로 회고 분석 결과 조회 API
+///
+/// 인증 없이 공유 토큰으로 분석 결과를 조회합니다.
+/// 사용자 ID는 노출하지 않으며, 발급 시 마스킹 옵션에 따라 닉네임을 가립니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/shared/analysis/{token}",
+    params(
+        ("token" = String, Path, description = "공유 토큰")
+    ),
+    responses(
+        (status = 200, description = "공유 분석 결과 조회 성공", body = SuccessSharedAnalysisResponse),
+        (status = 404, description = "존재하지 않거나 폐기된 공유 링크", body = ErrorResponse),
+        (status = 410, description = "만료된 공유 링크", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn get_shared_analysis(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<BaseResponse<SharedAnalysisResponse>>, AppError> {
+    let result = RetrospectService::get_shared_analysis(state, token).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "공유된 회고 분석 결과 조회를 성공했습니다.",
+    )))
+}
+
+/// 회고 전체 읽기 전용 공유 링크 발급 API
+///
+/// 회고의 질문/답변/참여자를 회고방 외부에 읽기 전용으로 공유할 수 있는 토큰을 발급합니다.
+/// 회고방 Owner만 발급할 수 있으며, 닉네임 마스킹 여부와 만료 기간을 지정할 수 있습니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/share",
+    params(
+        ("retrospectId" = i64, Path, description = "공유할 회고 ID")
+    ),
+    request_body = CreateAnalysisShareRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "공유 링크 발급 성공", body = SuccessAnalysisShareResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "Owner 권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고 없음", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn create_retrospect_share(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+    Json(req): Json<CreateAnalysisShareRequest>,
+) -> Result<Json<BaseResponse<AnalysisShareResponse>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    req.validate()?;
+
+    let user_id = user.user_id()?;
+
+    let result =
+        RetrospectService::create_retrospect_share(state, user_id, retrospect_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "공유 링크가 발급되었습니다.",
+    )))
+}
+
+/// 회고 전체 읽기 전용 공유 링크 폐기 API
+///
+/// 발급된 회고 공유 토큰을 폐기합니다. 폐기된 토큰으로는 더 이상 조회할 수 없습니다.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/retrospects/{retrospectId}/share/{token}",
+    params(
+        ("retrospectId" = i64, Path, description = "회고 ID"),
+        ("token" = String, Path, description = "폐기할 공유 토큰")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "공유 링크 폐기 성공", body = SuccessEmptyResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "Owner 권한 없음", body = ErrorResponse),
+        (status = 404, description = "회고 또는 공유 링크 없음", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn revoke_retrospect_share(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path((retrospect_id, token)): Path<(i64, String)>,
+) -> Result<Json<BaseResponse<()>>, AppError> {
+    let user_id = user.user_id()?;
+
+    RetrospectService::revoke_retrospect_share(state, user_id, retrospect_id, token).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        (),
+        "공유 링크가 폐기되었습니다.",
+    )))
+}
+
+/// 공유 링크로 회고 전체 조회 API
+///
+/// 인증 없이 공유 토큰으로 회고의 질문/답변/참여자를 읽기 전용으로 조회합니다.
+/// 제출 완료된 답변만 노출하며, 좋아요/댓글 정보와 사용자 ID는 포함하지 않습니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/shared/retrospects/{token}",
+    params(
+        ("token" = String, Path, description = "공유 토큰")
+    ),
+    responses(
+        (status = 200, description = "공유 회고 조회 성공", body = SuccessSharedRetrospectResponse),
+        (status = 404, description = "존재하지 않거나 폐기된 공유 링크", body = ErrorResponse),
+        (status = 410, description = "만료된 공유 링크", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn get_shared_retrospect(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Json<BaseResponse<SharedRetrospectResponse>>, AppError> {
+    let result = RetrospectService::get_shared_retrospect(state, token).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "공유된 회고 조회를 성공했습니다.",
+    )))
+}
+
+/// 회고 검색 API (API-023)
+///
+/// 사용자가 참여하는 모든 회고방의 회고를 프로젝트명/회고명 기준으로 검색합니다.
+/// 결과는 회고 날짜 내림차순(최신순), 동일 날짜인 경우 회고 시간 내림차순으로 정렬됩니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retrospects/search",
+    params(SearchQueryParams),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "검색을 성공했습니다.", body = SuccessSearchResponse),
+        (status = 400, description = "검색어 누락 또는 유효하지 않음", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn search_retrospects(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Query(params): Query<SearchQueryParams>,
+) -> Result<Json<BaseResponse<Vec<SearchRetrospectItem>>>, AppError> {
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::search_retrospects(state, user_id, params).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "검색을 성공했습니다.",
+    )))
+}
+
+/// 회고 내보내기 API (API-021)
+///
+/// 특정 회고 세션의 전체 내용(회고방 인사이트, 멤버별 답변 등)을 요약하여 PDF 파일로 생성하고 다운로드합니다.
+/// `format=json`이면 회고 메타 정보와 질문/답변/작성자/좋아요/댓글을 구조화된 JSON으로 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retrospects/{retrospectId}/export",
+    params(
+        ("retrospectId" = i64, Path, description = "내보낼 회고의 고유 식별자"),
+        ExportQueryParams
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "PDF 파일 다운로드 (format=json이면 JSON 응답)", content(
+            (String = "application/pdf"),
+            (SuccessRetrospectExportResponse = "application/json")
+        )),
+        (status = 400, description = "잘못된 요청 (retrospectId 유효성 오류)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
+        (status = 500, description = "PDF 생성 실패", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn export_retrospect(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+    Query(params): Query<ExportQueryParams>,
+) -> Result<Response, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    if params.format.unwrap_or_default() == ExportFormat::Json {
+        let result =
+            RetrospectService::export_retrospect_json(state, user_id, retrospect_id).await?;
+
+        return Ok(Json(BaseResponse::success_with_message(
+            result,
+            "회고 내보내기를 성공했습니다.",
+        ))
+        .into_response());
+    }
+
+    let pdf_bytes = RetrospectService::export_retrospect(state, user_id, retrospect_id).await?;
+
+    let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
+    let filename = format!("retrospect_report_{}_{}.pdf", retrospect_id, timestamp);
+
+    let headers = [
+        (
+            header::CONTENT_TYPE,
+            "application/pdf; charset=utf-8".to_string(),
+        ),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"{}\"", filename),
+        ),
+        (
+            header::CACHE_CONTROL,
+            "no-cache, no-store, must-revalidate".to_string(),
+        ),
+    ];
+
+    Ok((headers, pdf_bytes).into_response())
+}
+
+/// 회고 일정 캘린더 내보내기 API
+///
+/// 회고 일정을 개인 캘린더에 등록할 수 있도록 iCalendar(.ics) 파일로 반환합니다.
+/// 회고 제목, 시작 시각(회고 타임존 기준), 회고방 이름, 회고 방식을 VEVENT로 담습니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/retrospects/{retrospectId}/calendar.ics",
+    params(
+        ("retrospectId" = i64, Path, description = "회고 고유 식별자")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "iCalendar 파일 다운로드", content(
+            (String = "text/calendar")
+        )),
+        (status = 400, description = "잘못된 요청 (retrospectId 유효성 오류)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn export_calendar(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(retrospect_id): Path<i64>,
+) -> Result<Response, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
     let user_id = user.user_id()?;
+    let ics = RetrospectService::export_calendar(state, user_id, retrospect_id).await?;
 
-    // 서비스 호출
-    let result = RetrospectService::save_draft(state, user_id, retrospect_id, req).await?;
+    let headers = [
+        (
+            header::CONTENT_TYPE,
+            "text/calendar; charset=utf-8".to_string(),
+        ),
+        (
+            header::CONTENT_DISPOSITION,
+            format!("attachment; filename=\"retrospect_{}.ics\"", retrospect_id),
+        ),
+        (
+            header::CACHE_CONTROL,
+            "no-cache, no-store, must-revalidate".to_string(),
+        ),
+    ];
 
-    Ok(Json(BaseResponse::success_with_message(
-        result,
-        "임시 저장이 완료되었습니다.",
-    )))
+    Ok((headers, ics).into_response())
 }
 
-/// 회고 상세 정보 조회 API (API-012)
+/// 회고 답변 카테고리별 조회 API (API-020)
 ///
-/// 특정 회고 세션의 상세 정보(제목, 일시, 유형, 참여 멤버, 질문 리스트 및 전체 통계)를 조회합니다.
+/// `fields`를 지정하면 답변 아이템에 요청한 필드만 담아 반환합니다. (알 수 없는 필드명은 무시)
 #[utoipa::path(
     get,
-    path = "/api/v1/retrospects/{retrospectId}",
+    path = "/api/v1/retrospects/{retrospectId}/responses",
     params(
-        ("retrospectId" = i64, Path, description = "조회할 회고의 고유 식별자")
+        ("retrospectId" = i64, Path, description = "조회를 진행할 회고 세션 고유 ID"),
+        ResponsesQueryParams
     ),
     security(
         ("bearer_auth" = [])
     ),
     responses(
-        (status = 200, description = "회고 상세 정보 조회를 성공했습니다.", body = SuccessRetrospectDetailResponse),
-        (status = 400, description = "잘못된 Path Parameter", body = ErrorResponse),
+        (status = 200, description = "답변 리스트 조회를 성공했습니다.", body = SuccessResponsesListResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
         (status = 403, description = "접근 권한 없음", body = ErrorResponse),
         (status = 404, description = "존재하지 않는 회고", body = ErrorResponse),
@@ -509,146 +2717,180 @@ pub async fn save_draft(
     ),
     tag = "Retrospect"
 )]
-pub async fn get_retrospect_detail(
+pub async fn list_responses(
     user: AuthUser,
     State(state): State<AppState>,
     Path(retrospect_id): Path<i64>,
-) -> Result<Json<BaseResponse<RetrospectDetailResponse>>, AppError> {
-    // retrospectId 검증 (1 이상의 양수)
+    Query(params): Query<ResponsesQueryParams>,
+) -> Result<Response, AppError> {
     if retrospect_id < 1 {
         return Err(AppError::BadRequest(
             "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
         ));
     }
 
-    // 사용자 ID 추출
+    let category: ResponseCategory = params.category.parse().map_err(|_| {
+        AppError::RetroCategoryInvalid("유효하지 않은 카테고리 값입니다.".to_string())
+    })?;
+
+    if let Some(cursor) = params.cursor {
+        if cursor < 1 {
+            return Err(AppError::BadRequest(
+                "cursor는 1 이상의 양수여야 합니다.".to_string(),
+            ));
+        }
+    }
+
+    let size = params.size.unwrap_or(10);
+    if !(1..=100).contains(&size) {
+        return Err(AppError::BadRequest(
+            "size는 1~100 범위의 정수여야 합니다.".to_string(),
+        ));
+    }
+
+    let fields = parse_response_fields(params.fields.as_deref());
+
     let user_id = user.user_id()?;
 
-    // 서비스 호출
-    let result = RetrospectService::get_retrospect_detail(state, user_id, retrospect_id).await?;
+    let result = RetrospectService::list_responses(
+        state,
+        user_id,
+        retrospect_id,
+        category,
+        params.label,
+        params.cursor,
+        size,
+    )
+    .await?;
 
-    Ok(Json(BaseResponse::success_with_message(
-        result,
-        "회고 상세 정보 조회를 성공했습니다.",
-    )))
+    let message = "답변 리스트 조회를 성공했습니다.";
+    Ok(match fields {
+        Some(fields) => Json(BaseResponse::success_with_message(
+            result.select_fields(&fields),
+            message,
+        ))
+        .into_response(),
+        None => Json(BaseResponse::success_with_message(result, message)).into_response(),
+    })
 }
 
-/// 회고 최종 제출 API (API-017)
+/// 회고 질문 확정 API
 ///
-/// 작성한 모든 답변(총 5개)을 최종 제출합니다.
-/// 각 답변은 최대 1,000자까지 입력 가능하며, 제출 완료 시 회고 상태가 SUBMITTED로 변경됩니다.
+/// 지연 생성 모드(deferQuestions)로 만든 회고의 질문을 확정하고,
+/// 참여자 전원의 질문별 답변 레코드를 한 번에 생성합니다. 회고방 Owner만 가능합니다.
 #[utoipa::path(
     post,
-    path = "/api/v1/retrospects/{retrospectId}/submit",
+    path = "/api/v1/retrospects/{retrospectId}/finalize-questions",
     params(
-        ("retrospectId" = i64, Path, description = "제출할 회고의 고유 식별자")
+        ("retrospectId" = i64, Path, description = "회고 고유 식별자")
     ),
-    request_body = SubmitRetrospectRequest,
     security(
         ("bearer_auth" = [])
     ),
     responses(
-        (status = 200, description = "회고 제출이 성공적으로 완료되었습니다.", body = SuccessSubmitRetrospectResponse),
-        (status = 400, description = "잘못된 요청 (답변 누락, 답변 길이 초과, 공백만 입력 등)", body = ErrorResponse),
+        (status = 200, description = "회고 질문이 확정되었습니다.", body = SuccessFinalizeQuestionsResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 403, description = "이미 제출 완료된 회고", body = ErrorResponse),
-        (status = 404, description = "존재하지 않는 회고", body = ErrorResponse),
+        (status = 403, description = "회고방 Owner 권한 없음", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
+        (status = 409, description = "이미 질문이 확정된 회고", body = ErrorResponse),
         (status = 500, description = "서버 내부 오류", body = ErrorResponse)
     ),
     tag = "Retrospect"
 )]
-pub async fn submit_retrospect(
+pub async fn finalize_questions(
     user: AuthUser,
     State(state): State<AppState>,
     Path(retrospect_id): Path<i64>,
-    Json(req): Json<SubmitRetrospectRequest>,
-) -> Result<Json<BaseResponse<SubmitRetrospectResponse>>, AppError> {
-    // retrospectId 검증 (1 이상의 양수)
+) -> Result<Json<BaseResponse<FinalizeQuestionsResponse>>, AppError> {
     if retrospect_id < 1 {
         return Err(AppError::BadRequest(
             "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
         ));
     }
 
-    // 사용자 ID 추출
     let user_id = user.user_id()?;
 
-    // 서비스 호출
-    let result = RetrospectService::submit_retrospect(state, user_id, retrospect_id, req).await?;
+    let result = RetrospectService::finalize_questions(state, user_id, retrospect_id).await?;
 
     Ok(Json(BaseResponse::success_with_message(
         result,
-        "회고 제출이 성공적으로 완료되었습니다.",
+        "회고 질문이 확정되었습니다.",
     )))
 }
 
-/// 보관함 조회 API (API-019)
+/// 회고 답변 편집 잠금 API
 ///
-/// 완료된 회고 목록을 연도별로 그룹화하여 조회합니다.
-/// 기간 필터를 통해 특정 기간의 회고만 조회할 수 있습니다.
+/// 분석 전에 답변이 바뀌지 않도록 회고를 잠급니다. 잠금 중에는 답변 임시 저장/제출이 거부되고 조회는 가능합니다.
+/// 회고방 Owner만 가능하며, 권장 흐름은 제출 마감 → 잠금 → 분석입니다.
 #[utoipa::path(
-    get,
-    path = "/api/v1/retrospects/storage",
-    params(StorageQueryParams),
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/lock",
+    params(
+        ("retrospectId" = i64, Path, description = "회고 고유 식별자")
+    ),
     security(
         ("bearer_auth" = [])
     ),
     responses(
-        (status = 200, description = "보관함 조회를 성공했습니다.", body = SuccessStorageResponse),
-        (status = 400, description = "유효하지 않은 기간 필터", body = ErrorResponse),
+        (status = 200, description = "회고가 잠금되었습니다.", body = SuccessRetrospectLockResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "회고방 Owner 권한 없음", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
         (status = 500, description = "서버 내부 오류", body = ErrorResponse)
     ),
     tag = "Retrospect"
 )]
-pub async fn get_storage(
+pub async fn lock_retrospect(
     user: AuthUser,
     State(state): State<AppState>,
-    Query(params): Query<StorageQueryParams>,
-) -> Result<Json<BaseResponse<StorageResponse>>, AppError> {
-    // 사용자 ID 추출
+    Path(retrospect_id): Path<i64>,
+) -> Result<Json<BaseResponse<RetrospectLockResponse>>, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
     let user_id = user.user_id()?;
 
-    // 서비스 호출
-    let result = RetrospectService::get_storage(state, user_id, params).await?;
+    let result =
+        RetrospectService::set_retrospect_lock(state, user_id, retrospect_id, true).await?;
 
     Ok(Json(BaseResponse::success_with_message(
         result,
-        "보관함 조회를 성공했습니다.",
+        "회고가 잠금되었습니다.",
     )))
 }
 
-/// 회고 분석 API (API-022)
+/// 회고 답변 편집 잠금 해제 API
 ///
-/// 특정 회고 세션에 쌓인 모든 회고방 멤버의 답변을 종합 분석하여 AI 인사이트, 감정 통계, 맞춤형 미션을 생성합니다.
+/// 잠금된 회고의 답변 임시 저장/제출을 다시 허용합니다. 회고방 Owner만 가능합니다.
 #[utoipa::path(
-    post,
-    path = "/api/v1/retrospects/{retrospectId}/analysis",
+    delete,
+    path = "/api/v1/retrospects/{retrospectId}/lock",
     params(
-        ("retrospectId" = i64, Path, description = "분석할 회고 ID")
+        ("retrospectId" = i64, Path, description = "회고 고유 식별자")
     ),
-    request_body = (),
     security(
         ("bearer_auth" = [])
     ),
     responses(
-        (status = 200, description = "회고 분석 성공", body = SuccessAnalysisResponse),
-        (status = 400, description = "잘못된 Path Parameter", body = ErrorResponse),
+        (status = 200, description = "회고 잠금이 해제되었습니다.", body = SuccessRetrospectLockResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 403, description = "월간 한도 초과 또는 접근 권한 없음", body = ErrorResponse),
-        (status = 404, description = "회고 없음", body = ErrorResponse),
-        (status = 409, description = "이미 분석 완료된 회고", body = ErrorResponse),
-        (status = 422, description = "분석 데이터 부족", body = ErrorResponse),
-        (status = 500, description = "AI 분석 실패", body = ErrorResponse)
+        (status = 403, description = "회고방 Owner 권한 없음", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
     ),
     tag = "Retrospect"
 )]
-pub async fn analyze_retrospective_handler(
+pub async fn unlock_retrospect(
     user: AuthUser,
     State(state): State<AppState>,
     Path(retrospect_id): Path<i64>,
-) -> Result<Json<BaseResponse<AnalysisResponse>>, AppError> {
-    // retrospectId 검증 (1 이상의 양수)
+) -> Result<Json<BaseResponse<RetrospectLockResponse>>, AppError> {
     if retrospect_id < 1 {
         return Err(AppError::BadRequest(
             "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
@@ -657,182 +2899,166 @@ pub async fn analyze_retrospective_handler(
 
     let user_id = user.user_id()?;
 
-    // 서비스 호출
-    let result = RetrospectService::analyze_retrospective(state, user_id, retrospect_id).await?;
+    let result =
+        RetrospectService::set_retrospect_lock(state, user_id, retrospect_id, false).await?;
 
     Ok(Json(BaseResponse::success_with_message(
         result,
-        "회고 분석이 성공적으로 완료되었습니다.",
+        "회고 잠금이 해제되었습니다.",
     )))
 }
 
-/// 회고 검색 API (API-023)
+/// 회고 답변 타이핑 인디케이터 WebSocket
 ///
-/// 사용자가 참여하는 모든 회고방의 회고를 프로젝트명/회고명 기준으로 검색합니다.
-/// 결과는 회고 날짜 내림차순(최신순), 동일 날짜인 경우 회고 시간 내림차순으로 정렬됩니다.
+/// 같은 회고에 접속한 멤버끼리 "입력 중" 상태를 주고받습니다.
+/// 클라이언트는 `{"type":"TYPING_START"}` / `{"type":"TYPING_STOP"}`을 보내고,
+/// 다른 멤버의 `TypingEvent`를 수신합니다. 일정 시간 시작 이벤트가 없으면 자동으로 중지됩니다.
 #[utoipa::path(
     get,
-    path = "/api/v1/retrospects/search",
-    params(SearchQueryParams),
+    path = "/api/v1/retrospects/{retrospectId}/typing",
+    params(
+        ("retrospectId" = i64, Path, description = "회고 고유 식별자")
+    ),
     security(
         ("bearer_auth" = [])
     ),
     responses(
-        (status = 200, description = "검색을 성공했습니다.", body = SuccessSearchResponse),
-        (status = 400, description = "검색어 누락 또는 유효하지 않음", body = ErrorResponse),
+        (status = 101, description = "WebSocket 연결이 수립되었습니다. 이후 TypingEvent 메시지를 주고받습니다.", body = TypingEvent),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
         (status = 500, description = "서버 내부 오류", body = ErrorResponse)
     ),
     tag = "Retrospect"
 )]
-pub async fn search_retrospects(
+pub async fn typing_socket(
     user: AuthUser,
     State(state): State<AppState>,
-    Query(params): Query<SearchQueryParams>,
-) -> Result<Json<BaseResponse<Vec<SearchRetrospectItem>>>, AppError> {
+    Path(retrospect_id): Path<i64>,
+    ws: WebSocketUpgrade,
+) -> Result<Response, AppError> {
+    if retrospect_id < 1 {
+        return Err(AppError::BadRequest(
+            "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
     let user_id = user.user_id()?;
 
-    let result = RetrospectService::search_retrospects(state, user_id, params).await?;
+    let user_name = RetrospectService::authorize_typing(&state, user_id, retrospect_id).await?;
 
-    Ok(Json(BaseResponse::success_with_message(
-        result,
-        "검색을 성공했습니다.",
-    )))
+    let hub = state.typing_hub.clone();
+    Ok(ws.on_upgrade(move |socket| {
+        run_typing_session(socket, hub, retrospect_id, user_id, user_name)
+    }))
 }
 
-/// 회고 내보내기 API (API-021)
+/// 회고 이동 API
 ///
-/// 특정 회고 세션의 전체 내용(회고방 인사이트, 멤버별 답변 등)을 요약하여 PDF 파일로 생성하고 다운로드합니다.
+/// 회고를 다른 회고방으로 옮깁니다. 원본/대상 회고방 모두의 Owner만 가능하며,
+/// 이동 후 접근 권한은 대상 회고방 기준으로 판단됩니다.
 #[utoipa::path(
-    get,
-    path = "/api/v1/retrospects/{retrospectId}/export",
+    patch,
+    path = "/api/v1/retrospects/{retrospectId}/move",
+    request_body = MoveRetrospectRequest,
     params(
-        ("retrospectId" = i64, Path, description = "내보낼 회고의 고유 식별자")
+        ("retrospectId" = i64, Path, description = "이동할 회고의 고유 식별자")
     ),
     security(
         ("bearer_auth" = [])
     ),
     responses(
-        (status = 200, description = "PDF 파일 다운로드", content_type = "application/pdf"),
-        (status = 400, description = "잘못된 요청 (retrospectId 유효성 오류)", body = ErrorResponse),
+        (status = 200, description = "회고가 이동되었습니다.", body = SuccessMoveRetrospectResponse),
+        (status = 400, description = "잘못된 요청 (같은 회고방으로 이동 등)", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 404, description = "존재하지 않는 회고이거나 접근 권한 없음", body = ErrorResponse),
-        (status = 500, description = "PDF 생성 실패", body = ErrorResponse)
+        (status = 403, description = "원본 또는 대상 회고방 Owner 권한 없음", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고 또는 회고방", body = ErrorResponse),
+        (status = 409, description = "대상 회고방 진행 중 회고 수 제한 초과 또는 제목 중복", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
     ),
     tag = "Retrospect"
 )]
-pub async fn export_retrospect(
+pub async fn move_retrospect(
     user: AuthUser,
     State(state): State<AppState>,
     Path(retrospect_id): Path<i64>,
-) -> Result<impl IntoResponse, AppError> {
+    Json(req): Json<MoveRetrospectRequest>,
+) -> Result<Json<BaseResponse<MoveRetrospectResponse>>, AppError> {
     if retrospect_id < 1 {
         return Err(AppError::BadRequest(
             "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
         ));
     }
+    req.validate()?;
 
     let user_id = user.user_id()?;
 
-    let pdf_bytes = RetrospectService::export_retrospect(state, user_id, retrospect_id).await?;
-
-    let timestamp = Utc::now().format("%Y%m%d_%H%M%S").to_string();
-    let filename = format!("retrospect_report_{}_{}.pdf", retrospect_id, timestamp);
-
-    let headers = [
-        (
-            header::CONTENT_TYPE,
-            "application/pdf; charset=utf-8".to_string(),
-        ),
-        (
-            header::CONTENT_DISPOSITION,
-            format!("attachment; filename=\"{}\"", filename),
-        ),
-        (
-            header::CACHE_CONTROL,
-            "no-cache, no-store, must-revalidate".to_string(),
-        ),
-    ];
+    let result = RetrospectService::move_retrospect(state, user_id, retrospect_id, req).await?;
 
-    Ok((headers, pdf_bytes))
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "회고가 이동되었습니다.",
+    )))
 }
 
-/// 회고 답변 카테고리별 조회 API (API-020)
+/// 회고 복제 API
+///
+/// 원본 회고의 회고 방식, 질문 구성, 참고 URL을 그대로 재사용해 같은 회고방에 새 회고를 만듭니다.
+/// 답변과 AI 분석 결과(팀/개인 인사이트)는 복사하지 않으며, 시작 일시만 새로 지정합니다.
 #[utoipa::path(
-    get,
-    path = "/api/v1/retrospects/{retrospectId}/responses",
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/duplicate",
+    request_body = DuplicateRetrospectRequest,
     params(
-        ("retrospectId" = i64, Path, description = "조회를 진행할 회고 세션 고유 ID"),
-        ResponsesQueryParams
+        ("retrospectId" = i64, Path, description = "복제할 원본 회고의 고유 식별자")
     ),
     security(
         ("bearer_auth" = [])
     ),
     responses(
-        (status = 200, description = "답변 리스트 조회를 성공했습니다.", body = SuccessResponsesListResponse),
-        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 200, description = "회고가 복제되었습니다.", body = SuccessCreateRetrospectResponse),
+        (status = 400, description = "잘못된 요청 (프로젝트 이름 길이 초과, 날짜 형식 오류, 과거 시각 등)", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
-        (status = 403, description = "접근 권한 없음", body = ErrorResponse),
-        (status = 404, description = "존재하지 않는 회고", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고이거나 회고방 멤버가 아님", body = ErrorResponse),
+        (status = 409, description = "진행 중 회고 수 제한 초과 또는 회고 제목 중복", body = ErrorResponse),
         (status = 500, description = "서버 내부 오류", body = ErrorResponse)
     ),
     tag = "Retrospect"
 )]
-pub async fn list_responses(
+pub async fn duplicate_retrospect(
     user: AuthUser,
     State(state): State<AppState>,
     Path(retrospect_id): Path<i64>,
-    Query(params): Query<ResponsesQueryParams>,
-) -> Result<Json<BaseResponse<ResponsesListResponse>>, AppError> {
+    Json(req): Json<DuplicateRetrospectRequest>,
+) -> Result<Json<BaseResponse<CreateRetrospectResponse>>, AppError> {
     if retrospect_id < 1 {
         return Err(AppError::BadRequest(
             "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
         ));
     }
-
-    let category: ResponseCategory = params.category.parse().map_err(|_| {
-        AppError::RetroCategoryInvalid("유효하지 않은 카테고리 값입니다.".to_string())
-    })?;
-
-    if let Some(cursor) = params.cursor {
-        if cursor < 1 {
-            return Err(AppError::BadRequest(
-                "cursor는 1 이상의 양수여야 합니다.".to_string(),
-            ));
-        }
-    }
-
-    let size = params.size.unwrap_or(10);
-    if !(1..=100).contains(&size) {
-        return Err(AppError::BadRequest(
-            "size는 1~100 범위의 정수여야 합니다.".to_string(),
-        ));
-    }
+    req.validate()?;
 
     let user_id = user.user_id()?;
 
-    let result = RetrospectService::list_responses(
-        state,
-        user_id,
-        retrospect_id,
-        category,
-        params.cursor,
-        size,
-    )
-    .await?;
+    let result =
+        RetrospectService::duplicate_retrospect(state, user_id, retrospect_id, req).await?;
 
     Ok(Json(BaseResponse::success_with_message(
         result,
-        "답변 리스트 조회를 성공했습니다.",
+        "회고가 복제되었습니다.",
     )))
 }
 
 /// 회고 삭제 API (API-013)
+///
+/// 회고방은 기본적으로 보존됩니다. `deleteEmptyRoom=true`이면 남은 회고가 없을 때 회고방도 함께 삭제하며,
+/// 실제 삭제 여부는 응답의 `roomDeleted`로 확인할 수 있습니다.
 #[utoipa::path(
     delete,
     path = "/api/v1/retrospects/{retrospectId}",
     params(
-        ("retrospectId" = i64, Path, description = "삭제할 회고의 고유 식별자")
+        ("retrospectId" = i64, Path, description = "삭제할 회고의 고유 식별자"),
+        DeleteRetrospectQuery
     ),
     security(
         ("bearer_auth" = [])
@@ -850,7 +3076,8 @@ pub async fn delete_retrospect(
     user: AuthUser,
     State(state): State<AppState>,
     Path(retrospect_id): Path<i64>,
-) -> Result<Json<BaseResponse<()>>, AppError> {
+    Query(params): Query<DeleteRetrospectQuery>,
+) -> Result<Json<BaseResponse<DeleteRetrospectResponse>>, AppError> {
     if retrospect_id < 1 {
         return Err(AppError::BadRequest(
             "retrospectId는 1 이상의 양수여야 합니다.".to_string(),
@@ -859,10 +3086,16 @@ pub async fn delete_retrospect(
 
     let user_id = user.user_id()?;
 
-    RetrospectService::delete_retrospect(state, user_id, retrospect_id).await?;
+    let result = RetrospectService::delete_retrospect(
+        state,
+        user_id,
+        retrospect_id,
+        params.delete_empty_room,
+    )
+    .await?;
 
     Ok(Json(BaseResponse::success_with_message(
-        (),
+        result,
         "회고가 성공적으로 삭제되었습니다.",
     )))
 }
@@ -972,6 +3205,142 @@ pub async fn create_comment(
     )))
 }
 
+/// 회고 답변 AI 한 줄 요약 API
+///
+/// 답변을 AI로 한 줄 요약해 저장하고 반환합니다. 이미 요약이 있으면 새로 생성해 덮어씁니다.
+/// 제출 시 긴 답변은 자동으로 요약되며, 수동/자동 요약을 합산해 월간 사용량 한도가 적용됩니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/responses/{responseId}/summarize",
+    params(
+        ("responseId" = i64, Path, description = "요약할 답변의 고유 ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "답변 요약을 생성했습니다.", body = SuccessResponseSummaryResponse),
+        (status = 400, description = "잘못된 요청 (빈 답변 등)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "회고방 접근 권한 없음 또는 월간 요약 한도 초과", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고 답변", body = ErrorResponse),
+        (status = 500, description = "AI 요약 생성 실패 또는 서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Response"
+)]
+pub async fn summarize_response(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(response_id): Path<i64>,
+) -> Result<Json<BaseResponse<ResponseSummaryResponse>>, AppError> {
+    if response_id < 1 {
+        return Err(AppError::BadRequest(
+            "responseId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::summarize_response(state, user_id, response_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "답변 요약을 생성했습니다.",
+    )))
+}
+
+/// 회고 답변 라벨 설정 API
+///
+/// 답변에 "액션아이템", "칭찬" 같은 라벨을 설정합니다. 요청한 목록으로 전체 교체됩니다.
+/// 라벨은 자유 입력이며(최대 5개, 각 1~20자), 답변 작성자 또는 회고방 Moderator 이상만 설정할 수 있습니다.
+#[utoipa::path(
+    put,
+    path = "/api/v1/responses/{responseId}/labels",
+    params(
+        ("responseId" = i64, Path, description = "라벨을 설정할 답변의 고유 ID")
+    ),
+    request_body = UpdateResponseLabelsRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "답변 라벨을 설정했습니다.", body = SuccessResponseLabelsResponse),
+        (status = 400, description = "잘못된 요청 (라벨 개수/길이 초과 등)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "작성자 또는 Moderator 이상이 아님", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고 답변", body = ErrorResponse)
+    ),
+    tag = "Response"
+)]
+pub async fn update_response_labels(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(response_id): Path<i64>,
+    Json(req): Json<UpdateResponseLabelsRequest>,
+) -> Result<Json<BaseResponse<ResponseLabelsResponse>>, AppError> {
+    if response_id < 1 {
+        return Err(AppError::BadRequest(
+            "responseId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    req.validate()?;
+
+    let user_id = user.user_id()?;
+
+    let result =
+        RetrospectService::update_response_labels(state, user_id, response_id, req).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "답변 라벨을 설정했습니다.",
+    )))
+}
+
+/// 회고 답변 조회 기록 API
+///
+/// 답변을 열람할 때 호출해 조회수를 집계합니다.
+/// 작성자 본인의 조회는 제외하고, 같은 멤버의 반복 조회는 30분 안에서 1회만 반영합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/responses/{responseId}/views",
+    params(
+        ("responseId" = i64, Path, description = "조회한 답변의 고유 ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "답변 조회가 기록되었습니다.", body = SuccessResponseViewResponse),
+        (status = 400, description = "잘못된 요청 (responseId가 1 미만)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "회고방 멤버가 아님", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고 답변", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Response"
+)]
+pub async fn record_response_view(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(response_id): Path<i64>,
+) -> Result<Json<BaseResponse<ResponseViewResponse>>, AppError> {
+    if response_id < 1 {
+        return Err(AppError::BadRequest(
+            "responseId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::record_response_view(state, user_id, response_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "답변 조회가 기록되었습니다.",
+    )))
+}
+
 /// 회고 답변 좋아요 토글 API (API-025)
 ///
 /// 특정 회고 답변에 좋아요를 등록하거나 취소합니다.
@@ -1023,6 +3392,113 @@ pub async fn toggle_like(
     )))
 }
 
+/// 답변 좋아요 누른 멤버 목록 조회 API
+///
+/// 특정 회고 답변에 좋아요를 누른 멤버를 최근 좋아요 순으로 조회합니다.
+/// 좋아요가 없으면 빈 배열을 반환합니다.
+#[utoipa::path(
+    get,
+    path = "/api/v1/responses/{responseId}/likes",
+    params(
+        ("responseId" = i64, Path, description = "좋아요 멤버를 조회할 회고 답변의 고유 식별자"),
+        ("cursor" = Option<i64>, Query, description = "마지막으로 조회된 좋아요 ID"),
+        ("size" = Option<i32>, Query, description = "페이지당 조회 개수 (1~100, 기본값: 20)")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "좋아요 멤버 조회를 성공했습니다.", body = SuccessListLikesResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "접근 권한 없음", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고 답변", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Response"
+)]
+pub async fn list_likes(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(response_id): Path<i64>,
+    Query(query): Query<ListLikesQuery>,
+) -> Result<Json<BaseResponse<ListLikesResponse>>, AppError> {
+    if response_id < 1 {
+        return Err(AppError::BadRequest(
+            "responseId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    if let Some(cursor) = query.cursor {
+        if cursor < 1 {
+            return Err(AppError::BadRequest(
+                "cursor는 1 이상의 양수여야 합니다.".to_string(),
+            ));
+        }
+    }
+
+    let size = query.size.unwrap_or(20);
+    if !(1..=100).contains(&size) {
+        return Err(AppError::BadRequest(
+            "size는 1~100 범위의 정수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result =
+        RetrospectService::list_likes(state, user_id, response_id, query.cursor, size).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "좋아요 멤버 조회를 성공했습니다.",
+    )))
+}
+
+/// 답변 좋아요 일괄 취소 API
+///
+/// 조작된 좋아요를 정리하기 위해 답변의 모든 좋아요를 삭제하고 좋아요 수를 0으로 리셋합니다. (Owner/Moderator만 가능)
+/// 응답의 `previousLikeCount`로 취소 전 좋아요 수를 확인할 수 있습니다.
+#[utoipa::path(
+    delete,
+    path = "/api/v1/responses/{responseId}/likes/all",
+    params(
+        ("responseId" = i64, Path, description = "좋아요를 정리할 대상 답변의 고유 ID")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "좋아요 일괄 취소 성공", body = SuccessResetLikesResponse),
+        (status = 400, description = "잘못된 요청 (responseId가 1 미만)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "권한 없음", body = ErrorResponse),
+        (status = 404, description = "존재하지 않는 회고 답변", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse)
+    ),
+    tag = "Response"
+)]
+pub async fn reset_likes(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path(response_id): Path<i64>,
+) -> Result<Json<BaseResponse<ResetLikesResponse>>, AppError> {
+    if response_id < 1 {
+        return Err(AppError::BadRequest(
+            "responseId는 1 이상의 양수여야 합니다.".to_string(),
+        ));
+    }
+
+    let user_id = user.user_id()?;
+
+    let result = RetrospectService::reset_likes(state, user_id, response_id).await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "답변의 좋아요를 모두 취소했습니다.",
+    )))
+}
+
 /// 회고 어시스턴트 API (API-029)
 ///
 /// 회고 작성 시 특정 질문에 대해 AI 어시스턴트가 작성 가이드를 제공합니다.
@@ -1072,3 +3548,48 @@ pub async fn assistant_guide(
         "가이드가 성공적으로 생성되었습니다.",
     )))
 }
+
+/// 답변 AI 피드백 미리보기 API
+///
+/// 제출 전 작성 중인 답변에 대해 AI가 구체성/건설성 관점의 개선 제안을 제공합니다.
+/// 어시스턴트와 별도의 월간 사용 한도가 적용되며, 제출 여부와 무관하게 호출할 수 있습니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/retrospects/{retrospectId}/questions/{questionId}/feedback",
+    params(
+        ("retrospectId" = i64, Path, description = "회고의 고유 ID"),
+        ("questionId" = i32, Path, description = "질문 번호 (1~5)")
+    ),
+    request_body = AnswerFeedbackRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "피드백 생성 성공", body = SuccessAnswerFeedbackResponse),
+        (status = 400, description = "잘못된 요청 (빈 답변, content 길이 초과 등)", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "접근 권한 없음 또는 월간 사용 한도 초과", body = ErrorResponse),
+        (status = 404, description = "회고 또는 질문을 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류 또는 AI 서비스 오류", body = ErrorResponse)
+    ),
+    tag = "Retrospect"
+)]
+pub async fn answer_feedback(
+    user: AuthUser,
+    State(state): State<AppState>,
+    Path((retrospect_id, question_id)): Path<(i64, i32)>,
+    Json(req): Json<AnswerFeedbackRequest>,
+) -> Result<Json<BaseResponse<AnswerFeedbackResponse>>, AppError> {
+    req.validate()?;
+
+    let user_id = user.user_id()?;
+
+    let result =
+        RetrospectService::review_answer_feedback(state, user_id, retrospect_id, question_id, req)
+            .await?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "답변 피드백이 성공적으로 생성되었습니다.",
+    )))
+}
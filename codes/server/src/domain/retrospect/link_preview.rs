@@ -0,0 +1,442 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use regex::Regex;
+use reqwest::{header, redirect, Url};
+
+use crate::utils::error::AppError;
+
+/// 메타데이터 크롤링 요청 타임아웃 (리다이렉트 한 번당)
+const LINK_PREVIEW_TIMEOUT_SECS: u64 = 5;
+
+/// 읽어 들일 최대 응답 크기 (OpenGraph 메타는 <head>에 있으므로 앞부분만 읽음)
+const LINK_PREVIEW_MAX_BYTES: usize = 512 * 1024;
+
+/// 따라갈 최대 리다이렉트 횟수 (매 단계 호스트를 다시 검증)
+const LINK_PREVIEW_MAX_REDIRECTS: usize = 3;
+
+/// 미리보기 제목/설명 최대 길이
+const LINK_PREVIEW_TEXT_MAX_CHARS: usize = 300;
+
+const LINK_PREVIEW_USER_AGENT: &str = "MoalogLinkPreview/1.0";
+
+/// 참고자료 링크 메타데이터
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LinkMetadata {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+}
+
+/// 미리보기 대상 URL 파싱 (http/https, 호스트 필수, localhost 차단)
+pub fn parse_preview_url(raw: &str) -> Result<Url, AppError> {
+    let url = Url::parse(raw.trim())
+        .map_err(|_| AppError::RetroUrlInvalid("유효하지 않은 URL 형식입니다.".to_string()))?;
+
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(AppError::RetroUrlInvalid(
+            "http 또는 https URL만 허용됩니다.".to_string(),
+        ));
+    }
+
+    let host = url
+        .host_str()
+        .ok_or_else(|| AppError::RetroUrlInvalid("유효하지 않은 URL 형식입니다.".to_string()))?
+        .to_ascii_lowercase();
+    if host == "localhost" || host.ends_with(".localhost") {
+        return Err(blocked_host_error());
+    }
+    if let Some(ip) = literal_ip(&host) {
+        if is_blocked_ip(ip) {
+            return Err(blocked_host_error());
+        }
+    }
+
+    Ok(url)
+}
+
+/// 내부망으로 향할 수 있는 IP 여부 (loopback, 사설, 링크 로컬, CGNAT, 멀티캐스트 등)
+pub fn is_blocked_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => is_blocked_ipv4(v4),
+        IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+            Some(v4) => is_blocked_ipv4(v4),
+            None => is_blocked_ipv6(v6),
+        },
+    }
+}
+
+fn is_blocked_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_unspecified()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        // 0.0.0.0/8, 100.64.0.0/10 (CGNAT), 198.18.0.0/15 (벤치마크), 240.0.0.0/4 (예약)
+        || a == 0
+        || (a == 100 && (64..128).contains(&b))
+        || (a == 198 && (b & 0xfe) == 18)
+        || a >= 240
+}
+
+fn is_blocked_ipv6(ip: Ipv6Addr) -> bool {
+    let segments = ip.segments();
+    let first = segments[0];
+    ip.is_loopback()
+        || ip.is_unspecified()
+        || ip.is_multicast()
+        // fc00::/7 (unique local), fe80::/10 (link local)
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80
+        // 64:ff9b::/96 (NAT64, 게이트웨이를 거쳐 내부 IPv4로 연결될 수 있음)
+        || segments[..6] == [0x64, 0xff9b, 0, 0, 0, 0]
+}
+
+/// 호스트가 IP 리터럴이면 파싱 (IPv6는 대괄호 제거)
+fn literal_ip(host: &str) -> Option<IpAddr> {
+    host.trim_start_matches('[')
+        .trim_end_matches(']')
+        .parse()
+        .ok()
+}
+
+fn blocked_host_error() -> AppError {
+    AppError::RetroUrlInvalid("내부 네트워크 주소는 미리보기할 수 없습니다.".to_string())
+}
+
+/// 호스트를 조회해 공개 IP만 반환 (하나라도 내부망이면 차단)
+async fn resolve_public_addrs(url: &Url) -> Result<Vec<SocketAddr>, AppError> {
+    let host = url
+        .host_str()
+        .unwrap_or_default()
+        .trim_start_matches('[')
+        .trim_end_matches(']');
+    let port = url.port_or_known_default().unwrap_or(80);
+
+    let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host, port))
+        .await
+        .map_err(|e| AppError::InternalError(format!("호스트 조회 실패: {}", e)))?
+        .collect();
+
+    if addrs.is_empty() {
+        return Err(AppError::InternalError(format!(
+            "호스트 조회 결과 없음: {}",
+            host
+        )));
+    }
+    if addrs.iter().any(|addr| is_blocked_ip(addr.ip())) {
+        return Err(blocked_host_error());
+    }
+
+    Ok(addrs)
+}
+
+/// URL의 OpenGraph 메타데이터 크롤링
+///
+/// 매 요청(리다이렉트 포함)마다 호스트를 조회해 내부망 IP면 차단하고, 조회한 주소로 연결을 고정해
+/// DNS 재바인딩을 막습니다. HTML이 아니면 빈 메타데이터를 반환합니다.
+/// 내부망 주소는 `RetroUrlInvalid`, 그 외 통신 실패는 `InternalError`를 반환합니다.
+pub async fn fetch_link_metadata(url: &Url) -> Result<LinkMetadata, AppError> {
+    let mut current = url.clone();
+
+    for _ in 0..=LINK_PREVIEW_MAX_REDIRECTS {
+        let addrs = resolve_public_addrs(&current).await?;
+        let mut builder = reqwest::Client::builder()
+            .timeout(Duration::from_secs(LINK_PREVIEW_TIMEOUT_SECS))
+            .redirect(redirect::Policy::none())
+            // 환경 변수 프록시를 거치면 고정한 주소가 아닌 프록시가 호스트를 다시 조회하므로 사용하지 않음
+            .no_proxy()
+            .user_agent(LINK_PREVIEW_USER_AGENT);
+        if let Some(host) = current.host_str() {
+            if literal_ip(host).is_none() {
+                builder = builder.resolve_to_addrs(host, &addrs);
+            }
+        }
+        let client = builder.build().map_err(|e| {
+            AppError::InternalError(format!("미리보기 클라이언트 생성 실패: {}", e))
+        })?;
+
+        let mut response = client
+            .get(current.clone())
+            .header(header::ACCEPT, "text/html,application/xhtml+xml")
+            .send()
+            .await
+            .map_err(|e| AppError::InternalError(format!("미리보기 요청 실패: {}", e)))?;
+
+        // 리다이렉트는 다음 URL을 다시 검증한 뒤 따라감
+        if response.status().is_redirection() {
+            let location = response
+                .headers()
+                .get(header::LOCATION)
+                .and_then(|value| value.to_str().ok())
+                .ok_or_else(|| {
+                    AppError::InternalError("리다이렉트 위치가 없습니다.".to_string())
+                })?;
+            let next = current.join(location).map_err(|_| {
+                AppError::InternalError("리다이렉트 위치가 올바르지 않습니다.".to_string())
+            })?;
+            current = parse_preview_url(next.as_str())?;
+            continue;
+        }
+
+        if !response.status().is_success() {
+            return Err(AppError::InternalError(format!(
+                "미리보기 요청 실패: HTTP {}",
+                response.status()
+            )));
+        }
+
+        let is_html = response
+            .headers()
+            .get(header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|content_type| content_type.contains("html"));
+        if !is_html {
+            return Ok(LinkMetadata::default());
+        }
+
+        // 최대 크기까지만 읽음
+        let mut body: Vec<u8> = Vec::new();
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|e| AppError::InternalError(format!("미리보기 응답 읽기 실패: {}", e)))?
+        {
+            let remaining = LINK_PREVIEW_MAX_BYTES - body.len();
+            body.extend_from_slice(&chunk[..chunk.len().min(remaining)]);
+            if body.len() >= LINK_PREVIEW_MAX_BYTES {
+                break;
+            }
+        }
+
+        return Ok(parse_open_graph(&String::from_utf8_lossy(&body), &current));
+    }
+
+    Err(AppError::InternalError(
+        "리다이렉트 횟수를 초과했습니다.".to_string(),
+    ))
+}
+
+/// `<meta ...>` 태그 패턴
+fn meta_tag_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?is)<meta\s([^>]*)>").expect("meta 태그 정규식이 올바르지 않습니다")
+    })
+}
+
+/// 태그 속성 패턴 (name="value" / name='value')
+fn attribute_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r#"(?i)([a-z:_-]+)\s*=\s*(?:"([^"]*)"|'([^']*)')"#)
+            .expect("속성 정규식이 올바르지 않습니다")
+    })
+}
+
+/// `<title>` 태그 패턴
+fn title_tag_pattern() -> &'static Regex {
+    static PATTERN: OnceLock<Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| {
+        Regex::new(r"(?is)<title[^>]*>(.*?)</title>")
+            .expect("title 태그 정규식이 올바르지 않습니다")
+    })
+}
+
+/// HTML에서 OpenGraph 메타데이터 추출
+///
+/// og:* 를 우선하고, 없으면 twitter:* / description 메타 / `<title>` 순으로 대체합니다.
+/// 상대 경로 이미지는 페이지 URL 기준 절대 경로로 변환합니다.
+pub fn parse_open_graph(html: &str, page_url: &Url) -> LinkMetadata {
+    let mut og_title = None;
+    let mut og_description = None;
+    let mut og_image = None;
+    let mut twitter_title = None;
+    let mut twitter_description = None;
+    let mut twitter_image = None;
+    let mut meta_description = None;
+
+    for tag in meta_tag_pattern().captures_iter(html) {
+        let mut key = None;
+        let mut content = None;
+        for attr in attribute_pattern().captures_iter(&tag[1]) {
+            let value = attr
+                .get(2)
+                .or_else(|| attr.get(3))
+                .map(|m| m.as_str())
+                .unwrap_or_default();
+            match attr[1].to_ascii_lowercase().as_str() {
+                "property" | "name" => key = Some(value.to_ascii_lowercase()),
+                "content" => content = Some(value),
+                _ => {}
+            }
+        }
+
+        let (Some(key), Some(content)) = (key, content) else {
+            continue;
+        };
+        let slot = match key.as_str() {
+            "og:title" => &mut og_title,
+            "og:description" => &mut og_description,
+            "og:image" | "og:image:url" => &mut og_image,
+            "twitter:title" => &mut twitter_title,
+            "twitter:description" => &mut twitter_description,
+            "twitter:image" => &mut twitter_image,
+            "description" => &mut meta_description,
+            _ => continue,
+        };
+        if slot.is_none() {
+            *slot = clean_text(content);
+        }
+    }
+
+    let page_title = title_tag_pattern()
+        .captures(html)
+        .and_then(|caps| clean_text(&caps[1]));
+
+    LinkMetadata {
+        title: og_title.or(twitter_title).or(page_title),
+        description: og_description.or(twitter_description).or(meta_description),
+        image: og_image
+            .or(twitter_image)
+            .and_then(|image| page_url.join(&image).ok())
+            .filter(|image| matches!(image.scheme(), "http" | "https"))
+            .map(String::from),
+    }
+}
+
+/// HTML 엔티티 디코딩, 공백 정리, 길이 제한 (비어 있으면 None)
+fn clean_text(raw: &str) -> Option<String> {
+    let decoded = raw
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&#x27;", "'")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&");
+    let collapsed = decoded.split_whitespace().collect::<Vec<_>>().join(" ");
+    if collapsed.is_empty() {
+        return None;
+    }
+
+    Some(
+        collapsed
+            .chars()
+            .take(LINK_PREVIEW_TEXT_MAX_CHARS)
+            .collect(),
+    )
+}
+
+/// 크롤링 실패 시 기본 제목 (URL 호스트명)
+pub fn fallback_title(url: &Url) -> String {
+    url.host_str().unwrap_or_default().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page_url() -> Url {
+        Url::parse("https://blog.example.com/posts/1").unwrap()
+    }
+
+    #[test]
+    fn should_block_private_and_loopback_ips() {
+        for ip in [
+            "127.0.0.1",
+            "10.0.0.5",
+            "172.16.3.4",
+            "192.168.0.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "::1",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "198.18.0.1",
+            "198.19.255.254",
+            "240.0.0.1",
+            "255.255.255.254",
+            "64:ff9b::a00:1",
+        ] {
+            assert!(
+                is_blocked_ip(ip.parse().unwrap()),
+                "{} should be blocked",
+                ip
+            );
+        }
+        assert!(!is_blocked_ip("8.8.8.8".parse().unwrap()));
+        assert!(!is_blocked_ip("198.20.0.1".parse().unwrap()));
+        assert!(!is_blocked_ip("2606:4700::1111".parse().unwrap()));
+    }
+
+    #[test]
+    fn should_reject_internal_or_non_http_urls() {
+        for url in [
+            "http://localhost:8080/admin",
+            "http://api.localhost/",
+            "http://127.0.0.1/",
+            "http://[::1]/",
+            "ftp://example.com/file",
+            "not a url",
+        ] {
+            assert!(
+                matches!(parse_preview_url(url), Err(AppError::RetroUrlInvalid(_))),
+                "{} should be rejected",
+                url
+            );
+        }
+        assert!(parse_preview_url("https://github.com/YAPP-Github").is_ok());
+    }
+
+    #[test]
+    fn should_extract_open_graph_metadata() {
+        // Arrange
+        let html = r#"
+            <html><head>
+            <title>페이지 제목</title>
+            <meta property="og:title" content="회고 잘하는 법 &amp; 팁" />
+            <meta content='KPT 회고 가이드' property='og:description'>
+            <meta property="og:image" content="/images/cover.png">
+            </head></html>
+        "#;
+
+        // Act
+        let meta = parse_open_graph(html, &page_url());
+
+        // Assert
+        assert_eq!(meta.title.as_deref(), Some("회고 잘하는 법 & 팁"));
+        assert_eq!(meta.description.as_deref(), Some("KPT 회고 가이드"));
+        assert_eq!(
+            meta.image.as_deref(),
+            Some("https://blog.example.com/images/cover.png")
+        );
+    }
+
+    #[test]
+    fn should_fall_back_to_title_tag_and_description_meta() {
+        // Arrange
+        let html = r#"<title>
+            팀 블로그
+        </title><meta name="description" content="설명입니다">"#;
+
+        // Act
+        let meta = parse_open_graph(html, &page_url());
+
+        // Assert
+        assert_eq!(meta.title.as_deref(), Some("팀 블로그"));
+        assert_eq!(meta.description.as_deref(), Some("설명입니다"));
+        assert!(meta.image.is_none());
+    }
+
+    #[test]
+    fn should_use_host_as_fallback_title() {
+        assert_eq!(fallback_title(&page_url()), "blog.example.com");
+    }
+}
@@ -1,4 +1,12 @@
+pub mod analysis_progress;
+pub mod calendar;
 pub mod dto;
 pub mod entity;
 pub mod handler;
+pub mod link_preview;
+pub mod participation;
 pub mod service;
+pub mod slack;
+pub mod snapshot;
+pub mod summary_mail;
+pub mod typing;
@@ -0,0 +1,212 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+/// Excel이 UTF-8 CSV를 인식하도록 파일 앞에 붙이는 BOM
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// 참여 통계 CSV 헤더 (행 필드 순서와 동일)
+const PARTICIPATION_CSV_HEADERS: [&str; 7] = [
+    "회고 ID",
+    "회고 제목",
+    "회고 일시",
+    "참여자 수",
+    "제출 수",
+    "제출률(%)",
+    "평균 답변 길이",
+];
+
+/// 회고 하나의 참여 통계 행
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ParticipationRow {
+    pub retrospect_id: i64,
+    pub title: String,
+    /// 회고 일시 (회고 타임존 기준, "YYYY-MM-DD HH:MM")
+    pub start_time: String,
+    pub participant_count: usize,
+    pub submitted_count: usize,
+    /// 제출률 (소수점 첫째 자리까지, 참여자가 없으면 0)
+    pub submission_rate: f64,
+    /// 작성된 답변의 평균 글자 수 (소수점 첫째 자리까지, 답변이 없으면 0)
+    pub average_answer_length: f64,
+}
+
+impl ParticipationRow {
+    /// 참여자/제출 수와 작성된 답변 글자 수 목록으로 행 생성
+    pub fn new(
+        retrospect_id: i64,
+        title: String,
+        start_time: NaiveDateTime,
+        participant_count: usize,
+        submitted_count: usize,
+        answer_lengths: &[usize],
+    ) -> Self {
+        let submission_rate = if participant_count == 0 {
+            0.0
+        } else {
+            round_one_decimal(submitted_count as f64 * 100.0 / participant_count as f64)
+        };
+        let average_answer_length = if answer_lengths.is_empty() {
+            0.0
+        } else {
+            round_one_decimal(
+                answer_lengths.iter().sum::<usize>() as f64 / answer_lengths.len() as f64,
+            )
+        };
+
+        Self {
+            retrospect_id,
+            title,
+            start_time: start_time.format("%Y-%m-%d %H:%M").to_string(),
+            participant_count,
+            submitted_count,
+            submission_rate,
+            average_answer_length,
+        }
+    }
+}
+
+fn round_one_decimal(value: f64) -> f64 {
+    (value * 10.0).round() / 10.0
+}
+
+/// 스프레드시트가 수식으로 해석하는 시작 문자 (OWASP CSV Injection 권고)
+const FORMULA_PREFIXES: [char; 6] = ['=', '+', '-', '@', '\t', '\r'];
+
+/// 사용자 입력 셀이 수식으로 실행되지 않도록 수식 시작 문자 앞에 `'`를 붙임
+fn neutralize_formula(value: &str) -> String {
+    if value.starts_with(FORMULA_PREFIXES) {
+        format!("'{}", value)
+    } else {
+        value.to_string()
+    }
+}
+
+/// 참여 통계 CSV 생성 (UTF-8 BOM + 헤더 + 회고별 행)
+///
+/// 행이 없어도 헤더는 항상 포함합니다.
+/// 회고 제목처럼 사용자가 입력한 셀은 수식 주입을 막기 위해 `neutralize_formula`를 거칩니다.
+pub fn build_participation_csv(rows: &[ParticipationRow]) -> Result<Vec<u8>, csv::Error> {
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(UTF8_BOM.to_vec());
+
+    writer.write_record(PARTICIPATION_CSV_HEADERS)?;
+    for row in rows {
+        writer.serialize(ParticipationRow {
+            title: neutralize_formula(&row.title),
+            ..row.clone()
+        })?;
+    }
+
+    writer
+        .into_inner()
+        .map_err(|e| csv::Error::from(e.into_error()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start_time() -> NaiveDateTime {
+        NaiveDateTime::parse_from_str("2026-01-20 14:00:00", "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn should_calculate_submission_rate_and_average_length() {
+        // Arrange & Act
+        let row = ParticipationRow::new(1, "1월 회고".to_string(), start_time(), 3, 2, &[10, 15]);
+
+        // Assert
+        assert_eq!(row.start_time, "2026-01-20 14:00");
+        assert_eq!(row.submission_rate, 66.7);
+        assert_eq!(row.average_answer_length, 12.5);
+    }
+
+    #[test]
+    fn should_use_zero_when_no_participants_or_answers() {
+        // Arrange & Act
+        let row = ParticipationRow::new(1, "빈 회고".to_string(), start_time(), 0, 0, &[]);
+
+        // Assert
+        assert_eq!(row.submission_rate, 0.0);
+        assert_eq!(row.average_answer_length, 0.0);
+    }
+
+    #[test]
+    fn should_write_bom_and_header_only_when_no_rows() {
+        // Arrange & Act
+        let csv = build_participation_csv(&[]).unwrap();
+
+        // Assert
+        assert!(csv.starts_with(UTF8_BOM));
+        let body = String::from_utf8(csv[UTF8_BOM.len()..].to_vec()).unwrap();
+        assert_eq!(
+            body,
+            "회고 ID,회고 제목,회고 일시,참여자 수,제출 수,제출률(%),평균 답변 길이\n"
+        );
+    }
+
+    #[test]
+    fn should_write_row_per_retrospect() {
+        // Arrange
+        let rows = vec![ParticipationRow::new(
+            7,
+            "스프린트, 회고".to_string(),
+            start_time(),
+            4,
+            4,
+            &[20],
+        )];
+
+        // Act
+        let csv = build_participation_csv(&rows).unwrap();
+
+        // Assert
+        let body = String::from_utf8(csv[UTF8_BOM.len()..].to_vec()).unwrap();
+        let lines: Vec<&str> = body.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[1],
+            "7,\"스프린트, 회고\",2026-01-20 14:00,4,4,100.0,20.0"
+        );
+    }
+
+    #[test]
+    fn should_prefix_formula_titles_with_quote() {
+        // Arrange
+        let rows: Vec<ParticipationRow> = [
+            "=HYPERLINK(\"http://evil\")",
+            "+1",
+            "-1",
+            "@SUM(A1)",
+            "팀-회고",
+        ]
+        .iter()
+        .enumerate()
+        .map(|(i, title)| {
+            ParticipationRow::new(i as i64, title.to_string(), start_time(), 1, 1, &[5])
+        })
+        .collect();
+
+        // Act
+        let csv = build_participation_csv(&rows).unwrap();
+
+        // Assert
+        let body = String::from_utf8(csv[UTF8_BOM.len()..].to_vec()).unwrap();
+        let titles: Vec<String> = csv::ReaderBuilder::new()
+            .from_reader(body.as_bytes())
+            .records()
+            .map(|record| record.unwrap()[1].to_string())
+            .collect();
+        assert_eq!(
+            titles,
+            vec![
+                "'=HYPERLINK(\"http://evil\")",
+                "'+1",
+                "'-1",
+                "'@SUM(A1)",
+                "팀-회고"
+            ]
+        );
+    }
+}
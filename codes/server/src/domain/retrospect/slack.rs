@@ -0,0 +1,189 @@
+use std::time::Duration;
+
+use serde_json::{json, Value};
+
+use super::dto::EmotionRankItem;
+use crate::utils::error::AppError;
+
+/// Slack Incoming Webhook URL 접두사
+pub const SLACK_WEBHOOK_URL_PREFIX: &str = "https://hooks.slack.com/";
+
+/// Slack 전송 요청 타임아웃
+const SLACK_REQUEST_TIMEOUT_SECS: u64 = 5;
+
+/// Slack section 블록 텍스트 최대 길이 (Block Kit 제한 3000자)
+const SLACK_SECTION_TEXT_MAX_CHARS: usize = 3000;
+
+/// Slack Incoming Webhook URL 형식 확인
+pub fn is_slack_webhook_url(url: &str) -> bool {
+    url.starts_with(SLACK_WEBHOOK_URL_PREFIX) && url.len() > SLACK_WEBHOOK_URL_PREFIX.len()
+}
+
+/// Slack mrkdwn 제어 문자 이스케이프 (&, <, >)
+pub fn escape_mrkdwn(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// section 블록 텍스트 길이 제한 (초과 시 말줄임)
+fn truncate_section_text(text: String) -> String {
+    if text.chars().count() <= SLACK_SECTION_TEXT_MAX_CHARS {
+        return text;
+    }
+    let truncated: String = text
+        .chars()
+        .take(SLACK_SECTION_TEXT_MAX_CHARS - 3)
+        .collect();
+    format!("{}...", truncated)
+}
+
+/// 회고 분석 결과 Slack Block Kit 메시지 생성
+///
+/// 헤더(회고 제목), 회고방 컨텍스트, 팀 인사이트, 감정 순위 블록으로 구성합니다.
+pub fn build_analysis_message(
+    room_name: &str,
+    retrospect_title: &str,
+    team_insight: &str,
+    emotion_rank: &[EmotionRankItem],
+) -> Value {
+    let emotion_lines = emotion_rank
+        .iter()
+        .map(|item| {
+            format!(
+                "{}. *{}* ({}회) - {}",
+                item.rank,
+                escape_mrkdwn(&item.label),
+                item.count,
+                escape_mrkdwn(&item.description)
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    json!({
+        "text": format!("[{}] {} 회고 분석이 완료되었습니다.", room_name, retrospect_title),
+        "blocks": [
+            {
+                "type": "header",
+                "text": { "type": "plain_text", "text": format!("{} 회고 분석 결과", retrospect_title) }
+            },
+            {
+                "type": "context",
+                "elements": [
+                    { "type": "mrkdwn", "text": format!("회고방: *{}*", escape_mrkdwn(room_name)) }
+                ]
+            },
+            {
+                "type": "section",
+                "text": {
+                    "type": "mrkdwn",
+                    "text": truncate_section_text(format!("*팀 인사이트*\n{}", escape_mrkdwn(team_insight)))
+                }
+            },
+            { "type": "divider" },
+            {
+                "type": "section",
+                "text": {
+                    "type": "mrkdwn",
+                    "text": truncate_section_text(format!("*감정 순위*\n{}", emotion_lines))
+                }
+            }
+        ]
+    })
+}
+
+/// Slack Incoming Webhook으로 메시지 전송
+pub async fn send_slack_message(webhook_url: &str, payload: &Value) -> Result<(), AppError> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(SLACK_REQUEST_TIMEOUT_SECS))
+        .build()
+        .map_err(|e| AppError::InternalError(format!("Slack 클라이언트 생성 실패: {}", e)))?;
+
+    let response = client
+        .post(webhook_url)
+        .json(payload)
+        .send()
+        .await
+        .map_err(|e| AppError::InternalError(format!("Slack 전송 실패: {}", e)))?;
+
+    if !response.status().is_success() {
+        return Err(AppError::InternalError(format!(
+            "Slack 전송 실패: HTTP {}",
+            response.status()
+        )));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn emotion(rank: i32, label: &str, count: i32) -> EmotionRankItem {
+        EmotionRankItem {
+            rank,
+            label: label.to_string(),
+            description: format!("{} 설명", label),
+            count,
+        }
+    }
+
+    #[test]
+    fn should_accept_only_slack_webhook_urls() {
+        assert!(is_slack_webhook_url(
+            "https://hooks.slack.com/services/T000/B000/XXXX"
+        ));
+        assert!(!is_slack_webhook_url("https://hooks.slack.com/"));
+        assert!(!is_slack_webhook_url(
+            "http://hooks.slack.com/services/T000"
+        ));
+        assert!(!is_slack_webhook_url(
+            "https://example.com/hooks.slack.com/"
+        ));
+    }
+
+    #[test]
+    fn should_escape_mrkdwn_control_characters() {
+        assert_eq!(
+            escape_mrkdwn("<b> & <!here>"),
+            "&lt;b&gt; &amp; &lt;!here&gt;"
+        );
+    }
+
+    #[test]
+    fn should_build_block_kit_message_with_insight_and_emotions() {
+        // Act
+        let message = build_analysis_message(
+            "백엔드팀",
+            "스프린트 3",
+            "협업이 <좋았습니다>",
+            &[emotion(1, "뿌듯", 5), emotion(2, "피로", 3)],
+        );
+
+        // Assert
+        let blocks = message["blocks"].as_array().unwrap();
+        assert_eq!(blocks[0]["type"], "header");
+        assert_eq!(blocks[0]["text"]["text"], "스프린트 3 회고 분석 결과");
+        assert!(blocks[2]["text"]["text"]
+            .as_str()
+            .unwrap()
+            .contains("협업이 &lt;좋았습니다&gt;"));
+        let emotions = blocks[4]["text"]["text"].as_str().unwrap();
+        assert!(emotions.contains("1. *뿌듯* (5회)"));
+        assert!(emotions.contains("2. *피로* (3회)"));
+        assert!(message["text"].as_str().unwrap().contains("스프린트 3"));
+    }
+
+    #[test]
+    fn should_truncate_long_insight_to_section_limit() {
+        // Act
+        let message = build_analysis_message("방", "회고", &"가".repeat(5000), &[]);
+
+        // Assert
+        let text = message["blocks"][2]["text"]["text"].as_str().unwrap();
+        assert_eq!(text.chars().count(), SLACK_SECTION_TEXT_MAX_CHARS);
+        assert!(text.ends_with("..."));
+    }
+}
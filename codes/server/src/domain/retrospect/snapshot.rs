@@ -0,0 +1,364 @@
+use std::io::{self, Write};
+
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::member::entity::member_retro::RetrospectStatus;
+use crate::domain::retrospect::entity::retrospect::RetrospectMethod;
+use crate::utils::error::AppError;
+
+/// 스냅샷 포맷 식별자
+pub const SNAPSHOT_FORMAT: &str = "moalog.retro-room-snapshot";
+
+/// 스냅샷 포맷 버전 (필드 구조가 바뀌면 증가, 복원 시 호환성 판단 기준)
+pub const SNAPSHOT_VERSION: u32 = 2;
+
+/// 스냅샷 일시 표기 형식 (UTC 또는 회고 타임존 벽시계 시간)
+const SNAPSHOT_DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S";
+
+/// 회고방 설정 스냅샷 (초대 코드, Slack Webhook 등 비밀 값은 제외)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotRoom {
+    pub retro_room_id: i64,
+    pub title: String,
+    pub description: Option<String>,
+    /// 초대 코드로 참여한 멤버에게 부여할 역할 (OWNER/MODERATOR/MEMBER)
+    pub invite_grant_role: String,
+    pub max_active_retrospects: Option<i32>,
+    pub unique_retrospect_title: bool,
+    pub created_at: String,
+}
+
+/// 회고방 멤버 스냅샷
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotMember {
+    pub member_id: i64,
+    pub nickname: String,
+    /// 복원 시 회원 매칭에 사용하는 이메일 (이메일 공유를 허용한 멤버와 스냅샷 생성자만 포함)
+    #[serde(default)]
+    pub email: Option<String>,
+    /// 방 내 역할 (OWNER/MODERATOR/MEMBER)
+    pub role: String,
+    pub joined_at: String,
+}
+
+/// 회고 참여자 스냅샷
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotParticipant {
+    /// 탈퇴한 회원이면 null
+    pub member_id: Option<i64>,
+    pub status: RetrospectStatus,
+    pub submitted_at: Option<String>,
+    pub personal_insight: Option<String>,
+}
+
+/// 답변 댓글 스냅샷
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotComment {
+    pub member_id: i64,
+    pub content: String,
+    pub created_at: String,
+}
+
+/// 답변 좋아요 스냅샷
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotLike {
+    pub member_id: i64,
+    pub created_at: Option<String>,
+}
+
+/// 답변 스냅샷
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotResponse {
+    pub response_id: i64,
+    /// 작성자 회원 ID (탈퇴했거나 매핑이 없으면 null)
+    pub member_id: Option<i64>,
+    pub question: String,
+    pub content: String,
+    pub like_count: i32,
+    pub created_at: String,
+    pub updated_at: String,
+    pub comments: Vec<SnapshotComment>,
+    #[serde(default)]
+    pub likes: Vec<SnapshotLike>,
+}
+
+/// 회고 스냅샷
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotRetrospect {
+    pub retrospect_id: i64,
+    pub title: String,
+    pub retrospect_method: RetrospectMethod,
+    /// 커스텀 방식 질문 (기본 방식이면 null)
+    pub custom_questions: Option<Vec<String>>,
+    /// 커스텀 방식 질문별 placeholder (미설정이면 null, 이전 스냅샷 호환을 위해 생략 허용)
+    #[serde(default)]
+    pub custom_placeholders: Option<Vec<Option<String>>>,
+    /// 커스텀 방식 질문별 가중치 (미설정이면 null, 이전 스냅샷 호환을 위해 생략 허용)
+    #[serde(default)]
+    pub custom_weights: Option<Vec<Option<i32>>>,
+    /// 회고 시작 시각 (timezone 기준 벽시계 시간)
+    pub start_time: String,
+    pub timezone: String,
+    pub auto_analyze: bool,
+    pub min_answer_length: i32,
+    pub questions_finalized: bool,
+    pub insight: Option<String>,
+    pub created_at: String,
+    pub participants: Vec<SnapshotParticipant>,
+    pub responses: Vec<SnapshotResponse>,
+}
+
+/// 회고방 스냅샷 전체 문서 (복원 시 역직렬화 대상)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RoomSnapshot {
+    pub format: String,
+    pub version: u32,
+    /// 스냅샷 생성 시각 (UTC)
+    pub generated_at: String,
+    pub room: SnapshotRoom,
+    pub members: Vec<SnapshotMember>,
+    pub retrospects: Vec<SnapshotRetrospect>,
+}
+
+/// 스냅샷 일시 문자열 표기
+pub fn format_snapshot_datetime(value: NaiveDateTime) -> String {
+    value.format(SNAPSHOT_DATETIME_FORMAT).to_string()
+}
+
+/// 스냅샷 일시 문자열 파싱
+pub fn parse_snapshot_datetime(field: &str, value: &str) -> Result<NaiveDateTime, AppError> {
+    NaiveDateTime::parse_from_str(value, SNAPSHOT_DATETIME_FORMAT).map_err(|_| {
+        AppError::BadRequest(format!(
+            "스냅샷의 {} 값이 올바른 일시 형식이 아닙니다: {}",
+            field, value
+        ))
+    })
+}
+
+/// 스냅샷 JSON 파싱
+///
+/// 포맷과 버전을 먼저 확인해 다른 버전의 스냅샷은 구조 검사 전에 `SnapshotVersionMismatch`로 거부합니다.
+pub fn parse_snapshot(value: serde_json::Value) -> Result<RoomSnapshot, AppError> {
+    let format = value.get("format").and_then(|v| v.as_str());
+    let version = value.get("version").and_then(|v| v.as_u64());
+    if format != Some(SNAPSHOT_FORMAT) || version != Some(u64::from(SNAPSHOT_VERSION)) {
+        return Err(AppError::SnapshotVersionMismatch(format!(
+            "지원하지 않는 스냅샷입니다. ({} v{} 스냅샷만 복원할 수 있습니다.)",
+            SNAPSHOT_FORMAT, SNAPSHOT_VERSION
+        )));
+    }
+
+    serde_json::from_value(value)
+        .map_err(|e| AppError::BadRequest(format!("스냅샷 형식이 올바르지 않습니다: {}", e)))
+}
+
+/// 회고방 스냅샷 JSON 스트리밍 작성기
+///
+/// 헤더(포맷, 버전, 방 설정, 멤버)를 먼저 쓰고 회고를 하나씩 이어 쓰므로
+/// 전체 회고를 메모리에 모으지 않고도 `RoomSnapshot`과 같은 구조의 문서를 만듭니다.
+pub struct SnapshotWriter<W: Write> {
+    out: W,
+    retrospect_count: usize,
+}
+
+impl<W: Write> SnapshotWriter<W> {
+    /// 헤더를 쓰고 `retrospects` 배열을 연 상태로 작성기 생성
+    pub fn begin(
+        mut out: W,
+        generated_at: &str,
+        room: &SnapshotRoom,
+        members: &[SnapshotMember],
+    ) -> io::Result<Self> {
+        out.write_all(b"{\"format\":")?;
+        serde_json::to_writer(&mut out, SNAPSHOT_FORMAT)?;
+        write!(out, ",\"version\":{},\"generatedAt\":", SNAPSHOT_VERSION)?;
+        serde_json::to_writer(&mut out, generated_at)?;
+        out.write_all(b",\"room\":")?;
+        serde_json::to_writer(&mut out, room)?;
+        out.write_all(b",\"members\":")?;
+        serde_json::to_writer(&mut out, members)?;
+        out.write_all(b",\"retrospects\":[")?;
+        Ok(Self {
+            out,
+            retrospect_count: 0,
+        })
+    }
+
+    /// 회고 하나를 배열에 추가
+    pub fn push_retrospect(&mut self, retrospect: &SnapshotRetrospect) -> io::Result<()> {
+        if self.retrospect_count > 0 {
+            self.out.write_all(b",")?;
+        }
+        serde_json::to_writer(&mut self.out, retrospect)?;
+        self.retrospect_count += 1;
+        Ok(())
+    }
+
+    /// 배열과 문서를 닫고 내부 출력 반환
+    pub fn finish(mut self) -> io::Result<W> {
+        self.out.write_all(b"]}")?;
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_room() -> SnapshotRoom {
+        SnapshotRoom {
+            retro_room_id: 3,
+            title: "백엔드팀".to_string(),
+            description: Some("매주 금요일 회고".to_string()),
+            invite_grant_role: "MEMBER".to_string(),
+            max_active_retrospects: None,
+            unique_retrospect_title: false,
+            created_at: "2026-01-02T09:00:00".to_string(),
+        }
+    }
+
+    fn sample_retrospect(retrospect_id: i64) -> SnapshotRetrospect {
+        SnapshotRetrospect {
+            retrospect_id,
+            title: format!("스프린트 {}", retrospect_id),
+            retrospect_method: RetrospectMethod::Kpt,
+            custom_questions: None,
+            custom_placeholders: None,
+            custom_weights: None,
+            start_time: "2026-10-16T19:00:00".to_string(),
+            timezone: "Asia/Seoul".to_string(),
+            auto_analyze: false,
+            min_answer_length: 1,
+            questions_finalized: true,
+            insight: None,
+            created_at: "2026-10-10T09:00:00".to_string(),
+            participants: vec![SnapshotParticipant {
+                member_id: Some(1),
+                status: RetrospectStatus::Submitted,
+                submitted_at: Some("2026-10-16T20:00:00".to_string()),
+                personal_insight: None,
+            }],
+            responses: vec![SnapshotResponse {
+                response_id: retrospect_id * 10,
+                member_id: Some(1),
+                question: "좋았던 점은?".to_string(),
+                content: "배포가 \"빨라졌어요\"".to_string(),
+                like_count: 2,
+                created_at: "2026-10-16T19:10:00".to_string(),
+                updated_at: "2026-10-16T19:20:00".to_string(),
+                comments: vec![],
+                likes: vec![SnapshotLike {
+                    member_id: 1,
+                    created_at: None,
+                }],
+            }],
+        }
+    }
+
+    fn write_snapshot(retrospects: &[SnapshotRetrospect]) -> Vec<u8> {
+        let members = vec![SnapshotMember {
+            member_id: 1,
+            nickname: "제이".to_string(),
+            email: Some("jay@example.com".to_string()),
+            role: "OWNER".to_string(),
+            joined_at: "2026-01-02T09:00:00".to_string(),
+        }];
+        let mut writer =
+            SnapshotWriter::begin(Vec::new(), "2026-10-16T12:00:00", &sample_room(), &members)
+                .unwrap();
+        for retrospect in retrospects {
+            writer.push_retrospect(retrospect).unwrap();
+        }
+        writer.finish().unwrap()
+    }
+
+    #[test]
+    fn should_write_document_readable_as_room_snapshot() {
+        // Act
+        let bytes = write_snapshot(&[sample_retrospect(1), sample_retrospect(2)]);
+        let snapshot: RoomSnapshot = serde_json::from_slice(&bytes).unwrap();
+
+        // Assert
+        assert_eq!(snapshot.format, SNAPSHOT_FORMAT);
+        assert_eq!(snapshot.version, SNAPSHOT_VERSION);
+        assert_eq!(snapshot.generated_at, "2026-10-16T12:00:00");
+        assert_eq!(snapshot.room, sample_room());
+        assert_eq!(snapshot.members.len(), 1);
+        assert_eq!(
+            snapshot.retrospects,
+            vec![sample_retrospect(1), sample_retrospect(2)]
+        );
+    }
+
+    #[test]
+    fn should_write_empty_retrospect_array() {
+        // Act
+        let bytes = write_snapshot(&[]);
+        let json: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+        // Assert
+        assert_eq!(json["retrospects"].as_array().unwrap().len(), 0);
+        assert_eq!(json["room"]["retroRoomId"], 3);
+    }
+
+    #[test]
+    fn should_parse_written_snapshot() {
+        // Arrange
+        let bytes = write_snapshot(&[sample_retrospect(1)]);
+        let value: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+
+        // Act
+        let snapshot = parse_snapshot(value).unwrap();
+
+        // Assert
+        assert_eq!(snapshot.retrospects, vec![sample_retrospect(1)]);
+        assert_eq!(
+            snapshot.members[0].email.as_deref(),
+            Some("jay@example.com")
+        );
+    }
+
+    #[test]
+    fn should_reject_snapshot_with_other_version() {
+        // Arrange
+        let bytes = write_snapshot(&[]);
+        let mut value: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+        value["version"] = serde_json::json!(SNAPSHOT_VERSION + 1);
+
+        // Act
+        let err = parse_snapshot(value).unwrap_err();
+
+        // Assert
+        assert!(matches!(err, AppError::SnapshotVersionMismatch(_)));
+    }
+
+    #[test]
+    fn should_reject_snapshot_with_other_format() {
+        // Act
+        let err =
+            parse_snapshot(serde_json::json!({ "format": "other", "version": 1 })).unwrap_err();
+
+        // Assert
+        assert!(matches!(err, AppError::SnapshotVersionMismatch(_)));
+    }
+
+    #[test]
+    fn should_round_trip_snapshot_datetime() {
+        // Arrange
+        let value = parse_snapshot_datetime("createdAt", "2026-10-16T09:30:00").unwrap();
+
+        // Act & Assert
+        assert_eq!(format_snapshot_datetime(value), "2026-10-16T09:30:00");
+        assert!(parse_snapshot_datetime("createdAt", "2026-10-16 09:30").is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use std::collections::HashSet;
+
+use super::dto::RetrospectExportResponse;
+use crate::utils::markdown::{escape_markdown, markdown_to_html};
+
+/// 요약 메일에 표시하는 답변 한 줄 최대 글자 수
+pub const SUMMARY_ANSWER_MAX_CHARS: usize = 100;
+
+/// 요약 메일 발송 후보 (회고 참여자)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SummaryMailCandidate {
+    pub member_id: i64,
+    pub email: String,
+    /// 회원 알림 수신 설정
+    pub notification_enabled: bool,
+}
+
+/// 요약 메일 수신자 선정
+///
+/// 알림 수신을 끈 멤버, 회고방을 떠난 멤버, 회고방 구독 채널을 PUSH로 지정한 멤버, 이메일이 없는 멤버는 제외합니다.
+pub fn summary_mail_recipients(
+    candidates: &[SummaryMailCandidate],
+    room_member_ids: &HashSet<i64>,
+    push_subscriber_ids: &HashSet<i64>,
+) -> Vec<SummaryMailCandidate> {
+    candidates
+        .iter()
+        .filter(|c| c.notification_enabled)
+        .filter(|c| room_member_ids.contains(&c.member_id))
+        .filter(|c| !push_subscriber_ids.contains(&c.member_id))
+        .filter(|c| !c.email.trim().is_empty())
+        .cloned()
+        .collect()
+}
+
+/// 요약 메일 제목
+pub fn summary_mail_subject(retrospect_title: &str) -> String {
+    format!("[모아로그] {} 회고 요약", retrospect_title)
+}
+
+/// 답변 한 줄 요약 (줄바꿈을 공백으로 합치고, 최대 길이 초과 시 말줄임)
+fn summarize_answer(content: &str) -> String {
+    let single_line = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    if single_line.chars().count() <= SUMMARY_ANSWER_MAX_CHARS {
+        return single_line;
+    }
+    let truncated: String = single_line
+        .chars()
+        .take(SUMMARY_ANSWER_MAX_CHARS - 3)
+        .collect();
+    format!("{}...", truncated)
+}
+
+/// 회고 요약 Markdown 생성 (내보내기 데이터 기준)
+///
+/// 회고 제목, 팀 인사이트, 질문별 답변 요약 순으로 구성합니다.
+/// 사용자 입력은 모두 이스케이프하므로 답변 내용이 Markdown 서식으로 해석되지 않습니다.
+pub fn build_summary_markdown(export: &RetrospectExportResponse) -> String {
+    let mut markdown = format!(
+        "# {} 회고 요약\n\n- 회고방: {}\n- 참여자: {}명\n\n## 팀 인사이트\n\n",
+        escape_markdown(&export.title),
+        escape_markdown(&export.retro_room_name),
+        export.participants.len()
+    );
+
+    match export.insight.as_deref().map(str::trim) {
+        Some(insight) if !insight.is_empty() => {
+            let lines: Vec<String> = insight
+                .lines()
+                .map(|line| escape_markdown(line.trim_end()))
+                .collect();
+            markdown.push_str(&lines.join("  \n"));
+            markdown.push_str("\n\n");
+        }
+        _ => markdown.push_str("_아직 분석 결과가 없습니다._\n\n"),
+    }
+
+    markdown.push_str("## 질문별 답변\n");
+    for question in &export.questions {
+        markdown.push_str(&format!(
+            "\n### {}. {}\n\n",
+            question.index,
+            escape_markdown(&question.content)
+        ));
+        if question.answers.is_empty() {
+            markdown.push_str("_제출된 답변이 없습니다._\n");
+            continue;
+        }
+        for answer in &question.answers {
+            markdown.push_str(&format!(
+                "- **{}**: {}\n",
+                escape_markdown(&answer.author),
+                escape_markdown(&summarize_answer(&answer.content))
+            ));
+        }
+    }
+
+    markdown
+}
+
+/// 요약 Markdown을 메일용 HTML 문서로 변환
+pub fn build_summary_html(markdown: &str) -> String {
+    format!(
+        "<!DOCTYPE html>\n<html lang=\"ko\">\n<head><meta charset=\"utf-8\"></head>\n<body style=\"font-family: sans-serif; line-height: 1.6; color: #222;\">\n{}</body>\n</html>\n",
+        markdown_to_html(markdown)
+    )
+}
@@ -0,0 +1,329 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use axum::extract::ws::{Message, WebSocket};
+use tokio::sync::broadcast;
+use tracing::warn;
+
+use super::dto::{TypingClientMessage, TypingEvent, TypingEventType};
+
+/// 회고별 타이핑 이벤트 broadcast 채널 버퍼 크기
+const TYPING_CHANNEL_CAPACITY: usize = 64;
+
+/// 입력 중인 멤버
+#[derive(Debug, Clone)]
+struct Typist {
+    user_name: String,
+    /// 마지막 타이핑 시작 이벤트 수신 시각
+    last_seen: Instant,
+}
+
+/// 회고 하나의 타이핑 채널
+#[derive(Debug)]
+struct TypingChannel {
+    sender: broadcast::Sender<TypingEvent>,
+    typists: HashMap<i64, Typist>,
+}
+
+impl TypingChannel {
+    fn new() -> Self {
+        let (sender, _) = broadcast::channel(TYPING_CHANNEL_CAPACITY);
+        Self {
+            sender,
+            typists: HashMap::new(),
+        }
+    }
+
+    fn broadcast(
+        &self,
+        event_type: TypingEventType,
+        retrospect_id: i64,
+        member_id: i64,
+        user_name: &str,
+    ) {
+        // 구독자가 없으면 전송 실패는 무시
+        let _ = self.sender.send(TypingEvent {
+            event_type,
+            retrospect_id,
+            member_id,
+            user_name: user_name.to_string(),
+        });
+    }
+}
+
+/// 회고 답변 실시간 타이핑 인디케이터 (in-memory)
+///
+/// 회고별로 입력 중인 멤버를 기록하고 같은 회고 구독자에게 시작/중지 이벤트를 broadcast합니다.
+/// `idle_timeout` 동안 시작 이벤트가 다시 오지 않으면 `expire_idle`이 자동으로 중지 처리합니다.
+/// 상태는 영속화하지 않으며, Clone 시 내부 상태를 `Arc`로 공유하므로 AppState에 넣어 사용합니다.
+#[derive(Debug, Clone)]
+pub struct TypingIndicatorHub {
+    idle_timeout: Duration,
+    channels: Arc<Mutex<HashMap<i64, TypingChannel>>>,
+}
+
+impl TypingIndicatorHub {
+    pub fn new(idle_timeout: Duration) -> Self {
+        Self {
+            idle_timeout,
+            channels: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// 회고의 타이핑 이벤트 구독
+    pub fn subscribe(&self, retrospect_id: i64) -> broadcast::Receiver<TypingEvent> {
+        self.lock_channels()
+            .entry(retrospect_id)
+            .or_insert_with(TypingChannel::new)
+            .sender
+            .subscribe()
+    }
+
+    /// 타이핑 시작 (이미 입력 중이면 마지막 수신 시각만 갱신)
+    pub fn start_typing(&self, retrospect_id: i64, member_id: i64, user_name: &str) {
+        self.start_typing_at(retrospect_id, member_id, user_name, Instant::now());
+    }
+
+    /// 타이핑 중지 (입력 중이 아니었으면 무시)
+    pub fn stop_typing(&self, retrospect_id: i64, member_id: i64) {
+        let mut channels = self.lock_channels();
+        let Some(channel) = channels.get_mut(&retrospect_id) else {
+            return;
+        };
+
+        if let Some(typist) = channel.typists.remove(&member_id) {
+            channel.broadcast(
+                TypingEventType::TypingStop,
+                retrospect_id,
+                member_id,
+                &typist.user_name,
+            );
+        }
+    }
+
+    /// 시간 초과된 타이핑을 중지 처리하고 중지된 수를 반환
+    pub fn expire_idle(&self) -> usize {
+        self.expire_idle_at(Instant::now())
+    }
+
+    fn start_typing_at(&self, retrospect_id: i64, member_id: i64, user_name: &str, now: Instant) {
+        let mut channels = self.lock_channels();
+        let channel = channels
+            .entry(retrospect_id)
+            .or_insert_with(TypingChannel::new);
+
+        match channel.typists.get_mut(&member_id) {
+            Some(typist) => typist.last_seen = now,
+            None => {
+                channel.typists.insert(
+                    member_id,
+                    Typist {
+                        user_name: user_name.to_string(),
+                        last_seen: now,
+                    },
+                );
+                channel.broadcast(
+                    TypingEventType::TypingStart,
+                    retrospect_id,
+                    member_id,
+                    user_name,
+                );
+            }
+        }
+    }
+
+    fn expire_idle_at(&self, now: Instant) -> usize {
+        let mut channels = self.lock_channels();
+        let mut expired_count = 0;
+
+        for (retrospect_id, channel) in channels.iter_mut() {
+            let expired: Vec<(i64, Typist)> = channel
+                .typists
+                .iter()
+                .filter(|(_, typist)| now.duration_since(typist.last_seen) >= self.idle_timeout)
+                .map(|(member_id, typist)| (*member_id, typist.clone()))
+                .collect();
+
+            for (member_id, typist) in expired {
+                channel.typists.remove(&member_id);
+                channel.broadcast(
+                    TypingEventType::TypingStop,
+                    *retrospect_id,
+                    member_id,
+                    &typist.user_name,
+                );
+                expired_count += 1;
+            }
+        }
+
+        // 구독자도 입력 중인 멤버도 없는 채널은 정리 (메모리 누수 방지)
+        channels.retain(|_, channel| {
+            channel.sender.receiver_count() > 0 || !channel.typists.is_empty()
+        });
+
+        expired_count
+    }
+
+    fn lock_channels(&self) -> std::sync::MutexGuard<'_, HashMap<i64, TypingChannel>> {
+        match self.channels.lock() {
+            Ok(guard) => guard,
+            Err(poisoned) => {
+                warn!("타이핑 인디케이터 mutex poisoned, 복구합니다");
+                poisoned.into_inner()
+            }
+        }
+    }
+}
+
+/// 타이핑 WebSocket 세션 처리
+///
+/// 클라이언트의 시작/중지 메시지를 허브에 반영하고, 같은 회고의 다른 멤버 이벤트를 전달합니다.
+/// 연결이 끊기면 해당 멤버의 타이핑을 중지 처리합니다.
+pub async fn run_typing_session(
+    mut socket: WebSocket,
+    hub: TypingIndicatorHub,
+    retrospect_id: i64,
+    member_id: i64,
+    user_name: String,
+) {
+    let mut events = hub.subscribe(retrospect_id);
+
+    loop {
+        tokio::select! {
+            incoming = socket.recv() => match incoming {
+                Some(Ok(Message::Text(text))) => {
+                    match serde_json::from_str::<TypingClientMessage>(&text) {
+                        Ok(message) => match message.event_type {
+                            TypingEventType::TypingStart => {
+                                hub.start_typing(retrospect_id, member_id, &user_name)
+                            }
+                            TypingEventType::TypingStop => hub.stop_typing(retrospect_id, member_id),
+                        },
+                        Err(e) => warn!(
+                            retrospect_id = retrospect_id,
+                            member_id = member_id,
+                            error = %e,
+                            "알 수 없는 타이핑 메시지 무시"
+                        ),
+                    }
+                }
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                // ping/pong/binary 메시지는 무시
+                Some(Ok(_)) => {}
+            },
+            event = events.recv() => match event {
+                // 자신의 이벤트는 전달하지 않음
+                Ok(event) if event.member_id == member_id => {}
+                Ok(event) => {
+                    let Ok(payload) = serde_json::to_string(&event) else {
+                        continue;
+                    };
+                    if socket.send(Message::Text(payload)).await.is_err() {
+                        break;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(skipped)) => warn!(
+                    retrospect_id = retrospect_id,
+                    skipped = skipped,
+                    "타이핑 이벤트 수신 지연으로 일부 이벤트 누락"
+                ),
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+        }
+    }
+
+    hub.stop_typing(retrospect_id, member_id);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const IDLE_TIMEOUT: Duration = Duration::from_secs(5);
+
+    #[test]
+    fn should_broadcast_start_only_once_while_typing() {
+        // Arrange
+        let hub = TypingIndicatorHub::new(IDLE_TIMEOUT);
+        let mut events = hub.subscribe(1);
+        let now = Instant::now();
+
+        // Act
+        hub.start_typing_at(1, 10, "제이슨", now);
+        hub.start_typing_at(1, 10, "제이슨", now + Duration::from_secs(1));
+
+        // Assert
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.event_type, TypingEventType::TypingStart);
+        assert_eq!(event.member_id, 10);
+        assert_eq!(event.user_name, "제이슨");
+        assert!(events.try_recv().is_err());
+    }
+
+    #[test]
+    fn should_broadcast_stop_when_member_stops_typing() {
+        // Arrange
+        let hub = TypingIndicatorHub::new(IDLE_TIMEOUT);
+        let mut events = hub.subscribe(1);
+        hub.start_typing(1, 10, "제이슨");
+        events.try_recv().unwrap();
+
+        // Act
+        hub.stop_typing(1, 10);
+        hub.stop_typing(1, 10);
+
+        // Assert
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.event_type, TypingEventType::TypingStop);
+        assert!(events.try_recv().is_err());
+    }
+
+    #[test]
+    fn should_not_deliver_events_to_other_retrospects() {
+        // Arrange
+        let hub = TypingIndicatorHub::new(IDLE_TIMEOUT);
+        let mut other_events = hub.subscribe(2);
+
+        // Act
+        hub.start_typing(1, 10, "제이슨");
+
+        // Assert
+        assert!(other_events.try_recv().is_err());
+    }
+
+    #[test]
+    fn should_auto_stop_after_idle_timeout() {
+        // Arrange
+        let hub = TypingIndicatorHub::new(IDLE_TIMEOUT);
+        let mut events = hub.subscribe(1);
+        let now = Instant::now();
+        hub.start_typing_at(1, 10, "제이슨", now);
+        hub.start_typing_at(1, 20, "루시", now + Duration::from_secs(3));
+        events.try_recv().unwrap();
+        events.try_recv().unwrap();
+
+        // Act
+        let expired = hub.expire_idle_at(now + IDLE_TIMEOUT);
+
+        // Assert
+        assert_eq!(expired, 1);
+        let event = events.try_recv().unwrap();
+        assert_eq!(event.event_type, TypingEventType::TypingStop);
+        assert_eq!(event.member_id, 10);
+        assert!(events.try_recv().is_err());
+    }
+
+    #[test]
+    fn should_drop_channel_without_subscribers_or_typists() {
+        // Arrange
+        let hub = TypingIndicatorHub::new(IDLE_TIMEOUT);
+        drop(hub.subscribe(1));
+
+        // Act
+        hub.expire_idle();
+
+        // Assert
+        assert!(hub.lock_channels().is_empty());
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use validator::Validate;
+
+/// 업로드 서명 URL 발급 요청
+#[derive(Debug, Deserialize, Validate, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PresignUploadRequest {
+    /// 원본 파일 이름 (확장자 포함)
+    #[validate(length(min = 1, max = 255, message = "fileName은 1~255자여야 합니다."))]
+    pub file_name: String,
+    /// 파일 MIME 타입 (image/jpeg, image/png, image/gif, image/webp)
+    #[validate(length(min = 1, max = 100, message = "contentType은 1~100자여야 합니다."))]
+    pub content_type: String,
+    /// 파일 크기 (바이트)
+    #[validate(range(min = 1, message = "size는 1 이상이어야 합니다."))]
+    pub size: i64,
+}
+
+/// 업로드 서명 URL 발급 응답
+///
+/// 클라이언트는 `uploadUrl`로 `method` 요청을 보내며, `headers`를 그대로 포함해야 합니다.
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct PresignUploadResponse {
+    /// 서명된 업로드 URL
+    pub upload_url: String,
+    /// 업로드 HTTP 메서드 (항상 PUT)
+    pub method: String,
+    /// 업로드 요청에 반드시 포함해야 하는 헤더
+    pub headers: UploadHeaders,
+    /// 스토리지 객체 키
+    pub object_key: String,
+    /// 업로드 완료 후 파일 URL (서명 없음)
+    pub file_url: String,
+    /// 서명 URL 만료 시각 (UTC, yyyy-MM-ddTHH:mm:ss)
+    pub expires_at: String,
+}
+
+/// 업로드 요청 필수 헤더
+#[derive(Debug, Serialize, ToSchema)]
+pub struct UploadHeaders {
+    #[serde(rename = "Content-Type")]
+    pub content_type: String,
+    #[serde(rename = "Content-Length")]
+    pub content_length: String,
+}
+
+/// 업로드 서명 URL 발급 성공 응답 (Swagger 문서용)
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SuccessPresignUploadResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: PresignUploadResponse,
+}
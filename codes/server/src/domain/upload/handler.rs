@@ -0,0 +1,46 @@
+use axum::{extract::State, Json};
+use validator::Validate;
+
+use super::dto::{PresignUploadRequest, PresignUploadResponse};
+use super::service::UploadService;
+use crate::state::AppState;
+use crate::utils::auth::AuthUser;
+use crate::utils::error::AppError;
+use crate::utils::BaseResponse;
+
+/// 업로드 서명 URL 발급 API
+///
+/// 회고 답변 첨부 이미지를 S3 호환 스토리지에 직접 업로드할 수 있는 짧은 수명의 서명 URL을 발급합니다.
+/// - 이미지 파일(jpeg, png, gif, webp)만 허용하며, 최대 크기를 넘으면 413을 반환합니다.
+/// - 업로드 시 응답의 headers(Content-Type, Content-Length)를 그대로 보내야 서명이 유효합니다.
+#[utoipa::path(
+    post,
+    path = "/api/v1/uploads/presign",
+    request_body = PresignUploadRequest,
+    security(
+        ("bearer_auth" = [])
+    ),
+    responses(
+        (status = 200, description = "서명 URL 발급 성공", body = SuccessPresignUploadResponse),
+        (status = 400, description = "잘못된 요청 또는 허용되지 않은 파일 형식", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 413, description = "최대 파일 크기 초과", body = ErrorResponse),
+        (status = 500, description = "서버 내부 오류", body = ErrorResponse),
+        (status = 503, description = "업로드 스토리지 미설정", body = ErrorResponse)
+    ),
+    tag = "Upload"
+)]
+pub async fn presign_upload(
+    State(state): State<AppState>,
+    user: AuthUser,
+    Json(req): Json<PresignUploadRequest>,
+) -> Result<Json<BaseResponse<PresignUploadResponse>>, AppError> {
+    req.validate()?;
+    let member_id = user.user_id()?;
+    let result = UploadService::presign_upload(&state, member_id, req)?;
+
+    Ok(Json(BaseResponse::success_with_message(
+        result,
+        "업로드 URL을 발급했습니다.",
+    )))
+}
@@ -0,0 +1,168 @@
+use chrono::Utc;
+use tracing::info;
+use uuid::Uuid;
+
+use super::dto::{PresignUploadRequest, PresignUploadResponse, UploadHeaders};
+use super::presign::{object_url, presign_put_url, PresignPutRequest, StorageCredentials};
+use crate::state::AppState;
+use crate::utils::error::AppError;
+
+/// 업로드 객체 키 접두사
+const UPLOAD_KEY_PREFIX: &str = "uploads";
+
+/// 업로드 허용 이미지 형식 (MIME 타입, 허용 확장자). 첫 번째 확장자를 객체 키에 사용
+const ALLOWED_IMAGE_TYPES: &[(&str, &[&str])] = &[
+    ("image/jpeg", &["jpg", "jpeg"]),
+    ("image/png", &["png"]),
+    ("image/gif", &["gif"]),
+    ("image/webp", &["webp"]),
+];
+
+/// 파일 이름 확장자 추출 (소문자, 확장자가 없으면 None)
+fn file_extension(file_name: &str) -> Option<String> {
+    let (stem, ext) = file_name.trim().rsplit_once('.')?;
+    if stem.is_empty() || ext.is_empty() {
+        return None;
+    }
+    Some(ext.to_lowercase())
+}
+
+/// 업로드 허용 형식 확인 후 객체 키에 쓸 확장자 반환
+///
+/// MIME 타입이 허용 목록에 있고 파일 이름 확장자가 해당 타입과 일치해야 합니다.
+pub fn resolve_image_extension(
+    file_name: &str,
+    content_type: &str,
+) -> Result<&'static str, AppError> {
+    let content_type = content_type.trim().to_lowercase();
+    let (_, extensions) = ALLOWED_IMAGE_TYPES
+        .iter()
+        .find(|(mime, _)| *mime == content_type)
+        .ok_or_else(|| {
+            AppError::UploadContentTypeNotAllowed(
+                "이미지 파일(jpeg, png, gif, webp)만 업로드할 수 있습니다.".to_string(),
+            )
+        })?;
+
+    match file_extension(file_name) {
+        Some(ext) if extensions.contains(&ext.as_str()) => Ok(extensions[0]),
+        _ => Err(AppError::UploadContentTypeNotAllowed(
+            "파일 확장자가 contentType과 일치하지 않습니다.".to_string(),
+        )),
+    }
+}
+
+pub struct UploadService;
+
+impl UploadService {
+    /// 업로드 서명 URL 발급
+    pub fn presign_upload(
+        state: &AppState,
+        member_id: i64,
+        req: PresignUploadRequest,
+    ) -> Result<PresignUploadResponse, AppError> {
+        let config = &state.config;
+
+        // 1. 스토리지 설정 확인
+        if !config.is_storage_configured() {
+            return Err(AppError::UploadStorageUnavailable(
+                "파일 업로드를 사용할 수 없습니다. 잠시 후 다시 시도해주세요.".to_string(),
+            ));
+        }
+
+        // 2. 파일 형식 및 크기 검증
+        let extension = resolve_image_extension(&req.file_name, &req.content_type)?;
+        let size = req.size as u64;
+        if size > config.upload_max_size_bytes {
+            return Err(AppError::UploadFileTooLarge(format!(
+                "파일 크기는 최대 {}바이트까지 업로드할 수 있습니다.",
+                config.upload_max_size_bytes
+            )));
+        }
+
+        // 3. 객체 키 생성 (회원별 경로 + 날짜 + 무작위 이름)
+        let now = Utc::now().naive_utc();
+        let object_key = format!(
+            "{}/{}/{}/{}.{}",
+            UPLOAD_KEY_PREFIX,
+            member_id,
+            now.format("%Y%m%d"),
+            Uuid::new_v4(),
+            extension
+        );
+
+        // 4. 서명 URL 생성
+        let content_type = req.content_type.trim().to_lowercase();
+        let creds = StorageCredentials {
+            endpoint: &config.storage_endpoint,
+            bucket: &config.storage_bucket,
+            region: &config.storage_region,
+            access_key_id: &config.storage_access_key_id,
+            secret_access_key: &config.storage_secret_access_key,
+        };
+        let upload_url = presign_put_url(
+            &creds,
+            &PresignPutRequest {
+                object_key: &object_key,
+                content_type: &content_type,
+                content_length: size,
+                expires_secs: config.upload_url_expires_secs,
+                signed_at: now,
+            },
+        );
+        let expires_at = now + chrono::Duration::seconds(config.upload_url_expires_secs as i64);
+
+        info!(
+            member_id = member_id,
+            object_key = %object_key,
+            size = size,
+            "업로드 서명 URL 발급"
+        );
+
+        Ok(PresignUploadResponse {
+            upload_url,
+            method: "PUT".to_string(),
+            headers: UploadHeaders {
+                content_type,
+                content_length: size.to_string(),
+            },
+            file_url: object_url(&creds, &object_key),
+            object_key,
+            expires_at: expires_at.format("%Y-%m-%dT%H:%M:%S").to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_resolve_extension_for_allowed_image_types() {
+        assert_eq!(
+            resolve_image_extension("a.PNG", "image/png").unwrap(),
+            "png"
+        );
+        assert_eq!(
+            resolve_image_extension("a.jpeg", "image/jpeg").unwrap(),
+            "jpg"
+        );
+        assert_eq!(
+            resolve_image_extension("a.b.webp", " Image/WebP ").unwrap(),
+            "webp"
+        );
+    }
+
+    #[test]
+    fn should_reject_non_image_content_type() {
+        let err = resolve_image_extension("a.pdf", "application/pdf").unwrap_err();
+        assert!(matches!(err, AppError::UploadContentTypeNotAllowed(_)));
+    }
+
+    #[test]
+    fn should_reject_mismatched_or_missing_extension() {
+        assert!(resolve_image_extension("a.gif", "image/png").is_err());
+        assert!(resolve_image_extension("png", "image/png").is_err());
+        assert!(resolve_image_extension(".png", "image/png").is_err());
+    }
+}
@@ -1,6 +1,8 @@
 pub mod middleware;
+pub mod uptime;
 
 pub use middleware::request_id_middleware;
+pub use uptime::{init_start_time, uptime_seconds};
 
 // TODO: Phase 2에서 handler에서 RequestId 추출 시 사용 예정
 #[allow(unused_imports)]
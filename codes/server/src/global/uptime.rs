@@ -0,0 +1,16 @@
+use std::sync::OnceLock;
+use std::time::Instant;
+
+static START_TIME: OnceLock<Instant> = OnceLock::new();
+
+/// 서버 시작 시각 기록 (main에서 한 번 호출)
+pub fn init_start_time() {
+    START_TIME.get_or_init(Instant::now);
+}
+
+/// 서버 시작 이후 경과 시간(초)
+///
+/// `init_start_time` 호출 전이라면 최초 호출 시점부터 계산합니다.
+pub fn uptime_seconds() -> u64 {
+    START_TIME.get_or_init(Instant::now).elapsed().as_secs()
+}
@@ -0,0 +1,63 @@
+//! 읽기 전용 GraphQL API
+//!
+//! 여러 리소스(회고방, 멤버, 회고, 답변)를 단일 요청으로 조합 조회하려는 클라이언트를 위한 엔드포인트입니다.
+//! 리졸버는 기존 서비스 메서드를 그대로 호출하므로 권한 검증과 에러 코드는 REST API와 동일합니다.
+//! 1차 범위는 쿼리만 제공하며, 뮤테이션은 지원하지 않습니다.
+
+pub mod query;
+pub mod types;
+
+use async_graphql::{EmptyMutation, EmptySubscription, ErrorExtensions, Schema};
+use axum::{Extension, Json};
+
+use crate::state::AppState;
+use crate::utils::auth::AuthUser;
+use crate::utils::error::AppError;
+
+pub use query::QueryRoot;
+
+/// GraphQL 스키마 타입
+pub type AppSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+/// 쿼리 중첩 깊이 제한 (회고방 → 회고 → 상세 → 답변 조합까지 허용)
+const MAX_QUERY_DEPTH: usize = 8;
+
+/// 쿼리 복잡도 제한 (필드 수 기준)
+const MAX_QUERY_COMPLEXITY: usize = 200;
+
+/// 요청한 사용자 정보 (JWT 인증을 통과한 사용자만 리졸버에 전달됨)
+#[derive(Debug, Clone, Copy)]
+pub struct Viewer {
+    pub member_id: i64,
+}
+
+/// 애플리케이션 상태를 담은 GraphQL 스키마 생성
+pub fn build_schema(state: AppState) -> AppSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .data(state)
+        .limit_depth(MAX_QUERY_DEPTH)
+        .limit_complexity(MAX_QUERY_COMPLEXITY)
+        .finish()
+}
+
+/// AppError → GraphQL 에러 변환 (REST와 같은 에러 코드를 extensions.code로 전달)
+pub fn to_graphql_error(error: AppError) -> async_graphql::Error {
+    let code = error.error_code().to_string();
+    async_graphql::Error::new(error.message()).extend_with(|_, ext| ext.set("code", code))
+}
+
+/// GraphQL 쿼리 API
+///
+/// 기존 REST API와 같은 JWT 인증을 거친 뒤 쿼리를 실행합니다.
+/// 리졸버 에러는 GraphQL 표준에 따라 200 응답의 `errors` 배열로 전달됩니다.
+pub async fn graphql_handler(
+    Extension(schema): Extension<AppSchema>,
+    user: AuthUser,
+    Json(req): Json<async_graphql::Request>,
+) -> Result<Json<async_graphql::Response>, AppError> {
+    let member_id = user.user_id()?;
+
+    let response = schema.execute(req.data(Viewer { member_id })).await;
+
+    Ok(Json(response))
+}
@@ -0,0 +1,43 @@
+//! GraphQL 쿼리 루트
+
+use async_graphql::{Context, Object, Result};
+
+use super::to_graphql_error;
+use super::types::{load_retrospect, viewer_context, Retrospect, Room};
+use crate::domain::retrospect::service::RetrospectService;
+use crate::utils::error::AppError;
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    /// 참여 중인 회고방 목록 (API-006과 같은 정렬)
+    async fn rooms(&self, ctx: &Context<'_>) -> Result<Vec<Room>> {
+        let (state, member_id) = viewer_context(ctx)?;
+        let items = RetrospectService::list_retro_rooms(state, member_id)
+            .await
+            .map_err(to_graphql_error)?;
+        Ok(items.into_iter().map(Room::from).collect())
+    }
+
+    /// 참여 중인 회고방 단건 조회
+    async fn room(&self, ctx: &Context<'_>, retro_room_id: i64) -> Result<Room> {
+        let (state, member_id) = viewer_context(ctx)?;
+        RetrospectService::list_retro_rooms(state, member_id)
+            .await
+            .map_err(to_graphql_error)?
+            .into_iter()
+            .find(|item| item.retro_room_id == retro_room_id)
+            .map(Room::from)
+            .ok_or_else(|| {
+                to_graphql_error(AppError::RetroRoomNotFound(
+                    "존재하지 않거나 참여하지 않은 회고방입니다.".to_string(),
+                ))
+            })
+    }
+
+    /// 회고 상세 정보 (API-012와 같은 권한 검증)
+    async fn retrospect(&self, ctx: &Context<'_>, retrospect_id: i64) -> Result<Retrospect> {
+        load_retrospect(ctx, retrospect_id).await
+    }
+}
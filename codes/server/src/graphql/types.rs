@@ -0,0 +1,336 @@
+//! GraphQL 객체 타입
+//!
+//! REST 응답 DTO를 GraphQL 타입으로 옮겨 담고, 연관 리소스는 `ComplexObject` 리졸버로 이어서 조회합니다.
+
+use async_graphql::{ComplexObject, Context, Result, SimpleObject};
+
+use super::{to_graphql_error, Viewer};
+use crate::domain::retrospect::dto::{
+    ResponseCategory, ResponseListItem, ResponsesListResponse, RetroRoomListItem,
+    RetroRoomMemberItem, RetrospectDetailResponse, RetrospectListItem, RetrospectMemberItem,
+    RetrospectQuestionItem,
+};
+use crate::domain::retrospect::service::RetrospectService;
+use crate::state::AppState;
+use crate::utils::error::AppError;
+
+/// 답변 목록 기본 조회 개수 (REST API-020과 동일)
+const DEFAULT_RESPONSES_SIZE: i64 = 10;
+
+/// 요청자 ID와 애플리케이션 상태 조회
+pub(super) fn viewer_context(ctx: &Context<'_>) -> Result<(AppState, i64)> {
+    let state = ctx.data::<AppState>()?.clone();
+    let viewer = ctx.data::<Viewer>()?;
+    Ok((state, viewer.member_id))
+}
+
+/// 참여 중인 회고방
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct Room {
+    pub retro_room_id: i64,
+    pub retro_room_name: String,
+    /// 회고방 목록 표시 순서
+    pub order_index: i32,
+    /// 마지막 확인 이후 미확인 활동 수
+    pub unread_count: i64,
+}
+
+impl From<RetroRoomListItem> for Room {
+    fn from(item: RetroRoomListItem) -> Self {
+        Self {
+            retro_room_id: item.retro_room_id,
+            retro_room_name: item.retro_room_name,
+            order_index: item.order_index,
+            unread_count: item.unread_count,
+        }
+    }
+}
+
+#[ComplexObject]
+impl Room {
+    /// 회고방 멤버 목록 (API-030과 같은 정렬)
+    async fn members(&self, ctx: &Context<'_>) -> Result<Vec<RoomMember>> {
+        let (state, member_id) = viewer_context(ctx)?;
+        let items =
+            RetrospectService::list_retro_room_members(state, member_id, self.retro_room_id)
+                .await
+                .map_err(to_graphql_error)?;
+        Ok(items.into_iter().map(RoomMember::from).collect())
+    }
+
+    /// 회고방 내 회고 목록 (API-010과 같은 정렬)
+    async fn retrospects(&self, ctx: &Context<'_>) -> Result<Vec<RetrospectSummary>> {
+        let (state, member_id) = viewer_context(ctx)?;
+        let items = RetrospectService::list_retrospects(state, member_id, self.retro_room_id)
+            .await
+            .map_err(to_graphql_error)?;
+        Ok(items.into_iter().map(RetrospectSummary::from).collect())
+    }
+}
+
+/// 회고방 멤버
+#[derive(SimpleObject)]
+pub struct RoomMember {
+    pub member_id: i64,
+    pub nickname: String,
+    /// 회고방 내 역할 ("OWNER", "MODERATOR", "MEMBER")
+    pub role: String,
+    /// 회고방 참여 일시 (ISO 8601 형식)
+    pub joined_at: String,
+    /// 마지막 활동 일시 (ISO 8601 형식, 기록이 없으면 null)
+    pub last_seen_at: Option<String>,
+}
+
+impl From<RetroRoomMemberItem> for RoomMember {
+    fn from(item: RetroRoomMemberItem) -> Self {
+        Self {
+            member_id: item.member_id,
+            nickname: item.nickname,
+            role: item.role,
+            joined_at: item.joined_at,
+            last_seen_at: item.last_seen_at,
+        }
+    }
+}
+
+/// 회고방 내 회고 요약
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct RetrospectSummary {
+    pub retrospect_id: i64,
+    pub project_name: String,
+    pub retrospect_method: String,
+    pub retrospect_date: String,
+    pub retrospect_time: String,
+    /// 참여자 수
+    pub participant_count: i64,
+}
+
+impl From<RetrospectListItem> for RetrospectSummary {
+    fn from(item: RetrospectListItem) -> Self {
+        Self {
+            retrospect_id: item.retrospect_id,
+            project_name: item.project_name,
+            retrospect_method: item.retrospect_method,
+            retrospect_date: item.retrospect_date,
+            retrospect_time: item.retrospect_time,
+            participant_count: item.participant_count,
+        }
+    }
+}
+
+#[ComplexObject]
+impl RetrospectSummary {
+    /// 회고 상세 정보 (API-012)
+    async fn detail(&self, ctx: &Context<'_>) -> Result<Retrospect> {
+        load_retrospect(ctx, self.retrospect_id).await
+    }
+}
+
+/// 회고 상세 정보 조회 (API-012와 같은 권한 검증)
+pub(super) async fn load_retrospect(ctx: &Context<'_>, retrospect_id: i64) -> Result<Retrospect> {
+    let (state, member_id) = viewer_context(ctx)?;
+    let detail = RetrospectService::get_retrospect_detail(state, member_id, retrospect_id)
+        .await
+        .map_err(to_graphql_error)?;
+    Ok(Retrospect::new(retrospect_id, detail))
+}
+
+/// 회고 상세 정보
+#[derive(SimpleObject)]
+#[graphql(complex)]
+pub struct Retrospect {
+    pub retrospect_id: i64,
+    pub retro_room_id: i64,
+    pub title: String,
+    /// 회고 시작 날짜 (YYYY-MM-DD, timezone 기준)
+    pub start_time: String,
+    pub timezone: String,
+    /// 회고 유형 (KPT, FOUR_L, ...)
+    pub retro_category: String,
+    pub questions_finalized: bool,
+    pub is_locked: bool,
+    /// 요청자의 답변 수정 가능 여부
+    pub editable: bool,
+    pub total_like_count: i64,
+    pub total_comment_count: i64,
+    /// 참여 멤버 (참석 등록일 기준 오름차순)
+    pub members: Vec<RetrospectMember>,
+    /// 질문 리스트 (index 오름차순)
+    pub questions: Vec<RetrospectQuestion>,
+}
+
+impl Retrospect {
+    fn new(retrospect_id: i64, detail: RetrospectDetailResponse) -> Self {
+        Self {
+            retrospect_id,
+            retro_room_id: detail.retro_room_id,
+            title: detail.title,
+            start_time: detail.start_time,
+            timezone: detail.timezone,
+            retro_category: detail.retro_category.to_string(),
+            questions_finalized: detail.questions_finalized,
+            is_locked: detail.is_locked,
+            editable: detail.editable,
+            total_like_count: detail.total_like_count,
+            total_comment_count: detail.total_comment_count,
+            members: detail
+                .members
+                .into_iter()
+                .map(RetrospectMember::from)
+                .collect(),
+            questions: detail
+                .questions
+                .into_iter()
+                .map(RetrospectQuestion::from)
+                .collect(),
+        }
+    }
+}
+
+#[ComplexObject]
+impl Retrospect {
+    /// 답변 리스트 (API-020과 같은 커서 페이지네이션)
+    ///
+    /// category: ALL, QUESTION_1~QUESTION_5 (기본값 ALL), size: 1~100 (기본값 10)
+    async fn responses(
+        &self,
+        ctx: &Context<'_>,
+        category: Option<String>,
+        label: Option<String>,
+        cursor: Option<i64>,
+        size: Option<i64>,
+    ) -> Result<ResponseConnection> {
+        let category: ResponseCategory = match category {
+            Some(category) => category.parse().map_err(|_| {
+                to_graphql_error(AppError::RetroCategoryInvalid(
+                    "유효하지 않은 카테고리 값입니다.".to_string(),
+                ))
+            })?,
+            None => ResponseCategory::All,
+        };
+        if cursor.is_some_and(|cursor| cursor < 1) {
+            return Err(to_graphql_error(AppError::BadRequest(
+                "cursor는 1 이상의 양수여야 합니다.".to_string(),
+            )));
+        }
+        let size = size.unwrap_or(DEFAULT_RESPONSES_SIZE);
+        if !(1..=100).contains(&size) {
+            return Err(to_graphql_error(AppError::BadRequest(
+                "size는 1~100 범위의 정수여야 합니다.".to_string(),
+            )));
+        }
+
+        let (state, member_id) = viewer_context(ctx)?;
+        let result = RetrospectService::list_responses(
+            state,
+            member_id,
+            self.retrospect_id,
+            category,
+            label,
+            cursor,
+            size,
+        )
+        .await
+        .map_err(to_graphql_error)?;
+        Ok(ResponseConnection::from(result))
+    }
+}
+
+/// 회고 참여 멤버
+#[derive(SimpleObject)]
+pub struct RetrospectMember {
+    pub member_id: i64,
+    pub user_name: String,
+}
+
+impl From<RetrospectMemberItem> for RetrospectMember {
+    fn from(item: RetrospectMemberItem) -> Self {
+        Self {
+            member_id: item.member_id,
+            user_name: item.user_name,
+        }
+    }
+}
+
+/// 회고 질문
+#[derive(SimpleObject)]
+pub struct RetrospectQuestion {
+    /// 질문 순서 (1~5)
+    pub index: i32,
+    pub content: String,
+    pub required: bool,
+    /// 요청자의 답변 작성 여부
+    pub answered: bool,
+    pub placeholder: Option<String>,
+    /// 질문 가중치 (1~5)
+    pub weight: Option<i32>,
+}
+
+impl From<RetrospectQuestionItem> for RetrospectQuestion {
+    fn from(item: RetrospectQuestionItem) -> Self {
+        Self {
+            index: item.index,
+            content: item.content,
+            required: item.required,
+            answered: item.answered,
+            placeholder: item.placeholder,
+            weight: item.weight,
+        }
+    }
+}
+
+/// 답변 페이지
+#[derive(SimpleObject)]
+pub struct ResponseConnection {
+    pub responses: Vec<RetrospectResponse>,
+    pub has_next: bool,
+    /// 다음 조회를 위한 커서 ID (마지막 페이지면 null)
+    pub next_cursor: Option<i64>,
+}
+
+impl From<ResponsesListResponse> for ResponseConnection {
+    fn from(page: ResponsesListResponse) -> Self {
+        Self {
+            responses: page
+                .responses
+                .into_iter()
+                .map(RetrospectResponse::from)
+                .collect(),
+            has_next: page.has_next,
+            next_cursor: page.next_cursor,
+        }
+    }
+}
+
+/// 회고 답변
+#[derive(SimpleObject)]
+pub struct RetrospectResponse {
+    pub response_id: i64,
+    pub user_name: String,
+    pub content: String,
+    pub like_count: i64,
+    pub view_count: i64,
+    pub comment_count: i64,
+    /// AI 한 줄 요약
+    pub summary: Option<String>,
+    pub labels: Vec<String>,
+    pub question_weight: Option<i32>,
+}
+
+impl From<ResponseListItem> for RetrospectResponse {
+    fn from(item: ResponseListItem) -> Self {
+        Self {
+            response_id: item.response_id,
+            user_name: item.user_name,
+            content: item.content,
+            like_count: item.like_count,
+            view_count: item.view_count,
+            comment_count: item.comment_count,
+            summary: item.summary,
+            labels: item.labels,
+            question_weight: item.question_weight,
+        }
+    }
+}
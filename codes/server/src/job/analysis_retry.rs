@@ -0,0 +1,236 @@
+//! 실패한 회고 분석 재시도 잡
+//!
+//! `analyze_retrospective`의 AI 호출이 실패하면 `analysis_job`에 PENDING 작업이 등록됩니다.
+//! 이 잡은 재시도 시각이 지난 작업을 다시 분석하고, 실패할 때마다 대기 시간을 2배로 늘립니다.
+//! `analysis_retry_max_attempts`를 넘기거나 재시도로 해결되지 않는 오류면 FAILED로 확정합니다.
+//!
+//! 서버가 여러 대여도 한 작업은 한 인스턴스만 처리하도록, 처리 전에 `next_retry_at`을 임대 만료 시각으로
+//! 조건부 갱신해 작업 행을 선점합니다. 처리 중 인스턴스가 종료되면 임대가 끝난 뒤 다른 인스턴스가 다시 가져갑니다.
+
+use std::time::Duration;
+
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::sea_query::Expr;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set};
+use tracing::{error, info, warn};
+
+use crate::domain::retrospect::entity::analysis_job::{self, AnalysisJobStatus};
+use crate::domain::retrospect::service::RetrospectService;
+use crate::state::AppState;
+use crate::utils::error::AppError;
+
+/// 백오프 지수 상한 (기본 대기 시간의 최대 2^10배)
+const MAX_BACKOFF_EXPONENT: u32 = 10;
+
+/// 작업 선점 임대 시간 (AI 분석 1회가 끝나기에 충분한 시간)
+const CLAIM_LEASE_SECS: i64 = 600;
+
+/// 잡 1회 실행 결과
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct AnalysisRetrySummary {
+    /// 재시도로 분석에 성공한 작업 수
+    pub succeeded: usize,
+    /// 다음 재시도로 미뤄진 작업 수
+    pub rescheduled: usize,
+    /// 실패로 확정된 작업 수
+    pub failed: usize,
+}
+
+/// 회고 분석 재시도 잡을 백그라운드로 시작
+///
+/// `analysis_retry_interval_secs`가 0이면 잡을 시작하지 않습니다.
+pub fn spawn_analysis_retry_job(state: AppState) {
+    let interval_secs = state.config.analysis_retry_interval_secs;
+    if interval_secs == 0 {
+        info!("회고 분석 재시도 잡이 비활성화되어 있습니다 (ANALYSIS_RETRY_INTERVAL_SECS=0)");
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            ticker.tick().await;
+            match run_analysis_retry(&state, Utc::now().naive_utc()).await {
+                Ok(summary) => info!(
+                    succeeded = summary.succeeded,
+                    rescheduled = summary.rescheduled,
+                    failed = summary.failed,
+                    "회고 분석 재시도 잡 완료"
+                ),
+                Err(e) => error!("회고 분석 재시도 잡 실패: {}", e),
+            }
+        }
+    });
+}
+
+/// 재시도로 해결될 수 있는 분석 오류인지 판단 (AI 호출 실패만 재시도)
+pub fn is_retryable_analysis_error(error: &AppError) -> bool {
+    matches!(
+        error,
+        AppError::AiAnalysisFailed(_)
+            | AppError::AiConnectionFailed(_)
+            | AppError::AiServiceUnavailable(_)
+            | AppError::AiGeneralError(_)
+    )
+}
+
+/// `retry_count`번 재시도한 뒤 다음 재시도까지의 대기 시간 (base × 2^retry_count)
+pub fn analysis_retry_delay(base_delay_secs: i64, retry_count: i32) -> chrono::Duration {
+    let exponent = (retry_count.max(0) as u32).min(MAX_BACKOFF_EXPONENT);
+    chrono::Duration::seconds(base_delay_secs.saturating_mul(1_i64 << exponent))
+}
+
+/// 재시도 대기 중인 분석 작업 1회 처리
+pub async fn run_analysis_retry(
+    state: &AppState,
+    now: NaiveDateTime,
+) -> Result<AnalysisRetrySummary, AppError> {
+    let mut summary = AnalysisRetrySummary::default();
+
+    // 1. 재시도 시각이 지난 PENDING 작업 조회
+    let due_jobs = analysis_job::Entity::find()
+        .filter(analysis_job::Column::Status.eq(AnalysisJobStatus::Pending))
+        .filter(analysis_job::Column::NextRetryAt.lte(now))
+        .order_by_asc(analysis_job::Column::NextRetryAt)
+        .all(&state.db)
+        .await
+        .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+    for job in due_jobs {
+        let retrospect_id = job.retrospect_id;
+
+        // 2. 작업 선점 (조회 이후 다른 인스턴스가 먼저 가져갔으면 건너뜀)
+        if !claim_job(state, &job, now).await? {
+            info!(
+                retrospect_id = retrospect_id,
+                "다른 인스턴스가 처리 중인 분석 재시도 작업 건너뜀"
+            );
+            continue;
+        }
+
+        // 3. 분석 재실행 (요청자가 탈퇴했으면 재시도 불가)
+        let outcome = match job.requested_by {
+            Some(member_id) => {
+                RetrospectService::analyze_retrospective(state.clone(), member_id, retrospect_id)
+                    .await
+                    .map(|_| ())
+            }
+            None => Err(AppError::RetroInsufficientData(
+                "분석을 요청한 멤버가 탈퇴했습니다.".to_string(),
+            )),
+        };
+
+        // 4. 결과에 따라 상태 갱신
+        let retry_count = job.retry_count + 1;
+        let mut active: analysis_job::ActiveModel = job.into();
+        active.retry_count = Set(retry_count);
+        active.updated_at = Set(now);
+
+        match outcome {
+            Ok(()) | Err(AppError::RetroAlreadyAnalyzed(_)) => {
+                info!(retrospect_id = retrospect_id, "회고 분석 재시도 성공");
+                active.status = Set(AnalysisJobStatus::Succeeded);
+                summary.succeeded += 1;
+            }
+            Err(e)
+                if is_retryable_analysis_error(&e)
+                    && retry_count < state.config.analysis_retry_max_attempts =>
+            {
+                warn!(
+                    retrospect_id = retrospect_id,
+                    retry_count = retry_count,
+                    error = %e,
+                    "회고 분석 재시도 실패, 다음 재시도 예약"
+                );
+                active.last_error = Set(Some(e.to_string()));
+                active.next_retry_at = Set(now
+                    + analysis_retry_delay(
+                        state.config.analysis_retry_base_delay_secs,
+                        retry_count,
+                    ));
+                summary.rescheduled += 1;
+            }
+            Err(e) => {
+                warn!(
+                    retrospect_id = retrospect_id,
+                    retry_count = retry_count,
+                    error = %e,
+                    "회고 분석 재시도 실패 확정"
+                );
+                active.status = Set(AnalysisJobStatus::Failed);
+                active.last_error = Set(Some(e.to_string()));
+                summary.failed += 1;
+            }
+        }
+
+        active
+            .update(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+    }
+
+    Ok(summary)
+}
+
+/// 조회 시점의 `next_retry_at`이 그대로인 PENDING 작업만 임대 만료 시각으로 갱신해 선점
+///
+/// 갱신된 행이 없으면 다른 인스턴스가 이미 선점한 것이므로 false를 반환합니다.
+async fn claim_job(
+    state: &AppState,
+    job: &analysis_job::Model,
+    now: NaiveDateTime,
+) -> Result<bool, AppError> {
+    let result = analysis_job::Entity::update_many()
+        .col_expr(
+            analysis_job::Column::NextRetryAt,
+            Expr::value(now + chrono::Duration::seconds(CLAIM_LEASE_SECS)),
+        )
+        .col_expr(analysis_job::Column::UpdatedAt, Expr::value(now))
+        .filter(analysis_job::Column::AnalysisJobId.eq(job.analysis_job_id))
+        .filter(analysis_job::Column::Status.eq(AnalysisJobStatus::Pending))
+        .filter(analysis_job::Column::NextRetryAt.eq(job.next_retry_at))
+        .exec(&state.db)
+        .await
+        .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+    Ok(result.rows_affected == 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_double_delay_for_each_retry() {
+        // Act & Assert
+        assert_eq!(analysis_retry_delay(60, 0), chrono::Duration::seconds(60));
+        assert_eq!(analysis_retry_delay(60, 1), chrono::Duration::seconds(120));
+        assert_eq!(analysis_retry_delay(60, 3), chrono::Duration::seconds(480));
+    }
+
+    #[test]
+    fn should_cap_backoff_exponent() {
+        // Act & Assert
+        assert_eq!(
+            analysis_retry_delay(1, 50),
+            chrono::Duration::seconds(1 << MAX_BACKOFF_EXPONENT)
+        );
+    }
+
+    #[test]
+    fn should_retry_only_ai_call_failures() {
+        // Act & Assert
+        assert!(is_retryable_analysis_error(
+            &AppError::AiServiceUnavailable("timeout".to_string())
+        ));
+        assert!(is_retryable_analysis_error(&AppError::AiAnalysisFailed(
+            "invalid json".to_string()
+        )));
+        assert!(!is_retryable_analysis_error(
+            &AppError::AiMonthlyLimitExceeded("limit".to_string())
+        ));
+        assert!(!is_retryable_analysis_error(
+            &AppError::RetroInsufficientData("no data".to_string())
+        ));
+    }
+}
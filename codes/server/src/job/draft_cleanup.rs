@@ -0,0 +1,383 @@
+//! 오래된 임시저장(Draft) 답변 정리 잡
+//!
+//! 회고 시작 후 `draft_cleanup_cutoff_days`가 지나도록 제출되지 않은 임시저장 답변을 2단계로 정리합니다.
+//! 1. 경고: 회고방 Owner에게 `DRAFT_EXPIRY_WARNING` 알림을 발행하고 `member_retro.draft_warned_at`을 기록
+//! 2. 정리: 경고 후 `draft_cleanup_grace_days` 동안 변경이 없으면 답변 내용을 비움
+//!
+//! 유예 기간 중 답변이 수정되면 경고 상태를 초기화하고 다음 주기부터 다시 판단합니다.
+
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QuerySelect, Set, TransactionTrait,
+};
+use tracing::{error, info};
+
+use crate::domain::member::entity::member_response;
+use crate::domain::member::entity::member_retro::{self, RetrospectStatus};
+use crate::domain::member::entity::member_retro_room::{self, RoomRole};
+use crate::domain::notification::entity::notification::NotificationType;
+use crate::domain::notification::service::{
+    build_deep_link, DeepLinkTarget, NewNotification, NotificationService,
+};
+use crate::domain::retrospect::entity::{response, retrospect};
+use crate::state::AppState;
+use crate::utils::error::AppError;
+
+/// 임시저장 건별 처리 결정
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DraftAction {
+    /// 처리 대상 아님 (내용 없음 또는 유예 기간 중)
+    Skip,
+    /// Owner에게 정리 예고
+    Warn,
+    /// 유예 기간 경과, 답변 내용 정리
+    Clean,
+    /// 경고 이후 답변이 수정됨, 경고 상태 초기화
+    Reset,
+}
+
+/// 잡 1회 실행 결과
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct DraftCleanupSummary {
+    /// 정리 예고된 임시저장 수
+    pub warned: usize,
+    /// 내용이 정리된 임시저장 수
+    pub cleaned: usize,
+    /// 변경이 감지되어 경고가 초기화된 임시저장 수
+    pub reset: usize,
+    /// 비워진 답변(response) 수
+    pub cleaned_responses: usize,
+}
+
+/// 임시저장 정리 잡을 백그라운드로 시작
+///
+/// `draft_cleanup_interval_secs`가 0이면 잡을 시작하지 않습니다.
+pub fn spawn_draft_cleanup_job(state: AppState) {
+    let interval_secs = state.config.draft_cleanup_interval_secs;
+    if interval_secs == 0 {
+        info!("임시저장 정리 잡이 비활성화되어 있습니다 (DRAFT_CLEANUP_INTERVAL_SECS=0)");
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(Duration::from_secs(interval_secs));
+        loop {
+            ticker.tick().await;
+            match run_draft_cleanup(&state, Utc::now().naive_utc()).await {
+                Ok(summary) => info!(
+                    warned = summary.warned,
+                    cleaned = summary.cleaned,
+                    reset = summary.reset,
+                    cleaned_responses = summary.cleaned_responses,
+                    "임시저장 정리 잡 완료"
+                ),
+                Err(e) => error!("임시저장 정리 잡 실패: {}", e),
+            }
+        }
+    });
+}
+
+/// 임시저장 건별 처리 결정
+///
+/// - `has_content`: 비어있지 않은 답변이 하나라도 있는지
+/// - `last_edited_at`: 해당 사용자 답변들의 마지막 수정 시각
+pub fn decide_draft_action(
+    has_content: bool,
+    warned_at: Option<NaiveDateTime>,
+    last_edited_at: Option<NaiveDateTime>,
+    now: NaiveDateTime,
+    grace: chrono::Duration,
+) -> DraftAction {
+    match warned_at {
+        None if has_content => DraftAction::Warn,
+        None => DraftAction::Skip,
+        Some(_) if !has_content => DraftAction::Reset,
+        Some(warned) if last_edited_at.is_some_and(|edited| edited > warned) => DraftAction::Reset,
+        Some(warned) if warned + grace <= now => DraftAction::Clean,
+        Some(_) => DraftAction::Skip,
+    }
+}
+
+/// 임시저장 정리 1회 실행
+pub async fn run_draft_cleanup(
+    state: &AppState,
+    now: NaiveDateTime,
+) -> Result<DraftCleanupSummary, AppError> {
+    let cutoff = now - chrono::Duration::days(state.config.draft_cleanup_cutoff_days);
+    let grace = chrono::Duration::days(state.config.draft_cleanup_grace_days);
+    let mut summary = DraftCleanupSummary::default();
+
+    // 1. 시작 후 cutoff가 지난 회고의 미제출 참여 정보 조회
+    let stale_drafts: Vec<(member_retro::Model, Option<retrospect::Model>)> =
+        member_retro::Entity::find()
+            .find_also_related(retrospect::Entity)
+            .filter(member_retro::Column::Status.eq(RetrospectStatus::Draft))
+            .filter(member_retro::Column::MemberId.is_not_null())
+            .filter(retrospect::Column::StartTime.lt(cutoff))
+            .all(&state.db)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+    if stale_drafts.is_empty() {
+        return Ok(summary);
+    }
+
+    let retrospects: HashMap<i64, retrospect::Model> = stale_drafts
+        .iter()
+        .filter_map(|(_, r)| r.clone())
+        .map(|r| (r.retrospect_id, r))
+        .collect();
+    let retrospect_ids: Vec<i64> = retrospects.keys().copied().collect();
+
+    // 2. 대상 회고의 답변과 작성자 매핑 배치 조회
+    let responses: HashMap<i64, response::Model> = response::Entity::find()
+        .filter(response::Column::RetrospectId.is_in(retrospect_ids.clone()))
+        .all(&state.db)
+        .await
+        .map_err(|e| AppError::InternalError(e.to_string()))?
+        .into_iter()
+        .map(|r| (r.response_id, r))
+        .collect();
+
+    let response_ids: Vec<i64> = responses.keys().copied().collect();
+    let member_responses = member_response::Entity::find()
+        .filter(member_response::Column::ResponseId.is_in(response_ids))
+        .all(&state.db)
+        .await
+        .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+    // (member_id, retrospect_id) -> 답변 목록
+    let mut drafts_by_member: HashMap<(i64, i64), Vec<&response::Model>> = HashMap::new();
+    for mr in &member_responses {
+        if let (Some(member_id), Some(resp)) = (mr.member_id, responses.get(&mr.response_id)) {
+            drafts_by_member
+                .entry((member_id, resp.retrospect_id))
+                .or_default()
+                .push(resp);
+        }
+    }
+
+    // 3. 건별 처리 결정
+    let mut to_warn: BTreeMap<i64, Vec<i64>> = BTreeMap::new(); // retrospect_id -> member_retro_ids
+    let txn = state
+        .db
+        .begin()
+        .await
+        .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+    for (draft, _) in &stale_drafts {
+        let Some(member_id) = draft.member_id else {
+            continue;
+        };
+        let answers = drafts_by_member
+            .get(&(member_id, draft.retrospect_id))
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+        let has_content = answers.iter().any(|r| !r.content.trim().is_empty());
+        let last_edited_at = answers.iter().map(|r| r.updated_at).max();
+
+        match decide_draft_action(
+            has_content,
+            draft.draft_warned_at,
+            last_edited_at,
+            now,
+            grace,
+        ) {
+            DraftAction::Skip => {}
+            DraftAction::Warn => {
+                to_warn
+                    .entry(draft.retrospect_id)
+                    .or_default()
+                    .push(draft.member_retro_id);
+            }
+            DraftAction::Reset => {
+                let mut active: member_retro::ActiveModel = draft.clone().into();
+                active.draft_warned_at = Set(None);
+                active
+                    .update(&txn)
+                    .await
+                    .map_err(|e| AppError::InternalError(e.to_string()))?;
+                summary.reset += 1;
+            }
+            DraftAction::Clean => {
+                for answer in answers.iter().filter(|r| !r.content.is_empty()) {
+                    let mut active: response::ActiveModel = (*answer).clone().into();
+                    active.content = Set(String::new());
+                    active.updated_at = Set(now);
+                    active
+                        .update(&txn)
+                        .await
+                        .map_err(|e| AppError::InternalError(e.to_string()))?;
+                    summary.cleaned_responses += 1;
+                }
+                let mut active: member_retro::ActiveModel = draft.clone().into();
+                active.draft_warned_at = Set(None);
+                active
+                    .update(&txn)
+                    .await
+                    .map_err(|e| AppError::InternalError(e.to_string()))?;
+                summary.cleaned += 1;
+            }
+        }
+    }
+
+    // 4. 정리 예고: 회고방 Owner에게 회고 단위로 알림 발행
+    if !to_warn.is_empty() {
+        let room_ids: Vec<i64> = to_warn
+            .keys()
+            .filter_map(|id| retrospects.get(id))
+            .map(|r| r.retrospect_room_id)
+            .collect();
+
+        let owners: Vec<(i64, i64)> = member_retro_room::Entity::find()
+            .select_only()
+            .column(member_retro_room::Column::RetrospectRoomId)
+            .column(member_retro_room::Column::MemberId)
+            .filter(member_retro_room::Column::RetrospectRoomId.is_in(room_ids))
+            .filter(member_retro_room::Column::Role.eq(RoomRole::Owner))
+            .filter(member_retro_room::Column::MemberId.is_not_null())
+            .into_tuple::<(i64, Option<i64>)>()
+            .all(&txn)
+            .await
+            .map_err(|e| AppError::InternalError(e.to_string()))?
+            .into_iter()
+            .filter_map(|(room_id, member_id)| member_id.map(|m| (room_id, m)))
+            .collect();
+
+        for (retrospect_id, member_retro_ids) in &to_warn {
+            let Some(retro) = retrospects.get(retrospect_id) else {
+                continue;
+            };
+
+            for (_, owner_id) in owners
+                .iter()
+                .filter(|(room_id, _)| *room_id == retro.retrospect_room_id)
+            {
+                NotificationService::publish(
+                    &txn,
+                    NewNotification {
+                        member_id: *owner_id,
+                        notification_type: NotificationType::DraftExpiryWarning,
+                        message: format!(
+                            "'{}' 회고에 제출되지 않은 임시저장 답변이 {}건 있습니다. {}일 동안 변경이 없으면 정리됩니다.",
+                            retro.title,
+                            member_retro_ids.len(),
+                            state.config.draft_cleanup_grace_days
+                        ),
+                        retrospect_room_id: Some(retro.retrospect_room_id),
+                        retrospect_id: Some(*retrospect_id),
+                        source_key: None,
+                        deep_link: build_deep_link(
+                            &state.config.deep_link_base_path,
+                            &NotificationType::DraftExpiryWarning,
+                            DeepLinkTarget {
+                                retrospect_room_id: Some(retro.retrospect_room_id),
+                                retrospect_id: Some(*retrospect_id),
+                                response_id: None,
+                            },
+                        ),
+                    },
+                )
+                .await?;
+            }
+
+            member_retro::Entity::update_many()
+                .col_expr(
+                    member_retro::Column::DraftWarnedAt,
+                    sea_orm::sea_query::Expr::value(now),
+                )
+                .filter(member_retro::Column::MemberRetroId.is_in(member_retro_ids.clone()))
+                .exec(&txn)
+                .await
+                .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+            summary.warned += member_retro_ids.len();
+        }
+    }
+
+    txn.commit()
+        .await
+        .map_err(|e| AppError::InternalError(e.to_string()))?;
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn at(day: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 1, day)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn should_warn_when_draft_has_content_and_not_warned() {
+        // Act
+        let action =
+            decide_draft_action(true, None, Some(at(1)), at(20), chrono::Duration::days(7));
+
+        // Assert
+        assert_eq!(action, DraftAction::Warn);
+    }
+
+    #[test]
+    fn should_skip_when_draft_is_empty_and_not_warned() {
+        // Act
+        let action = decide_draft_action(false, None, None, at(20), chrono::Duration::days(7));
+
+        // Assert
+        assert_eq!(action, DraftAction::Skip);
+    }
+
+    #[test]
+    fn should_skip_during_grace_period() {
+        // Act
+        let action = decide_draft_action(
+            true,
+            Some(at(15)),
+            Some(at(1)),
+            at(20),
+            chrono::Duration::days(7),
+        );
+
+        // Assert
+        assert_eq!(action, DraftAction::Skip);
+    }
+
+    #[test]
+    fn should_clean_after_grace_period_without_changes() {
+        // Act
+        let action = decide_draft_action(
+            true,
+            Some(at(10)),
+            Some(at(1)),
+            at(20),
+            chrono::Duration::days(7),
+        );
+
+        // Assert
+        assert_eq!(action, DraftAction::Clean);
+    }
+
+    #[test]
+    fn should_reset_when_edited_after_warning() {
+        // Act
+        let action = decide_draft_action(
+            true,
+            Some(at(10)),
+            Some(at(12)),
+            at(20),
+            chrono::Duration::days(7),
+        );
+
+        // Assert
+        assert_eq!(action, DraftAction::Reset);
+    }
+}
@@ -0,0 +1,11 @@
+//! 백그라운드 잡 모듈
+//!
+//! 서버 기동 시 `tokio::spawn`으로 주기 실행되는 정리/유지보수 작업을 제공합니다.
+
+pub mod analysis_retry;
+pub mod draft_cleanup;
+pub mod typing_expiry;
+
+pub use analysis_retry::spawn_analysis_retry_job;
+pub use draft_cleanup::spawn_draft_cleanup_job;
+pub use typing_expiry::spawn_typing_expiry_job;
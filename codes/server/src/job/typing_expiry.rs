@@ -0,0 +1,27 @@
+//! 타이핑 인디케이터 자동 중지 잡
+//!
+//! 클라이언트가 중지 이벤트 없이 입력을 멈추거나 연결 정리가 늦어져도
+//! `typing_idle_timeout_secs`가 지나면 입력 중지 이벤트가 전달되도록 주기적으로 정리합니다.
+
+use std::time::Duration;
+
+use tracing::debug;
+
+use crate::state::AppState;
+
+/// 정리 주기 (타이핑 상태는 초 단위로만 의미가 있어 고정값 사용)
+const TYPING_EXPIRY_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 타이핑 자동 중지 잡을 백그라운드로 시작
+pub fn spawn_typing_expiry_job(state: AppState) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(TYPING_EXPIRY_INTERVAL);
+        loop {
+            ticker.tick().await;
+            let expired = state.typing_hub.expire_idle();
+            if expired > 0 {
+                debug!(expired = expired, "타이핑 인디케이터 자동 중지");
+            }
+        }
+    });
+}
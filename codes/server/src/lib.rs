@@ -6,6 +6,8 @@ pub mod automation;
 pub mod config;
 pub mod domain;
 pub mod event;
+pub mod graphql;
+pub mod job;
 pub mod monitoring;
 pub mod state;
 pub mod utils;
@@ -5,6 +5,8 @@ mod config;
 mod domain;
 mod event;
 mod global;
+mod graphql;
+mod job;
 mod monitoring;
 mod state;
 mod utils;
@@ -19,6 +21,11 @@ use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 use crate::config::AppConfig;
+use crate::domain::admin::dto::{
+    AiUsagePeriod, AiUsageSummaryItem, MethodStatItem, MethodStatsResponse,
+    SuccessAiUsageSummaryResponse, SuccessMethodStatsResponse,
+};
+use crate::domain::ai::entity::ai_usage_log::AiUsagePurpose;
 use crate::domain::auth::dto::{
     EmailLoginRequest, EmailLoginResponse, LogoutRequest, SignupRequest, SignupResponse,
     SocialLoginRequest, SocialLoginResponse, SuccessEmailLoginResponse, SuccessLogoutResponse,
@@ -26,33 +33,93 @@ use crate::domain::auth::dto::{
     TokenRefreshRequest, TokenRefreshResponse,
 };
 use crate::domain::member::dto::{
-    MemberProfileResponse, SuccessProfileResponse, SuccessWithdrawResponse,
+    BadgeItem, BadgesResponse, DndSettingsResponse, EmailSharingResponse, MemberProfileResponse,
+    MentionItem, MentionSourceType, MentionsResponse, NotificationReadType,
+    NotificationSettingsResponse, ReadAllNotificationsResponse, RecentResponseItem,
+    RecentResponsesResponse, SuccessBadgesResponse, SuccessDndSettingsResponse,
+    SuccessEmailSharingResponse, SuccessMentionsResponse, SuccessNotificationSettingsResponse,
+    SuccessProfileResponse, SuccessReadAllNotificationsResponse, SuccessRecentResponsesResponse,
+    SuccessWithdrawResponse, UpdateDndRequest, UpdateEmailSharingRequest,
+    UpdateNotificationSettingsRequest,
 };
+use crate::domain::member::entity::member_badge::BadgeType;
 use crate::domain::member::entity::member_retro::RetrospectStatus;
+use crate::domain::member::entity::room_membership_history::MembershipEvent;
 use crate::domain::retrospect::dto::{
-    AnalysisResponse, AssistantRequest, AssistantResponse, CommentItem, CreateCommentRequest,
-    CreateCommentResponse, CreateParticipantResponse, CreateRetrospectRequest,
-    CreateRetrospectResponse, DeleteRetroRoomResponse, DraftItem, DraftSaveRequest,
-    DraftSaveResponse, EmotionRankItem, GuideItem, GuideType, JoinRetroRoomRequest,
-    JoinRetroRoomResponse, LikeToggleResponse, ListCommentsQuery, ListCommentsResponse,
-    MissionItem, PersonalMissionItem, ReferenceItem, ResponseCategory, ResponseListItem,
-    ResponsesListResponse, RetroRoomCreateRequest, RetroRoomCreateResponse, RetroRoomListItem,
-    RetroRoomMemberItem, RetroRoomOrderItem, RetrospectDetailResponse, RetrospectListItem,
-    RetrospectMemberItem, RetrospectQuestionItem, SearchRetrospectItem, StorageRangeFilter,
-    StorageResponse, StorageRetrospectItem, StorageYearGroup, SubmitAnswerItem,
+    AnalysisProgressEvent, AnalysisResponse, AnalysisShareResponse, AnalysisStage,
+    AnalysisVersionCompareResponse, AnalysisVersionItem, AnalysisVersionsResponse,
+    AnswerFeedbackRequest, AnswerFeedbackResponse, AssignableRoomRole, AssistantRequest,
+    AssistantResponse, AutoCategorizeItem, AutoCategorizeResponse, BatchCreateRetrospectRequest,
+    BatchCreateRetrospectResponse, BatchSkippedRoomItem, CheckInQrResponse, CheckInRequest,
+    CommentItem, CreateAnalysisShareRequest, CreateCommentRequest, CreateCommentResponse,
+    CreateCustomMethodRequest, CreateParticipantResponse, CreateRetrospectRequest,
+    CreateRetrospectResponse, CustomMethodItem, DeleteRetroRoomResponse, DeleteRetrospectResponse,
+    DraftItem, DraftSaveRequest, DraftSaveResponse, DuplicateRetrospectRequest,
+    EditSessionResponse, EmotionRankChangeItem, EmotionRankItem, ExportAnswerItem,
+    ExportCommentItem, ExportFormat, ExportQuestionItem, FeedbackAspect, FeedbackSuggestionItem,
+    FinalizeQuestionsResponse, GuideItem, GuideType, HallOfFameItem, HallOfFameResponse,
+    HeartbeatResponse, InviteCodeItem, InviteCodeResponse, InviteGrantRole, InviteMetaResponse,
+    JoinRetroRoomRequest, JoinRetroRoomResponse, KickMemberResponse, LeaveRetroRoomResponse,
+    LikeMemberItem, LikeToggleResponse, ListCommentsQuery, ListCommentsResponse, ListLikesQuery,
+    ListLikesResponse, MemberOrderItem, MembershipHistoryItem, MembershipHistoryResponse,
+    MentionCandidateItem, MissionItem, MoveRetrospectRequest, MoveRetrospectResponse,
+    PersonalMissionItem, QuestionCoverageItem, QuestionCoverageResponse,
+    QuestionValidationErrorItem, ReferenceItem, ReferencePreviewRequest, ReferencePreviewResponse,
+    RegenerateInviteCodeRequest, RemindRequest, RemindResponse, RemindTemplate, ResetLikesResponse,
+    ResponseCategory, ResponseLabelsResponse, ResponseListItem, ResponseSummaryResponse,
+    ResponseViewResponse, ResponsesListResponse, RestoreRetroRoomRequest, RestoreRetroRoomResponse,
+    RetroRoomCreateRequest, RetroRoomCreateResponse, RetroRoomListItem, RetroRoomMemberItem,
+    RetroRoomOrderItem, RetroRoomPreviewResponse, RetroRoomSettingsResponse,
+    RetrospectDetailResponse, RetrospectExportResponse, RetrospectListItem, RetrospectLockResponse,
+    RetrospectMemberItem, RetrospectQuestionItem, RoomInviteResponse, RoomMemberEmailItem,
+    RoomMemberEmailsResponse, RoomSubscriptionResponse, SearchRetrospectItem,
+    SharedAnalysisResponse, SharedAnswerItem, SharedPersonalMissionItem, SharedQuestionItem,
+    SharedRetrospectResponse, SimilarityWarningItem, SlackIntegrationResponse,
+    StartWritingResponse, StorageRangeFilter, StorageResponse, StorageRetrospectItem,
+    StorageYearGroup, SubmitAnswerItem, SubmitPreviewItem, SubmitPreviewResponse,
     SubmitRetrospectRequest, SubmitRetrospectResponse, SuccessAnalysisResponse,
-    SuccessAssistantResponse, SuccessCreateCommentResponse, SuccessCreateParticipantResponse,
-    SuccessCreateRetrospectResponse, SuccessDeleteRetroRoomResponse,
-    SuccessDeleteRetrospectResponse, SuccessDraftSaveResponse, SuccessEmptyResponse,
-    SuccessJoinRetroRoomResponse, SuccessLikeToggleResponse, SuccessListCommentsResponse,
-    SuccessReferencesListResponse, SuccessResponsesListResponse, SuccessRetroRoomCreateResponse,
-    SuccessRetroRoomListResponse, SuccessRetroRoomMembersResponse, SuccessRetrospectDetailResponse,
-    SuccessRetrospectListResponse, SuccessSearchResponse, SuccessStorageResponse,
-    SuccessSubmitRetrospectResponse, SuccessUpdateRetroRoomNameResponse,
-    UpdateRetroRoomNameRequest, UpdateRetroRoomNameResponse, UpdateRetroRoomOrderRequest,
+    SuccessAnalysisShareResponse, SuccessAnalysisVersionCompareResponse,
+    SuccessAnalysisVersionsResponse, SuccessAnswerFeedbackResponse, SuccessAssistantResponse,
+    SuccessAutoCategorizeResponse, SuccessBatchCreateRetrospectResponse, SuccessCheckInQrResponse,
+    SuccessCreateCommentResponse, SuccessCreateParticipantResponse,
+    SuccessCreateRetrospectResponse, SuccessCustomMethodListResponse, SuccessCustomMethodResponse,
+    SuccessDeleteRetroRoomResponse, SuccessDeleteRetrospectResponse, SuccessDraftSaveResponse,
+    SuccessEditSessionResponse, SuccessEmptyResponse, SuccessFinalizeQuestionsResponse,
+    SuccessHallOfFameResponse, SuccessHeartbeatResponse, SuccessInviteCodeResponse,
+    SuccessInviteMetaResponse, SuccessJoinRetroRoomResponse, SuccessKickMemberResponse,
+    SuccessLeaveRetroRoomResponse, SuccessLikeToggleResponse, SuccessListCommentsResponse,
+    SuccessListLikesResponse, SuccessMembershipHistoryResponse, SuccessMentionCandidatesResponse,
+    SuccessMoveRetrospectResponse, SuccessQuestionCoverageResponse,
+    SuccessReferencePreviewResponse, SuccessReferencesListResponse, SuccessRemindResponse,
+    SuccessResetLikesResponse, SuccessResponseLabelsResponse, SuccessResponseSummaryResponse,
+    SuccessResponseViewResponse, SuccessResponsesListResponse, SuccessRestoreRetroRoomResponse,
+    SuccessRetroRoomCreateResponse, SuccessRetroRoomListResponse, SuccessRetroRoomMembersResponse,
+    SuccessRetroRoomPreviewResponse, SuccessRetroRoomSettingsResponse,
+    SuccessRetrospectDetailResponse, SuccessRetrospectExportResponse,
+    SuccessRetrospectListResponse, SuccessRetrospectLockResponse, SuccessRoomInviteResponse,
+    SuccessRoomMemberEmailsResponse, SuccessRoomSubscriptionResponse, SuccessSearchResponse,
+    SuccessSharedAnalysisResponse, SuccessSharedRetrospectResponse,
+    SuccessSlackIntegrationResponse, SuccessStartWritingResponse, SuccessStorageResponse,
+    SuccessSubmitPreviewResponse, SuccessSubmitRetrospectResponse,
+    SuccessTransferOwnershipResponse, SuccessTrendingResponsesResponse,
+    SuccessUpdateMemberRoleResponse, SuccessUpdateRetroRoomNameResponse,
+    SuccessUpdateSubmittedAnswersResponse, SuccessValidateQuestionsResponse,
+    TransferOwnershipRequest, TransferOwnershipResponse, TrendingResponseItem,
+    TrendingResponsesResponse, TypingClientMessage, TypingEvent, TypingEventType,
+    UpdateMemberOrderRequest, UpdateMemberRoleRequest, UpdateMemberRoleResponse,
+    UpdateResponseLabelsRequest, UpdateRetroRoomNameRequest, UpdateRetroRoomNameResponse,
+    UpdateRetroRoomOrderRequest, UpdateRetroRoomSettingsRequest, UpdateRoomSubscriptionRequest,
+    UpdateSlackIntegrationRequest, UpdateSubmittedAnswersRequest, UpdateSubmittedAnswersResponse,
+    ValidateQuestionsRequest, ValidateQuestionsResponse,
 };
+use crate::domain::retrospect::entity::response::AnswerCategory;
 use crate::domain::retrospect::entity::retrospect::RetrospectMethod;
+use crate::domain::retrospect::entity::room_subscription::SubscriptionChannel;
+use crate::domain::upload::dto::{
+    PresignUploadRequest, PresignUploadResponse, SuccessPresignUploadResponse, UploadHeaders,
+};
 use crate::state::AppState;
+use crate::utils::response::FieldErrorItem;
 use crate::utils::{BaseResponse, ErrorResponse};
 
 /// OpenAPI 문서 정의
@@ -60,6 +127,7 @@ use crate::utils::{BaseResponse, ErrorResponse};
 #[openapi(
     paths(
         health_check,
+        health_info,
         domain::auth::handler::social_login,
         domain::auth::handler::signup,
         domain::auth::handler::refresh_token,
@@ -68,39 +136,114 @@ use crate::utils::{BaseResponse, ErrorResponse};
         domain::auth::handler::auth_test,
         // RetroRoom APIs
         domain::retrospect::handler::create_retro_room,
+        domain::retrospect::handler::preview_retro_room,
+        domain::retrospect::handler::get_invite_meta,
         domain::retrospect::handler::join_retro_room,
         domain::retrospect::handler::list_retro_rooms,
         domain::retrospect::handler::list_retro_room_members,
+        domain::retrospect::handler::list_member_emails,
+        domain::retrospect::handler::update_member_order,
+        domain::retrospect::handler::update_member_role,
+        domain::retrospect::handler::kick_member,
+        domain::retrospect::handler::transfer_ownership,
+        domain::retrospect::handler::list_mention_candidates,
+        domain::retrospect::handler::list_trending_responses,
+        domain::retrospect::handler::get_hall_of_fame,
+        domain::retrospect::handler::mark_retro_room_seen,
+        domain::retrospect::handler::subscribe_retro_room,
+        domain::retrospect::handler::unsubscribe_retro_room,
         domain::retrospect::handler::update_retro_room_order,
         domain::retrospect::handler::update_retro_room_name,
+        domain::retrospect::handler::update_retro_room_settings,
+        domain::retrospect::handler::update_slack_integration,
+        domain::retrospect::handler::create_room_snapshot,
+        domain::retrospect::handler::export_participation_csv,
+        domain::retrospect::handler::restore_retro_room,
+        domain::retrospect::handler::regenerate_invite_code,
+        domain::retrospect::handler::get_room_invite,
+        domain::retrospect::handler::create_custom_method,
+        domain::retrospect::handler::list_custom_methods,
+        domain::retrospect::handler::validate_questions,
+        domain::retrospect::handler::list_membership_history,
         domain::retrospect::handler::delete_retro_room,
+        domain::retrospect::handler::leave_retro_room,
         domain::retrospect::handler::list_retrospects,
         // Retrospect APIs
         domain::retrospect::handler::create_retrospect,
+        domain::retrospect::handler::batch_create_retrospects,
         domain::retrospect::handler::create_participant,
+        domain::retrospect::handler::remind_participants,
+        domain::retrospect::handler::issue_check_in_qr,
+        domain::retrospect::handler::check_in,
         domain::retrospect::handler::list_references,
+        domain::retrospect::handler::preview_reference,
         domain::retrospect::handler::save_draft,
+        domain::retrospect::handler::start_edit_session,
+        domain::retrospect::handler::start_writing,
+        domain::retrospect::handler::heartbeat,
+        domain::retrospect::handler::move_retrospect,
+        domain::retrospect::handler::duplicate_retrospect,
+        domain::retrospect::handler::typing_socket,
+        domain::retrospect::handler::finalize_questions,
+        domain::retrospect::handler::lock_retrospect,
+        domain::retrospect::handler::unlock_retrospect,
+        domain::retrospect::handler::record_response_view,
         domain::retrospect::handler::get_retrospect_detail,
+        domain::retrospect::handler::get_question_coverage,
         domain::retrospect::handler::submit_retrospect,
+        domain::retrospect::handler::preview_submit,
+        domain::retrospect::handler::update_submitted_answers,
         domain::retrospect::handler::get_storage,
         domain::retrospect::handler::analyze_retrospective_handler,
+        domain::retrospect::handler::stream_analysis_progress,
+        domain::retrospect::handler::list_analysis_versions,
+        domain::retrospect::handler::compare_analysis_versions,
+        domain::retrospect::handler::auto_categorize_responses,
+        domain::retrospect::handler::create_analysis_share,
+        domain::retrospect::handler::revoke_analysis_share,
+        domain::retrospect::handler::get_shared_analysis,
+        domain::retrospect::handler::create_retrospect_share,
+        domain::retrospect::handler::revoke_retrospect_share,
+        domain::retrospect::handler::get_shared_retrospect,
         domain::retrospect::handler::search_retrospects,
         domain::retrospect::handler::list_responses,
         domain::retrospect::handler::export_retrospect,
+        domain::retrospect::handler::export_calendar,
         domain::retrospect::handler::delete_retrospect,
         domain::retrospect::handler::list_comments,
         domain::retrospect::handler::create_comment,
+        domain::retrospect::handler::summarize_response,
+        domain::retrospect::handler::update_response_labels,
         domain::retrospect::handler::toggle_like,
+        domain::retrospect::handler::list_likes,
+        domain::retrospect::handler::reset_likes,
         domain::retrospect::handler::assistant_guide,
+        domain::retrospect::handler::answer_feedback,
         // Member APIs
         domain::member::handler::get_profile,
-        domain::member::handler::withdraw
+        domain::member::handler::withdraw,
+        domain::member::handler::list_recent_responses,
+        domain::member::handler::list_mentions,
+        domain::member::handler::mark_mention_read,
+        domain::member::handler::read_all_notifications,
+        domain::member::handler::update_dnd,
+        domain::member::handler::update_email_sharing,
+        domain::member::handler::update_notification_settings,
+        domain::member::handler::list_badges,
+        // Upload APIs
+        domain::upload::handler::presign_upload,
+        // Admin APIs
+        domain::admin::handler::get_ai_usage_summary,
+        domain::admin::handler::get_method_stats
     ),
     components(
         schemas(
             ErrorResponse,
+            FieldErrorItem,
             HealthResponse,
             SuccessHealthResponse,
+            HealthInfoResponse,
+            SuccessHealthInfoResponse,
             SocialLoginRequest,
             SocialLoginResponse,
             SuccessSocialLoginResponse,
@@ -122,37 +265,142 @@ use crate::utils::{BaseResponse, ErrorResponse};
             JoinRetroRoomRequest,
             JoinRetroRoomResponse,
             SuccessJoinRetroRoomResponse,
+            RetroRoomPreviewResponse,
+            SuccessRetroRoomPreviewResponse,
+            InviteMetaResponse,
+            SuccessInviteMetaResponse,
             RetroRoomListItem,
             SuccessRetroRoomListResponse,
             RetroRoomMemberItem,
             SuccessRetroRoomMembersResponse,
+            RoomMemberEmailItem,
+            RoomMemberEmailsResponse,
+            SuccessRoomMemberEmailsResponse,
+            MentionCandidateItem,
+            SuccessMentionCandidatesResponse,
+            TrendingResponseItem,
+            TrendingResponsesResponse,
+            SuccessTrendingResponsesResponse,
+            HallOfFameItem,
+            HallOfFameResponse,
+            SuccessHallOfFameResponse,
+            SubscriptionChannel,
+            UpdateRoomSubscriptionRequest,
+            RoomSubscriptionResponse,
+            SuccessRoomSubscriptionResponse,
             RetroRoomOrderItem,
             UpdateRetroRoomOrderRequest,
+            MemberOrderItem,
+            UpdateMemberOrderRequest,
             SuccessEmptyResponse,
+            AssignableRoomRole,
+            UpdateMemberRoleRequest,
+            UpdateMemberRoleResponse,
+            SuccessUpdateMemberRoleResponse,
+            KickMemberResponse,
+            SuccessKickMemberResponse,
+            TransferOwnershipRequest,
+            TransferOwnershipResponse,
+            SuccessTransferOwnershipResponse,
             UpdateRetroRoomNameRequest,
             UpdateRetroRoomNameResponse,
             SuccessUpdateRetroRoomNameResponse,
+            UpdateRetroRoomSettingsRequest,
+            RetroRoomSettingsResponse,
+            SuccessRetroRoomSettingsResponse,
+            UpdateSlackIntegrationRequest,
+            SlackIntegrationResponse,
+            SuccessSlackIntegrationResponse,
+            RestoreRetroRoomRequest,
+            RestoreRetroRoomResponse,
+            SuccessRestoreRetroRoomResponse,
+            InviteGrantRole,
+            RegenerateInviteCodeRequest,
+            InviteCodeResponse,
+            SuccessInviteCodeResponse,
+            InviteCodeItem,
+            RoomInviteResponse,
+            SuccessRoomInviteResponse,
+            CreateCustomMethodRequest,
+            ValidateQuestionsRequest,
+            ValidateQuestionsResponse,
+            QuestionValidationErrorItem,
+            SuccessValidateQuestionsResponse,
+            CustomMethodItem,
+            SuccessCustomMethodResponse,
+            SuccessCustomMethodListResponse,
+            MembershipHistoryItem,
+            MembershipHistoryResponse,
+            SuccessMembershipHistoryResponse,
+            MembershipEvent,
+            ResponseSummaryResponse,
+            SuccessResponseSummaryResponse,
+            UpdateResponseLabelsRequest,
+            ResponseLabelsResponse,
+            SuccessResponseLabelsResponse,
             DeleteRetroRoomResponse,
             SuccessDeleteRetroRoomResponse,
+            LeaveRetroRoomResponse,
+            SuccessLeaveRetroRoomResponse,
             RetrospectListItem,
             SuccessRetrospectListResponse,
             // Retrospect DTOs
             CreateRetrospectRequest,
             CreateRetrospectResponse,
             SuccessCreateRetrospectResponse,
+            BatchCreateRetrospectRequest,
+            BatchSkippedRoomItem,
+            BatchCreateRetrospectResponse,
+            SuccessBatchCreateRetrospectResponse,
             RetrospectMethod,
             CreateParticipantResponse,
             SuccessCreateParticipantResponse,
+            RemindTemplate,
+            RemindRequest,
+            RemindResponse,
+            SuccessRemindResponse,
+            CheckInRequest,
+            CheckInQrResponse,
+            SuccessCheckInQrResponse,
             ReferenceItem,
             SuccessReferencesListResponse,
+            ReferencePreviewRequest,
+            ReferencePreviewResponse,
+            SuccessReferencePreviewResponse,
             DraftSaveRequest,
             DraftItem,
             DraftSaveResponse,
             SuccessDraftSaveResponse,
+            EditSessionResponse,
+            SuccessEditSessionResponse,
+            StartWritingResponse,
+            SuccessStartWritingResponse,
+            HeartbeatResponse,
+            SuccessHeartbeatResponse,
+            MoveRetrospectRequest,
+            MoveRetrospectResponse,
+            SuccessMoveRetrospectResponse,
+            DuplicateRetrospectRequest,
+            TypingEventType,
+            TypingClientMessage,
+            TypingEvent,
+            FinalizeQuestionsResponse,
+            SuccessFinalizeQuestionsResponse,
+            RetrospectLockResponse,
+            SuccessRetrospectLockResponse,
+            ResponseViewResponse,
+            SuccessResponseViewResponse,
             SubmitRetrospectRequest,
             SubmitRetrospectResponse,
             SubmitAnswerItem,
+            SimilarityWarningItem,
             SuccessSubmitRetrospectResponse,
+            SubmitPreviewResponse,
+            SubmitPreviewItem,
+            SuccessSubmitPreviewResponse,
+            UpdateSubmittedAnswersRequest,
+            UpdateSubmittedAnswersResponse,
+            SuccessUpdateSubmittedAnswersResponse,
             RetrospectStatus,
             StorageRangeFilter,
             StorageRetrospectItem,
@@ -163,20 +411,58 @@ use crate::utils::{BaseResponse, ErrorResponse};
             RetrospectMemberItem,
             RetrospectQuestionItem,
             SuccessRetrospectDetailResponse,
+            QuestionCoverageItem,
+            QuestionCoverageResponse,
+            SuccessQuestionCoverageResponse,
             AnalysisResponse,
             EmotionRankItem,
             MissionItem,
             PersonalMissionItem,
             SuccessAnalysisResponse,
+            AnalysisVersionItem,
+            AnalysisVersionsResponse,
+            SuccessAnalysisVersionsResponse,
+            EmotionRankChangeItem,
+            AnalysisVersionCompareResponse,
+            SuccessAnalysisVersionCompareResponse,
+            AnalysisStage,
+            AnalysisProgressEvent,
+            AnswerCategory,
+            AutoCategorizeItem,
+            AutoCategorizeResponse,
+            SuccessAutoCategorizeResponse,
+            CreateAnalysisShareRequest,
+            AnalysisShareResponse,
+            SuccessAnalysisShareResponse,
+            SharedPersonalMissionItem,
+            SharedAnalysisResponse,
+            SuccessSharedAnalysisResponse,
+            SharedAnswerItem,
+            SharedQuestionItem,
+            SharedRetrospectResponse,
+            SuccessSharedRetrospectResponse,
             SearchRetrospectItem,
             SuccessSearchResponse,
+            DeleteRetrospectResponse,
             SuccessDeleteRetrospectResponse,
+            ExportFormat,
+            ExportCommentItem,
+            ExportAnswerItem,
+            ExportQuestionItem,
+            RetrospectExportResponse,
+            SuccessRetrospectExportResponse,
             ResponseCategory,
             ResponseListItem,
             ResponsesListResponse,
             SuccessResponsesListResponse,
             LikeToggleResponse,
             SuccessLikeToggleResponse,
+            ListLikesQuery,
+            LikeMemberItem,
+            ListLikesResponse,
+            SuccessListLikesResponse,
+            ResetLikesResponse,
+            SuccessResetLikesResponse,
             ListCommentsQuery,
             CommentItem,
             ListCommentsResponse,
@@ -189,10 +475,51 @@ use crate::utils::{BaseResponse, ErrorResponse};
             GuideItem,
             GuideType,
             SuccessAssistantResponse,
+            AnswerFeedbackRequest,
+            AnswerFeedbackResponse,
+            FeedbackAspect,
+            FeedbackSuggestionItem,
+            SuccessAnswerFeedbackResponse,
             // Member DTOs
             MemberProfileResponse,
             SuccessProfileResponse,
-            SuccessWithdrawResponse
+            SuccessWithdrawResponse,
+            RecentResponseItem,
+            RecentResponsesResponse,
+            SuccessRecentResponsesResponse,
+            MentionSourceType,
+            MentionItem,
+            MentionsResponse,
+            SuccessMentionsResponse,
+            NotificationReadType,
+            ReadAllNotificationsResponse,
+            SuccessReadAllNotificationsResponse,
+            UpdateDndRequest,
+            DndSettingsResponse,
+            SuccessDndSettingsResponse,
+            UpdateEmailSharingRequest,
+            EmailSharingResponse,
+            SuccessEmailSharingResponse,
+            UpdateNotificationSettingsRequest,
+            NotificationSettingsResponse,
+            SuccessNotificationSettingsResponse,
+            BadgeType,
+            BadgeItem,
+            BadgesResponse,
+            SuccessBadgesResponse,
+            // Upload DTOs
+            PresignUploadRequest,
+            PresignUploadResponse,
+            UploadHeaders,
+            SuccessPresignUploadResponse,
+            // Admin DTOs
+            AiUsagePeriod,
+            AiUsagePurpose,
+            AiUsageSummaryItem,
+            SuccessAiUsageSummaryResponse,
+            MethodStatItem,
+            MethodStatsResponse,
+            SuccessMethodStatsResponse
         )
     ),
     tags(
@@ -201,7 +528,9 @@ use crate::utils::{BaseResponse, ErrorResponse};
         (name = "RetroRoom", description = "회고방 관리 API"),
         (name = "Retrospect", description = "회고 API"),
         (name = "Response", description = "회고 답변 API"),
-        (name = "Member", description = "회원 API")
+        (name = "Member", description = "회원 API"),
+        (name = "Upload", description = "파일 업로드 API"),
+        (name = "Admin", description = "관리자 API")
     ),
     modifiers(&SecurityAddon),
     info(
@@ -233,6 +562,15 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // 로깅 초기화
     utils::init_logging();
 
+    // 업타임 계산을 위한 서버 시작 시각 기록
+    global::init_start_time();
+
+    // 관리 커맨드: 데이터 정합성 검증 (`integrity-check [--fix]`)
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("integrity-check") {
+        return run_integrity_check_command(&args[1..]).await;
+    }
+
     // 설정 로드
     let config = AppConfig::from_env()?;
     let port = config.server_port;
@@ -245,15 +583,35 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let db = crate::config::establish_connection(&database_url).await?;
 
     // AI 서비스 초기화
-    let ai_service = domain::ai::service::AiService::new(&config);
+    let ai_service = domain::ai::service::AiService::new(&config).with_usage_logging(db.clone());
+
+    // 로그인 시도 잠금 기록 초기화 (이메일·IP 기준)
+    let login_guard = domain::auth::login_guard::LoginAttemptGuard::new(
+        config.login_max_failed_attempts,
+        std::time::Duration::from_secs(config.login_lockout_secs),
+    )
+    .with_ip_limit(config.login_max_attempts_per_ip);
 
     // 애플리케이션 상태 생성
     let app_state = AppState {
         db,
         config: config.clone(),
         ai_service,
+        login_guard,
+        typing_hub: domain::retrospect::typing::TypingIndicatorHub::new(
+            std::time::Duration::from_secs(config.typing_idle_timeout_secs),
+        ),
+        last_seen: domain::member::last_seen::LastSeenThrottle::new(
+            std::time::Duration::from_secs(domain::member::last_seen::LAST_SEEN_THROTTLE_SECS),
+        ),
+        analysis_progress: domain::retrospect::analysis_progress::AnalysisProgressHub::new(),
     };
 
+    // 백그라운드 잡 시작
+    job::spawn_draft_cleanup_job(app_state.clone());
+    job::spawn_analysis_retry_job(app_state.clone());
+    job::spawn_typing_expiry_job(app_state.clone());
+
     // CORS 설정
     let allowed_origins = [
         "http://localhost:3000",
@@ -287,9 +645,25 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         ])
         .allow_credentials(true);
 
+    // Prometheus 메트릭 레코더 설치 (비활성화 시 /metrics는 404)
+    let metrics_handle = if config.metrics_enabled {
+        Some(monitoring::metrics::install_recorder()?)
+    } else {
+        None
+    };
+
+    // GraphQL 스키마 생성 (읽기 전용)
+    let graphql_schema = graphql::build_schema(app_state.clone());
+
     // 라우터 구성
     let app = Router::new()
         .route("/health", get(health_check))
+        .route("/health/info", get(health_info))
+        // Prometheus 메트릭 (METRICS_INTERNAL_ONLY=true면 내부망 요청만 허용)
+        .route(
+            monitoring::metrics::METRICS_PATH,
+            get(monitoring::metrics::metrics_handler).layer(axum::Extension(metrics_handle)),
+        )
         // [API-001] 소셜 로그인
         .route(
             "/api/v1/auth/social-login",
@@ -330,6 +704,16 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             axum::routing::post(domain::retrospect::handler::create_retro_room)
                 .get(domain::retrospect::handler::list_retro_rooms),
         )
+        // 회고방 초대 미리보기 (인증 불필요)
+        .route(
+            "/api/v1/retro-rooms/preview",
+            axum::routing::get(domain::retrospect::handler::preview_retro_room),
+        )
+        // 초대 링크 OpenGraph 메타 (인증 불필요)
+        .route(
+            "/api/v1/retro-rooms/invite/:code/meta",
+            axum::routing::get(domain::retrospect::handler::get_invite_meta),
+        )
         .route(
             "/api/v1/retro-rooms/join",
             axum::routing::post(domain::retrospect::handler::join_retro_room),
@@ -342,14 +726,116 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             "/api/v1/retro-rooms/:retro_room_id/name",
             axum::routing::patch(domain::retrospect::handler::update_retro_room_name),
         )
+        // 회고방 설정 변경 (진행 중 회고 수 제한)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/settings",
+            axum::routing::patch(domain::retrospect::handler::update_retro_room_settings),
+        )
+        // 회고방 Slack 연동 설정 (Owner 전용, 분석 결과 전송)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/integrations/slack",
+            axum::routing::put(domain::retrospect::handler::update_slack_integration),
+        )
+        // 회고방 데이터 백업 스냅샷 (Owner 전용)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/snapshot",
+            axum::routing::post(domain::retrospect::handler::create_room_snapshot),
+        )
+        // 회고방 참여 통계 CSV 내보내기 (멤버 전용)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/participation.csv",
+            axum::routing::get(domain::retrospect::handler::export_participation_csv),
+        )
+        // 회고방 스냅샷 복원 (새 회고방 생성)
+        .route(
+            "/api/v1/retro-rooms/restore",
+            axum::routing::post(domain::retrospect::handler::restore_retro_room),
+        )
+        // 회고방 초대 코드 재발급 (Owner 전용, 참여 역할 지정)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/invite-code",
+            axum::routing::post(domain::retrospect::handler::regenerate_invite_code),
+        )
+        // 회고방 초대 코드 조회 (Moderator 이상)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/invite",
+            axum::routing::get(domain::retrospect::handler::get_room_invite),
+        )
+        // 커스텀 회고 방식 생성 / 목록 조회
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/custom-methods",
+            axum::routing::post(domain::retrospect::handler::create_custom_method)
+                .get(domain::retrospect::handler::list_custom_methods),
+        )
+        // 회고방 참여 기록 조회 (Owner 전용)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/membership-history",
+            axum::routing::get(domain::retrospect::handler::list_membership_history),
+        )
         .route(
             "/api/v1/retro-rooms/:retro_room_id",
             axum::routing::delete(domain::retrospect::handler::delete_retro_room),
         )
+        // 회고방 나가기 (Owner 제외)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/leave",
+            axum::routing::delete(domain::retrospect::handler::leave_retro_room),
+        )
         .route(
             "/api/v1/retro-rooms/:retro_room_id/members",
             axum::routing::get(domain::retrospect::handler::list_retro_room_members),
         )
+        // 회고방 멤버 이메일 목록 조회 (Owner 전용)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/members/emails",
+            axum::routing::get(domain::retrospect::handler::list_member_emails),
+        )
+        // 회고방 멤버 표시 순서 변경 (Owner 전용)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/members/order",
+            axum::routing::patch(domain::retrospect::handler::update_member_order),
+        )
+        // 회고방 멤버 역할 변경 (Owner 전용)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/members/:member_id/role",
+            axum::routing::patch(domain::retrospect::handler::update_member_role),
+        )
+        // 회고방 멤버 강퇴 (Owner 전용)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/members/:member_id",
+            axum::routing::delete(domain::retrospect::handler::kick_member),
+        )
+        // 회고방 Owner 위임 (Owner 전용)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/owner",
+            axum::routing::patch(domain::retrospect::handler::transfer_ownership),
+        )
+        // 회고방 멤버 mention 자동완성
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/members/mention",
+            axum::routing::get(domain::retrospect::handler::list_mention_candidates),
+        )
+        // 회고방 좋아요 급상승 답변 조회
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/trending-responses",
+            axum::routing::get(domain::retrospect::handler::list_trending_responses),
+        )
+        // 회고방 명예의 전당 (좋아요 수 상위 답변)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/hall-of-fame",
+            axum::routing::get(domain::retrospect::handler::get_hall_of_fame),
+        )
+        // 회고방 활동 확인 처리 (미확인 활동 수 초기화)
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/seen",
+            axum::routing::patch(domain::retrospect::handler::mark_retro_room_seen),
+        )
+        // 회고방 새 회고 알림 구독 설정 / 해제
+        .route(
+            "/api/v1/retro-rooms/:retro_room_id/subscribe",
+            axum::routing::put(domain::retrospect::handler::subscribe_retro_room)
+                .delete(domain::retrospect::handler::unsubscribe_retro_room),
+        )
         .route(
             "/api/v1/retro-rooms/:retro_room_id/retrospects",
             axum::routing::get(domain::retrospect::handler::list_retrospects),
@@ -359,14 +845,43 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             "/api/v1/retrospects",
             axum::routing::post(domain::retrospect::handler::create_retrospect),
         )
+        // 여러 회고방에 회고 일괄 생성
+        .route(
+            "/api/v1/retrospects/batch",
+            axum::routing::post(domain::retrospect::handler::batch_create_retrospects),
+        )
         .route(
             "/api/v1/retrospects/:retrospect_id/participants",
             axum::routing::post(domain::retrospect::handler::create_participant),
         )
+        // 미제출 참여자 제출 독려 (Owner 전용)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/remind",
+            axum::routing::post(domain::retrospect::handler::remind_participants),
+        )
+        // 오프라인 회고 체크인 QR 발급 (Owner 전용)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/check-in/qr",
+            axum::routing::get(domain::retrospect::handler::issue_check_in_qr),
+        )
+        // QR 체크인으로 참석 등록
+        .route(
+            "/api/v1/retrospects/:retrospect_id/check-in",
+            axum::routing::post(domain::retrospect::handler::check_in),
+        )
         .route(
             "/api/v1/retrospects/:retrospect_id/references",
             axum::routing::get(domain::retrospect::handler::list_references),
         )
+        // 참고자료 링크 미리보기 (OpenGraph)
+        .route(
+            "/api/v1/references/preview",
+            axum::routing::post(domain::retrospect::handler::preview_reference),
+        )
+        .route(
+            "/api/v1/retrospects/validate-questions",
+            axum::routing::post(domain::retrospect::handler::validate_questions),
+        )
         .route(
             "/api/v1/retrospects/search",
             axum::routing::get(domain::retrospect::handler::search_retrospects),
@@ -380,18 +895,121 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             axum::routing::get(domain::retrospect::handler::get_retrospect_detail)
                 .delete(domain::retrospect::handler::delete_retrospect),
         )
+        // 질문별 답변 작성률 조회 (작성률 낮은 질문 우선)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/question-coverage",
+            axum::routing::get(domain::retrospect::handler::get_question_coverage),
+        )
         .route(
             "/api/v1/retrospects/:retrospect_id/drafts",
             axum::routing::put(domain::retrospect::handler::save_draft),
         )
+        // 회고 답변 작성 세션 시작 (다중 기기 충돌 감지)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/drafts/session",
+            axum::routing::post(domain::retrospect::handler::start_edit_session),
+        )
+        // 회고 답변 작성 시작 기록 (작성 소요 시간 측정)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/start-writing",
+            axum::routing::post(domain::retrospect::handler::start_writing),
+        )
+        // 회고 답변 작성 하트비트 (작성 중 상태 유지)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/heartbeat",
+            axum::routing::post(domain::retrospect::handler::heartbeat),
+        )
+        // 회고 질문 확정 (지연 생성 모드, 참여자별 답변 레코드 일괄 생성)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/finalize-questions",
+            axum::routing::post(domain::retrospect::handler::finalize_questions),
+        )
+        // 회고 답변 편집 잠금/해제 (Owner 전용)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/lock",
+            axum::routing::post(domain::retrospect::handler::lock_retrospect)
+                .delete(domain::retrospect::handler::unlock_retrospect),
+        )
+        // 회고 답변 실시간 타이핑 인디케이터 (WebSocket)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/typing",
+            axum::routing::get(domain::retrospect::handler::typing_socket),
+        )
+        // 회고 다른 회고방으로 이동 (원본/대상 방 Owner 전용)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/move",
+            axum::routing::patch(domain::retrospect::handler::move_retrospect),
+        )
+        // 회고 복제 (질문 구성·참고 URL 재사용, 답변·인사이트 제외)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/duplicate",
+            axum::routing::post(domain::retrospect::handler::duplicate_retrospect),
+        )
         .route(
             "/api/v1/retrospects/:retrospect_id/submit",
             axum::routing::post(domain::retrospect::handler::submit_retrospect),
         )
+        // 회고 제출 미리보기 (저장 없이 검증 결과·질문별 요약 반환)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/submit/preview",
+            axum::routing::post(domain::retrospect::handler::preview_submit),
+        )
+        // 제출 완료 답변 재수정 (회고 시작 전까지)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/answers",
+            axum::routing::put(domain::retrospect::handler::update_submitted_answers),
+        )
         .route(
             "/api/v1/retrospects/:retrospect_id/analysis",
             axum::routing::post(domain::retrospect::handler::analyze_retrospective_handler),
         )
+        // 회고 분석 진행 상태 스트리밍 (SSE)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/analysis/stream",
+            axum::routing::get(domain::retrospect::handler::stream_analysis_progress),
+        )
+        // 회고 분석 버전 목록/비교
+        .route(
+            "/api/v1/retrospects/:retrospect_id/analysis/versions",
+            axum::routing::get(domain::retrospect::handler::list_analysis_versions),
+        )
+        .route(
+            "/api/v1/retrospects/:retrospect_id/analysis/versions/compare",
+            axum::routing::get(domain::retrospect::handler::compare_analysis_versions),
+        )
+        // 답변 카테고리 자동 분류 (저장은 운영진 이상)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/auto-categorize",
+            axum::routing::post(domain::retrospect::handler::auto_categorize_responses),
+        )
+        // 회고 분석 결과 공유 링크 발급/폐기 (Owner 전용)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/analysis/share",
+            axum::routing::post(domain::retrospect::handler::create_analysis_share),
+        )
+        .route(
+            "/api/v1/retrospects/:retrospect_id/analysis/share/:token",
+            axum::routing::delete(domain::retrospect::handler::revoke_analysis_share),
+        )
+        // 공유 링크로 분석 결과 조회 (인증 불필요)
+        .route(
+            "/api/v1/shared/analysis/:token",
+            axum::routing::get(domain::retrospect::handler::get_shared_analysis),
+        )
+        // 회고 전체 읽기 전용 공유 링크 발급/폐기 (Owner 전용)
+        .route(
+            "/api/v1/retrospects/:retrospect_id/share",
+            axum::routing::post(domain::retrospect::handler::create_retrospect_share),
+        )
+        .route(
+            "/api/v1/retrospects/:retrospect_id/share/:token",
+            axum::routing::delete(domain::retrospect::handler::revoke_retrospect_share),
+        )
+        // 공유 링크로 회고 전체 조회 (인증 불필요)
+        .route(
+            "/api/v1/shared/retrospects/:token",
+            axum::routing::get(domain::retrospect::handler::get_shared_retrospect),
+        )
         .route(
             "/api/v1/retrospects/:retrospect_id/responses",
             axum::routing::get(domain::retrospect::handler::list_responses),
@@ -400,33 +1018,127 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             "/api/v1/retrospects/:retrospect_id/export",
             axum::routing::get(domain::retrospect::handler::export_retrospect),
         )
+        // 회고 일정 캘린더(.ics) 내보내기
+        .route(
+            "/api/v1/retrospects/:retrospect_id/calendar.ics",
+            axum::routing::get(domain::retrospect::handler::export_calendar),
+        )
         .route(
             "/api/v1/responses/:response_id/comments",
             axum::routing::get(domain::retrospect::handler::list_comments)
                 .post(domain::retrospect::handler::create_comment),
         )
-        // [API-025] 회고 답변 좋아요 토글
+        // 회고 답변 AI 한 줄 요약
+        .route(
+            "/api/v1/responses/:response_id/summarize",
+            axum::routing::post(domain::retrospect::handler::summarize_response),
+        )
+        // 회고 답변 라벨 설정
+        .route(
+            "/api/v1/responses/:response_id/labels",
+            axum::routing::put(domain::retrospect::handler::update_response_labels),
+        )
+        // 회고 답변 조회 기록 (조회수 집계)
+        .route(
+            "/api/v1/responses/:response_id/views",
+            axum::routing::post(domain::retrospect::handler::record_response_view),
+        )
+        // [API-025] 회고 답변 좋아요 토글 / 좋아요 누른 멤버 목록 조회
         .route(
             "/api/v1/responses/:response_id/likes",
-            axum::routing::post(domain::retrospect::handler::toggle_like),
+            axum::routing::get(domain::retrospect::handler::list_likes)
+                .post(domain::retrospect::handler::toggle_like),
+        )
+        // 답변 좋아요 일괄 취소 (Owner/Moderator 전용)
+        .route(
+            "/api/v1/responses/:response_id/likes/all",
+            axum::routing::delete(domain::retrospect::handler::reset_likes),
         )
         // 로그인된 유저 프로필 조회
         .route(
             "/api/v1/members/me",
             axum::routing::get(domain::member::handler::get_profile),
         )
+        // 최근 수정한 답변 조회
+        .route(
+            "/api/v1/members/me/recent-responses",
+            axum::routing::get(domain::member::handler::list_recent_responses),
+        )
+        // 내 멘션 목록 조회
+        .route(
+            "/api/v1/members/me/mentions",
+            axum::routing::get(domain::member::handler::list_mentions),
+        )
+        // 멘션 읽음 처리
+        .route(
+            "/api/v1/members/me/mentions/:mention_id/read",
+            axum::routing::patch(domain::member::handler::mark_mention_read),
+        )
+        // 알림 일괄 읽음 처리
+        .route(
+            "/api/v1/members/me/notifications/read-all",
+            axum::routing::post(domain::member::handler::read_all_notifications),
+        )
+        // 방해금지 시간대 설정
+        .route(
+            "/api/v1/members/me/dnd",
+            axum::routing::put(domain::member::handler::update_dnd),
+        )
+        // 이메일 공유 설정
+        .route(
+            "/api/v1/members/me/email-sharing",
+            axum::routing::put(domain::member::handler::update_email_sharing),
+        )
+        // 알림 수신 설정
+        .route(
+            "/api/v1/members/me/notification-settings",
+            axum::routing::put(domain::member::handler::update_notification_settings),
+        )
+        // 내 뱃지 목록 조회
+        .route(
+            "/api/v1/members/me/badges",
+            axum::routing::get(domain::member::handler::list_badges),
+        )
+        // 업로드 서명 URL 발급
+        .route(
+            "/api/v1/uploads/presign",
+            axum::routing::post(domain::upload::handler::presign_upload),
+        )
         // [API-025] 서비스 탈퇴
         .route(
             "/api/v1/members/withdraw",
             axum::routing::post(domain::member::handler::withdraw),
         )
+        // AI 토큰 사용량 조회 (관리자 전용)
+        .route(
+            "/api/v1/admin/ai-usage",
+            axum::routing::get(domain::admin::handler::get_ai_usage_summary),
+        )
+        // 회고 방식 사용 통계 (관리자 전용)
+        .route(
+            "/api/v1/admin/method-stats",
+            axum::routing::get(domain::admin::handler::get_method_stats),
+        )
         // [API-029] 회고 어시스턴트
         .route(
             "/api/v1/retrospects/:retrospect_id/questions/:question_id/assistant",
             axum::routing::post(domain::retrospect::handler::assistant_guide),
         )
+        // 답변 AI 피드백 미리보기
+        .route(
+            "/api/v1/retrospects/:retrospect_id/questions/:question_id/feedback",
+            axum::routing::post(domain::retrospect::handler::answer_feedback),
+        )
+        // GraphQL 조회 (쿼리 전용, JWT 인증 필요)
+        .route(
+            "/graphql",
+            axum::routing::post(graphql::graphql_handler).layer(axum::Extension(graphql_schema)),
+        )
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", ApiDoc::openapi()))
-        // 레이어 순서: 아래에서 위로 적용됨 (request_id → cors → TraceLayer → handler)
+        // 레이어 순서: 아래에서 위로 적용됨 (request_id → cors → TraceLayer → metrics → handler)
+        .layer(axum::middleware::from_fn(
+            monitoring::metrics::http_metrics_middleware,
+        ))
         .layer(TraceLayer::new_for_http())
         .layer(cors)
         .layer(axum::middleware::from_fn(global::request_id_middleware))
@@ -436,7 +1148,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", port)).await?;
     info!("Server running on http://0.0.0.0:{}", port);
 
-    axum::serve(listener, app).await?;
+    // `/metrics` 내부망 판별을 위해 peer 주소(ConnectInfo)를 함께 전달
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -463,6 +1180,91 @@ struct HealthResponse {
     status: String,
 }
 
+/// 헬스 체크 상세 정보 엔드포인트 (배포 추적용)
+///
+/// `HEALTH_INFO_DETAIL=false`(프로덕션 기본값)이면 commit hash와 빌드 시각은 응답에서 제외됩니다.
+#[utoipa::path(
+    get,
+    path = "/health/info",
+    responses(
+        (status = 200, description = "빌드/실행 정보 조회 성공", body = SuccessHealthInfoResponse)
+    ),
+    tag = "Health"
+)]
+async fn health_info(
+    axum::extract::State(state): axum::extract::State<AppState>,
+) -> axum::Json<BaseResponse<HealthInfoResponse>> {
+    let detail = state.config.health_info_detail;
+
+    let build_time = env!("BUILD_TIMESTAMP")
+        .parse::<i64>()
+        .ok()
+        .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+        .map(|dt| dt.to_rfc3339());
+
+    axum::Json(BaseResponse::success(HealthInfoResponse {
+        status: "healthy".to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        git_commit: detail.then(|| env!("GIT_COMMIT_HASH").to_string()),
+        build_time: build_time.filter(|_| detail),
+        environment: state.config.app_env.clone(),
+        uptime_seconds: global::uptime_seconds(),
+    }))
+}
+
+/// 헬스 체크 상세 정보 응답 DTO
+#[derive(serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct HealthInfoResponse {
+    /// 서버 상태
+    status: String,
+    /// 빌드 버전 (Cargo 패키지 버전)
+    version: String,
+    /// git commit hash (상세 정보 비공개 시 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    git_commit: Option<String>,
+    /// 빌드 시각 (RFC3339, UTC, 상세 정보 비공개 시 생략)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    build_time: Option<String>,
+    /// 실행 환경 (dev/prod)
+    environment: String,
+    /// 서버 업타임 (초)
+    uptime_seconds: u64,
+}
+
+/// 데이터 정합성 검증 관리 커맨드
+///
+/// 기본은 dry-run으로 고아 레코드 수만 출력하며, `--fix`를 명시한 경우에만 정리합니다.
+/// 스키마 동기화 없이 DB에 직접 연결합니다.
+async fn run_integrity_check_command(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut fix = false;
+    for arg in args {
+        match arg.as_str() {
+            "--fix" => fix = true,
+            "--dry-run" => {}
+            other => {
+                return Err(format!(
+                    "알 수 없는 옵션입니다: {} (사용법: integrity-check [--dry-run | --fix])",
+                    other
+                )
+                .into())
+            }
+        }
+    }
+
+    let database_url = std::env::var("DATABASE_URL").map_err(|_| "DATABASE_URL must be set")?;
+    let db = sea_orm::Database::connect(&database_url).await?;
+
+    if fix {
+        warn!("--fix 모드로 실행합니다. 고아 레코드가 삭제/수정됩니다.");
+    }
+
+    let reports = config::integrity::run_integrity_check(&db, fix).await?;
+    println!("{}", config::integrity::format_report(&reports, fix));
+
+    Ok(())
+}
+
 /// PDF 폰트 파일 존재 여부 검증
 fn validate_pdf_fonts() {
     let font_dir = std::env::var("PDF_FONT_DIR").unwrap_or_else(|_| "./fonts".to_string());
@@ -503,3 +1305,12 @@ struct SuccessHealthResponse {
     pub message: String,
     pub result: HealthResponse,
 }
+
+#[derive(serde::Serialize, utoipa::ToSchema)]
+#[serde(rename_all = "camelCase")]
+struct SuccessHealthInfoResponse {
+    pub is_success: bool,
+    pub code: String,
+    pub message: String,
+    pub result: HealthInfoResponse,
+}
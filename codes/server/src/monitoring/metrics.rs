@@ -0,0 +1,167 @@
+//! Prometheus metrics for HTTP requests and AI calls
+//!
+//! - `http_requests_total{method, path, status}` / `http_request_duration_seconds{method, path}`
+//! - `ai_calls_total{purpose, outcome}` / `ai_call_duration_seconds{purpose}`
+//!
+//! `path`는 라우트 템플릿(예: `/api/v1/retrospects/:retrospect_id`)을 사용해 라벨 카디널리티를 제한합니다.
+//! 에러율은 `status` 라벨로 계산합니다 (예: `sum(rate(http_requests_total{status=~"5.."}[5m])) / sum(rate(http_requests_total[5m]))`).
+
+use std::net::{IpAddr, SocketAddr};
+use std::time::{Duration, Instant};
+
+use axum::{
+    extract::{ConnectInfo, MatchedPath, Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Extension,
+};
+use metrics_exporter_prometheus::{BuildError, Matcher, PrometheusBuilder, PrometheusHandle};
+use tracing::warn;
+
+use crate::state::AppState;
+
+pub const HTTP_REQUESTS_TOTAL: &str = "http_requests_total";
+pub const HTTP_REQUEST_DURATION_SECONDS: &str = "http_request_duration_seconds";
+pub const AI_CALLS_TOTAL: &str = "ai_calls_total";
+pub const AI_CALL_DURATION_SECONDS: &str = "ai_call_duration_seconds";
+
+/// 메트릭 엔드포인트 경로 (스크랩 요청 자체는 HTTP 메트릭에서 제외)
+pub const METRICS_PATH: &str = "/metrics";
+
+/// 라우트에 매칭되지 않은 요청(404 등)의 `path` 라벨
+const UNMATCHED_PATH: &str = "unmatched";
+
+const HTTP_DURATION_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+/// AI 호출은 타임아웃(30초)까지 고려한 버킷
+const AI_DURATION_BUCKETS: &[f64] = &[0.5, 1.0, 2.0, 5.0, 10.0, 15.0, 20.0, 30.0, 60.0];
+
+/// AI 호출 결과 (`outcome` 라벨)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AiCallOutcome {
+    Success,
+    Timeout,
+    Error,
+    /// 응답은 받았지만 파싱·검증에 실패 (`AiAnalysisFailed` 등)
+    ParseError,
+}
+
+impl AiCallOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AiCallOutcome::Success => "success",
+            AiCallOutcome::Timeout => "timeout",
+            AiCallOutcome::Error => "error",
+            AiCallOutcome::ParseError => "parse_error",
+        }
+    }
+}
+
+/// Prometheus 빌더 (히스토그램 버킷 설정 포함)
+pub fn prometheus_builder() -> Result<PrometheusBuilder, BuildError> {
+    PrometheusBuilder::new()
+        .set_buckets_for_metric(
+            Matcher::Full(HTTP_REQUEST_DURATION_SECONDS.to_string()),
+            HTTP_DURATION_BUCKETS,
+        )?
+        .set_buckets_for_metric(
+            Matcher::Full(AI_CALL_DURATION_SECONDS.to_string()),
+            AI_DURATION_BUCKETS,
+        )
+}
+
+/// 전역 메트릭 레코더 설치 (프로세스당 한 번)
+pub fn install_recorder() -> Result<PrometheusHandle, BuildError> {
+    prometheus_builder()?.install_recorder()
+}
+
+/// AI 호출 결과와 소요 시간 기록
+pub fn record_ai_call(purpose: &str, outcome: AiCallOutcome, elapsed: Duration) {
+    metrics::counter!(
+        AI_CALLS_TOTAL,
+        "purpose" => purpose.to_string(),
+        "outcome" => outcome.as_str(),
+    )
+    .increment(1);
+    metrics::histogram!(AI_CALL_DURATION_SECONDS, "purpose" => purpose.to_string())
+        .record(elapsed.as_secs_f64());
+}
+
+/// HTTP 요청 수와 처리 시간 기록
+pub fn record_http_request(method: &str, path: &str, status: StatusCode, elapsed: Duration) {
+    metrics::counter!(
+        HTTP_REQUESTS_TOTAL,
+        "method" => method.to_string(),
+        "path" => path.to_string(),
+        "status" => status.as_u16().to_string(),
+    )
+    .increment(1);
+    metrics::histogram!(
+        HTTP_REQUEST_DURATION_SECONDS,
+        "method" => method.to_string(),
+        "path" => path.to_string(),
+    )
+    .record(elapsed.as_secs_f64());
+}
+
+/// HTTP 요청 메트릭 미들웨어
+pub async fn http_metrics_middleware(request: Request, next: Next) -> Response {
+    let path = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| UNMATCHED_PATH.to_string());
+
+    if path == METRICS_PATH {
+        return next.run(request).await;
+    }
+
+    let method = request.method().to_string();
+    let started_at = Instant::now();
+    let response = next.run(request).await;
+
+    record_http_request(&method, &path, response.status(), started_at.elapsed());
+    response
+}
+
+/// 내부망 IP 여부 (loopback, 사설, 링크 로컬, IPv6 unique local)
+pub fn is_internal_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(v4) => v4.is_loopback() || v4.is_private() || v4.is_link_local(),
+        IpAddr::V6(v6) => match v6.to_ipv4_mapped() {
+            Some(v4) => is_internal_ip(IpAddr::V4(v4)),
+            None => {
+                let first = v6.segments()[0];
+                // fc00::/7 (unique local), fe80::/10 (link local)
+                v6.is_loopback() || (first & 0xfe00) == 0xfc00 || (first & 0xffc0) == 0xfe80
+            }
+        },
+    }
+}
+
+/// Prometheus 메트릭 노출 엔드포인트 (text exposition format)
+///
+/// 메트릭이 비활성화되어 있거나, `METRICS_INTERNAL_ONLY=true`인데 외부 IP에서 요청하면 404를 반환합니다.
+/// 내부망 판별은 소켓 peer 주소 기준이므로 리버스 프록시 뒤에서는 프록시에서도 `/metrics`를 차단해야 합니다.
+pub async fn metrics_handler(
+    State(state): State<AppState>,
+    Extension(handle): Extension<Option<PrometheusHandle>>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+) -> Response {
+    let Some(handle) = handle else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+
+    if state.config.metrics_internal_only && !is_internal_ip(addr.ip()) {
+        warn!(remote_addr = %addr, "외부 네트워크에서 /metrics 접근 차단");
+        return StatusCode::NOT_FOUND.into_response();
+    }
+
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        handle.render(),
+    )
+        .into_response()
+}
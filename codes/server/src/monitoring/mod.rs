@@ -4,12 +4,14 @@
 //! - Log file watching and parsing
 //! - Discord webhook notifications
 //! - Event processing loop
+//! - Prometheus metrics for HTTP requests and AI calls
 
 #![allow(dead_code)]
 #![allow(unused_imports)]
 
 pub mod discord_alert;
 pub mod log_watcher;
+pub mod metrics;
 pub mod processor;
 
 pub use discord_alert::DiscordAlert;
@@ -1,5 +1,9 @@
 use crate::config::AppConfig;
 use crate::domain::ai::service::AiService;
+use crate::domain::auth::login_guard::LoginAttemptGuard;
+use crate::domain::member::last_seen::LastSeenThrottle;
+use crate::domain::retrospect::analysis_progress::AnalysisProgressHub;
+use crate::domain::retrospect::typing::TypingIndicatorHub;
 use sea_orm::DatabaseConnection;
 
 #[derive(Clone)]
@@ -7,4 +11,12 @@ pub struct AppState {
     pub db: DatabaseConnection,
     pub config: AppConfig,
     pub ai_service: AiService,
+    /// 이메일 로그인 연속 실패 잠금 기록
+    pub login_guard: LoginAttemptGuard,
+    /// 회고 답변 실시간 타이핑 인디케이터
+    pub typing_hub: TypingIndicatorHub,
+    /// 회원 마지막 활동 시각 갱신 스로틀
+    pub last_seen: LastSeenThrottle,
+    /// 회고 분석 진행 상태 스트리밍 채널
+    pub analysis_progress: AnalysisProgressHub,
 }
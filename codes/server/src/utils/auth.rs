@@ -3,6 +3,7 @@ use axum::{
     http::request::Parts,
 };
 
+use crate::domain::member::service::MemberService;
 use crate::state::AppState;
 use crate::utils::cookie::ACCESS_TOKEN_COOKIE;
 use crate::utils::error::AppError;
@@ -50,6 +51,17 @@ impl FromRequestParts<AppState> for AuthUser {
         // 토큰 검증 및 디코딩 (access token만 허용)
         let claims = decode_access_token(&token, &state.config.jwt_secret)?;
 
+        // 마지막 활동 시각 갱신 (스로틀 간격마다 한 번, 응답을 기다리게 하지 않도록 백그라운드 처리)
+        if let Ok(member_id) = claims.sub.parse::<i64>() {
+            if state.last_seen.should_touch(member_id) {
+                let db = state.db.clone();
+                let interval = state.last_seen.interval();
+                tokio::spawn(async move {
+                    MemberService::touch_last_seen(&db, member_id, interval).await;
+                });
+            }
+        }
+
         Ok(AuthUser(claims))
     }
 }
@@ -8,7 +8,7 @@ use std::fmt;
 use tracing::error;
 use validator::ValidationErrors;
 
-use super::response::ErrorResponse;
+use super::response::{ErrorResponse, FieldErrorItem};
 
 /// 애플리케이션 전역 에러 타입
 /// API 명세에 정의된 에러 코드를 사용합니다.
@@ -132,6 +132,15 @@ pub enum AppError {
     /// AI4032: 월간 어시스턴트 사용 횟수 초과 (403)
     AiAssistantLimitExceeded(String),
 
+    /// AI4033: 월간 답변 피드백 사용 횟수 초과 (403)
+    AiFeedbackLimitExceeded(String),
+
+    /// AI4034: 월간 답변 요약 사용 횟수 초과 (403)
+    AiSummaryLimitExceeded(String),
+
+    /// AI4035: 월간 답변 자동 분류 사용 횟수 초과 (403)
+    AiCategorizeLimitExceeded(String),
+
     /// RETRO4221: 분석할 회고 답변 데이터 부족 (422)
     RetroInsufficientData(String),
 
@@ -164,6 +173,75 @@ pub enum AppError {
 
     /// MEMBER4042: 존재하지 않는 사용자 (404)
     MemberNotFound(String),
+
+    /// RETRO4008: 분석이 완료되지 않은 회고 (400)
+    RetroNotAnalyzed(String),
+
+    /// SHARE4041: 존재하지 않거나 폐기된 공유 링크 (404)
+    SharedLinkNotFound(String),
+
+    /// SHARE4101: 만료된 공유 링크 (410)
+    SharedLinkExpired(String),
+
+    /// RETRO4093: 다른 기기에서 시작된 작성 세션과 충돌 (409)
+    EditSessionConflict(String),
+
+    /// RETRO4094: 진행 중인 회고 수 제한 초과 (409)
+    TooManyActiveRetrospects(String),
+
+    /// RETRO4009: 유효하지 않거나 만료·사용된 체크인 토큰 (400)
+    InvalidCheckInToken(String),
+
+    /// RETRO4291: 제출 독려 발송 간격 제한 (429)
+    RemindRateLimited(String),
+
+    /// AUTH4291: 로그인 연속 실패로 일시 잠금된 계정 (429)
+    AccountLocked(String),
+
+    /// MEMBER4043: 존재하지 않는 멘션 (404)
+    MentionNotFound(String),
+
+    /// RETRO4095: 같은 회고방 내 회고 제목 중복 (409)
+    RetrospectTitleDuplicate(String),
+
+    /// RETRO4010: 답변 최소 글자 수 미달 (400)
+    RetroAnswerTooShort(String),
+
+    /// RETRO4011: 질문이 아직 확정되지 않은 회고 (400)
+    RetroQuestionsNotFinalized(String),
+
+    /// RETRO4096: 이미 질문이 확정된 회고 (409)
+    RetroQuestionsAlreadyFinalized(String),
+
+    /// RETRO4044: 존재하지 않는 커스텀 회고 방식 (404)
+    CustomMethodNotFound(String),
+
+    /// UPLOAD4001: 허용되지 않은 업로드 파일 형식 (400)
+    UploadContentTypeNotAllowed(String),
+
+    /// UPLOAD4131: 업로드 최대 파일 크기 초과 (413)
+    UploadFileTooLarge(String),
+
+    /// UPLOAD5031: 업로드 스토리지 미설정 (503)
+    UploadStorageUnavailable(String),
+
+    /// RETRO4292: 작성 하트비트 최소 간격 제한 (429)
+    HeartbeatRateLimited(String),
+
+    /// RETRO4012: 지원하지 않는 스냅샷 포맷 또는 버전 (400)
+    SnapshotVersionMismatch(String),
+
+    /// RETRO4097: 잠금된 회고 - 답변 임시 저장/제출 불가 (409)
+    RetrospectLocked(String),
+
+    /// RETRO4293: 멤버 이메일 목록 조회 간격 제한 (429)
+    MemberEmailsRateLimited(String),
+
+    /// RETRO4034: Owner는 회고방을 나갈 수 없음 - 위임 필요 (403)
+    OwnerCannotLeave(String),
+
+    /// COMMON400: 여러 필드의 검증 실패 (400, 응답에 fieldErrors 포함)
+    FieldValidationFailed(Vec<FieldError>),
 }
 
 impl AppError {
@@ -210,6 +288,9 @@ impl AppError {
             AppError::QuestionNotFound(msg) => msg.clone(),
             AppError::AiMonthlyLimitExceeded(msg) => msg.clone(),
             AppError::AiAssistantLimitExceeded(msg) => msg.clone(),
+            AppError::AiFeedbackLimitExceeded(msg) => msg.clone(),
+            AppError::AiSummaryLimitExceeded(msg) => msg.clone(),
+            AppError::AiCategorizeLimitExceeded(msg) => msg.clone(),
             AppError::RetroInsufficientData(msg) => msg.clone(),
             AppError::AiAnalysisFailed(msg) => msg.clone(),
             AppError::AiConnectionFailed(msg) => msg.clone(),
@@ -220,6 +301,32 @@ impl AppError {
             AppError::PdfGenerationFailed(_) => "PDF 생성 중 서버 에러가 발생했습니다.".to_string(),
             AppError::RetroDeleteAccessDenied(msg) => msg.clone(),
             AppError::MemberNotFound(msg) => msg.clone(),
+            AppError::RetroNotAnalyzed(msg) => msg.clone(),
+            AppError::SharedLinkNotFound(msg) => msg.clone(),
+            AppError::SharedLinkExpired(msg) => msg.clone(),
+            AppError::EditSessionConflict(msg) => msg.clone(),
+            AppError::TooManyActiveRetrospects(msg) => msg.clone(),
+            AppError::InvalidCheckInToken(msg) => msg.clone(),
+            AppError::RemindRateLimited(msg) => msg.clone(),
+            AppError::AccountLocked(msg) => msg.clone(),
+            AppError::MentionNotFound(msg) => msg.clone(),
+            AppError::RetrospectTitleDuplicate(msg) => msg.clone(),
+            AppError::RetroAnswerTooShort(msg) => msg.clone(),
+            AppError::RetroQuestionsNotFinalized(msg) => msg.clone(),
+            AppError::RetroQuestionsAlreadyFinalized(msg) => msg.clone(),
+            AppError::CustomMethodNotFound(msg) => msg.clone(),
+            AppError::UploadContentTypeNotAllowed(msg) => msg.clone(),
+            AppError::UploadFileTooLarge(msg) => msg.clone(),
+            AppError::UploadStorageUnavailable(msg) => msg.clone(),
+            AppError::HeartbeatRateLimited(msg) => msg.clone(),
+            AppError::SnapshotVersionMismatch(msg) => msg.clone(),
+            AppError::RetrospectLocked(msg) => msg.clone(),
+            AppError::MemberEmailsRateLimited(msg) => msg.clone(),
+            AppError::OwnerCannotLeave(msg) => msg.clone(),
+            AppError::FieldValidationFailed(errors) => format!(
+                "잘못된 요청입니다: {}개 항목의 입력값이 올바르지 않습니다.",
+                errors.len()
+            ),
         }
     }
 
@@ -266,6 +373,9 @@ impl AppError {
             AppError::QuestionNotFound(_) => "RETRO4043",
             AppError::AiMonthlyLimitExceeded(_) => "AI4031",
             AppError::AiAssistantLimitExceeded(_) => "AI4032",
+            AppError::AiFeedbackLimitExceeded(_) => "AI4033",
+            AppError::AiSummaryLimitExceeded(_) => "AI4034",
+            AppError::AiCategorizeLimitExceeded(_) => "AI4035",
             AppError::RetroInsufficientData(_) => "RETRO4221",
             AppError::AiAnalysisFailed(_) => "AI5001",
             AppError::AiConnectionFailed(_) => "AI5002",
@@ -276,6 +386,29 @@ impl AppError {
             AppError::PdfGenerationFailed(_) => "COMMON500",
             AppError::RetroDeleteAccessDenied(_) => "RETRO4031",
             AppError::MemberNotFound(_) => "MEMBER4042",
+            AppError::RetroNotAnalyzed(_) => "RETRO4008",
+            AppError::SharedLinkNotFound(_) => "SHARE4041",
+            AppError::SharedLinkExpired(_) => "SHARE4101",
+            AppError::EditSessionConflict(_) => "RETRO4093",
+            AppError::TooManyActiveRetrospects(_) => "RETRO4094",
+            AppError::InvalidCheckInToken(_) => "RETRO4009",
+            AppError::RemindRateLimited(_) => "RETRO4291",
+            AppError::AccountLocked(_) => "AUTH4291",
+            AppError::MentionNotFound(_) => "MEMBER4043",
+            AppError::RetrospectTitleDuplicate(_) => "RETRO4095",
+            AppError::RetroAnswerTooShort(_) => "RETRO4010",
+            AppError::RetroQuestionsNotFinalized(_) => "RETRO4011",
+            AppError::RetroQuestionsAlreadyFinalized(_) => "RETRO4096",
+            AppError::CustomMethodNotFound(_) => "RETRO4044",
+            AppError::UploadContentTypeNotAllowed(_) => "UPLOAD4001",
+            AppError::UploadFileTooLarge(_) => "UPLOAD4131",
+            AppError::UploadStorageUnavailable(_) => "UPLOAD5031",
+            AppError::HeartbeatRateLimited(_) => "RETRO4292",
+            AppError::SnapshotVersionMismatch(_) => "RETRO4012",
+            AppError::RetrospectLocked(_) => "RETRO4097",
+            AppError::MemberEmailsRateLimited(_) => "RETRO4293",
+            AppError::OwnerCannotLeave(_) => "RETRO4034",
+            AppError::FieldValidationFailed(_) => "COMMON400",
         }
     }
 
@@ -322,6 +455,9 @@ impl AppError {
             AppError::QuestionNotFound(_) => StatusCode::NOT_FOUND,
             AppError::AiMonthlyLimitExceeded(_) => StatusCode::FORBIDDEN,
             AppError::AiAssistantLimitExceeded(_) => StatusCode::FORBIDDEN,
+            AppError::AiFeedbackLimitExceeded(_) => StatusCode::FORBIDDEN,
+            AppError::AiSummaryLimitExceeded(_) => StatusCode::FORBIDDEN,
+            AppError::AiCategorizeLimitExceeded(_) => StatusCode::FORBIDDEN,
             AppError::RetroInsufficientData(_) => StatusCode::UNPROCESSABLE_ENTITY,
             AppError::AiAnalysisFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::AiConnectionFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
@@ -332,6 +468,29 @@ impl AppError {
             AppError::PdfGenerationFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::RetroDeleteAccessDenied(_) => StatusCode::FORBIDDEN,
             AppError::MemberNotFound(_) => StatusCode::NOT_FOUND,
+            AppError::RetroNotAnalyzed(_) => StatusCode::BAD_REQUEST,
+            AppError::SharedLinkNotFound(_) => StatusCode::NOT_FOUND,
+            AppError::SharedLinkExpired(_) => StatusCode::GONE,
+            AppError::EditSessionConflict(_) => StatusCode::CONFLICT,
+            AppError::TooManyActiveRetrospects(_) => StatusCode::CONFLICT,
+            AppError::InvalidCheckInToken(_) => StatusCode::BAD_REQUEST,
+            AppError::RemindRateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::AccountLocked(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::MentionNotFound(_) => StatusCode::NOT_FOUND,
+            AppError::RetrospectTitleDuplicate(_) => StatusCode::CONFLICT,
+            AppError::RetroAnswerTooShort(_) => StatusCode::BAD_REQUEST,
+            AppError::RetroQuestionsNotFinalized(_) => StatusCode::BAD_REQUEST,
+            AppError::RetroQuestionsAlreadyFinalized(_) => StatusCode::CONFLICT,
+            AppError::CustomMethodNotFound(_) => StatusCode::NOT_FOUND,
+            AppError::UploadContentTypeNotAllowed(_) => StatusCode::BAD_REQUEST,
+            AppError::UploadFileTooLarge(_) => StatusCode::PAYLOAD_TOO_LARGE,
+            AppError::UploadStorageUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::HeartbeatRateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::SnapshotVersionMismatch(_) => StatusCode::BAD_REQUEST,
+            AppError::RetrospectLocked(_) => StatusCode::CONFLICT,
+            AppError::MemberEmailsRateLimited(_) => StatusCode::TOO_MANY_REQUESTS,
+            AppError::OwnerCannotLeave(_) => StatusCode::FORBIDDEN,
+            AppError::FieldValidationFailed(_) => StatusCode::BAD_REQUEST,
         }
     }
 }
@@ -373,7 +532,11 @@ impl IntoResponse for AppError {
             }
         }
 
-        let error_response = ErrorResponse::new(error_code, message);
+        let error_response = match self {
+            AppError::FieldValidationFailed(errors) => ErrorResponse::new(error_code, message)
+                .with_field_errors(errors.into_iter().map(FieldErrorItem::from).collect()),
+            _ => ErrorResponse::new(error_code, message),
+        };
 
         (status, Json(error_response)).into_response()
     }
@@ -499,6 +662,60 @@ fn extract_nested_error_messages(errors: &ValidationErrors) -> Vec<String> {
     messages
 }
 
+/// 필드 단위 검증 실패 (필드명은 요청 JSON 키 기준)
+#[derive(Debug)]
+pub struct FieldError {
+    pub field: String,
+    pub error: AppError,
+}
+
+impl From<FieldError> for FieldErrorItem {
+    fn from(e: FieldError) -> Self {
+        FieldErrorItem {
+            field: e.field,
+            code: e.error.error_code().to_string(),
+            message: e.error.message(),
+        }
+    }
+}
+
+/// 여러 필드의 검증 결과를 모아 한 번에 반환하기 위한 수집기
+///
+/// 실패가 하나면 원래 에러를 그대로, 여럿이면 `FieldValidationFailed`로 묶어 반환합니다.
+#[derive(Debug, Default)]
+pub struct FieldErrors {
+    errors: Vec<FieldError>,
+}
+
+impl FieldErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 검증 결과를 확인하고 실패 시 필드 에러로 기록
+    pub fn check<T>(&mut self, field: &str, result: Result<T, AppError>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.errors.push(FieldError {
+                    field: field.to_string(),
+                    error,
+                });
+                None
+            }
+        }
+    }
+
+    /// 모은 검증 실패를 에러로 변환
+    pub fn finish(mut self) -> Result<(), AppError> {
+        match self.errors.len() {
+            0 => Ok(()),
+            1 => Err(self.errors.remove(0).error),
+            _ => Err(AppError::FieldValidationFailed(self.errors)),
+        }
+    }
+}
+
 /// 편의 함수들
 #[allow(dead_code)]
 impl AppError {
@@ -642,4 +859,75 @@ mod tests {
         // Assert
         assert_eq!(app_error.error_code(), "RETRO4004");
     }
+
+    #[test]
+    fn should_return_no_error_when_all_fields_pass() {
+        // Arrange
+        let mut errors = FieldErrors::new();
+
+        // Act
+        let value = errors.check("title", Ok::<_, AppError>(1));
+
+        // Assert
+        assert_eq!(value, Some(1));
+        assert!(errors.finish().is_ok());
+    }
+
+    #[test]
+    fn should_return_original_error_when_single_field_fails() {
+        // Arrange
+        let mut errors = FieldErrors::new();
+        errors.check::<()>("date", Err(AppError::bad_request("날짜 오류")));
+
+        // Act
+        let error = errors.finish().unwrap_err();
+
+        // Assert
+        assert!(matches!(error, AppError::BadRequest(_)));
+        assert_eq!(error.message(), "잘못된 요청입니다: 날짜 오류");
+    }
+
+    #[test]
+    fn should_combine_errors_when_multiple_fields_fail() {
+        // Arrange
+        let mut errors = FieldErrors::new();
+        errors.check::<()>("date", Err(AppError::bad_request("날짜 오류")));
+        errors.check::<()>("time", Err(AppError::bad_request("시간 오류")));
+
+        // Act
+        let error = errors.finish().unwrap_err();
+
+        // Assert
+        assert_eq!(error.error_code(), "COMMON400");
+        assert_eq!(error.status_code(), StatusCode::BAD_REQUEST);
+        match error {
+            AppError::FieldValidationFailed(errors) => {
+                assert_eq!(errors.len(), 2);
+                assert_eq!(errors[1].field, "time");
+            }
+            other => panic!("Expected FieldValidationFailed error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_serialize_field_errors_only_when_present() {
+        // Arrange
+        let plain = ErrorResponse::new("COMMON400", "잘못된 요청입니다.");
+        let with_fields =
+            ErrorResponse::new("COMMON400", "잘못된 요청입니다.").with_field_errors(vec![
+                FieldErrorItem {
+                    field: "retrospectDate".to_string(),
+                    code: "COMMON400".to_string(),
+                    message: "날짜 오류".to_string(),
+                },
+            ]);
+
+        // Act
+        let plain_json = serde_json::to_value(&plain).unwrap();
+        let fields_json = serde_json::to_value(&with_fields).unwrap();
+
+        // Assert
+        assert!(plain_json.get("fieldErrors").is_none());
+        assert_eq!(fields_json["fieldErrors"][0]["field"], "retrospectDate");
+    }
 }
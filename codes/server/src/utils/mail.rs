@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use lettre::message::{header::ContentType, Mailbox, MultiPart, SinglePart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{AsyncSmtpTransport, AsyncTransport, Message, Tokio1Executor};
+
+use crate::config::AppConfig;
+use crate::utils::error::AppError;
+
+/// SMTP 요청 타임아웃
+const SMTP_TIMEOUT_SECS: u64 = 10;
+
+/// SMTP 메일 발송기
+pub struct Mailer {
+    transport: AsyncSmtpTransport<Tokio1Executor>,
+    from: Mailbox,
+}
+
+impl Mailer {
+    /// 설정으로 발송기 생성 (메일 발송이 설정되지 않았으면 None)
+    pub fn from_config(config: &AppConfig) -> Result<Option<Self>, AppError> {
+        if !config.is_mail_configured() {
+            return Ok(None);
+        }
+
+        let from: Mailbox = config
+            .mail_from
+            .parse()
+            .map_err(|e| AppError::InternalError(format!("MAIL_FROM 형식 오류: {}", e)))?;
+        let transport = AsyncSmtpTransport::<Tokio1Executor>::starttls_relay(&config.smtp_host)
+            .map_err(|e| AppError::InternalError(format!("SMTP 연결 설정 실패: {}", e)))?
+            .port(config.smtp_port)
+            .credentials(Credentials::new(
+                config.smtp_username.clone(),
+                config.smtp_password.clone(),
+            ))
+            .timeout(Some(Duration::from_secs(SMTP_TIMEOUT_SECS)))
+            .build();
+
+        Ok(Some(Self { transport, from }))
+    }
+
+    /// HTML 메일 발송 (HTML을 표시하지 못하는 클라이언트용 텍스트 본문 포함)
+    pub async fn send(
+        &self,
+        to: &str,
+        subject: &str,
+        html_body: String,
+        text_body: String,
+    ) -> Result<(), AppError> {
+        let to: Mailbox = to
+            .parse()
+            .map_err(|e| AppError::InternalError(format!("수신자 주소 형식 오류: {}", e)))?;
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .to(to)
+            .subject(subject)
+            .multipart(
+                MultiPart::alternative()
+                    .singlepart(
+                        SinglePart::builder()
+                            .header(ContentType::TEXT_PLAIN)
+                            .body(text_body),
+                    )
+                    .singlepart(
+                        SinglePart::builder()
+                            .header(ContentType::TEXT_HTML)
+                            .body(html_body),
+                    ),
+            )
+            .map_err(|e| AppError::InternalError(format!("메일 생성 실패: {}", e)))?;
+
+        self.transport
+            .send(message)
+            .await
+            .map_err(|e| AppError::InternalError(format!("메일 발송 실패: {}", e)))?;
+
+        Ok(())
+    }
+}
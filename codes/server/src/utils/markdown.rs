@@ -0,0 +1,32 @@
+use pulldown_cmark::{html, CowStr, Event, Options, Parser};
+
+/// 사용자 입력을 Markdown 본문에 넣기 전 서식 문자 이스케이프
+///
+/// 답변에 포함된 `#`, `*`, `[`, `<` 등이 제목·강조·링크·HTML로 해석되지 않도록 백슬래시로 이스케이프합니다.
+pub fn escape_markdown(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        if matches!(
+            c,
+            '\\' | '`' | '*' | '_' | '[' | ']' | '<' | '>' | '#' | '|' | '~'
+        ) {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+/// Markdown을 HTML로 변환
+///
+/// 원문에 포함된 HTML 태그는 렌더링하지 않고 텍스트로 이스케이프합니다.
+pub fn markdown_to_html(markdown: &str) -> String {
+    let parser = Parser::new_ext(markdown, Options::ENABLE_TABLES).map(|event| match event {
+        Event::Html(raw) | Event::InlineHtml(raw) => Event::Text(CowStr::from(raw.into_string())),
+        event => event,
+    });
+
+    let mut output = String::with_capacity(markdown.len() * 3 / 2);
+    html::push_html(&mut output, parser);
+    output
+}
@@ -3,7 +3,10 @@ pub mod cookie;
 pub mod error;
 pub mod jwt;
 pub mod logging;
+pub mod mail;
+pub mod markdown;
 pub mod response;
+pub mod timezone;
 
 pub use error::AppError;
 pub use logging::init_logging;
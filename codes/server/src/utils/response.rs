@@ -51,6 +51,21 @@ pub struct ErrorResponse {
     pub code: String,
     pub message: String,
     pub result: Option<()>,
+    /// 필드별 검증 실패 목록 (여러 필드가 함께 실패한 경우에만 포함)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub field_errors: Option<Vec<FieldErrorItem>>,
+}
+
+/// 필드별 검증 실패 항목
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldErrorItem {
+    /// 요청 필드명
+    pub field: String,
+    /// 에러 코드
+    pub code: String,
+    /// 에러 메시지
+    pub message: String,
 }
 
 impl ErrorResponse {
@@ -61,6 +76,13 @@ impl ErrorResponse {
             code: code.into(),
             message: message.into(),
             result: None,
+            field_errors: None,
         }
     }
+
+    /// 필드별 검증 실패 목록 추가
+    pub fn with_field_errors(mut self, field_errors: Vec<FieldErrorItem>) -> Self {
+        self.field_errors = Some(field_errors);
+        self
+    }
 }
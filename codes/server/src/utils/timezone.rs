@@ -0,0 +1,125 @@
+use chrono::{Duration, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::utils::error::AppError;
+
+/// 타임존 미지정 시 사용하는 기본 타임존 (KST)
+pub const DEFAULT_TIMEZONE: &str = "Asia/Seoul";
+
+/// 요청으로 받은 IANA 타임존 이름 검증
+///
+/// 미지정(None 또는 공백)이면 기본 타임존(KST)을, 알 수 없는 이름이면 400 에러를 반환합니다.
+pub fn parse_timezone(name: Option<&str>) -> Result<Tz, AppError> {
+    match name.map(str::trim).filter(|s| !s.is_empty()) {
+        None => Ok(chrono_tz::Asia::Seoul),
+        Some(name) => name.parse::<Tz>().map_err(|_| {
+            AppError::BadRequest(format!(
+                "지원하지 않는 타임존입니다: {} (예: Asia/Seoul)",
+                name
+            ))
+        }),
+    }
+}
+
+/// 저장된 타임존 이름을 해석 (해석 불가 시 KST로 폴백)
+pub fn resolve_timezone(name: &str) -> Tz {
+    name.parse::<Tz>().unwrap_or(chrono_tz::Asia::Seoul)
+}
+
+/// 해당 타임존 기준 현재 시각 (naive, 벽시계 시간)
+pub fn now_in(tz: Tz) -> NaiveDateTime {
+    Utc::now().with_timezone(&tz).naive_local()
+}
+
+/// UTC naive 시각을 해당 타임존의 벽시계 시간으로 변환
+pub fn utc_to_local(utc: NaiveDateTime, tz: Tz) -> NaiveDateTime {
+    utc.and_utc().with_timezone(&tz).naive_local()
+}
+
+/// 해당 타임존의 벽시계 시간을 UTC naive 시각으로 변환
+///
+/// 서머타임 종료로 두 번 나타나는 시각은 앞선 시각으로, 서머타임 시작으로 건너뛴 시각은 직전 오프셋 기준으로 변환합니다.
+pub fn local_to_utc(local: NaiveDateTime, tz: Tz) -> NaiveDateTime {
+    match tz.from_local_datetime(&local).earliest() {
+        Some(dt) => dt.naive_utc(),
+        None => {
+            let offset = tz.offset_from_utc_datetime(&local).fix().local_minus_utc();
+            local - Duration::seconds(i64::from(offset))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    #[test]
+    fn should_fall_back_to_kst_when_timezone_missing() {
+        assert_eq!(parse_timezone(None).unwrap(), chrono_tz::Asia::Seoul);
+        assert_eq!(parse_timezone(Some("  ")).unwrap(), chrono_tz::Asia::Seoul);
+    }
+
+    #[test]
+    fn should_parse_iana_timezone() {
+        assert_eq!(
+            parse_timezone(Some("America/New_York")).unwrap(),
+            chrono_tz::America::New_York
+        );
+    }
+
+    #[test]
+    fn should_reject_unknown_timezone() {
+        assert!(parse_timezone(Some("Mars/Olympus")).is_err());
+    }
+
+    #[test]
+    fn should_resolve_invalid_stored_timezone_to_kst() {
+        assert_eq!(resolve_timezone("invalid"), chrono_tz::Asia::Seoul);
+        assert_eq!(resolve_timezone("Europe/London"), chrono_tz::Europe::London);
+    }
+
+    #[test]
+    fn should_convert_utc_to_local_wall_clock() {
+        // Arrange
+        let utc = NaiveDate::from_ymd_opt(2026, 1, 31)
+            .unwrap()
+            .and_hms_opt(20, 0, 0)
+            .unwrap();
+
+        // Act
+        let kst = utc_to_local(utc, chrono_tz::Asia::Seoul);
+        let la = utc_to_local(utc, chrono_tz::America::Los_Angeles);
+
+        // Assert
+        assert_eq!(kst.format("%Y-%m-%d %H:%M").to_string(), "2026-02-01 05:00");
+        assert_eq!(la.format("%Y-%m-%d %H:%M").to_string(), "2026-01-31 12:00");
+    }
+
+    #[test]
+    fn should_convert_local_wall_clock_to_utc() {
+        // Arrange
+        let local = NaiveDate::from_ymd_opt(2026, 2, 1)
+            .unwrap()
+            .and_hms_opt(5, 0, 0)
+            .unwrap();
+        let skipped = NaiveDate::from_ymd_opt(2026, 3, 8)
+            .unwrap()
+            .and_hms_opt(2, 30, 0)
+            .unwrap();
+
+        // Act
+        let from_kst = local_to_utc(local, chrono_tz::Asia::Seoul);
+        let from_skipped = local_to_utc(skipped, chrono_tz::America::New_York);
+
+        // Assert
+        assert_eq!(
+            from_kst.format("%Y-%m-%d %H:%M").to_string(),
+            "2026-01-31 20:00"
+        );
+        assert_eq!(
+            from_skipped.format("%Y-%m-%d %H:%M").to_string(),
+            "2026-03-08 07:30"
+        );
+    }
+}
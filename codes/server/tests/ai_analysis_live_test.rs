@@ -51,6 +51,14 @@ struct MissionItem {
 
 // ===== 프롬프트 (prompt.rs와 동일) =====
 
+/// 팀원별 개인 미션 수 (서버 설정 `MISSIONS_PER_USER`와 동일, 기본 3)
+fn missions_per_user() -> usize {
+    std::env::var("MISSIONS_PER_USER")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(3)
+}
+
 fn system_prompt() -> String {
     r#"당신은 팀 회고 데이터를 종합 분석하는 따뜻한 AI 분석가입니다.
 팀원들이 작성한 회고 답변을 분석하여 팀 인사이트, 감정 통계, 개인별 맞춤 미션을 생성합니다.
@@ -81,7 +89,7 @@ fn system_prompt() -> String {
 - count: 해당 감정과 연관된 응답 수 (추정치)
 
 ### 3. 개인 미션 (personalMissions)
-- 각 팀원의 답변을 근거로 성장 미션 3개를 제안해요.
+- 각 팀원의 답변을 근거로 성장 미션 {missions_per_user}개를 제안해요.
 - missionTitle: 동사형 행동 미션 (예: "감정 표현 적극적으로 하기", "스프린트 분량 조절하기")
 - missionDesc: 해당 팀원의 답변에서 드러난 근거를 바탕으로 구체적인 제안을 작성해요. 상냥체(~어요)로 작성하세요.
   - 좋은 예: "즉각적인 응답과 활발한 협업툴 사용은 팀 운영의 안정성을 높였고, 스프린트 분량 조절과 작은 PR 단위로 나누면 더 효율적인 리뷰가 가능해져요."
@@ -141,11 +149,11 @@ fn system_prompt() -> String {
 1. 모든 텍스트는 상냥체(~어요/했어요)로 작성합니다. 격식체(~습니다) 절대 금지.
 2. emotionRank는 반드시 정확히 3개여야 합니다.
 3. emotionRank의 description은 1문장, 최대 30자 내외로 짧게 작성합니다.
-4. 각 사용자의 missions는 반드시 정확히 3개여야 합니다.
+4. 각 사용자의 missions는 반드시 정확히 {missions_per_user}개여야 합니다. 출력 형식 예시의 미션 수와 달라도 이 개수를 따릅니다.
 5. emotionRank는 count 기준 내림차순으로 정렬합니다.
 6. personalMissions는 입력 데이터의 userId를 그대로 사용합니다.
 7. JSON 형식만 출력합니다. 마크다운 코드 블록이나 추가 설명을 포함하지 마세요."#
-        .to_string()
+        .replace("{missions_per_user}", &missions_per_user().to_string())
 }
 
 struct MemberAnswerData {
@@ -428,8 +436,8 @@ async fn live_analysis_api_call() {
                     for pm in &analysis.personal_missions {
                         assert_eq!(
                             pm.missions.len(),
-                            3,
-                            "each user must have exactly 3 missions"
+                            missions_per_user(),
+                            "each user must have exactly MISSIONS_PER_USER missions"
                         );
                     }
                     println!("\n✅ 모든 검증 통과!");
@@ -56,6 +56,7 @@ fn should_serialize_join_response_in_camel_case() {
     let response = JoinRetroRoomResponse {
         retro_room_id: 456,
         title: "프로젝트".to_string(),
+        role: "MODERATOR".to_string(),
         joined_at: "2026-01-26T10:00:00".to_string(),
     };
 
@@ -67,6 +68,7 @@ fn should_serialize_join_response_in_camel_case() {
     assert!(parsed.get("retroRoomId").is_some());
     assert!(parsed.get("title").is_some());
     assert!(parsed.get("joinedAt").is_some());
+    assert_eq!(parsed["role"], "MODERATOR");
     // snake_case 키가 없어야 함
     assert!(parsed.get("retro_room_id").is_none());
     assert!(parsed.get("joined_at").is_none());
@@ -16,6 +16,7 @@ fn should_serialize_list_item_in_camel_case() {
         retro_room_id: 1,
         retro_room_name: "테스트 룸".to_string(),
         order_index: 1,
+        unread_count: 0,
     };
 
     // Act
@@ -25,6 +26,7 @@ fn should_serialize_list_item_in_camel_case() {
     assert!(json.contains("retroRoomId"));
     assert!(json.contains("retroRoomName"));
     assert!(json.contains("orderIndex"));
+    assert!(json.contains("unreadCount"));
     assert!(!json.contains("retro_room_id"));
 }
 
@@ -58,11 +60,13 @@ fn should_serialize_list_with_multiple_items() {
                 retro_room_id: 1,
                 retro_room_name: "룸1".to_string(),
                 order_index: 1,
+                unread_count: 0,
             },
             RetroRoomListItem {
                 retro_room_id: 2,
                 retro_room_name: "룸2".to_string(),
                 order_index: 2,
+                unread_count: 0,
             },
         ],
     };
@@ -82,6 +86,7 @@ fn should_preserve_order_index_values() {
         retro_room_id: 1,
         retro_room_name: "테스트".to_string(),
         order_index: 999,
+        unread_count: 0,
     };
 
     // Act
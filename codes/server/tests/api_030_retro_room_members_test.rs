@@ -18,6 +18,7 @@ fn should_serialize_member_item_in_camel_case() {
         nickname: "홍길동".to_string(),
         role: "OWNER".to_string(),
         joined_at: "2026-01-26T10:00:00".to_string(),
+        last_seen_at: None,
     };
 
     // Act
@@ -38,6 +39,33 @@ fn should_serialize_member_item_in_camel_case() {
     assert!(parsed.get("joined_at").is_none());
 }
 
+#[test]
+fn should_serialize_last_seen_at_or_null() {
+    // Arrange
+    let seen = RetroRoomMemberItem {
+        member_id: 1,
+        nickname: "홍길동".to_string(),
+        role: "MEMBER".to_string(),
+        joined_at: "2026-01-26T10:00:00".to_string(),
+        last_seen_at: Some("2026-10-13T09:30:00".to_string()),
+    };
+    let never_seen = RetroRoomMemberItem {
+        member_id: 2,
+        nickname: "김철수".to_string(),
+        role: "MEMBER".to_string(),
+        joined_at: "2026-01-27T10:00:00".to_string(),
+        last_seen_at: None,
+    };
+
+    // Act
+    let seen_json = serde_json::to_value(&seen).unwrap();
+    let never_seen_json = serde_json::to_value(&never_seen).unwrap();
+
+    // Assert
+    assert_eq!(seen_json["lastSeenAt"], "2026-10-13T09:30:00");
+    assert!(never_seen_json["lastSeenAt"].is_null());
+}
+
 #[test]
 fn should_serialize_empty_members_response() {
     // Arrange
@@ -71,18 +99,21 @@ fn should_serialize_list_with_multiple_members() {
                 nickname: "방장".to_string(),
                 role: "OWNER".to_string(),
                 joined_at: "2026-01-20T09:00:00".to_string(),
+                last_seen_at: None,
             },
             RetroRoomMemberItem {
                 member_id: 2,
                 nickname: "멤버1".to_string(),
                 role: "MEMBER".to_string(),
                 joined_at: "2026-01-21T10:00:00".to_string(),
+                last_seen_at: None,
             },
             RetroRoomMemberItem {
                 member_id: 3,
                 nickname: "멤버2".to_string(),
                 role: "MEMBER".to_string(),
                 joined_at: "2026-01-22T11:00:00".to_string(),
+                last_seen_at: None,
             },
         ],
     };
@@ -116,12 +147,14 @@ fn should_preserve_owner_first_sorting() {
                 nickname: "오너".to_string(),
                 role: "OWNER".to_string(),
                 joined_at: "2026-01-15T08:00:00".to_string(),
+                last_seen_at: None,
             },
             RetroRoomMemberItem {
                 member_id: 20,
                 nickname: "첫번째멤버".to_string(),
                 role: "MEMBER".to_string(),
                 joined_at: "2026-01-16T09:00:00".to_string(),
+                last_seen_at: None,
             },
         ],
     };
@@ -145,6 +178,7 @@ fn should_preserve_timestamp_format() {
         nickname: "테스터".to_string(),
         role: "MEMBER".to_string(),
         joined_at: "2026-12-31T23:59:59".to_string(),
+        last_seen_at: None,
     };
 
     // Act
@@ -165,6 +199,7 @@ fn should_handle_role_values() {
             nickname: "테스트".to_string(),
             role: role.to_string(),
             joined_at: "2026-01-26T10:00:00".to_string(),
+            last_seen_at: None,
         };
 
         // Act
@@ -188,6 +223,7 @@ fn should_serialize_success_response_structure() {
             nickname: "사용자".to_string(),
             role: "OWNER".to_string(),
             joined_at: "2026-02-01T12:00:00".to_string(),
+            last_seen_at: None,
         }],
     };
 
@@ -227,6 +263,7 @@ fn should_handle_unicode_nicknames() {
             nickname: nickname.to_string(),
             role: "MEMBER".to_string(),
             joined_at: "2026-01-26T10:00:00".to_string(),
+            last_seen_at: None,
         };
 
         // Act
@@ -246,6 +283,7 @@ fn should_handle_large_member_id() {
         nickname: "대용량ID".to_string(),
         role: "MEMBER".to_string(),
         joined_at: "2026-01-26T10:00:00".to_string(),
+        last_seen_at: None,
     };
 
     // Act
@@ -0,0 +1,51 @@
+//! 이메일 로그인 시도 잠금 테스트
+//!
+//! `login_by_email`이 DB 조회 전에 호출하는 `AuthService::check_login_attempt`를 통해
+//! 존재하는 이메일(성공 시도)도 한도에 도달하면 `AccountLocked`가 되는지 검증합니다.
+
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
+
+use server::domain::auth::login_guard::LoginAttemptGuard;
+use server::domain::auth::service::AuthService;
+use server::utils::error::AppError;
+
+const LOCKOUT: Duration = Duration::from_secs(900);
+
+fn ip(last: u8) -> IpAddr {
+    IpAddr::V4(Ipv4Addr::new(203, 0, 113, last))
+}
+
+#[test]
+fn should_lock_known_email_after_repeated_attempts() {
+    // Arrange: 존재하는 이메일로 로그인에 계속 성공하는 상황 (IP를 바꿔가며 시도)
+    let guard = LoginAttemptGuard::new(5, LOCKOUT).with_ip_limit(100);
+
+    // Act
+    let results: Vec<_> = (0..6)
+        .map(|i| AuthService::check_login_attempt(&guard, "member@moalog.me", ip(i)))
+        .collect();
+
+    // Assert
+    assert!(results[..5].iter().all(Result::is_ok));
+    let err = results[5].as_ref().unwrap_err();
+    assert!(matches!(err, AppError::AccountLocked(_)));
+    assert_eq!(err.error_code(), "AUTH4291");
+}
+
+#[test]
+fn should_lock_ip_that_rotates_emails() {
+    // Arrange: 한 IP에서 이메일을 바꿔가며 시도
+    let guard = LoginAttemptGuard::new(5, LOCKOUT).with_ip_limit(3);
+    for i in 0..3 {
+        AuthService::check_login_attempt(&guard, &format!("user{}@moalog.me", i), ip(1)).unwrap();
+    }
+
+    // Act
+    let same_ip = AuthService::check_login_attempt(&guard, "new@moalog.me", ip(1));
+    let other_ip = AuthService::check_login_attempt(&guard, "new@moalog.me", ip(2));
+
+    // Assert
+    assert!(matches!(same_ip, Err(AppError::AccountLocked(_))));
+    assert!(other_ip.is_ok());
+}
@@ -0,0 +1,58 @@
+//! GraphQL 스키마 테스트
+//!
+//! 테스트 대상:
+//! - POST /graphql
+//! - 읽기 전용 스키마 구성 (쿼리만 노출, 뮤테이션 없음)
+//! - AppError → GraphQL 에러 변환 (extensions.code)
+
+use async_graphql::{EmptyMutation, EmptySubscription, Request, Schema};
+use server::graphql::{to_graphql_error, QueryRoot};
+use server::utils::error::AppError;
+
+fn schema() -> Schema<QueryRoot, EmptyMutation, EmptySubscription> {
+    Schema::new(QueryRoot, EmptyMutation, EmptySubscription)
+}
+
+#[test]
+fn should_expose_query_only_schema() {
+    // Act
+    let sdl = schema().sdl();
+
+    // Assert
+    assert!(sdl.contains("rooms: [Room!]!"));
+    assert!(sdl.contains("room(retroRoomId: Int!): Room!"));
+    assert!(sdl.contains("retrospect(retrospectId: Int!): Retrospect!"));
+    assert!(sdl.contains(
+        "responses(category: String, label: String, cursor: Int, size: Int): ResponseConnection!"
+    ));
+    assert!(!sdl.contains("mutation:"));
+}
+
+#[tokio::test]
+async fn should_return_error_when_viewer_missing() {
+    // Act
+    let response = schema()
+        .execute(Request::new("{ rooms { retroRoomId } }"))
+        .await;
+
+    // Assert
+    assert_eq!(response.errors.len(), 1);
+}
+
+#[test]
+fn should_convert_app_error_with_error_code_extension() {
+    // Arrange
+    let error = AppError::RetroRoomNotFound("존재하지 않는 회고방입니다.".to_string());
+
+    // Act
+    let gql_error = to_graphql_error(error);
+
+    // Assert
+    assert_eq!(gql_error.message, "존재하지 않는 회고방입니다.");
+    let code = gql_error
+        .extensions
+        .as_ref()
+        .and_then(|ext| ext.get("code"))
+        .cloned();
+    assert_eq!(code, Some(async_graphql::Value::from("RETRO4041")));
+}
@@ -0,0 +1,72 @@
+//! 회원 뱃지 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/members/me/badges
+//! - BadgesResponse 직렬화
+//! - 뱃지 규칙 평가 (이벤트별 조건, 중복 부여 방지)
+
+use std::collections::HashSet;
+
+use server::domain::member::badge::{
+    badge_rule, earned_badges, BadgeEvent, BadgeStats, BADGE_RULES,
+};
+use server::domain::member::dto::{BadgeItem, BadgesResponse};
+use server::domain::member::entity::member_badge::BadgeType;
+
+#[test]
+fn should_serialize_badges_response() {
+    // Arrange
+    let rule = badge_rule(BadgeType::SubmitStreak3).unwrap();
+    let response = BadgesResponse {
+        badges: vec![BadgeItem {
+            badge_type: BadgeType::SubmitStreak3,
+            name: rule.name.to_string(),
+            description: rule.description.to_string(),
+            earned_at: "2026-10-16T21:00:00".to_string(),
+        }],
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    let badge = &json["badges"][0];
+    assert_eq!(badge["badgeType"], "SUBMIT_STREAK_3");
+    assert_eq!(badge["name"], "꾸준한 회고러");
+    assert_eq!(badge["earnedAt"], "2026-10-16T21:00:00");
+}
+
+#[test]
+fn should_award_popular_answer_at_like_threshold() {
+    // Arrange
+    let below = BadgeStats {
+        response_like_count: 9,
+        ..Default::default()
+    };
+    let reached = BadgeStats {
+        response_like_count: 10,
+        ..Default::default()
+    };
+
+    // Act & Assert
+    assert!(earned_badges(BadgeEvent::LikeReceived, &below, &HashSet::new()).is_empty());
+    assert_eq!(
+        earned_badges(BadgeEvent::LikeReceived, &reached, &HashSet::new()),
+        vec![BadgeType::PopularAnswer]
+    );
+}
+
+#[test]
+fn should_not_award_any_badge_when_all_owned() {
+    // Arrange
+    let stats = BadgeStats {
+        submitted_count: 100,
+        submit_streak: 100,
+        response_like_count: 100,
+    };
+    let owned: HashSet<BadgeType> = BADGE_RULES.iter().map(|rule| rule.badge_type).collect();
+
+    // Act & Assert
+    assert!(earned_badges(BadgeEvent::RetrospectSubmitted, &stats, &owned).is_empty());
+    assert!(earned_badges(BadgeEvent::LikeReceived, &stats, &owned).is_empty());
+}
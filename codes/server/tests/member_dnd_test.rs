@@ -0,0 +1,92 @@
+//! 방해금지 시간대 설정 테스트
+//!
+//! 테스트 대상:
+//! - PUT /api/v1/members/me/dnd
+//! - UpdateDndRequest 역직렬화
+//! - DndSettingsResponse 직렬화
+//! - 방해금지 시간대 알림 보류 해제 시각 계산
+
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use server::domain::member::dto::{DndSettingsResponse, UpdateDndRequest};
+use server::domain::notification::service::dnd_release_at;
+
+fn time(h: u32, m: u32) -> NaiveTime {
+    NaiveTime::from_hms_opt(h, m, 0).unwrap()
+}
+
+fn utc(y: i32, mo: u32, d: u32, h: u32, m: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, mo, d)
+        .unwrap()
+        .and_hms_opt(h, m, 0)
+        .unwrap()
+}
+
+#[test]
+fn should_deserialize_dnd_request() {
+    // Act
+    let req: UpdateDndRequest = serde_json::from_str(
+        r#"{"dndStart": "23:00", "dndEnd": "07:00", "timezone": "America/New_York"}"#,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(req.dnd_start.as_deref(), Some("23:00"));
+    assert_eq!(req.dnd_end.as_deref(), Some("07:00"));
+    assert_eq!(req.timezone.as_deref(), Some("America/New_York"));
+}
+
+#[test]
+fn should_deserialize_dnd_clear_request() {
+    // Act
+    let req: UpdateDndRequest =
+        serde_json::from_str(r#"{"dndStart": null, "dndEnd": null}"#).unwrap();
+
+    // Assert
+    assert!(req.dnd_start.is_none());
+    assert!(req.dnd_end.is_none());
+    assert!(req.timezone.is_none());
+}
+
+#[test]
+fn should_serialize_dnd_settings_in_camel_case() {
+    // Arrange
+    let response = DndSettingsResponse {
+        enabled: true,
+        dnd_start: Some("23:00".to_string()),
+        dnd_end: Some("07:00".to_string()),
+        timezone: Some("Asia/Seoul".to_string()),
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["enabled"], true);
+    assert_eq!(json["dndStart"], "23:00");
+    assert_eq!(json["dndEnd"], "07:00");
+    assert_eq!(json["timezone"], "Asia/Seoul");
+}
+
+#[test]
+fn should_hold_notification_until_dnd_end_in_member_timezone() {
+    // Arrange: 2026-10-16 04:00 UTC = 2026-10-16 00:00 America/New_York (EDT)
+    let now = utc(2026, 10, 16, 4, 0);
+
+    // Act
+    let release = dnd_release_at(now, time(22, 0), time(8, 0), chrono_tz::America::New_York);
+
+    // Assert: 2026-10-16 08:00 EDT = 2026-10-16 12:00 UTC
+    assert_eq!(release, Some(utc(2026, 10, 16, 12, 0)));
+}
+
+#[test]
+fn should_deliver_immediately_for_daytime_dnd_outside_window() {
+    // Arrange: 2026-10-16 09:00 UTC = 2026-10-16 18:00 KST
+    let now = utc(2026, 10, 16, 9, 0);
+
+    // Act
+    let release = dnd_release_at(now, time(13, 0), time(14, 0), chrono_tz::Asia::Seoul);
+
+    // Assert
+    assert_eq!(release, None);
+}
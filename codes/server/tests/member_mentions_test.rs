@@ -0,0 +1,58 @@
+//! 내 멘션 목록 조회 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/members/me/mentions
+//! - PATCH /api/v1/members/me/mentions/{mentionId}/read
+//! - MentionsQuery 역직렬화
+//! - MentionsResponse 직렬화
+
+use server::domain::member::dto::{
+    MentionItem, MentionSourceType, MentionsQuery, MentionsResponse,
+};
+
+#[test]
+fn should_deserialize_mentions_query_with_unread_only() {
+    // Act
+    let query: MentionsQuery =
+        serde_json::from_str(r#"{"cursor": 5, "size": 10, "unreadOnly": true}"#).unwrap();
+
+    // Assert
+    assert_eq!(query.cursor, Some(5));
+    assert_eq!(query.size, Some(10));
+    assert_eq!(query.unread_only, Some(true));
+}
+
+#[test]
+fn should_serialize_mentions_in_camel_case() {
+    // Arrange
+    let response = MentionsResponse {
+        mentions: vec![MentionItem {
+            mention_id: 12,
+            source_type: MentionSourceType::Comment,
+            retrospect_id: 7,
+            retrospect_title: "스프린트 3 회고".to_string(),
+            response_id: 42,
+            comment_id: Some(3),
+            author_nickname: "제이슨".to_string(),
+            snippet: "@케이 이 부분 같이 봐요".to_string(),
+            is_read: false,
+            created_at: "2026-01-26T10:00:00".to_string(),
+        }],
+        has_next: false,
+        next_cursor: None,
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    let item = &json["mentions"][0];
+    assert_eq!(item["mentionId"], 12);
+    assert_eq!(item["sourceType"], "COMMENT");
+    assert_eq!(item["retrospectTitle"], "스프린트 3 회고");
+    assert_eq!(item["commentId"], 3);
+    assert_eq!(item["authorNickname"], "제이슨");
+    assert_eq!(item["isRead"], false);
+    assert_eq!(json["hasNext"], false);
+    assert!(json["nextCursor"].is_null());
+}
@@ -0,0 +1,42 @@
+//! 회원 알림 수신 설정 테스트
+//!
+//! 테스트 대상:
+//! - PUT /api/v1/members/me/notification-settings
+//! - UpdateNotificationSettingsRequest 역직렬화, NotificationSettingsResponse 직렬화
+
+use server::domain::member::dto::{
+    NotificationSettingsResponse, UpdateNotificationSettingsRequest,
+};
+
+#[test]
+fn should_deserialize_notification_settings_request() {
+    // Act
+    let req: UpdateNotificationSettingsRequest =
+        serde_json::from_str(r#"{"notificationEnabled": false}"#).unwrap();
+
+    // Assert
+    assert!(!req.notification_enabled);
+}
+
+#[test]
+fn should_reject_notification_settings_request_without_flag() {
+    // Act
+    let result = serde_json::from_str::<UpdateNotificationSettingsRequest>("{}");
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_serialize_notification_settings_response_in_camel_case() {
+    // Arrange
+    let response = NotificationSettingsResponse {
+        notification_enabled: true,
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["notificationEnabled"], true);
+}
@@ -0,0 +1,60 @@
+//! 알림 일괄 읽음 처리 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/members/me/notifications/read-all
+//! - ReadAllNotificationsQuery 역직렬화
+//! - NotificationReadType → NotificationType 변환
+//! - ReadAllNotificationsResponse 직렬화
+
+use server::domain::member::dto::{
+    NotificationReadType, ReadAllNotificationsQuery, ReadAllNotificationsResponse,
+};
+use server::domain::notification::entity::notification::NotificationType;
+
+#[test]
+fn should_deserialize_read_all_query_with_type() {
+    // Act
+    let query: ReadAllNotificationsQuery =
+        serde_json::from_str(r#"{"type": "RESPONSE_LIKED"}"#).unwrap();
+    let empty: ReadAllNotificationsQuery = serde_json::from_str("{}").unwrap();
+
+    // Assert
+    assert_eq!(query.read_type, Some(NotificationReadType::ResponseLiked));
+    assert_eq!(empty.read_type, None);
+}
+
+#[test]
+fn should_reject_unknown_read_type() {
+    // Act
+    let result = serde_json::from_str::<ReadAllNotificationsQuery>(r#"{"type": "UNKNOWN"}"#);
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_map_read_type_to_notification_type() {
+    // Act & Assert - 멘션은 알림 테이블 유형이 아님
+    assert_eq!(NotificationReadType::Mention.notification_type(), None);
+    assert_eq!(
+        NotificationReadType::ResponseCommented.notification_type(),
+        Some(NotificationType::ResponseCommented)
+    );
+    assert_eq!(
+        NotificationReadType::RoleChanged.notification_type(),
+        Some(NotificationType::RoleChanged)
+    );
+}
+
+#[test]
+fn should_serialize_read_all_response_in_camel_case() {
+    // Arrange
+    let response = ReadAllNotificationsResponse { read_count: 7 };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["readCount"], 7);
+    assert!(json.get("read_count").is_none());
+}
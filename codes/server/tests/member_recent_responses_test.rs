@@ -0,0 +1,61 @@
+//! 최근 수정한 답변 조회 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/members/me/recent-responses
+//! - RecentResponsesQuery 역직렬화
+//! - RecentResponsesResponse 직렬화
+
+use server::domain::member::dto::{
+    RecentResponseItem, RecentResponsesQuery, RecentResponsesResponse,
+};
+
+#[test]
+fn should_deserialize_recent_responses_query_with_defaults() {
+    // Act
+    let query: RecentResponsesQuery = serde_json::from_str("{}").unwrap();
+
+    // Assert
+    assert!(query.cursor.is_none());
+    assert!(query.size.is_none());
+}
+
+#[test]
+fn should_deserialize_recent_responses_query_with_cursor() {
+    // Act
+    let query: RecentResponsesQuery =
+        serde_json::from_str(r#"{"cursor": 42, "size": 10}"#).unwrap();
+
+    // Assert
+    assert_eq!(query.cursor, Some(42));
+    assert_eq!(query.size, Some(10));
+}
+
+#[test]
+fn should_serialize_recent_responses_in_camel_case() {
+    // Arrange
+    let response = RecentResponsesResponse {
+        responses: vec![RecentResponseItem {
+            response_id: 42,
+            retrospect_id: 7,
+            retrospect_title: "스프린트 3 회고".to_string(),
+            question: "문제라고 판단되었던 점이 있나요?".to_string(),
+            content: "리뷰가 늦어졌어요".to_string(),
+            updated_at: "2026-01-26T10:00:00".to_string(),
+        }],
+        has_next: true,
+        next_cursor: Some(42),
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    let item = &json["responses"][0];
+    assert_eq!(item["responseId"], 42);
+    assert_eq!(item["retrospectId"], 7);
+    assert_eq!(item["retrospectTitle"], "스프린트 3 회고");
+    assert_eq!(item["updatedAt"], "2026-01-26T10:00:00");
+    assert_eq!(json["hasNext"], true);
+    assert_eq!(json["nextCursor"], 42);
+    assert!(json.get("next_cursor").is_none());
+}
@@ -0,0 +1,109 @@
+//! Prometheus 메트릭 테스트
+//!
+//! 테스트 대상:
+//! - GET /metrics 노출 형식 (AI 호출, HTTP 요청 카운터/히스토그램)
+//! - 내부망 IP 판별 (METRICS_INTERNAL_ONLY)
+
+use std::net::IpAddr;
+use std::time::Duration;
+
+use axum::http::StatusCode;
+use server::monitoring::metrics::{
+    is_internal_ip, prometheus_builder, record_ai_call, record_http_request, AiCallOutcome,
+};
+
+fn render<F: FnOnce()>(record: F) -> String {
+    let recorder = prometheus_builder().unwrap().build_recorder();
+    let handle = recorder.handle();
+    metrics::with_local_recorder(&recorder, record);
+    handle.render()
+}
+
+#[test]
+fn should_render_ai_calls_by_purpose_and_outcome() {
+    // Act
+    let output = render(|| {
+        record_ai_call("ANALYSIS", AiCallOutcome::Success, Duration::from_secs(3));
+        record_ai_call("ANALYSIS", AiCallOutcome::Timeout, Duration::from_secs(30));
+        record_ai_call(
+            "ASSISTANT",
+            AiCallOutcome::Error,
+            Duration::from_millis(800),
+        );
+        record_ai_call(
+            "ASSISTANT",
+            AiCallOutcome::ParseError,
+            Duration::from_secs(2),
+        );
+    });
+
+    // Assert
+    assert!(output.contains(r#"ai_calls_total{purpose="ANALYSIS",outcome="success"} 1"#));
+    assert!(output.contains(r#"ai_calls_total{purpose="ANALYSIS",outcome="timeout"} 1"#));
+    assert!(output.contains(r#"ai_calls_total{purpose="ASSISTANT",outcome="error"} 1"#));
+    assert!(output.contains(r#"ai_calls_total{purpose="ASSISTANT",outcome="parse_error"} 1"#));
+    assert!(output.contains(r#"ai_call_duration_seconds_bucket{purpose="ANALYSIS",le="30"} 2"#));
+    assert!(output.contains(r#"ai_call_duration_seconds_count{purpose="ANALYSIS"} 2"#));
+}
+
+#[test]
+fn should_render_http_requests_by_route_template_and_status() {
+    // Act
+    let output = render(|| {
+        let path = "/api/v1/retrospects/:retrospect_id";
+        record_http_request("GET", path, StatusCode::OK, Duration::from_millis(20));
+        record_http_request("GET", path, StatusCode::OK, Duration::from_millis(40));
+        record_http_request(
+            "GET",
+            path,
+            StatusCode::INTERNAL_SERVER_ERROR,
+            Duration::from_millis(5),
+        );
+    });
+
+    // Assert
+    assert!(output.contains(
+        r#"http_requests_total{method="GET",path="/api/v1/retrospects/:retrospect_id",status="200"} 2"#
+    ));
+    assert!(output.contains(
+        r#"http_requests_total{method="GET",path="/api/v1/retrospects/:retrospect_id",status="500"} 1"#
+    ));
+    assert!(output.contains(
+        r#"http_request_duration_seconds_bucket{method="GET",path="/api/v1/retrospects/:retrospect_id",le="0.025"} 2"#
+    ));
+}
+
+#[test]
+fn should_treat_loopback_and_private_addresses_as_internal() {
+    // Arrange
+    let internal = [
+        "127.0.0.1",
+        "10.0.3.7",
+        "172.16.0.1",
+        "192.168.1.20",
+        "::1",
+        "fd00::1",
+        "::ffff:10.0.0.1",
+    ];
+
+    // Act & Assert
+    for ip in internal {
+        assert!(is_internal_ip(ip.parse::<IpAddr>().unwrap()), "{}", ip);
+    }
+}
+
+#[test]
+fn should_treat_public_addresses_as_external() {
+    // Arrange
+    let external = [
+        "8.8.8.8",
+        "172.32.0.1",
+        "2001:4860:4860::8888",
+        "::ffff:1.1.1.1",
+    ];
+
+    // Act & Assert
+    for ip in external {
+        assert!(!is_internal_ip(ip.parse::<IpAddr>().unwrap()), "{}", ip);
+    }
+}
@@ -0,0 +1,62 @@
+//! 참고자료 링크 미리보기 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/references/preview
+//! - ReferencePreviewRequest 검증
+//! - ReferencePreviewResponse 직렬화
+//! - 내부망 주소 차단
+
+use server::domain::retrospect::dto::{ReferencePreviewRequest, ReferencePreviewResponse};
+use server::domain::retrospect::link_preview::parse_preview_url;
+use server::utils::error::AppError;
+use validator::Validate;
+
+// ============== 요청 검증 테스트 ==============
+
+#[test]
+fn should_reject_empty_url() {
+    // Arrange
+    let req: ReferencePreviewRequest = serde_json::from_str(r#"{"url": ""}"#).unwrap();
+
+    // Act & Assert
+    assert!(req.validate().is_err());
+}
+
+#[test]
+fn should_reject_metadata_endpoint_of_cloud_provider() {
+    // Act
+    let result = parse_preview_url("http://169.254.169.254/latest/meta-data/");
+
+    // Assert
+    match result {
+        Err(e @ AppError::RetroUrlInvalid(_)) => {
+            assert_eq!(e.error_code(), "RETRO4006");
+            assert_eq!(e.status_code(), axum::http::StatusCode::BAD_REQUEST);
+        }
+        other => panic!("Expected RetroUrlInvalid error, got {:?}", other),
+    }
+}
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_fallback_preview_response() {
+    // Arrange
+    let response = ReferencePreviewResponse {
+        url: "https://github.com/YAPP-Github".to_string(),
+        title: "github.com".to_string(),
+        description: None,
+        image: None,
+        fetched: false,
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["url"], "https://github.com/YAPP-Github");
+    assert_eq!(json["title"], "github.com");
+    assert!(json["description"].is_null());
+    assert!(json["image"].is_null());
+    assert_eq!(json["fetched"], false);
+}
@@ -0,0 +1,96 @@
+//! 회고 답변 라벨 설정 테스트
+//!
+//! 테스트 대상:
+//! - PUT /api/v1/responses/{responseId}/labels
+//! - UpdateResponseLabelsRequest 유효성 검증
+//! - ResponseLabelsResponse 직렬화
+
+use server::domain::retrospect::dto::{ResponseLabelsResponse, UpdateResponseLabelsRequest};
+use validator::Validate;
+
+fn labels(values: &[&str]) -> UpdateResponseLabelsRequest {
+    UpdateResponseLabelsRequest {
+        labels: values.iter().map(|v| v.to_string()).collect(),
+    }
+}
+
+// ============== 유효성 검증 테스트 ==============
+
+#[test]
+fn should_validate_labels_within_limits() {
+    // Arrange
+    let req = labels(&["액션아이템", "칭찬"]);
+
+    // Act & Assert
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_allow_empty_labels_to_clear_all() {
+    // Arrange
+    let req: UpdateResponseLabelsRequest = serde_json::from_str(r#"{"labels":[]}"#).unwrap();
+
+    // Act & Assert
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_fail_validation_when_more_than_five_labels() {
+    // Arrange
+    let req = labels(&["a", "b", "c", "d", "e", "f"]);
+
+    // Act
+    let result = req.validate();
+
+    // Assert
+    assert!(result.is_err());
+    assert!(result.unwrap_err().field_errors().contains_key("labels"));
+}
+
+#[test]
+fn should_fail_validation_when_label_is_blank() {
+    // Arrange
+    let req = labels(&["칭찬", "   "]);
+
+    // Act & Assert
+    assert!(req.validate().is_err());
+}
+
+#[test]
+fn should_fail_validation_when_label_exceeds_20_chars() {
+    // Arrange
+    let too_long = "가".repeat(21);
+    let req = labels(&[too_long.as_str()]);
+
+    // Act & Assert
+    assert!(req.validate().is_err());
+}
+
+#[test]
+fn should_measure_label_length_without_edge_whitespace() {
+    // Arrange
+    let label = format!("  {}  ", "가".repeat(20));
+    let req = labels(&[label.as_str()]);
+
+    // Act & Assert
+    assert!(req.validate().is_ok());
+}
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_labels_response_in_camel_case() {
+    // Arrange
+    let response = ResponseLabelsResponse {
+        response_id: 501,
+        labels: vec!["액션아이템".to_string(), "칭찬".to_string()],
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["responseId"], 501);
+    assert_eq!(json["labels"][0], "액션아이템");
+    assert!(json.get("response_id").is_none());
+}
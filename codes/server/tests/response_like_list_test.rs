@@ -0,0 +1,71 @@
+//! 답변 좋아요 누른 멤버 목록 조회 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/responses/{responseId}/likes
+//! - ListLikesQuery 역직렬화
+//! - ListLikesResponse 직렬화 (list_comments와 동일한 hasNext/nextCursor 구조)
+
+use server::domain::retrospect::dto::{LikeMemberItem, ListLikesQuery, ListLikesResponse};
+
+#[test]
+fn should_deserialize_list_likes_query() {
+    // Act
+    let query: ListLikesQuery = serde_json::from_str(r#"{"cursor": 30, "size": 10}"#).unwrap();
+    let empty: ListLikesQuery = serde_json::from_str("{}").unwrap();
+
+    // Assert
+    assert_eq!(query.cursor, Some(30));
+    assert_eq!(query.size, Some(10));
+    assert_eq!(empty.cursor, None);
+    assert_eq!(empty.size, None);
+}
+
+#[test]
+fn should_serialize_list_likes_in_camel_case() {
+    // Arrange
+    let response = ListLikesResponse {
+        likes: vec![
+            LikeMemberItem {
+                like_id: 31,
+                member_id: 2,
+                nickname: "카이".to_string(),
+            },
+            LikeMemberItem {
+                like_id: 28,
+                member_id: 5,
+                nickname: "제이슨".to_string(),
+            },
+        ],
+        has_next: true,
+        next_cursor: Some(28),
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    let item = &json["likes"][0];
+    assert_eq!(item["likeId"], 31);
+    assert_eq!(item["memberId"], 2);
+    assert_eq!(item["nickname"], "카이");
+    assert_eq!(json["hasNext"], true);
+    assert_eq!(json["nextCursor"], 28);
+}
+
+#[test]
+fn should_serialize_empty_likes_as_empty_array() {
+    // Arrange
+    let response = ListLikesResponse {
+        likes: vec![],
+        has_next: false,
+        next_cursor: None,
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["likes"], serde_json::json!([]));
+    assert_eq!(json["hasNext"], false);
+    assert!(json["nextCursor"].is_null());
+}
@@ -0,0 +1,31 @@
+//! 답변 좋아요 일괄 취소 테스트
+//!
+//! 테스트 대상:
+//! - DELETE /api/v1/responses/{responseId}/likes/all
+//! - ResetLikesResponse 직렬화
+
+use server::domain::retrospect::dto::{ResetLikesResponse, SuccessResetLikesResponse};
+
+#[test]
+fn should_serialize_reset_likes_response_in_camel_case() {
+    // Arrange
+    let response = SuccessResetLikesResponse {
+        is_success: true,
+        code: "COMMON200".to_string(),
+        message: "답변의 좋아요를 모두 취소했습니다.".to_string(),
+        result: ResetLikesResponse {
+            response_id: 42,
+            previous_like_count: 17,
+            total_likes: 0,
+        },
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["result"]["responseId"], 42);
+    assert_eq!(json["result"]["previousLikeCount"], 17);
+    assert_eq!(json["result"]["totalLikes"], 0);
+    assert!(json["result"].get("previous_like_count").is_none());
+}
@@ -0,0 +1,42 @@
+//! 회고 답변 조회수 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/responses/{response_id}/views
+//! - ResponseViewResponse 직렬화
+
+use server::domain::retrospect::dto::ResponseViewResponse;
+
+#[test]
+fn should_serialize_counted_response_view() {
+    // Arrange
+    let response = ResponseViewResponse {
+        response_id: 501,
+        view_count: 31,
+        counted: true,
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["responseId"], 501);
+    assert_eq!(json["viewCount"], 31);
+    assert_eq!(json["counted"], true);
+}
+
+#[test]
+fn should_serialize_uncounted_response_view() {
+    // Arrange: 본인 답변이거나 중복 조회면 조회수 유지
+    let response = ResponseViewResponse {
+        response_id: 501,
+        view_count: 30,
+        counted: false,
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["viewCount"], 30);
+    assert_eq!(json["counted"], false);
+}
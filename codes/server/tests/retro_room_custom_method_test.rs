@@ -0,0 +1,200 @@
+//! 회고방 커스텀 회고 방식 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retro-rooms/{retro_room_id}/custom-methods
+//! - GET /api/v1/retro-rooms/{retro_room_id}/custom-methods
+//! - CreateCustomMethodRequest 역직렬화 및 검증 (이름 길이, 질문 1~5개, 질문별 길이, placeholder 길이, 가중치 범위)
+//! - CustomMethodItem 직렬화
+//! - RetrospectMethod CUSTOM 직렬화/역직렬화
+
+use server::domain::retrospect::dto::{CreateCustomMethodRequest, CustomMethodItem};
+use server::domain::retrospect::entity::retrospect::RetrospectMethod;
+use validator::Validate;
+
+fn create_request(name: &str, questions: Vec<&str>) -> CreateCustomMethodRequest {
+    CreateCustomMethodRequest {
+        name: name.to_string(),
+        questions: questions.into_iter().map(String::from).collect(),
+        placeholders: vec![],
+        weights: vec![],
+    }
+}
+
+// ============== 역직렬화/검증 테스트 ==============
+
+#[test]
+fn should_deserialize_custom_method_request() {
+    // Act
+    let req: CreateCustomMethodRequest = serde_json::from_str(
+        r#"{"name":"스프린트 회고","questions":["이번 스프린트 목표는?","다음에 바꿀 점은?"]}"#,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(req.name, "스프린트 회고");
+    assert_eq!(req.questions.len(), 2);
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_fail_validation_when_questions_empty() {
+    // Arrange
+    let req = create_request("스프린트 회고", vec![]);
+
+    // Act
+    let result = req.validate();
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_fail_validation_when_questions_exceed_five() {
+    // Arrange
+    let req = create_request("스프린트 회고", vec!["1", "2", "3", "4", "5", "6"]);
+
+    // Act
+    let result = req.validate();
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_fail_validation_when_question_blank() {
+    // Arrange
+    let req = create_request("스프린트 회고", vec!["좋았던 점은?", "   "]);
+
+    // Act
+    let result = req.validate();
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_fail_validation_when_question_too_long() {
+    // Arrange
+    let long_question = "가".repeat(201);
+    let req = create_request("스프린트 회고", vec![long_question.as_str()]);
+
+    // Act
+    let result = req.validate();
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_fail_validation_when_name_too_long() {
+    // Arrange
+    let long_name = "가".repeat(21);
+    let req = create_request(&long_name, vec!["좋았던 점은?"]);
+
+    // Act
+    let result = req.validate();
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_deserialize_placeholders_with_null_entries() {
+    // Act
+    let req: CreateCustomMethodRequest = serde_json::from_str(
+        r#"{"name":"스프린트 회고","questions":["목표는?","바꿀 점은?"],"placeholders":["예) 배포 자동화",null]}"#,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(
+        req.placeholders,
+        vec![Some("예) 배포 자동화".to_string()), None]
+    );
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_fail_validation_when_placeholder_too_long() {
+    // Arrange
+    let mut req = create_request("스프린트 회고", vec!["좋았던 점은?"]);
+    req.placeholders = vec![Some("가".repeat(101))];
+
+    // Act
+    let result = req.validate();
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_deserialize_weights_with_null_entries() {
+    // Act
+    let req: CreateCustomMethodRequest = serde_json::from_str(
+        r#"{"name":"스프린트 회고","questions":["목표는?","바꿀 점은?"],"weights":[5,null]}"#,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(req.weights, vec![Some(5), None]);
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_fail_validation_when_weight_out_of_range() {
+    for weight in [0, 6, -1] {
+        // Arrange
+        let mut req = create_request("스프린트 회고", vec!["좋았던 점은?"]);
+        req.weights = vec![Some(weight)];
+
+        // Act
+        let result = req.validate();
+
+        // Assert
+        assert!(result.is_err(), "가중치 {}는 거부되어야 합니다", weight);
+    }
+}
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_custom_method_item() {
+    // Arrange
+    let item = CustomMethodItem {
+        custom_method_id: 3,
+        retro_room_id: 1,
+        name: "스프린트 회고".to_string(),
+        questions: vec!["목표는?".to_string(), "바꿀 점은?".to_string()],
+        placeholders: vec![Some("예) 배포 자동화".to_string()), None],
+        weights: vec![Some(5), None],
+        question_count: 2,
+        created_at: "2026-10-16T09:00:00".to_string(),
+    };
+
+    // Act
+    let json = serde_json::to_value(&item).unwrap();
+
+    // Assert
+    assert_eq!(json["customMethodId"], 3);
+    assert_eq!(json["retroRoomId"], 1);
+    assert_eq!(json["name"], "스프린트 회고");
+    assert_eq!(json["questions"][1], "바꿀 점은?");
+    assert_eq!(json["placeholders"][0], "예) 배포 자동화");
+    assert!(json["placeholders"][1].is_null());
+    assert_eq!(json["weights"][0], 5);
+    assert!(json["weights"][1].is_null());
+    assert_eq!(json["questionCount"], 2);
+    assert_eq!(json["createdAt"], "2026-10-16T09:00:00");
+}
+
+#[test]
+fn should_round_trip_custom_retrospect_method() {
+    // Act
+    let method: RetrospectMethod = serde_json::from_str(r#""CUSTOM""#).unwrap();
+    let json = serde_json::to_string(&method).unwrap();
+
+    // Assert
+    assert_eq!(method, RetrospectMethod::Custom);
+    assert_eq!(json, r#""CUSTOM""#);
+    assert!(method.default_questions().is_empty());
+}
@@ -0,0 +1,74 @@
+//! 회고방 초대 코드 재발급 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retro-rooms/{retro_room_id}/invite-code
+//! - RegenerateInviteCodeRequest 역직렬화 (부여 역할 제한)
+//! - InviteCodeResponse 직렬화
+
+use server::domain::member::entity::member_retro_room::RoomRole;
+use server::domain::retrospect::dto::{
+    InviteCodeResponse, InviteGrantRole, RegenerateInviteCodeRequest,
+};
+
+// ============== 역직렬화 테스트 ==============
+
+#[test]
+fn should_default_grant_role_to_none_when_omitted() {
+    // Act
+    let req: RegenerateInviteCodeRequest = serde_json::from_str("{}").unwrap();
+
+    // Assert
+    assert!(req.grant_role.is_none());
+}
+
+#[test]
+fn should_deserialize_moderator_grant_role() {
+    // Act
+    let req: RegenerateInviteCodeRequest =
+        serde_json::from_str(r#"{"grantRole":"MODERATOR"}"#).unwrap();
+
+    // Assert
+    assert_eq!(req.grant_role, Some(InviteGrantRole::Moderator));
+}
+
+#[test]
+fn should_reject_owner_grant_role() {
+    // Act
+    let result = serde_json::from_str::<RegenerateInviteCodeRequest>(r#"{"grantRole":"OWNER"}"#);
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_map_grant_role_to_room_role() {
+    // Act & Assert
+    assert_eq!(RoomRole::from(InviteGrantRole::Member), RoomRole::Member);
+    assert_eq!(
+        RoomRole::from(InviteGrantRole::Moderator),
+        RoomRole::Moderator
+    );
+}
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_invite_code_response_in_camel_case() {
+    // Arrange
+    let response = InviteCodeResponse {
+        retro_room_id: 1,
+        invite_code: "INV-1234-5678".to_string(),
+        grant_role: InviteGrantRole::Moderator,
+        expires_at: "2026-02-02T10:00:00".to_string(),
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retroRoomId"], 1);
+    assert_eq!(json["inviteCode"], "INV-1234-5678");
+    assert_eq!(json["grantRole"], "MODERATOR");
+    assert_eq!(json["expiresAt"], "2026-02-02T10:00:00");
+    assert!(json.get("invite_code").is_none());
+}
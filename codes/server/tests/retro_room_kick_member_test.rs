@@ -0,0 +1,25 @@
+//! 회고방 멤버 강퇴 테스트
+//!
+//! 테스트 대상:
+//! - DELETE /api/v1/retro-rooms/{retroRoomId}/members/{memberId}
+//! - KickMemberResponse 직렬화
+
+use server::domain::retrospect::dto::KickMemberResponse;
+
+#[test]
+fn should_serialize_kick_member_response_in_camel_case() {
+    // Arrange
+    let response = KickMemberResponse {
+        retro_room_id: 1,
+        member_id: 3,
+        kicked_at: "2026-10-16T10:00:00".to_string(),
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retroRoomId"], 1);
+    assert_eq!(json["memberId"], 3);
+    assert_eq!(json["kickedAt"], "2026-10-16T10:00:00");
+}
@@ -0,0 +1,23 @@
+//! 회고방 나가기 테스트
+//!
+//! 테스트 대상:
+//! - DELETE /api/v1/retro-rooms/{retroRoomId}/leave
+//! - LeaveRetroRoomResponse 직렬화
+
+use server::domain::retrospect::dto::LeaveRetroRoomResponse;
+
+#[test]
+fn should_serialize_leave_response_in_camel_case() {
+    // Arrange
+    let response = LeaveRetroRoomResponse {
+        retro_room_id: 789,
+        left_at: "2026-10-16T10:00:00".to_string(),
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retroRoomId"], 789);
+    assert_eq!(json["leftAt"], "2026-10-16T10:00:00");
+}
@@ -0,0 +1,68 @@
+//! 회고방 멤버 이메일 목록 조회 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/retro-rooms/{retroRoomId}/members/emails
+//! - PUT /api/v1/members/me/email-sharing
+//! - RoomMemberEmailsResponse 직렬화, UpdateEmailSharingRequest 역직렬화
+
+use server::domain::member::dto::UpdateEmailSharingRequest;
+use server::domain::retrospect::dto::{RoomMemberEmailItem, RoomMemberEmailsResponse};
+
+#[test]
+fn should_serialize_member_emails_in_camel_case() {
+    // Arrange
+    let response = RoomMemberEmailsResponse {
+        retro_room_id: 1,
+        members: vec![RoomMemberEmailItem {
+            member_id: 3,
+            nickname: "제이슨".to_string(),
+            email: "jason@example.com".to_string(),
+        }],
+        excluded_count: 2,
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retroRoomId"], 1);
+    assert_eq!(json["members"][0]["memberId"], 3);
+    assert_eq!(json["members"][0]["nickname"], "제이슨");
+    assert_eq!(json["members"][0]["email"], "jason@example.com");
+    assert_eq!(json["excludedCount"], 2);
+}
+
+#[test]
+fn should_serialize_empty_members_when_nobody_shares_email() {
+    // Arrange
+    let response = RoomMemberEmailsResponse {
+        retro_room_id: 1,
+        members: vec![],
+        excluded_count: 4,
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["members"], serde_json::json!([]));
+    assert_eq!(json["excludedCount"], 4);
+}
+
+#[test]
+fn should_deserialize_email_sharing_request() {
+    // Act
+    let req: UpdateEmailSharingRequest = serde_json::from_str(r#"{"shareEmail": true}"#).unwrap();
+
+    // Assert
+    assert!(req.share_email);
+}
+
+#[test]
+fn should_reject_email_sharing_request_without_flag() {
+    // Act
+    let result = serde_json::from_str::<UpdateEmailSharingRequest>("{}");
+
+    // Assert
+    assert!(result.is_err());
+}
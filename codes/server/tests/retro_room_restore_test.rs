@@ -0,0 +1,145 @@
+//! 회고방 스냅샷 복원 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retro-rooms/restore
+//! - 스냅샷 포맷·버전 검증
+//! - 복원 요청 DTO 검증
+
+use serde_json::json;
+use server::domain::retrospect::dto::RestoreRetroRoomRequest;
+use server::domain::retrospect::snapshot::{parse_snapshot, SNAPSHOT_FORMAT, SNAPSHOT_VERSION};
+use server::utils::error::AppError;
+use validator::Validate;
+
+fn create_snapshot_json(version: u32) -> serde_json::Value {
+    json!({
+        "format": SNAPSHOT_FORMAT,
+        "version": version,
+        "generatedAt": "2026-10-16T12:00:00",
+        "room": {
+            "retroRoomId": 5,
+            "title": "프론트팀",
+            "description": null,
+            "inviteGrantRole": "MEMBER",
+            "maxActiveRetrospects": null,
+            "uniqueRetrospectTitle": false,
+            "createdAt": "2026-03-01T10:00:00"
+        },
+        "members": [{
+            "memberId": 2,
+            "nickname": "케이",
+            "email": "kay@example.com",
+            "role": "OWNER",
+            "joinedAt": "2026-03-01T10:00:00"
+        }],
+        "retrospects": [{
+            "retrospectId": 11,
+            "title": "분기 회고",
+            "retrospectMethod": "KPT",
+            "customQuestions": null,
+            "startTime": "2026-10-16T19:00:00",
+            "timezone": "Asia/Seoul",
+            "autoAnalyze": false,
+            "minAnswerLength": 1,
+            "questionsFinalized": true,
+            "insight": null,
+            "createdAt": "2026-10-01T09:00:00",
+            "participants": [],
+            "responses": [{
+                "responseId": 101,
+                "memberId": 2,
+                "question": "Keep",
+                "content": "코드 리뷰 문화",
+                "likeCount": 1,
+                "createdAt": "2026-10-16T19:05:00",
+                "updatedAt": "2026-10-16T19:06:00",
+                "comments": []
+            }]
+        }]
+    })
+}
+
+// ============== 스냅샷 파싱 테스트 ==============
+
+#[test]
+fn should_parse_current_version_snapshot() {
+    // Act
+    let snapshot = parse_snapshot(create_snapshot_json(SNAPSHOT_VERSION)).unwrap();
+
+    // Assert
+    assert_eq!(snapshot.room.title, "프론트팀");
+    assert_eq!(
+        snapshot.members[0].email.as_deref(),
+        Some("kay@example.com")
+    );
+    assert_eq!(snapshot.retrospects[0].responses.len(), 1);
+    // 좋아요 목록이 없는 스냅샷도 빈 목록으로 읽음
+    assert!(snapshot.retrospects[0].responses[0].likes.is_empty());
+}
+
+#[test]
+fn should_reject_snapshot_with_other_version() {
+    // Act
+    let error = parse_snapshot(create_snapshot_json(SNAPSHOT_VERSION + 1)).unwrap_err();
+
+    // Assert
+    assert_eq!(error.error_code(), "RETRO4012");
+    assert_eq!(error.status_code(), axum::http::StatusCode::BAD_REQUEST);
+}
+
+#[test]
+fn should_reject_document_without_snapshot_format() {
+    // Act
+    let error = parse_snapshot(json!({ "version": SNAPSHOT_VERSION })).unwrap_err();
+
+    // Assert
+    assert!(matches!(error, AppError::SnapshotVersionMismatch(_)));
+}
+
+#[test]
+fn should_reject_malformed_snapshot_as_bad_request() {
+    // Arrange
+    let mut value = create_snapshot_json(SNAPSHOT_VERSION);
+    value["room"] = json!("not-an-object");
+
+    // Act
+    let error = parse_snapshot(value).unwrap_err();
+
+    // Assert
+    assert!(matches!(error, AppError::BadRequest(_)));
+    assert_eq!(error.error_code(), "COMMON400");
+}
+
+// ============== 요청 DTO 테스트 ==============
+
+#[test]
+fn should_deserialize_restore_request_with_defaults() {
+    // Arrange
+    let body = json!({ "snapshot": create_snapshot_json(SNAPSHOT_VERSION) });
+
+    // Act
+    let req: RestoreRetroRoomRequest = serde_json::from_value(body).unwrap();
+
+    // Assert
+    assert!(req.validate().is_ok());
+    assert!(req.title.is_none());
+    assert!(req.include_comments.is_none());
+    assert!(req.include_likes.is_none());
+}
+
+#[test]
+fn should_fail_validation_when_title_too_long() {
+    // Arrange
+    let body = json!({
+        "snapshot": create_snapshot_json(SNAPSHOT_VERSION),
+        "title": "가".repeat(21),
+        "includeComments": false
+    });
+
+    // Act
+    let req: RestoreRetroRoomRequest = serde_json::from_value(body).unwrap();
+
+    // Assert
+    assert!(req.validate().is_err());
+    assert_eq!(req.include_comments, Some(false));
+}
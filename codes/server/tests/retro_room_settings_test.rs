@@ -0,0 +1,111 @@
+//! 회고방 설정 변경 테스트
+//!
+//! 테스트 대상:
+//! - PATCH /api/v1/retro-rooms/{retro_room_id}/settings
+//! - UpdateRetroRoomSettingsRequest 유효성 검증
+//! - RetroRoomSettingsResponse 직렬화
+
+use server::domain::retrospect::dto::{RetroRoomSettingsResponse, UpdateRetroRoomSettingsRequest};
+use validator::Validate;
+
+// ============== 유효성 검증 테스트 ==============
+
+#[test]
+fn should_validate_settings_request_with_limit() {
+    // Arrange
+    let req = UpdateRetroRoomSettingsRequest {
+        max_active_retrospects: Some(5),
+        unique_retrospect_title: None,
+    };
+
+    // Act & Assert
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_allow_clearing_limit_with_null() {
+    // Arrange
+    let req: UpdateRetroRoomSettingsRequest =
+        serde_json::from_str(r#"{"maxActiveRetrospects":null}"#).unwrap();
+
+    // Act & Assert
+    assert!(req.max_active_retrospects.is_none());
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_fail_validation_when_limit_is_zero() {
+    // Arrange
+    let req = UpdateRetroRoomSettingsRequest {
+        max_active_retrospects: Some(0),
+        unique_retrospect_title: None,
+    };
+
+    // Act
+    let result = req.validate();
+
+    // Assert
+    assert!(result.is_err());
+    assert!(result
+        .unwrap_err()
+        .field_errors()
+        .contains_key("max_active_retrospects"));
+}
+
+#[test]
+fn should_fail_validation_when_limit_exceeds_100() {
+    // Arrange
+    let req = UpdateRetroRoomSettingsRequest {
+        max_active_retrospects: Some(101),
+        unique_retrospect_title: None,
+    };
+
+    // Act & Assert
+    assert!(req.validate().is_err());
+}
+
+#[test]
+fn should_keep_unique_title_setting_when_omitted() {
+    // Arrange
+    let req: UpdateRetroRoomSettingsRequest =
+        serde_json::from_str(r#"{"maxActiveRetrospects":3}"#).unwrap();
+
+    // Act & Assert
+    assert!(req.unique_retrospect_title.is_none());
+}
+
+#[test]
+fn should_deserialize_unique_title_setting() {
+    // Arrange
+    let req: UpdateRetroRoomSettingsRequest =
+        serde_json::from_str(r#"{"maxActiveRetrospects":null,"uniqueRetrospectTitle":true}"#)
+            .unwrap();
+
+    // Act & Assert
+    assert_eq!(req.unique_retrospect_title, Some(true));
+    assert!(req.validate().is_ok());
+}
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_settings_response_in_camel_case() {
+    // Arrange
+    let response = RetroRoomSettingsResponse {
+        retro_room_id: 1,
+        max_active_retrospects: None,
+        unique_retrospect_title: true,
+        updated_at: "2026-01-26T10:00:00".to_string(),
+    };
+
+    // Act
+    let json = serde_json::to_string(&response).unwrap();
+    let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+    // Assert
+    assert_eq!(parsed["retroRoomId"], 1);
+    assert!(parsed["maxActiveRetrospects"].is_null());
+    assert_eq!(parsed["uniqueRetrospectTitle"], true);
+    assert!(parsed.get("updatedAt").is_some());
+    assert!(parsed.get("max_active_retrospects").is_none());
+}
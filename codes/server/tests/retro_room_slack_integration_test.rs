@@ -0,0 +1,120 @@
+//! 회고방 Slack 연동 테스트
+//!
+//! 테스트 대상:
+//! - PUT /api/v1/retro-rooms/{retro_room_id}/integrations/slack
+//! - UpdateSlackIntegrationRequest 역직렬화 및 유효성 검증
+//! - SlackIntegrationResponse 직렬화
+//! - 분석 결과 Slack Block Kit 메시지 생성
+
+use server::domain::retrospect::dto::{
+    EmotionRankItem, SlackIntegrationResponse, UpdateSlackIntegrationRequest,
+};
+use server::domain::retrospect::slack::{build_analysis_message, is_slack_webhook_url};
+use validator::Validate;
+
+// ============== 역직렬화/검증 테스트 ==============
+
+#[test]
+fn should_deserialize_slack_webhook_url() {
+    // Act
+    let req: UpdateSlackIntegrationRequest =
+        serde_json::from_str(r#"{"webhookUrl":"https://hooks.slack.com/services/T000/B000/XXXX"}"#)
+            .unwrap();
+
+    // Assert
+    assert!(req.validate().is_ok());
+    assert!(is_slack_webhook_url(req.webhook_url.as_deref().unwrap()));
+}
+
+#[test]
+fn should_allow_disconnecting_with_null() {
+    // Act
+    let req: UpdateSlackIntegrationRequest =
+        serde_json::from_str(r#"{"webhookUrl":null}"#).unwrap();
+
+    // Assert
+    assert!(req.webhook_url.is_none());
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_fail_validation_for_too_long_url() {
+    // Arrange
+    let req = UpdateSlackIntegrationRequest {
+        webhook_url: Some(format!("https://hooks.slack.com/{}", "a".repeat(250))),
+    };
+
+    // Act & Assert
+    assert!(req.validate().is_err());
+}
+
+#[test]
+fn should_accept_url_up_to_column_length() {
+    // Arrange: retro_room.slack_webhook_url 컬럼 길이(255자) 경계
+    let prefix = "https://hooks.slack.com/";
+    let max_len = UpdateSlackIntegrationRequest {
+        webhook_url: Some(format!("{}{}", prefix, "a".repeat(255 - prefix.len()))),
+    };
+    let over_len = UpdateSlackIntegrationRequest {
+        webhook_url: Some(format!("{}{}", prefix, "a".repeat(256 - prefix.len()))),
+    };
+
+    // Act & Assert
+    assert!(max_len.validate().is_ok());
+    assert!(over_len.validate().is_err());
+}
+
+#[test]
+fn should_reject_non_slack_webhook_url() {
+    // Act & Assert
+    assert!(!is_slack_webhook_url(
+        "https://discord.com/api/webhooks/1/abc"
+    ));
+}
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_integration_response_without_url() {
+    // Arrange
+    let response = SlackIntegrationResponse {
+        retro_room_id: 3,
+        connected: true,
+        updated_at: "2026-10-16T09:00:00".to_string(),
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retroRoomId"], 3);
+    assert_eq!(json["connected"], true);
+    assert_eq!(json["updatedAt"], "2026-10-16T09:00:00");
+    assert!(json.get("webhookUrl").is_none());
+}
+
+#[test]
+fn should_build_analysis_message_with_emotion_rank() {
+    // Arrange
+    let emotions = vec![EmotionRankItem {
+        rank: 1,
+        label: "뿌듯".to_string(),
+        description: "목표를 달성했어요".to_string(),
+        count: 4,
+    }];
+
+    // Act
+    let message = build_analysis_message("백엔드팀", "스프린트 3", "협업이 좋았습니다", &emotions);
+
+    // Assert
+    let blocks = message["blocks"].as_array().unwrap();
+    assert_eq!(blocks.len(), 5);
+    assert!(blocks[2]["text"]["text"]
+        .as_str()
+        .unwrap()
+        .contains("협업이 좋았습니다"));
+    assert!(blocks[4]["text"]["text"]
+        .as_str()
+        .unwrap()
+        .contains("1. *뿌듯* (4회) - 목표를 달성했어요"));
+}
@@ -0,0 +1,134 @@
+//! 회고방 데이터 백업 스냅샷 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retro-rooms/{retro_room_id}/snapshot
+//! - 스냅샷 문서 포맷 (format, version 태그)
+//! - SnapshotWriter 스트리밍 직렬화
+
+use server::domain::member::entity::member_retro::RetrospectStatus;
+use server::domain::retrospect::entity::retrospect::RetrospectMethod;
+use server::domain::retrospect::snapshot::{
+    RoomSnapshot, SnapshotComment, SnapshotMember, SnapshotParticipant, SnapshotResponse,
+    SnapshotRetrospect, SnapshotRoom, SnapshotWriter, SNAPSHOT_FORMAT, SNAPSHOT_VERSION,
+};
+
+fn create_room() -> SnapshotRoom {
+    SnapshotRoom {
+        retro_room_id: 5,
+        title: "프론트팀".to_string(),
+        description: None,
+        invite_grant_role: "MODERATOR".to_string(),
+        max_active_retrospects: Some(3),
+        unique_retrospect_title: true,
+        created_at: "2026-03-01T10:00:00".to_string(),
+    }
+}
+
+fn create_retrospect() -> SnapshotRetrospect {
+    SnapshotRetrospect {
+        retrospect_id: 11,
+        title: "분기 회고".to_string(),
+        retrospect_method: RetrospectMethod::Custom,
+        custom_questions: Some(vec!["잘한 점".to_string(), "아쉬운 점".to_string()]),
+        custom_placeholders: None,
+        start_time: "2026-10-16T19:00:00".to_string(),
+        timezone: "Asia/Seoul".to_string(),
+        auto_analyze: true,
+        min_answer_length: 10,
+        questions_finalized: true,
+        insight: Some("팀워크가 좋아졌습니다".to_string()),
+        created_at: "2026-10-01T09:00:00".to_string(),
+        participants: vec![SnapshotParticipant {
+            member_id: None,
+            status: RetrospectStatus::Analyzed,
+            submitted_at: Some("2026-10-16T20:00:00".to_string()),
+            personal_insight: None,
+        }],
+        responses: vec![SnapshotResponse {
+            response_id: 101,
+            member_id: None,
+            question: "잘한 점".to_string(),
+            content: "코드 리뷰 문화".to_string(),
+            like_count: 1,
+            created_at: "2026-10-16T19:05:00".to_string(),
+            updated_at: "2026-10-16T19:06:00".to_string(),
+            comments: vec![SnapshotComment {
+                member_id: 2,
+                content: "동의합니다".to_string(),
+                created_at: "2026-10-16T21:00:00".to_string(),
+            }],
+            likes: vec![],
+        }],
+    }
+}
+
+fn write(retrospects: &[SnapshotRetrospect]) -> serde_json::Value {
+    let members = vec![SnapshotMember {
+        member_id: 2,
+        nickname: "케이".to_string(),
+        email: Some("kay@example.com".to_string()),
+        role: "OWNER".to_string(),
+        joined_at: "2026-03-01T10:00:00".to_string(),
+    }];
+    let mut writer =
+        SnapshotWriter::begin(Vec::new(), "2026-10-16T12:00:00", &create_room(), &members).unwrap();
+    for retrospect in retrospects {
+        writer.push_retrospect(retrospect).unwrap();
+    }
+    serde_json::from_slice(&writer.finish().unwrap()).unwrap()
+}
+
+// ============== 포맷 테스트 ==============
+
+#[test]
+fn should_tag_snapshot_with_format_and_version() {
+    // Act
+    let json = write(&[]);
+
+    // Assert
+    assert_eq!(json["format"], SNAPSHOT_FORMAT);
+    assert_eq!(json["version"], SNAPSHOT_VERSION);
+    assert_eq!(json["generatedAt"], "2026-10-16T12:00:00");
+}
+
+#[test]
+fn should_serialize_room_settings_in_camel_case() {
+    // Act
+    let json = write(&[]);
+
+    // Assert
+    let room = &json["room"];
+    assert_eq!(room["retroRoomId"], 5);
+    assert_eq!(room["inviteGrantRole"], "MODERATOR");
+    assert_eq!(room["maxActiveRetrospects"], 3);
+    assert_eq!(room["uniqueRetrospectTitle"], true);
+    assert!(room.get("slackWebhookUrl").is_none());
+    assert_eq!(json["members"][0]["role"], "OWNER");
+}
+
+#[test]
+fn should_serialize_retrospect_with_responses_and_comments() {
+    // Act
+    let json = write(&[create_retrospect()]);
+
+    // Assert
+    let retrospect = &json["retrospects"][0];
+    assert_eq!(retrospect["retrospectMethod"], "CUSTOM");
+    assert_eq!(retrospect["customQuestions"][1], "아쉬운 점");
+    assert_eq!(retrospect["participants"][0]["status"], "ANALYZED");
+    assert!(retrospect["participants"][0]["memberId"].is_null());
+    assert_eq!(
+        retrospect["responses"][0]["comments"][0]["content"],
+        "동의합니다"
+    );
+}
+
+#[test]
+fn should_round_trip_into_room_snapshot() {
+    // Act
+    let snapshot: RoomSnapshot = serde_json::from_value(write(&[create_retrospect()])).unwrap();
+
+    // Assert
+    assert_eq!(snapshot.room, create_room());
+    assert_eq!(snapshot.retrospects, vec![create_retrospect()]);
+}
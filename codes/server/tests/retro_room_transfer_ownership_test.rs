@@ -0,0 +1,43 @@
+//! 회고방 Owner 위임 테스트
+//!
+//! 테스트 대상:
+//! - PATCH /api/v1/retro-rooms/{retroRoomId}/owner
+//! - TransferOwnershipRequest 역직렬화, TransferOwnershipResponse 직렬화
+
+use server::domain::retrospect::dto::{TransferOwnershipRequest, TransferOwnershipResponse};
+
+#[test]
+fn should_deserialize_transfer_ownership_request() {
+    // Act
+    let req: TransferOwnershipRequest = serde_json::from_str(r#"{"memberId": 3}"#).unwrap();
+
+    // Assert
+    assert_eq!(req.member_id, 3);
+}
+
+#[test]
+fn should_reject_transfer_ownership_request_without_member_id() {
+    // Act
+    let result = serde_json::from_str::<TransferOwnershipRequest>("{}");
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_serialize_transfer_ownership_response_in_camel_case() {
+    // Arrange
+    let response = TransferOwnershipResponse {
+        retro_room_id: 1,
+        previous_owner_id: 1,
+        new_owner_id: 3,
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retroRoomId"], 1);
+    assert_eq!(json["previousOwnerId"], 1);
+    assert_eq!(json["newOwnerId"], 3);
+}
@@ -0,0 +1,78 @@
+//! 회고방 좋아요 급상승 답변 조회 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/retro-rooms/{retro_room_id}/trending-responses
+//! - TrendingResponsesQuery 역직렬화
+//! - TrendingResponsesResponse 직렬화
+
+use server::domain::retrospect::dto::{
+    TrendingResponseItem, TrendingResponsesQuery, TrendingResponsesResponse,
+};
+
+#[test]
+fn should_deserialize_trending_query() {
+    // Act
+    let query: TrendingResponsesQuery =
+        serde_json::from_str(r#"{"hours": 6, "limit": 5}"#).unwrap();
+
+    // Assert
+    assert_eq!(query.hours, Some(6));
+    assert_eq!(query.limit, Some(5));
+}
+
+#[test]
+fn should_deserialize_empty_trending_query() {
+    // Act
+    let query: TrendingResponsesQuery = serde_json::from_str("{}").unwrap();
+
+    // Assert
+    assert!(query.hours.is_none());
+    assert!(query.limit.is_none());
+}
+
+#[test]
+fn should_serialize_trending_responses_in_camel_case() {
+    // Arrange
+    let response = TrendingResponsesResponse {
+        hours: 24,
+        responses: vec![TrendingResponseItem {
+            response_id: 42,
+            retrospect_id: 7,
+            retrospect_title: "스프린트 3 회고".to_string(),
+            question: "좋았던 점은?".to_string(),
+            user_name: "제이슨".to_string(),
+            content: "배포 자동화가 자리잡았어요".to_string(),
+            recent_like_count: 5,
+            like_count: 8,
+        }],
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["hours"], 24);
+    let item = &json["responses"][0];
+    assert_eq!(item["responseId"], 42);
+    assert_eq!(item["retrospectId"], 7);
+    assert_eq!(item["retrospectTitle"], "스프린트 3 회고");
+    assert_eq!(item["question"], "좋았던 점은?");
+    assert_eq!(item["userName"], "제이슨");
+    assert_eq!(item["recentLikeCount"], 5);
+    assert_eq!(item["likeCount"], 8);
+}
+
+#[test]
+fn should_serialize_empty_trending_responses() {
+    // Arrange
+    let response = TrendingResponsesResponse {
+        hours: 1,
+        responses: vec![],
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["responses"].as_array().unwrap().len(), 0);
+}
@@ -0,0 +1,50 @@
+//! 회고 답변 카테고리 자동 분류 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retrospects/{retrospectId}/auto-categorize 응답 직렬화
+//! - 카테고리 값 형식 (KEEP/PROBLEM/TRY/UNCATEGORIZED)
+
+use server::domain::retrospect::dto::{AutoCategorizeItem, AutoCategorizeResponse};
+use server::domain::retrospect::entity::response::AnswerCategory;
+
+#[test]
+fn should_serialize_auto_categorize_response_in_camel_case() {
+    // Arrange
+    let response = AutoCategorizeResponse {
+        retrospect_id: 7,
+        saved: true,
+        items: vec![
+            AutoCategorizeItem {
+                response_id: 1,
+                category: AnswerCategory::Keep,
+            },
+            AutoCategorizeItem {
+                response_id: 2,
+                category: AnswerCategory::Uncategorized,
+            },
+        ],
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retrospectId"], 7);
+    assert_eq!(json["saved"], true);
+    assert_eq!(json["items"][0]["responseId"], 1);
+    assert_eq!(json["items"][0]["category"], "KEEP");
+    assert_eq!(json["items"][1]["category"], "UNCATEGORIZED");
+}
+
+#[test]
+fn should_serialize_every_category_in_screaming_snake_case() {
+    // Act & Assert
+    for (category, expected) in [
+        (AnswerCategory::Keep, "KEEP"),
+        (AnswerCategory::Problem, "PROBLEM"),
+        (AnswerCategory::Try, "TRY"),
+        (AnswerCategory::Uncategorized, "UNCATEGORIZED"),
+    ] {
+        assert_eq!(serde_json::to_value(category).unwrap(), expected);
+    }
+}
@@ -0,0 +1,93 @@
+//! 회고 일정 캘린더 내보내기 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/retrospects/{retrospect_id}/calendar.ics
+//! - iCalendar 텍스트 이스케이프 및 라인 접기
+//! - VEVENT 생성 (타임존 포함)
+
+use chrono::{NaiveDate, NaiveDateTime};
+use server::domain::retrospect::calendar::{
+    build_ics, escape_ics_text, fold_ics_line, CalendarEvent,
+};
+
+fn datetime(y: i32, mo: u32, d: u32, h: u32, m: u32) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(y, mo, d)
+        .unwrap()
+        .and_hms_opt(h, m, 0)
+        .unwrap()
+}
+
+fn create_event(summary: &str) -> CalendarEvent {
+    CalendarEvent {
+        uid: "retrospect-7@moalog.me".to_string(),
+        summary: summary.to_string(),
+        description: "회고방: 백엔드팀\n회고 방식: KPT\n질문 수: 3".to_string(),
+        location: "백엔드팀".to_string(),
+        start_local: datetime(2026, 10, 20, 19, 30),
+        duration_minutes: 60,
+        timezone: chrono_tz::Europe::London,
+        stamp_utc: datetime(2026, 10, 16, 9, 0),
+    }
+}
+
+// ============== 이스케이프 테스트 ==============
+
+#[test]
+fn should_escape_comma_semicolon_and_backslash() {
+    // Act
+    let escaped = escape_ics_text(r"KPT; Keep, Problem\Try");
+
+    // Assert
+    assert_eq!(escaped, r"KPT\; Keep\, Problem\\Try");
+}
+
+#[test]
+fn should_escape_crlf_as_single_newline() {
+    // Act
+    let escaped = escape_ics_text("첫 줄\r\n둘째 줄\r셋째 줄");
+
+    // Assert
+    assert_eq!(escaped, r"첫 줄\n둘째 줄\n셋째 줄");
+}
+
+#[test]
+fn should_keep_short_line_unfolded() {
+    // Act & Assert
+    assert_eq!(fold_ics_line("SUMMARY:회고"), "SUMMARY:회고");
+}
+
+// ============== VEVENT 생성 테스트 ==============
+
+#[test]
+fn should_build_vevent_with_escaped_summary() {
+    // Act
+    let ics = build_ics(&create_event("스프린트 3, 마무리; 회고"));
+
+    // Assert
+    assert!(ics.contains("BEGIN:VEVENT\r\n"));
+    assert!(ics.contains("UID:retrospect-7@moalog.me\r\n"));
+    assert!(ics.contains("SUMMARY:스프린트 3\\, 마무리\\; 회고\r\n"));
+    assert!(ics.contains("LOCATION:백엔드팀\r\n"));
+    assert!(ics.contains("END:VEVENT\r\n"));
+}
+
+#[test]
+fn should_include_timezone_definition_and_local_times() {
+    // Act
+    let ics = build_ics(&create_event("스프린트 회고"));
+
+    // Assert: 2026-10-20 런던은 BST(+0100)
+    assert!(ics.contains("TZID:Europe/London\r\n"));
+    assert!(ics.contains("TZOFFSETTO:+0100\r\n"));
+    assert!(ics.contains("DTSTART;TZID=Europe/London:20261020T193000\r\n"));
+    assert!(ics.contains("DTEND;TZID=Europe/London:20261020T203000\r\n"));
+}
+
+#[test]
+fn should_use_crlf_line_endings_only() {
+    // Act
+    let ics = build_ics(&create_event("스프린트 회고"));
+
+    // Assert
+    assert!(!ics.replace("\r\n", "").contains('\n'));
+}
@@ -0,0 +1,52 @@
+//! 회고 QR 체크인 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/retrospects/{retrospectId}/check-in/qr
+//! - POST /api/v1/retrospects/{retrospectId}/check-in
+//! - CheckInRequest 검증, CheckInQrResponse 직렬화
+
+use server::domain::retrospect::dto::{CheckInQrResponse, CheckInRequest};
+use validator::Validate;
+
+#[test]
+fn should_accept_check_in_request_with_token() {
+    // Arrange
+    let req: CheckInRequest =
+        serde_json::from_str(r#"{"token":"0f8fad5bd9cb469fa16570867728950e"}"#).unwrap();
+
+    // Act & Assert
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_reject_empty_or_too_long_check_in_token() {
+    // Arrange
+    let empty = CheckInRequest {
+        token: String::new(),
+    };
+    let too_long = CheckInRequest {
+        token: "a".repeat(65),
+    };
+
+    // Act & Assert
+    assert!(empty.validate().is_err());
+    assert!(too_long.validate().is_err());
+}
+
+#[test]
+fn should_serialize_check_in_qr_response_in_camel_case() {
+    // Arrange
+    let response = CheckInQrResponse {
+        token: "abc".to_string(),
+        qr_payload: r#"{"retrospectId":1,"token":"abc"}"#.to_string(),
+        expires_at: "2026-01-24T19:03:00+09:00".to_string(),
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["token"], "abc");
+    assert_eq!(json["qrPayload"], r#"{"retrospectId":1,"token":"abc"}"#);
+    assert_eq!(json["expiresAt"], "2026-01-24T19:03:00+09:00");
+}
@@ -0,0 +1,47 @@
+//! 회고 삭제 응답 테스트
+//!
+//! 테스트 대상:
+//! - DELETE /api/v1/retrospects/{retrospectId}
+//! - deleteEmptyRoom 옵션별 응답 형식
+
+use server::domain::retrospect::dto::{DeleteRetrospectQuery, DeleteRetrospectResponse};
+
+#[test]
+fn should_keep_room_by_default() {
+    // Act
+    let query = DeleteRetrospectQuery::default();
+
+    // Assert
+    assert!(!query.delete_empty_room);
+}
+
+#[test]
+fn should_serialize_room_preserved_response() {
+    // Arrange
+    let response = DeleteRetrospectResponse {
+        retrospect_id: 100,
+        room_deleted: false,
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retrospectId"], 100);
+    assert_eq!(json["roomDeleted"], false);
+}
+
+#[test]
+fn should_serialize_room_deleted_response() {
+    // Arrange
+    let response = DeleteRetrospectResponse {
+        retrospect_id: 100,
+        room_deleted: true,
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["roomDeleted"], true);
+}
@@ -0,0 +1,119 @@
+//! 회고 답변 임시 저장 부분 저장 방지 테스트
+//!
+//! 테스트 대상:
+//! - RetrospectService::prepare_draft_updates (임시 저장 API의 트랜잭션 전 단계)
+//! - 한 건이라도 검증(개수/중복/범위/길이)에 실패하면 변경분을 하나도 만들지 않음
+//! - 전체 통과 시 요청한 모든 질문의 변경분 생성
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sea_orm::Set;
+use server::domain::retrospect::dto::DraftItem;
+use server::domain::retrospect::entity::response;
+use server::domain::retrospect::service::RetrospectService;
+use server::utils::error::AppError;
+
+fn fixed_time() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 10, 16)
+        .unwrap()
+        .and_hms_opt(9, 0, 0)
+        .unwrap()
+}
+
+fn responses(count: i64) -> Vec<response::Model> {
+    (0..count)
+        .map(|i| response::Model {
+            response_id: 100 + i,
+            question: format!("질문 {}", i + 1),
+            content: "기존 답변".to_string(),
+            created_at: fixed_time(),
+            updated_at: fixed_time(),
+            summary: None,
+            like_count: 0,
+            view_count: 0,
+            last_edit_session: None,
+            auto_category: None,
+            retrospect_id: 1,
+        })
+        .collect()
+}
+
+fn draft(question_number: i32, content: Option<&str>) -> DraftItem {
+    DraftItem {
+        question_number,
+        content: content.map(str::to_string),
+    }
+}
+
+#[test]
+fn should_prepare_every_draft_when_all_valid() {
+    // Arrange
+    let responses = responses(3);
+    let drafts = vec![draft(3, Some("  세 번째 답변  ")), draft(1, None)];
+
+    // Act
+    let updates = RetrospectService::prepare_draft_updates(
+        &drafts,
+        &responses,
+        Some("device-a"),
+        fixed_time(),
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(updates.len(), 2);
+    assert_eq!(updates[0].0, 3);
+    assert_eq!(updates[0].1.response_id.as_ref(), &102);
+    assert_eq!(updates[0].1.content, Set("세 번째 답변".to_string()));
+    assert_eq!(updates[0].1.updated_at, Set(fixed_time()));
+    assert_eq!(
+        updates[0].1.last_edit_session,
+        Set(Some("device-a".to_string()))
+    );
+    assert_eq!(updates[1].0, 1);
+    assert_eq!(updates[1].1.response_id.as_ref(), &100);
+    assert_eq!(updates[1].1.content, Set(String::new()));
+}
+
+#[test]
+fn should_prepare_nothing_when_middle_draft_exceeds_1000_chars() {
+    // Arrange
+    let responses = responses(3);
+    let too_long = "가".repeat(1001);
+    let drafts = vec![
+        draft(1, Some("정상 답변")),
+        draft(2, Some(&too_long)),
+        draft(3, Some("정상 답변")),
+    ];
+
+    // Act
+    let result = RetrospectService::prepare_draft_updates(&drafts, &responses, None, fixed_time());
+
+    // Assert
+    assert!(matches!(result, Err(AppError::RetroAnswerTooLong(_))));
+}
+
+#[test]
+fn should_prepare_nothing_when_question_number_is_duplicated() {
+    // Arrange
+    let responses = responses(3);
+    let drafts = vec![draft(1, Some("첫 저장")), draft(1, Some("덮어쓰기"))];
+
+    // Act
+    let result = RetrospectService::prepare_draft_updates(&drafts, &responses, None, fixed_time());
+
+    // Assert
+    assert!(matches!(result, Err(AppError::BadRequest(_))));
+}
+
+#[test]
+fn should_prepare_nothing_when_question_number_is_out_of_range() {
+    // Arrange
+    let responses = responses(3);
+    let drafts = vec![draft(1, Some("정상 답변")), draft(4, Some("범위 밖"))];
+
+    // Act
+    let result = RetrospectService::prepare_draft_updates(&drafts, &responses, None, fixed_time());
+
+    // Assert
+    assert!(matches!(result, Err(AppError::BadRequest(_))));
+}
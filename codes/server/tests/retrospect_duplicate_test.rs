@@ -0,0 +1,67 @@
+//! 회고 복제 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retrospects/{retrospectId}/duplicate
+//! - DuplicateRetrospectRequest 역직렬화 및 검증 (프로젝트 이름 길이, 시작 일시 필드)
+
+use server::domain::retrospect::dto::DuplicateRetrospectRequest;
+use validator::Validate;
+
+#[test]
+fn should_deserialize_duplicate_request_with_start_at_only() {
+    // Act
+    let req: DuplicateRetrospectRequest =
+        serde_json::from_str(r#"{"startAt": "2026-11-02T19:00:00+09:00"}"#).unwrap();
+
+    // Assert
+    assert_eq!(req.start_at.as_deref(), Some("2026-11-02T19:00:00+09:00"));
+    assert_eq!(req.project_name, None);
+    assert_eq!(req.retrospect_date, None);
+    assert_eq!(req.retrospect_time, None);
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_deserialize_duplicate_request_with_separate_fields() {
+    // Act
+    let req: DuplicateRetrospectRequest = serde_json::from_str(
+        r#"{"projectName": "11월 스프린트", "retrospectDate": "2026-11-02", "retrospectTime": "19:00"}"#,
+    )
+    .unwrap();
+
+    // Assert
+    assert_eq!(req.project_name.as_deref(), Some("11월 스프린트"));
+    assert_eq!(req.retrospect_date.as_deref(), Some("2026-11-02"));
+    assert_eq!(req.retrospect_time.as_deref(), Some("19:00"));
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_fail_validation_when_project_name_too_long() {
+    // Arrange
+    let req: DuplicateRetrospectRequest = serde_json::from_str(&format!(
+        r#"{{"projectName": "{}", "startAt": "2026-11-02T19:00"}}"#,
+        "가".repeat(21)
+    ))
+    .unwrap();
+
+    // Act
+    let result = req.validate();
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_fail_validation_when_time_format_invalid() {
+    // Arrange
+    let req: DuplicateRetrospectRequest =
+        serde_json::from_str(r#"{"retrospectDate": "2026-11-02", "retrospectTime": "7:00"}"#)
+            .unwrap();
+
+    // Act
+    let result = req.validate();
+
+    // Assert
+    assert!(result.is_err());
+}
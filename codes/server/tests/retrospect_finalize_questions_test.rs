@@ -0,0 +1,69 @@
+//! 회고 질문 지연 생성 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retrospects (deferQuestions)
+//! - POST /api/v1/retrospects/{retrospect_id}/finalize-questions
+//! - FinalizeQuestionsResponse 직렬화
+
+use server::domain::retrospect::dto::{CreateRetrospectRequest, FinalizeQuestionsResponse};
+
+// ============== 역직렬화 테스트 ==============
+
+#[test]
+fn should_deserialize_defer_questions_flag() {
+    // Arrange
+    let json = r#"{
+        "retroRoomId": 1,
+        "projectName": "웹 3팀",
+        "retrospectDate": "2026-12-01",
+        "retrospectTime": "14:00",
+        "retrospectMethod": "KPT",
+        "deferQuestions": true
+    }"#;
+
+    // Act
+    let req: CreateRetrospectRequest = serde_json::from_str(json).unwrap();
+
+    // Assert
+    assert_eq!(req.defer_questions, Some(true));
+}
+
+#[test]
+fn should_default_defer_questions_to_none() {
+    // Arrange
+    let json = r#"{
+        "retroRoomId": 1,
+        "projectName": "웹 3팀",
+        "retrospectDate": "2026-12-01",
+        "retrospectTime": "14:00",
+        "retrospectMethod": "KPT"
+    }"#;
+
+    // Act
+    let req: CreateRetrospectRequest = serde_json::from_str(json).unwrap();
+
+    // Assert
+    assert!(req.defer_questions.is_none());
+}
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_finalize_questions_response() {
+    // Arrange
+    let response = FinalizeQuestionsResponse {
+        retrospect_id: 3,
+        participant_count: 4,
+        question_count: 3,
+        finalized_at: "2026-10-16T09:00:00".to_string(),
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retrospectId"], 3);
+    assert_eq!(json["participantCount"], 4);
+    assert_eq!(json["questionCount"], 3);
+    assert_eq!(json["finalizedAt"], "2026-10-16T09:00:00");
+}
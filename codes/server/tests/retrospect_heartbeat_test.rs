@@ -0,0 +1,57 @@
+//! 회고 답변 작성 하트비트 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retrospects/{retrospect_id}/heartbeat
+//! - HeartbeatResponse 직렬화
+//! - 마지막 하트비트 기준 작성 중 여부 판단
+
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use server::domain::retrospect::dto::HeartbeatResponse;
+use server::domain::retrospect::service::{is_actively_writing, WRITING_ACTIVE_TIMEOUT_SECS};
+
+fn now() -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(2026, 10, 16)
+        .unwrap()
+        .and_hms_opt(21, 0, 0)
+        .unwrap()
+}
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_heartbeat_response_in_camel_case() {
+    // Arrange
+    let response = HeartbeatResponse {
+        retrospect_id: 7,
+        last_active_at: "2026-10-16T12:00:00".to_string(),
+        active_timeout_secs: 60,
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retrospectId"], 7);
+    assert_eq!(json["lastActiveAt"], "2026-10-16T12:00:00");
+    assert_eq!(json["activeTimeoutSecs"], 60);
+}
+
+// ============== 작성 중 판단 테스트 ==============
+
+#[test]
+fn should_treat_recent_heartbeat_as_writing() {
+    // Act & Assert
+    assert!(is_actively_writing(
+        Some(now() - Duration::seconds(5)),
+        now()
+    ));
+}
+
+#[test]
+fn should_treat_stale_heartbeat_as_inactive() {
+    // Act & Assert
+    assert!(!is_actively_writing(
+        Some(now() - Duration::seconds(WRITING_ACTIVE_TIMEOUT_SECS + 1)),
+        now()
+    ));
+}
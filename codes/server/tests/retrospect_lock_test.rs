@@ -0,0 +1,28 @@
+//! 회고 답변 편집 잠금 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retrospects/{retrospect_id}/lock
+//! - DELETE /api/v1/retrospects/{retrospect_id}/lock
+//! - RetrospectLockResponse 직렬화
+
+use server::domain::retrospect::dto::RetrospectLockResponse;
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_retrospect_lock_response() {
+    // Arrange
+    let response = RetrospectLockResponse {
+        retrospect_id: 3,
+        is_locked: true,
+        updated_at: "2026-10-16T09:00:00".to_string(),
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retrospectId"], 3);
+    assert_eq!(json["isLocked"], true);
+    assert_eq!(json["updatedAt"], "2026-10-16T09:00:00");
+}
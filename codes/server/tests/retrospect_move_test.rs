@@ -0,0 +1,72 @@
+//! 회고 이동 테스트
+//!
+//! 테스트 대상:
+//! - PATCH /api/v1/retrospects/{retrospect_id}/move
+//! - MoveRetrospectRequest 역직렬화 및 검증
+//! - MoveRetrospectResponse 직렬화 (이동 후 소속 방, 대상 방 비멤버 참여자 경고)
+
+use server::domain::retrospect::dto::{
+    MoveRetrospectRequest, MoveRetrospectResponse, RetrospectMemberItem,
+};
+use validator::Validate;
+
+// ============== 역직렬화/검증 테스트 ==============
+
+#[test]
+fn should_deserialize_target_room_id() {
+    // Act
+    let req: MoveRetrospectRequest = serde_json::from_str(r#"{"targetRoomId":7}"#).unwrap();
+
+    // Assert
+    assert_eq!(req.target_room_id, 7);
+    assert!(req.validate().is_ok());
+}
+
+#[test]
+fn should_reject_missing_target_room_id() {
+    // Act
+    let result = serde_json::from_str::<MoveRetrospectRequest>("{}");
+
+    // Assert
+    assert!(result.is_err());
+}
+
+#[test]
+fn should_fail_validation_for_non_positive_target_room_id() {
+    // Arrange
+    let req: MoveRetrospectRequest = serde_json::from_str(r#"{"targetRoomId":0}"#).unwrap();
+
+    // Act
+    let result = req.validate();
+
+    // Assert
+    assert!(result.is_err());
+}
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_move_response_with_target_room() {
+    // Arrange
+    let response = MoveRetrospectResponse {
+        retrospect_id: 10,
+        source_room_id: 1,
+        target_room_id: 2,
+        non_member_participants: vec![RetrospectMemberItem {
+            member_id: 5,
+            user_name: "제이슨".to_string(),
+        }],
+        moved_at: "2026-10-16T09:00:00".to_string(),
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retrospectId"], 10);
+    assert_eq!(json["sourceRoomId"], 1);
+    assert_eq!(json["targetRoomId"], 2);
+    assert_eq!(json["nonMemberParticipants"][0]["memberId"], 5);
+    assert_eq!(json["nonMemberParticipants"][0]["userName"], "제이슨");
+    assert_eq!(json["movedAt"], "2026-10-16T09:00:00");
+}
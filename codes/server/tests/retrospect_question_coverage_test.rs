@@ -0,0 +1,51 @@
+//! 질문별 답변 작성률 조회 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/retrospects/{retrospectId}/question-coverage
+//! - QuestionCoverageResponse 직렬화
+
+use server::domain::retrospect::dto::{QuestionCoverageItem, QuestionCoverageResponse};
+
+#[test]
+fn should_serialize_question_coverage_in_camel_case() {
+    // Arrange
+    let response = QuestionCoverageResponse {
+        retrospect_id: 100,
+        participant_count: 3,
+        questions: vec![QuestionCoverageItem {
+            question_number: 2,
+            question: "아쉬운 점은 무엇인가요?".to_string(),
+            answered_count: 1,
+            coverage_rate: 33.3,
+        }],
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retrospectId"], 100);
+    assert_eq!(json["participantCount"], 3);
+    let item = &json["questions"][0];
+    assert_eq!(item["questionNumber"], 2);
+    assert_eq!(item["question"], "아쉬운 점은 무엇인가요?");
+    assert_eq!(item["answeredCount"], 1);
+    assert_eq!(item["coverageRate"], 33.3);
+}
+
+#[test]
+fn should_serialize_empty_questions_when_no_participants() {
+    // Arrange
+    let response = QuestionCoverageResponse {
+        retrospect_id: 100,
+        participant_count: 0,
+        questions: vec![],
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["participantCount"], 0);
+    assert_eq!(json["questions"], serde_json::json!([]));
+}
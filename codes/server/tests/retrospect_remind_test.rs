@@ -0,0 +1,65 @@
+//! 회고 제출 독려 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retrospects/{retrospectId}/remind
+//! - RemindRequest 검증, RemindTemplate 메시지, RemindResponse 직렬화
+
+use server::domain::retrospect::dto::{RemindRequest, RemindResponse, RemindTemplate};
+use validator::Validate;
+
+#[test]
+fn should_use_gentle_template_when_request_body_is_empty() {
+    // Arrange
+    let req: RemindRequest = serde_json::from_str("{}").unwrap();
+
+    // Act
+    let template = req.template.unwrap_or_default();
+
+    // Assert
+    assert!(req.validate().is_ok());
+    assert_eq!(template, RemindTemplate::Gentle);
+}
+
+#[test]
+fn should_render_template_with_retrospect_title() {
+    // Arrange
+    let req: RemindRequest = serde_json::from_str(r#"{"template":"LAST_CALL"}"#).unwrap();
+
+    // Act
+    let message = req.template.unwrap().render("3월 스프린트 회고");
+
+    // Assert
+    assert!(message.contains("'3월 스프린트 회고'"));
+}
+
+#[test]
+fn should_reject_custom_message_over_100_chars() {
+    // Arrange
+    let req = RemindRequest {
+        template: None,
+        custom_message: Some("가".repeat(101)),
+    };
+
+    // Act & Assert
+    assert!(req.validate().is_err());
+}
+
+#[test]
+fn should_serialize_remind_response_in_camel_case() {
+    // Arrange
+    let response = RemindResponse {
+        target_count: 4,
+        sent_count: 2,
+        failed_count: 0,
+        skipped_count: 2,
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["targetCount"], 4);
+    assert_eq!(json["sentCount"], 2);
+    assert_eq!(json["failedCount"], 0);
+    assert_eq!(json["skippedCount"], 2);
+}
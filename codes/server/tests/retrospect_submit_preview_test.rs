@@ -0,0 +1,75 @@
+//! 회고 제출 미리보기 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retrospects/{retrospectId}/submit/preview
+//! - SubmitPreviewResponse 직렬화 (errors는 fieldErrors와 같은 형식)
+
+use server::domain::retrospect::dto::{SubmitPreviewItem, SubmitPreviewResponse};
+use server::utils::response::FieldErrorItem;
+
+#[test]
+fn should_serialize_submit_preview_in_camel_case() {
+    // Arrange
+    let response = SubmitPreviewResponse {
+        valid: false,
+        question_count: 2,
+        answered_count: 1,
+        errors: vec![FieldErrorItem {
+            field: "answers[1].content".to_string(),
+            code: "RETRO4007".to_string(),
+            message: "답변 내용은 공백만으로 구성될 수 없습니다.".to_string(),
+        }],
+        answers: vec![
+            SubmitPreviewItem {
+                question_number: 1,
+                question: "좋았던 점은?".to_string(),
+                answered: true,
+                content_length: 12,
+            },
+            SubmitPreviewItem {
+                question_number: 2,
+                question: "아쉬운 점은?".to_string(),
+                answered: false,
+                content_length: 0,
+            },
+        ],
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["questionCount"], 2);
+    assert_eq!(json["answeredCount"], 1);
+    assert_eq!(json["errors"][0]["field"], "answers[1].content");
+    assert_eq!(json["errors"][0]["code"], "RETRO4007");
+    assert_eq!(json["answers"][0]["questionNumber"], 1);
+    assert_eq!(json["answers"][0]["contentLength"], 12);
+    assert_eq!(json["answers"][1]["answered"], false);
+    assert!(json.get("question_count").is_none());
+}
+
+#[test]
+fn should_serialize_valid_preview_with_empty_errors() {
+    // Arrange
+    let response = SubmitPreviewResponse {
+        valid: true,
+        question_count: 1,
+        answered_count: 1,
+        errors: vec![],
+        answers: vec![SubmitPreviewItem {
+            question_number: 1,
+            question: "좋았던 점은?".to_string(),
+            answered: true,
+            content_length: 5,
+        }],
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["valid"], true);
+    assert_eq!(json["errors"], serde_json::json!([]));
+}
@@ -0,0 +1,184 @@
+//! 회고 요약 메일 테스트
+//!
+//! 테스트 대상:
+//! - 요약 Markdown 생성 (회고 제목, 팀 인사이트, 질문별 답변 요약)
+//! - Markdown → HTML 변환 (사용자 입력 HTML 이스케이프)
+//! - 수신자 선정 (알림 수신 설정, 회고방 멤버십, 구독 채널)
+
+use std::collections::HashSet;
+
+use server::domain::retrospect::dto::{
+    ExportAnswerItem, ExportQuestionItem, RetrospectExportResponse,
+};
+use server::domain::retrospect::entity::retrospect::RetrospectMethod;
+use server::domain::retrospect::summary_mail::{
+    build_summary_html, build_summary_markdown, summary_mail_recipients, summary_mail_subject,
+    SummaryMailCandidate, SUMMARY_ANSWER_MAX_CHARS,
+};
+use server::utils::markdown::{escape_markdown, markdown_to_html};
+
+fn answer(author: &str, content: &str) -> ExportAnswerItem {
+    ExportAnswerItem {
+        response_id: 1,
+        author: author.to_string(),
+        content: content.to_string(),
+        like_count: 0,
+        comments: vec![],
+    }
+}
+
+fn export(insight: Option<&str>, questions: Vec<ExportQuestionItem>) -> RetrospectExportResponse {
+    RetrospectExportResponse {
+        retrospect_id: 1,
+        title: "스프린트 3".to_string(),
+        retro_room_name: "백엔드팀".to_string(),
+        retrospect_method: RetrospectMethod::Kpt,
+        start_time: "2026-10-16T19:00:00".to_string(),
+        insight: insight.map(str::to_string),
+        participants: vec!["카이".to_string(), "제이슨".to_string()],
+        questions,
+    }
+}
+
+fn candidate(member_id: i64, email: &str, notification_enabled: bool) -> SummaryMailCandidate {
+    SummaryMailCandidate {
+        member_id,
+        email: email.to_string(),
+        notification_enabled,
+    }
+}
+
+#[test]
+fn should_build_summary_markdown_with_insight_and_answers_by_question() {
+    // Arrange
+    let source = export(
+        Some("협업이 좋았습니다.\n배포 자동화가 필요합니다."),
+        vec![
+            ExportQuestionItem {
+                index: 1,
+                content: "잘한 점은?".to_string(),
+                answers: vec![answer("카이", "코드 리뷰를\n빠르게 했어요")],
+            },
+            ExportQuestionItem {
+                index: 2,
+                content: "아쉬운 점은?".to_string(),
+                answers: vec![],
+            },
+        ],
+    );
+
+    // Act
+    let markdown = build_summary_markdown(&source);
+
+    // Assert
+    assert!(markdown.starts_with("# 스프린트 3 회고 요약\n"));
+    assert!(markdown.contains("- 회고방: 백엔드팀\n- 참여자: 2명"));
+    assert!(markdown.contains("## 팀 인사이트\n\n협업이 좋았습니다.  \n배포 자동화가 필요합니다."));
+    assert!(markdown.contains("### 1. 잘한 점은?\n\n- **카이**: 코드 리뷰를 빠르게 했어요\n"));
+    assert!(markdown.contains("### 2. 아쉬운 점은?\n\n_제출된 답변이 없습니다._\n"));
+}
+
+#[test]
+fn should_note_missing_insight_when_not_analyzed() {
+    // Act
+    let markdown = build_summary_markdown(&export(None, vec![]));
+
+    // Assert
+    assert!(markdown.contains("## 팀 인사이트\n\n_아직 분석 결과가 없습니다._"));
+}
+
+#[test]
+fn should_truncate_long_answers_in_summary() {
+    // Arrange
+    let source = export(
+        None,
+        vec![ExportQuestionItem {
+            index: 1,
+            content: "질문".to_string(),
+            answers: vec![answer("카이", &"가".repeat(300))],
+        }],
+    );
+
+    // Act
+    let markdown = build_summary_markdown(&source);
+
+    // Assert
+    let line = markdown
+        .lines()
+        .find(|l| l.starts_with("- **카이**: "))
+        .unwrap();
+    let summary = line.trim_start_matches("- **카이**: ");
+    assert_eq!(summary.chars().count(), SUMMARY_ANSWER_MAX_CHARS);
+    assert!(summary.ends_with("..."));
+}
+
+#[test]
+fn should_escape_user_input_when_rendering_html() {
+    // Arrange
+    let source = export(
+        Some("<script>alert(1)</script>"),
+        vec![ExportQuestionItem {
+            index: 1,
+            content: "# 질문".to_string(),
+            answers: vec![answer("카이", "[링크](javascript:alert(1)) **굵게**")],
+        }],
+    );
+
+    // Act
+    let html = build_summary_html(&build_summary_markdown(&source));
+
+    // Assert
+    assert!(!html.contains("<script>"));
+    assert!(html.contains("&lt;script&gt;"));
+    assert!(!html.contains("<a href"));
+    assert!(!html.contains("<strong>굵게</strong>"));
+    assert!(html.contains("<h3>1. # 질문</h3>"));
+    assert!(html.contains("<h1>스프린트 3 회고 요약</h1>"));
+}
+
+#[test]
+fn should_escape_markdown_control_characters() {
+    assert_eq!(escape_markdown("*a* _b_ [c]"), r"\*a\* \_b\_ \[c\]");
+    assert_eq!(escape_markdown("일반 텍스트"), "일반 텍스트");
+}
+
+#[test]
+fn should_render_raw_html_as_text() {
+    // Act
+    let html = markdown_to_html("**강조**\n\n<img src=x onerror=alert(1)>");
+
+    // Assert
+    assert!(html.contains("<strong>강조</strong>"));
+    assert!(!html.contains("<img"));
+    assert!(html.contains("&lt;img src=x onerror=alert(1)&gt;"));
+}
+
+#[test]
+fn should_select_recipients_respecting_notification_and_subscription_settings() {
+    // Arrange
+    let candidates = vec![
+        candidate(1, "a@moalog.me", true),
+        candidate(2, "b@moalog.me", false), // 알림 수신 끔
+        candidate(3, "c@moalog.me", true),  // 회고방을 떠남
+        candidate(4, "d@moalog.me", true),  // PUSH 채널 구독
+        candidate(5, "", true),             // 이메일 없음
+        candidate(6, "f@moalog.me", true),
+    ];
+    let room_member_ids: HashSet<i64> = [1, 2, 4, 5, 6].into_iter().collect();
+    let push_subscriber_ids: HashSet<i64> = [4].into_iter().collect();
+
+    // Act
+    let recipients = summary_mail_recipients(&candidates, &room_member_ids, &push_subscriber_ids);
+
+    // Assert
+    let ids: Vec<i64> = recipients.iter().map(|r| r.member_id).collect();
+    assert_eq!(ids, vec![1, 6]);
+}
+
+#[test]
+fn should_build_subject_with_retrospect_title() {
+    assert_eq!(
+        summary_mail_subject("스프린트 3"),
+        "[모아로그] 스프린트 3 회고 요약"
+    );
+}
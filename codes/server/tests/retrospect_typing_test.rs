@@ -0,0 +1,59 @@
+//! 회고 답변 타이핑 인디케이터 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/retrospects/{retrospect_id}/typing (WebSocket)
+//! - TypingClientMessage 역직렬화
+//! - TypingEvent 직렬화
+
+use server::domain::retrospect::dto::{TypingClientMessage, TypingEvent, TypingEventType};
+
+// ============== 역직렬화 테스트 ==============
+
+#[test]
+fn should_deserialize_typing_start_message() {
+    // Act
+    let message: TypingClientMessage = serde_json::from_str(r#"{"type":"TYPING_START"}"#).unwrap();
+
+    // Assert
+    assert_eq!(message.event_type, TypingEventType::TypingStart);
+}
+
+#[test]
+fn should_deserialize_typing_stop_message() {
+    // Act
+    let message: TypingClientMessage = serde_json::from_str(r#"{"type":"TYPING_STOP"}"#).unwrap();
+
+    // Assert
+    assert_eq!(message.event_type, TypingEventType::TypingStop);
+}
+
+#[test]
+fn should_reject_unknown_typing_message_type() {
+    // Act
+    let result = serde_json::from_str::<TypingClientMessage>(r#"{"type":"TYPING"}"#);
+
+    // Assert
+    assert!(result.is_err());
+}
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_typing_event() {
+    // Arrange
+    let event = TypingEvent {
+        event_type: TypingEventType::TypingStart,
+        retrospect_id: 3,
+        member_id: 10,
+        user_name: "제이슨".to_string(),
+    };
+
+    // Act
+    let json = serde_json::to_value(&event).unwrap();
+
+    // Assert
+    assert_eq!(json["type"], "TYPING_START");
+    assert_eq!(json["retrospectId"], 3);
+    assert_eq!(json["memberId"], 10);
+    assert_eq!(json["userName"], "제이슨");
+}
@@ -0,0 +1,112 @@
+//! 커스텀 질문 사전 검증 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/retrospects/validate-questions
+//! - 질문 개수·공백·길이 검증 결과
+//! - 커스텀 회고 방식 생성 검증과의 규칙 일치
+
+use server::domain::retrospect::dto::{
+    CreateCustomMethodRequest, ValidateQuestionsRequest, ValidateQuestionsResponse,
+};
+use server::domain::retrospect::service::RetrospectService;
+use validator::Validate;
+
+fn validate(questions: Vec<String>) -> ValidateQuestionsResponse {
+    RetrospectService::validate_questions(ValidateQuestionsRequest { questions })
+}
+
+fn questions(items: &[&str]) -> Vec<String> {
+    items.iter().map(|q| q.to_string()).collect()
+}
+
+#[test]
+fn should_pass_valid_questions() {
+    // Act
+    let result = validate(questions(&["이번 스프린트 목표는?", "다음에 바꿀 점은?"]));
+
+    // Assert
+    assert!(result.valid);
+    assert_eq!(result.question_count, 2);
+    assert!(result.errors.is_empty());
+}
+
+#[test]
+fn should_report_each_invalid_question_with_number() {
+    // Arrange
+    let long_question = "가".repeat(201);
+
+    // Act
+    let result = validate(vec![
+        "좋았던 점은?".to_string(),
+        "   ".to_string(),
+        long_question,
+    ]);
+
+    // Assert
+    assert!(!result.valid);
+    assert_eq!(result.errors.len(), 2);
+    assert_eq!(result.errors[0].question_number, Some(2));
+    assert_eq!(result.errors[0].code, "BLANK_QUESTION");
+    assert_eq!(result.errors[1].question_number, Some(3));
+    assert_eq!(result.errors[1].code, "QUESTION_TOO_LONG");
+}
+
+#[test]
+fn should_report_count_error_without_question_number() {
+    // Act
+    let empty = validate(vec![]);
+    let too_many = validate(questions(&["1", "2", "3", "4", "5", "6"]));
+
+    // Assert
+    assert!(!empty.valid);
+    assert_eq!(empty.errors[0].question_number, None);
+    assert_eq!(empty.errors[0].code, "INVALID_QUESTION_COUNT");
+    assert_eq!(too_many.question_count, 6);
+    assert_eq!(too_many.errors.len(), 1);
+}
+
+#[test]
+fn should_match_custom_method_creation_validation() {
+    // Arrange
+    let cases = vec![
+        questions(&["좋았던 점은?"]),
+        questions(&[]),
+        questions(&["1", "2", "3", "4", "5", "6"]),
+        questions(&["좋았던 점은?", " "]),
+        vec!["가".repeat(200)],
+        vec!["가".repeat(201)],
+    ];
+
+    for case in cases {
+        // Act
+        let precheck = validate(case.clone());
+        let create = CreateCustomMethodRequest {
+            name: "스프린트 회고".to_string(),
+            questions: case.clone(),
+            placeholders: vec![],
+            weights: vec![],
+        }
+        .validate();
+
+        // Assert
+        assert_eq!(precheck.valid, create.is_ok(), "{:?}", case);
+    }
+}
+
+#[test]
+fn should_serialize_validation_result_in_camel_case() {
+    // Arrange
+    let result = validate(questions(&[" "]));
+
+    // Act
+    let json = serde_json::to_value(&result).unwrap();
+
+    // Assert
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["questionCount"], 1);
+    assert_eq!(json["errors"][0]["questionNumber"], 1);
+    assert_eq!(
+        json["errors"][0]["message"],
+        "각 질문은 1자 이상 200자 이하여야 합니다"
+    );
+}
@@ -0,0 +1,58 @@
+//! 회고방 참여 기록 조회 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/retro-rooms/{retro_room_id}/membership-history
+//! - MembershipEvent 직렬화
+//! - MembershipHistoryResponse 직렬화
+
+use server::domain::member::entity::room_membership_history::MembershipEvent;
+use server::domain::retrospect::dto::{MembershipHistoryItem, MembershipHistoryResponse};
+
+#[test]
+fn should_serialize_membership_event_in_screaming_snake_case() {
+    // Act & Assert
+    assert_eq!(serde_json::to_value(MembershipEvent::Join).unwrap(), "JOIN");
+    assert_eq!(
+        serde_json::to_value(MembershipEvent::Leave).unwrap(),
+        "LEAVE"
+    );
+    assert_eq!(serde_json::to_value(MembershipEvent::Kick).unwrap(), "KICK");
+}
+
+#[test]
+fn should_serialize_membership_history_in_camel_case() {
+    // Arrange
+    let response = MembershipHistoryResponse {
+        retro_room_id: 3,
+        histories: vec![
+            MembershipHistoryItem {
+                history_id: 2,
+                member_id: Some(10),
+                nickname: Some("제이슨".to_string()),
+                event: MembershipEvent::Join,
+                created_at: "2026-01-26T10:00:00".to_string(),
+            },
+            MembershipHistoryItem {
+                history_id: 1,
+                member_id: None,
+                nickname: None,
+                event: MembershipEvent::Leave,
+                created_at: "2026-01-25T09:00:00".to_string(),
+            },
+        ],
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["retroRoomId"], 3);
+    let first = &json["histories"][0];
+    assert_eq!(first["historyId"], 2);
+    assert_eq!(first["memberId"], 10);
+    assert_eq!(first["nickname"], "제이슨");
+    assert_eq!(first["event"], "JOIN");
+    assert_eq!(first["createdAt"], "2026-01-26T10:00:00");
+    assert!(json["histories"][1]["memberId"].is_null());
+    assert!(first.get("created_at").is_none());
+}
@@ -0,0 +1,76 @@
+//! 회고방 역할 계층 테스트
+//!
+//! 테스트 대상:
+//! - RoomRole 계층 비교 (Owner > Moderator > Member)
+//! - RoomRole::is_at_least (require_room_role의 최소 역할 판정)
+//! - 역할 변경 요청 역할 (AssignableRoomRole → RoomRole, Owner 부여 불가)
+
+use server::domain::member::entity::member_retro_room::RoomRole;
+use server::domain::retrospect::dto::UpdateMemberRoleRequest;
+
+#[test]
+fn should_order_roles_by_hierarchy() {
+    // Assert
+    assert!(RoomRole::Owner > RoomRole::Moderator);
+    assert!(RoomRole::Moderator > RoomRole::Member);
+    assert!(RoomRole::Owner > RoomRole::Member);
+}
+
+#[test]
+fn should_allow_equal_or_higher_role() {
+    // Assert: Moderator 최소 역할
+    assert!(RoomRole::Owner.is_at_least(&RoomRole::Moderator));
+    assert!(RoomRole::Moderator.is_at_least(&RoomRole::Moderator));
+    assert!(!RoomRole::Member.is_at_least(&RoomRole::Moderator));
+
+    // Assert: Owner 최소 역할
+    assert!(RoomRole::Owner.is_at_least(&RoomRole::Owner));
+    assert!(!RoomRole::Moderator.is_at_least(&RoomRole::Owner));
+}
+
+#[test]
+fn should_sort_members_with_owner_first() {
+    // Arrange
+    let mut roles = vec![RoomRole::Member, RoomRole::Owner, RoomRole::Moderator];
+
+    // Act: 멤버 목록과 동일하게 역할 내림차순 정렬
+    roles.sort_by(|a, b| b.cmp(a));
+
+    // Assert
+    assert_eq!(
+        roles,
+        vec![RoomRole::Owner, RoomRole::Moderator, RoomRole::Member]
+    );
+}
+
+#[test]
+fn should_expose_role_as_screaming_case_string() {
+    // Assert
+    assert_eq!(RoomRole::Owner.as_str(), "OWNER");
+    assert_eq!(RoomRole::Moderator.as_str(), "MODERATOR");
+    assert_eq!(RoomRole::Member.as_str(), "MEMBER");
+}
+
+#[test]
+fn should_convert_assignable_role_request_to_room_role() {
+    // Arrange
+    let json = r#"{"role":"MODERATOR"}"#;
+
+    // Act
+    let req: UpdateMemberRoleRequest = serde_json::from_str(json).unwrap();
+
+    // Assert
+    assert_eq!(RoomRole::from(req.role), RoomRole::Moderator);
+}
+
+#[test]
+fn should_reject_owner_in_role_change_request() {
+    // Arrange
+    let json = r#"{"role":"OWNER"}"#;
+
+    // Act
+    let result = serde_json::from_str::<UpdateMemberRoleRequest>(json);
+
+    // Assert
+    assert!(result.is_err());
+}
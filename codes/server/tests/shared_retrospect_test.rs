@@ -0,0 +1,58 @@
+//! 회고 전체 읽기 전용 공유 테스트
+//!
+//! 테스트 대상:
+//! - GET /api/v1/shared/retrospects/{token}
+//! - SharedRetrospectResponse 직렬화 (사용자 ID 비노출)
+//! - ShareScope 직렬화
+
+use server::domain::retrospect::dto::{
+    SharedAnswerItem, SharedQuestionItem, SharedRetrospectResponse,
+};
+use server::domain::retrospect::entity::retrospect::RetrospectMethod;
+use server::domain::retrospect::entity::shared_link::ShareScope;
+
+#[test]
+fn should_serialize_share_scope_in_screaming_snake_case() {
+    // Act & Assert
+    assert_eq!(
+        serde_json::to_value(ShareScope::Analysis).unwrap(),
+        "ANALYSIS"
+    );
+    assert_eq!(
+        serde_json::to_value(ShareScope::Retrospect).unwrap(),
+        "RETROSPECT"
+    );
+}
+
+#[test]
+fn should_serialize_shared_retrospect_without_user_ids() {
+    // Arrange
+    let response = SharedRetrospectResponse {
+        project_name: "나만의 회고 플랫폼".to_string(),
+        retrospect_method: RetrospectMethod::Kpt,
+        retrospect_date: "2026-01-24".to_string(),
+        participants: vec!["제**".to_string(), "스***".to_string()],
+        questions: vec![SharedQuestionItem {
+            index: 1,
+            content: "계속 유지하고 싶은 좋은 점은 무엇인가요?".to_string(),
+            answers: vec![SharedAnswerItem {
+                user_name: "제**".to_string(),
+                content: "코드 리뷰 문화가 좋았어요".to_string(),
+            }],
+        }],
+    };
+
+    // Act
+    let json: serde_json::Value = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["projectName"], "나만의 회고 플랫폼");
+    assert_eq!(json["retrospectMethod"], "KPT");
+    assert_eq!(json["retrospectDate"], "2026-01-24");
+    assert_eq!(json["participants"][0], "제**");
+    let question = &json["questions"][0];
+    assert_eq!(question["index"], 1);
+    assert_eq!(question["answers"][0]["userName"], "제**");
+    assert!(question["answers"][0].get("userId").is_none());
+    assert!(question["answers"][0].get("likeCount").is_none());
+}
@@ -0,0 +1,160 @@
+//! 업로드 서명 URL 발급 테스트
+//!
+//! 테스트 대상:
+//! - POST /api/v1/uploads/presign
+//! - PresignUploadRequest 역직렬화 및 유효성 검증
+//! - PresignUploadResponse 직렬화
+//! - 허용 이미지 형식 판별 및 SigV4 서명 URL 생성
+
+use chrono::NaiveDate;
+use server::domain::upload::dto::{PresignUploadRequest, PresignUploadResponse, UploadHeaders};
+use server::domain::upload::presign::{
+    object_url, presign_put_url, PresignPutRequest, StorageCredentials,
+};
+use server::domain::upload::service::resolve_image_extension;
+use validator::Validate;
+
+fn minio_credentials() -> StorageCredentials<'static> {
+    StorageCredentials {
+        endpoint: "http://localhost:9000",
+        bucket: "moalog",
+        region: "ap-northeast-2",
+        access_key_id: "minioadmin",
+        secret_access_key: "minioadmin",
+    }
+}
+
+// ============== 역직렬화/검증 테스트 ==============
+
+#[test]
+fn should_deserialize_presign_request() {
+    // Act
+    let req: PresignUploadRequest = serde_json::from_str(
+        r#"{"fileName":"회고 사진.png","contentType":"image/png","size":204800}"#,
+    )
+    .unwrap();
+
+    // Assert
+    assert!(req.validate().is_ok());
+    assert_eq!(req.file_name, "회고 사진.png");
+    assert_eq!(req.size, 204800);
+}
+
+#[test]
+fn should_fail_validation_for_empty_file_name() {
+    // Arrange
+    let req = PresignUploadRequest {
+        file_name: String::new(),
+        content_type: "image/png".to_string(),
+        size: 100,
+    };
+
+    // Act & Assert
+    assert!(req.validate().is_err());
+}
+
+#[test]
+fn should_fail_validation_for_non_positive_size() {
+    // Arrange
+    let req = PresignUploadRequest {
+        file_name: "a.png".to_string(),
+        content_type: "image/png".to_string(),
+        size: 0,
+    };
+
+    // Act & Assert
+    assert!(req.validate().is_err());
+}
+
+// ============== 파일 형식 테스트 ==============
+
+#[test]
+fn should_allow_jpeg_with_jpg_extension() {
+    // Act & Assert
+    assert_eq!(
+        resolve_image_extension("photo.JPG", "image/jpeg").unwrap(),
+        "jpg"
+    );
+}
+
+#[test]
+fn should_reject_svg_image() {
+    // Act
+    let err = resolve_image_extension("logo.svg", "image/svg+xml").unwrap_err();
+
+    // Assert
+    assert_eq!(err.error_code(), "UPLOAD4001");
+    assert_eq!(err.status_code(), axum::http::StatusCode::BAD_REQUEST);
+}
+
+// ============== 서명 URL 테스트 ==============
+
+#[test]
+fn should_sign_url_for_custom_endpoint_with_port() {
+    // Arrange
+    let req = PresignPutRequest {
+        object_key: "uploads/7/20261016/abc.webp",
+        content_type: "image/webp",
+        content_length: 512,
+        expires_secs: 300,
+        signed_at: NaiveDate::from_ymd_opt(2026, 10, 16)
+            .unwrap()
+            .and_hms_opt(12, 30, 0)
+            .unwrap(),
+    };
+
+    // Act
+    let url = presign_put_url(&minio_credentials(), &req);
+
+    // Assert
+    assert!(url.starts_with("http://localhost:9000/moalog/uploads/7/20261016/abc.webp?"));
+    assert!(
+        url.contains("X-Amz-Credential=minioadmin%2F20261016%2Fap-northeast-2%2Fs3%2Faws4_request")
+    );
+    assert!(url.contains("X-Amz-Expires=300"));
+    assert!(url.contains("&X-Amz-Signature="));
+}
+
+#[test]
+fn should_build_object_url_without_signature() {
+    // Act
+    let url = object_url(&minio_credentials(), "uploads/7/20261016/abc.webp");
+
+    // Assert
+    assert_eq!(
+        url,
+        "http://localhost:9000/moalog/uploads/7/20261016/abc.webp"
+    );
+}
+
+// ============== 직렬화 테스트 ==============
+
+#[test]
+fn should_serialize_presign_response_with_upload_headers() {
+    // Arrange
+    let response = PresignUploadResponse {
+        upload_url: "http://localhost:9000/moalog/uploads/7/a.png?X-Amz-Signature=00".to_string(),
+        method: "PUT".to_string(),
+        headers: UploadHeaders {
+            content_type: "image/png".to_string(),
+            content_length: "512".to_string(),
+        },
+        object_key: "uploads/7/a.png".to_string(),
+        file_url: "http://localhost:9000/moalog/uploads/7/a.png".to_string(),
+        expires_at: "2026-10-16T12:35:00".to_string(),
+    };
+
+    // Act
+    let json = serde_json::to_value(&response).unwrap();
+
+    // Assert
+    assert_eq!(json["method"], "PUT");
+    assert_eq!(json["headers"]["Content-Type"], "image/png");
+    assert_eq!(json["headers"]["Content-Length"], "512");
+    assert_eq!(json["objectKey"], "uploads/7/a.png");
+    assert_eq!(
+        json["fileUrl"],
+        "http://localhost:9000/moalog/uploads/7/a.png"
+    );
+    assert_eq!(json["expiresAt"], "2026-10-16T12:35:00");
+}